@@ -14,9 +14,9 @@ use tauri::{AppHandle, Manager, State};
 
 use baras_core::boss::{
     AreaType, BossEncounterDefinition, BossTimerDefinition, BossWithPath, ChallengeDefinition,
-    CounterDefinition, EntityDefinition, PhaseDefinition, find_custom_file, load_area_config,
-    load_bosses_from_file, load_bosses_with_custom, load_bosses_with_paths, merge_boss_definition,
-    save_bosses_to_file,
+    CounterDefinition, EntityDefinition, LintIssue, PhaseDefinition, find_custom_file, lint_boss,
+    load_area_config, load_bosses_from_file, load_bosses_with_custom, load_bosses_with_paths,
+    merge_boss_definition, save_bosses_to_file,
 };
 use baras_core::timers::{TimerPreferences, boss_timer_key};
 
@@ -973,6 +973,29 @@ pub async fn select_boss_notes(
     service.select_boss_notes(&boss_id).await
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// Definition Lint
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Statically check the bosses in an area file (with user customizations merged).
+/// Pass `boss_id` to lint a single boss.
+#[tauri::command]
+pub async fn lint_encounter_definitions(
+    file_path: String,
+    boss_id: Option<String>,
+) -> Result<Vec<LintIssue>, String> {
+    let path = PathBuf::from(&file_path);
+    if !path.exists() {
+        return Err(format!("File not found: {}", file_path));
+    }
+
+    Ok(load_file_with_custom(&path)?
+        .iter()
+        .filter(|bwp| boss_id.as_ref().is_none_or(|id| &bwp.boss.id == id))
+        .flat_map(|bwp| lint_boss(&bwp.boss))
+        .collect())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Export / Import Commands
 // ═══════════════════════════════════════════════════════════════════════════════
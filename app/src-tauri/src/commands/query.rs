@@ -4,10 +4,10 @@
 
 use baras_core::query::{
     AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow,
-    DamageTakenSummary, DataTab, EffectChartData, EffectWindow, EncounterTimeline,
-    EntityBreakdown, HpPoint, NpcHealthRow, PlayerDeath, RaidOverviewRow, RotationAnalysis,
-    TimeRange,
-    TimeSeriesPoint,
+    CooldownEfficiencyRow, DamageTakenSummary, DataTab, EffectChartData, EffectWindow,
+    EncounterTimeline, EntityBreakdown, ExportFormat, ExportSource, GcdUptimeAnalysis,
+    HealingMatrix, HpPoint, NpcHealthRow, PlayerDeath, RaidOverviewRow, RotationAnalysis,
+    TimeRange, TimeSeriesPoint,
 };
 use tauri::State;
 
@@ -235,6 +235,15 @@ pub async fn query_combat_log_count(
         .await
 }
 
+/// Check combat log filter syntax (returns the syntax error, if any).
+#[tauri::command]
+pub fn validate_combat_log_filter(
+    handle: State<'_, ServiceHandle>,
+    filter: String,
+) -> Result<(), String> {
+    handle.validate_combat_log_filter(&filter)
+}
+
 /// Find matching rows in combat log (for Find feature).
 #[tauri::command]
 pub async fn query_combat_log_find(
@@ -322,3 +331,54 @@ pub async fn query_rotation(
         .query_rotation(encounter_idx, source_name, anchor_ability_id, time_range)
         .await
 }
+
+/// Query GCD uptime and downtime gaps for a player.
+#[tauri::command]
+pub async fn query_gcd_uptime(
+    handle: State<'_, ServiceHandle>,
+    encounter_idx: Option<u32>,
+    source_name: String,
+    time_range: Option<TimeRange>,
+) -> Result<GcdUptimeAnalysis, String> {
+    handle
+        .query_gcd_uptime(encounter_idx, source_name, time_range)
+        .await
+}
+
+/// Query cooldown usage efficiency per player.
+#[tauri::command]
+pub async fn query_cooldown_efficiency(
+    handle: State<'_, ServiceHandle>,
+    encounter_idx: Option<u32>,
+    time_range: Option<TimeRange>,
+) -> Result<Vec<CooldownEfficiencyRow>, String> {
+    handle
+        .query_cooldown_efficiency(encounter_idx, time_range)
+        .await
+}
+
+/// Query the healer × recipient healing matrix.
+#[tauri::command]
+pub async fn query_healing_matrix(
+    handle: State<'_, ServiceHandle>,
+    encounter_idx: Option<u32>,
+    time_range: Option<TimeRange>,
+) -> Result<HealingMatrix, String> {
+    handle.query_healing_matrix(encounter_idx, time_range).await
+}
+
+/// Export a Data Explorer table or chart to CSV or parquet (returns rows written).
+#[tauri::command]
+pub async fn export_table(
+    handle: State<'_, ServiceHandle>,
+    encounter_idx: Option<u32>,
+    source: ExportSource,
+    time_range: Option<TimeRange>,
+    duration_secs: Option<f32>,
+    format: ExportFormat,
+    path: String,
+) -> Result<usize, String> {
+    handle
+        .export_table(encounter_idx, source, time_range, duration_secs, format, path)
+        .await
+}
@@ -8,8 +8,12 @@ use tauri::{AppHandle, State};
 use baras_core::EncounterSummary;
 use baras_core::PlayerMetrics;
 use baras_core::context::{AppConfig, AppConfigExt, OverlayAppearanceConfig};
+use baras_core::encounter::annotations::EncounterAnnotation;
+use baras_core::encounter::dummy::DummyParseHistory;
+use baras_core::encounter::personal_best::PersonalRank;
+use baras_core::encounter::pvp::PvpMatch;
 
-use crate::overlay::{MetricType, OverlayCommand, OverlayType, SharedOverlayState};
+use crate::overlay::{MetricType, OverlayManager, OverlayType, SharedOverlayState};
 use crate::service::{LogFileInfo, ServiceHandle, SessionInfo};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -314,6 +318,74 @@ pub async fn set_encounter_parsely_link(
     Ok(handle.set_encounter_parsely_link(encounter_id, link).await)
 }
 
+#[tauri::command]
+pub async fn get_encounter_annotations(
+    encounter_id: u64,
+    handle: State<'_, ServiceHandle>,
+) -> Result<Vec<EncounterAnnotation>, String> {
+    Ok(handle.encounter_annotations(encounter_id).await)
+}
+
+#[tauri::command]
+pub async fn add_encounter_annotation(
+    encounter_id: u64,
+    annotation: EncounterAnnotation,
+    handle: State<'_, ServiceHandle>,
+) -> Result<EncounterAnnotation, String> {
+    handle.add_encounter_annotation(encounter_id, annotation).await
+}
+
+#[tauri::command]
+pub async fn update_encounter_annotation(
+    encounter_id: u64,
+    annotation_id: u64,
+    text: String,
+    handle: State<'_, ServiceHandle>,
+) -> Result<(), String> {
+    handle
+        .update_encounter_annotation(encounter_id, annotation_id, text)
+        .await
+}
+
+#[tauri::command]
+pub async fn remove_encounter_annotation(
+    encounter_id: u64,
+    annotation_id: u64,
+    handle: State<'_, ServiceHandle>,
+) -> Result<(), String> {
+    handle
+        .remove_encounter_annotation(encounter_id, annotation_id)
+        .await
+}
+
+#[tauri::command]
+pub async fn export_encounter_report(
+    encounter_idx: Option<u32>,
+    path: String,
+    handle: State<'_, ServiceHandle>,
+) -> Result<String, String> {
+    handle.export_encounter_report(encounter_idx, path).await
+}
+
+#[tauri::command]
+pub async fn get_dummy_parse_history(
+    handle: State<'_, ServiceHandle>,
+) -> Result<Vec<DummyParseHistory>, String> {
+    Ok(handle.dummy_parse_history().await)
+}
+
+#[tauri::command]
+pub async fn get_personal_ranks(
+    handle: State<'_, ServiceHandle>,
+) -> Result<Vec<PersonalRank>, String> {
+    Ok(handle.personal_ranks().await)
+}
+
+#[tauri::command]
+pub async fn get_pvp_matches(handle: State<'_, ServiceHandle>) -> Result<Vec<PvpMatch>, String> {
+    Ok(handle.pvp_matches().await)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Profile Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -357,43 +429,7 @@ pub async fn load_profile(
     handle: State<'_, ServiceHandle>,
     overlay_state: State<'_, SharedOverlayState>,
 ) -> Result<(), String> {
-    let old_config = handle.config().await;
-    let old_slots = old_config.overlay_settings.raid_overlay.total_slots();
-
-    let mut config = old_config;
-    config.load_profile(&name).map_err(|e| e.to_string())?;
-    let new_slots = config.overlay_settings.raid_overlay.total_slots();
-
-    *handle.shared.config.write().await = config.clone();
-    config.save().map_err(|e| e.to_string())?;
-
-    // Update raid registry max slots if grid size changed between profiles
-    if new_slots != old_slots {
-        handle
-            .shared
-            .raid_registry
-            .lock()
-            .unwrap_or_else(|p| p.into_inner())
-            .set_max_slots(new_slots);
-    }
-
-    // Reset move mode on profile switch
-    let txs: Vec<_> = {
-        if let Ok(mut state) = overlay_state.lock() {
-            state.move_mode = false;
-            state.rearrange_mode = false;
-            state.all_txs().into_iter().cloned().collect()
-        } else {
-            vec![]
-        }
-    };
-
-    // Broadcast move mode reset to all overlays
-    for tx in txs {
-        let _ = tx.send(OverlayCommand::SetMoveMode(false)).await;
-    }
-
-    Ok(())
+    OverlayManager::load_profile(&overlay_state, &handle, &name).await
 }
 
 #[tauri::command]
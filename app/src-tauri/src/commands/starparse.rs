@@ -340,6 +340,8 @@ fn convert_to_boss_timer(xml: &XmlConfigTimer) -> BossTimerDefinition {
         phases: Vec::new(),
         counter_condition: None,
         difficulties: Vec::new(),
+        roles: Vec::new(),
+        disciplines: Vec::new(),
         enabled: xml.enabled.unwrap_or(true),
         can_be_refreshed: !xml.ignore_repeated.unwrap_or(false),
         repeats: xml.repeat.unwrap_or(0),
@@ -461,7 +463,20 @@ fn group_timers(xml_timers: &[XmlConfigTimer]) -> GroupedTimers {
             continue;
         };
 
-        if let Some(&(file_stem, boss_id)) = lookup.get(boss_name) {
+        // Timers exported from a localized client name bosses in that language:
+        // match them through the NPC IDs the name tables know for that name
+        let mapped = lookup.get(boss_name).or_else(|| {
+            baras_core::game_data::ids_for_name(boss_name)
+                .into_iter()
+                .filter_map(baras_core::game_data::lookup_boss)
+                .find_map(|info| {
+                    lookup
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case(info.boss))
+                        .map(|(_, mapped)| mapped)
+                })
+        });
+        if let Some(&(file_stem, boss_id)) = mapped {
             let timer = convert_to_boss_timer(xml);
             encounter_timers
                 .entry(file_stem.to_string())
@@ -1,10 +1,12 @@
 //! Global hotkey registration
 //!
-//! Registers global keyboard shortcuts for overlay visibility, move mode, and rearrange mode.
+//! Registers global keyboard shortcuts for overlay visibility, move mode, rearrange mode,
+//! and user-bound timer/counter actions.
 //! Supported on Windows, macOS, Linux X11, and Linux Wayland (via XDG GlobalShortcuts portal).
 
 use crate::overlay::{OverlayCommand, OverlayManager, OverlayType, SharedOverlayState};
 use crate::service::ServiceHandle;
+use baras_core::context::HotkeyAction;
 use tracing::{error, info, warn};
 
 #[cfg(target_os = "linux")]
@@ -96,6 +98,20 @@ async fn try_portal_shortcuts(
         );
     }
     
+    // Action hotkeys are identified by their index in the config
+    let action_labels: Vec<_> = hotkeys.actions.iter().map(|a| a.action.label()).collect();
+    let action_ids: Vec<_> = (0..hotkeys.actions.len())
+        .map(|i| format!("action-{}", i))
+        .collect();
+    for (i, binding) in hotkeys.actions.iter().enumerate() {
+        converted_keys.push(convert_key_format(&binding.key));
+        shortcuts.push(
+            NewShortcut::new(action_ids[i].as_str(), action_labels[i].as_str())
+                .preferred_trigger(converted_keys.last().unwrap().as_str())
+        );
+    }
+    let actions: Vec<HotkeyAction> = hotkeys.actions.iter().map(|a| a.action.clone()).collect();
+
     if shortcuts.is_empty() {
         info!("No hotkeys configured, skipping portal registration");
         return Ok(());
@@ -144,8 +160,18 @@ async fn try_portal_shortcuts(
                                 toggle_rearrange_mode_hotkey(state, handle).await;
                             });
                         }
-                        _ => {
-                            warn!("Unknown shortcut ID: {}", shortcut_id);
+                        id => {
+                            let action = id
+                                .strip_prefix("action-")
+                                .and_then(|i| i.parse::<usize>().ok())
+                                .and_then(|i| actions.get(i).cloned());
+                            if let Some(action) = action {
+                                tauri::async_runtime::spawn(async move {
+                                    action_hotkey(handle, action).await;
+                                });
+                            } else {
+                                warn!("Unknown shortcut ID: {}", shortcut_id);
+                            }
                         }
                     }
                 }
@@ -283,9 +309,43 @@ pub fn spawn_register_hotkeys(
                 warn!(hotkey = %key_str, "Invalid rearrange mode hotkey format");
             }
         }
+
+        // Register timer/counter action hotkeys
+        for binding in &hotkeys.actions {
+            let key_str = &binding.key;
+            if let Ok(shortcut) = key_str.parse::<Shortcut>() {
+                let handle = service_handle.clone();
+                let action = binding.action.clone();
+
+                if let Err(e) =
+                    global_shortcut.on_shortcut(shortcut, move |_app, _shortcut, event| {
+                        if event.state == tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                            let handle = handle.clone();
+                            let action = action.clone();
+                            tauri::async_runtime::spawn(async move {
+                                action_hotkey(handle, action).await;
+                            });
+                        }
+                    })
+                {
+                    error!(error = %e, hotkey = %key_str, "Failed to register action hotkey");
+                } else {
+                    info!(hotkey = %key_str, action = %binding.action.label(), "Registered action hotkey");
+                }
+            } else {
+                warn!(hotkey = %key_str, "Invalid action hotkey format");
+            }
+        }
     });
 }
 
+/// Hotkey handler: Start/reset a manual timer or change a counter
+async fn action_hotkey(service: ServiceHandle, action: HotkeyAction) {
+    if let Err(e) = service.run_hotkey_action(&action).await {
+        warn!(error = %e, action = %action.label(), "Hotkey action failed");
+    }
+}
+
 /// Hotkey handler: Toggle overlay visibility
 async fn toggle_visibility_hotkey(
     overlay_state: SharedOverlayState,
@@ -203,7 +203,15 @@ pub fn run() {
             commands::get_active_file,
             commands::get_session_info,
             commands::get_encounter_history,
+            commands::get_dummy_parse_history,
+            commands::get_personal_ranks,
+            commands::get_pvp_matches,
             commands::set_encounter_parsely_link,
+            commands::get_encounter_annotations,
+            commands::add_encounter_annotation,
+            commands::update_encounter_annotation,
+            commands::remove_encounter_annotation,
+            commands::export_encounter_report,
             // File browser commands
             commands::open_historical_file,
             commands::resume_live_tailing,
@@ -230,6 +238,7 @@ pub fn run() {
             commands::update_boss_notes,
             commands::get_area_bosses_for_notes,
             commands::select_boss_notes,
+            commands::lint_encounter_definitions,
             // Encounter export/import
             commands::export_encounter_toml,
             commands::save_export_file,
@@ -269,11 +278,16 @@ pub fn run() {
             commands::query_combat_log,
             commands::query_combat_log_count,
             commands::query_combat_log_find,
+            commands::validate_combat_log_filter,
             commands::query_source_names,
             commands::query_target_names,
             commands::query_player_deaths,
             commands::query_npc_health,
             commands::query_rotation,
+            commands::query_gcd_uptime,
+            commands::query_cooldown_efficiency,
+            commands::query_healing_matrix,
+            commands::export_table,
             commands::query_damage_taken_summary,
             commands::query_encounter_timeline,
             commands::list_encounter_files,
@@ -3,11 +3,12 @@
 //! Provides a clean interface for spawning, shutting down, and updating overlays.
 //! This consolidates the duplicated logic that was scattered across commands.
 
-use baras_core::context::{OverlayPositionConfig, OverlaySettings};
+use baras_core::context::{AppConfigExt, OverlayPositionConfig, OverlaySettings};
 use baras_overlay::{
     platform, CooldownConfig, DotTrackerConfig, EffectsABConfig, EffectsLayout, NotesConfig,
     OverlayConfigUpdate, OverlayData, RaidGridLayout, RaidOverlayConfig,
 };
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use super::metrics::create_entries_for_type;
@@ -410,7 +411,7 @@ impl OverlayManager {
         // from rapid toggle clicks spawning duplicate overlays
         let (tx, needs_monitor_save, current_move_mode) = {
             let mut s = state.lock().map_err(|e| e.to_string())?;
-            if s.is_running(kind) {
+            if s.is_running(kind) || Self::hidden_by_conditions(kind, s.move_mode, service) {
                 return Ok(true);
             }
             // Spawn while holding lock (spawn is synchronous, so this is safe)
@@ -557,6 +558,9 @@ impl OverlayManager {
                     }
                     continue;
                 }
+                if Self::hidden_by_conditions(kind, s.move_mode, service) {
+                    continue;
+                }
                 // Spawn while holding lock (spawn is synchronous, so this is safe)
                 let Ok(result) = Self::spawn(kind, &config.overlay_settings) else {
                     continue;
@@ -712,7 +716,7 @@ impl OverlayManager {
             // Check if running, spawn, and insert
             let spawn_result = {
                 let mut s = state.lock().map_err(|e| e.to_string())?;
-                if s.is_running(kind) {
+                if s.is_running(kind) || Self::hidden_by_conditions(kind, s.move_mode, service) {
                     continue;
                 }
                 let Ok(result) = Self::spawn(kind, &config.overlay_settings) else {
@@ -747,6 +751,52 @@ impl OverlayManager {
         Ok(())
     }
 
+    /// Load a saved profile into the active overlay settings and persist it.
+    /// Running overlays pick up the new settings on the next `refresh_settings`.
+    pub async fn load_profile(
+        state: &SharedOverlayState,
+        service: &ServiceHandle,
+        name: &str,
+    ) -> Result<(), String> {
+        let old_config = service.config().await;
+        let old_slots = old_config.overlay_settings.raid_overlay.total_slots();
+
+        let mut config = old_config;
+        config.load_profile(name).map_err(|e| e.to_string())?;
+        let new_slots = config.overlay_settings.raid_overlay.total_slots();
+
+        *service.shared.config.write().await = config.clone();
+        config.save().map_err(|e| e.to_string())?;
+
+        // Update raid registry max slots if grid size changed between profiles
+        if new_slots != old_slots {
+            service
+                .shared
+                .raid_registry
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .set_max_slots(new_slots);
+        }
+
+        // Reset move mode on profile switch
+        let txs: Vec<_> = {
+            if let Ok(mut s) = state.lock() {
+                s.move_mode = false;
+                s.rearrange_mode = false;
+                s.all_txs().into_iter().cloned().collect()
+            } else {
+                vec![]
+            }
+        };
+
+        // Broadcast move mode reset to all overlays
+        for tx in txs {
+            let _ = tx.send(OverlayCommand::SetMoveMode(false)).await;
+        }
+
+        Ok(())
+    }
+
     /// Toggle move mode for all overlays.
     /// Returns the new move mode state.
     pub async fn toggle_move_mode(
@@ -799,6 +849,9 @@ impl OverlayManager {
             service.update_config(config).await?;
         }
 
+        // Move mode shows conditionally hidden overlays so they can be positioned
+        Self::apply_visibility_conditions(state, service).await?;
+
         // Notify frontend to update UI buttons
         service.emit_overlay_status_changed();
 
@@ -845,12 +898,15 @@ impl OverlayManager {
         for overlay_type in Self::all_overlay_types() {
             let key = overlay_type.config_key();
             let enabled = settings.enabled.get(key).copied().unwrap_or(false);
-            let running = {
+            let (running, hidden) = {
                 let s = state.lock().map_err(|e| e.to_string())?;
-                s.is_running(overlay_type)
+                (
+                    s.is_running(overlay_type),
+                    Self::hidden_by_conditions(overlay_type, s.move_mode, service),
+                )
             };
 
-            if running && !enabled {
+            if running && (!enabled || hidden) {
                 // Shutdown if running but disabled
                 if let Ok(mut s) = state.lock()
                     && let Some(handle) = s.remove(overlay_type)
@@ -858,7 +914,7 @@ impl OverlayManager {
                     let _ = handle.tx.try_send(OverlayCommand::Shutdown);
                 }
                 service.set_overlay_active(key, false);
-            } else if !running && enabled && globally_visible {
+            } else if !running && enabled && !hidden && globally_visible {
                 // Start if not running but enabled (only if global visibility is on)
                 if let Ok(result) = Self::spawn(overlay_type, settings)
                     && let Ok(mut s) = state.lock()
@@ -890,7 +946,11 @@ impl OverlayManager {
         }
 
         // Only respawn raid if global visibility is on
+        let raid_hidden = state
+            .lock()
+            .is_ok_and(|s| Self::hidden_by_conditions(OverlayType::Raid, s.move_mode, service));
         let raid_respawned = if globally_visible
+            && !raid_hidden
             && (raid_was_running || raid_enabled)
             && let Ok(result) = Self::spawn(OverlayType::Raid, settings)
             && let Ok(mut s) = state.lock()
@@ -942,6 +1002,100 @@ impl OverlayManager {
         Ok(true)
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Conditional Visibility
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Whether an overlay is held back by its visibility conditions.
+    /// Conditions are suspended in move mode so every enabled overlay can be positioned.
+    fn hidden_by_conditions(kind: OverlayType, move_mode: bool, service: &ServiceHandle) -> bool {
+        !move_mode && service.shared.is_conditionally_hidden(kind.config_key())
+    }
+
+    /// Spawn or shut down enabled overlays to match their visibility conditions.
+    /// Does nothing while overlays are globally or temporarily hidden.
+    pub async fn apply_visibility_conditions(
+        state: &SharedOverlayState,
+        service: &ServiceHandle,
+    ) -> Result<(), String> {
+        let config = service.config().await;
+        let settings = &config.overlay_settings;
+        if !settings.overlays_visible
+            || service.shared.conversation_hiding_active.load(Ordering::SeqCst)
+            || service.shared.not_live_hiding_active.load(Ordering::SeqCst)
+        {
+            return Ok(());
+        }
+
+        let mut to_spawn = Vec::new();
+        let mut to_shutdown = Vec::new();
+        let move_mode = {
+            let mut s = state.lock().map_err(|e| e.to_string())?;
+            for kind in Self::all_overlay_types() {
+                let hidden = Self::hidden_by_conditions(kind, s.move_mode, service);
+                let running = s.is_running(kind);
+                if running && hidden {
+                    if matches!(kind, OverlayType::Raid) {
+                        s.rearrange_mode = false;
+                        service.set_rearrange_mode(false);
+                    }
+                    to_shutdown.extend(s.remove(kind));
+                } else if !running && !hidden && settings.is_enabled(kind.config_key()) {
+                    to_spawn.push(kind);
+                }
+            }
+            s.move_mode
+        };
+
+        for handle in to_shutdown {
+            service.set_overlay_active(handle.kind.config_key(), false);
+            Self::shutdown_no_position(handle).await;
+        }
+
+        let combat_data = if to_spawn.is_empty() {
+            None
+        } else {
+            service.current_combat_data().await
+        };
+        for kind in to_spawn {
+            let tx = {
+                let mut s = state.lock().map_err(|e| e.to_string())?;
+                let Ok(result) = Self::spawn(kind, settings) else {
+                    continue;
+                };
+                let tx = result.handle.tx.clone();
+                s.insert(result.handle);
+                tx
+            };
+
+            let config_update =
+                Self::create_config_update(kind, settings, config.european_number_format);
+            let _ = tx.send(OverlayCommand::UpdateConfig(config_update)).await;
+            Self::sync_move_mode(&tx, move_mode).await;
+            Self::send_initial_data(kind, &tx, combat_data.as_ref()).await;
+            if matches!(kind, OverlayType::Notes)
+                && let Some(notes_data) = service.get_current_notes().await
+            {
+                let _ = tx
+                    .send(OverlayCommand::UpdateData(OverlayData::Notes(notes_data)))
+                    .await;
+            }
+            if matches!(kind, OverlayType::Raid) {
+                service.refresh_raid_frames().await;
+            }
+            service.set_overlay_active(kind.config_key(), true);
+        }
+
+        // Timers A and B share one status flag
+        let timers_running = state
+            .lock()
+            .is_ok_and(|s| s.is_running(OverlayType::TimersA) || s.is_running(OverlayType::TimersB));
+        service.set_overlay_active("timers", timers_running);
+
+        service.emit_overlay_status_changed();
+        Ok(())
+    }
+
     /// Get all overlay types for iteration.
     fn all_overlay_types() -> Vec<OverlayType> {
         let mut types = vec![
@@ -522,6 +522,19 @@ async fn process_overlay_update(
                 }
             }
         }
+        OverlayUpdate::ProfileSwitchRequested(name) => {
+            if let Err(e) = OverlayManager::load_profile(overlay_state, service_handle, &name).await
+            {
+                tracing::warn!(profile = %name, error = %e, "Automatic profile switch failed");
+                return;
+            }
+            let _ = OverlayManager::refresh_settings(overlay_state, service_handle).await;
+            service_handle.emit_overlay_status_changed();
+            let _ = service_handle.app_handle.emit("profile-switched", name);
+        }
+        OverlayUpdate::VisibilityConditionsChanged => {
+            let _ = OverlayManager::apply_visibility_conditions(overlay_state, service_handle).await;
+        }
         OverlayUpdate::NotLiveStateChanged { is_live } => {
             // Check if auto-hide when not live is enabled
             let hide_enabled = shared
@@ -1,6 +1,8 @@
 use crate::service::ServiceCommand;
-use baras_core::directory_watcher::DirectoryEvent;
-use tracing::{debug, error, info};
+use baras_core::boss::load_bosses_from_file;
+use baras_core::directory_watcher::{DefinitionChanges, DirectoryEvent};
+use baras_core::{DefinitionConfig, EFFECTS_DSL_VERSION};
+use tracing::{debug, error, info, warn};
 
 /// Convert a DirectoryEvent into a ServiceCommand (if action needed)
 pub fn translate_event(event: DirectoryEvent) -> Option<ServiceCommand> {
@@ -28,3 +30,59 @@ pub fn translate_event(event: DirectoryEvent) -> Option<ServiceCommand> {
         DirectoryEvent::DirectoryIndexed { .. } => None,
     }
 }
+
+/// Parse changed user definition files before hot-reloading them.
+///
+/// Returns reload commands for the sets whose files all parsed, plus one
+/// message per broken file. A broken set is not reloaded: the session keeps
+/// its previous definitions (and the effects loader would delete an invalid
+/// user file).
+pub fn check_definition_changes(changes: &DefinitionChanges) -> (Vec<ServiceCommand>, Vec<String>) {
+    let mut commands = Vec::new();
+    let mut errors = Vec::new();
+
+    if !changes.encounters.is_empty() {
+        let before = errors.len();
+        for path in changes.encounters.iter().filter(|p| p.exists()) {
+            if let Err(e) = load_bosses_from_file(path) {
+                errors.push(e);
+            }
+        }
+        if errors.len() == before {
+            info!(count = changes.encounters.len(), "Encounter definitions changed on disk, reloading");
+            commands.push(ServiceCommand::ReloadTimerDefinitions);
+        }
+    }
+
+    if let Some(path) = &changes.effects {
+        let parsed = if path.exists() {
+            std::fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|c| toml::from_str::<DefinitionConfig>(&c).map_err(|e| e.to_string()))
+                .and_then(|config| {
+                    if config.version == EFFECTS_DSL_VERSION {
+                        Ok(())
+                    } else {
+                        Err(format!(
+                            "version {} does not match expected {}",
+                            config.version, EFFECTS_DSL_VERSION
+                        ))
+                    }
+                })
+        } else {
+            Ok(())
+        };
+        match parsed {
+            Ok(()) => {
+                info!("User effects changed on disk, reloading");
+                commands.push(ServiceCommand::ReloadEffectDefinitions);
+            }
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    for e in &errors {
+        warn!(error = %e, "Skipping hot-reload of invalid definition file");
+    }
+    (commands, errors)
+}
@@ -7,16 +7,24 @@ use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tokio::sync::mpsc;
 
-use baras_core::EncounterSummary;
-use baras_core::context::{AppConfig, AppConfigExt, resolve};
+use baras_core::{EncounterSummary, SessionCache};
+use baras_core::context::{AppConfig, AppConfigExt, HotkeyAction, resolve};
 use baras_core::encounter::EncounterState;
-use baras_core::game_data::Discipline;
+use baras_core::encounter::annotations::{
+    AnnotationStore, EncounterAnnotation, default_annotations_path,
+};
+use baras_core::encounter::dummy::{DummyParse, DummyParseHistory, build_dummy_parse_history};
+use baras_core::encounter::personal_best::PersonalRank;
+use baras_core::encounter::pvp::{PvpMatch, build_pvp_matches};
+use baras_core::game_data::{Discipline, GcdEstimate};
 use baras_core::query::{
     AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow,
-    DamageTakenSummary, DataTab, EffectChartData, EffectWindow, EncounterTimeline,
-    EntityBreakdown, HpPoint, NpcHealthRow, PlayerDeath, RaidOverviewRow, TimeRange,
-    TimeSeriesPoint,
+    CooldownEfficiencyRow, DamageTakenSummary, DataTab, EffectChartData, EffectWindow,
+    EncounterTimeline, EntityBreakdown, ExportFormat, ExportSource, GcdUptimeAnalysis,
+    HealingMatrix, HpPoint, LogFilter, NpcHealthRow, PlayerDeath, RaidOverviewRow, TimeRange,
+    TimeSeriesPoint, write_export,
 };
+use baras_core::report::{EncounterReport, render_html, render_markdown};
 use tauri::{AppHandle, Emitter};
 
 use super::{AreaVisitInfo, CombatData, LogFileInfo, ServiceCommand, SessionInfo};
@@ -129,6 +137,37 @@ impl ServiceHandle {
             .collect()
     }
 
+    /// Training dummy parse history per character and discipline, cut at the
+    /// configured parse length from the sessions in the area cache.
+    pub async fn dummy_parse_history(&self) -> Vec<DummyParseHistory> {
+        let limit = self.shared.config.read().await.dummy_parse.limit;
+        let mut parses: Vec<DummyParse> = {
+            let area_cache = self.shared.area_cache.read().await;
+            area_cache
+                .entries
+                .values()
+                .flat_map(|file_index| &file_index.dummy_sessions)
+                .filter_map(|session| session.parse(limit))
+                .collect()
+        };
+        parses.extend(
+            self.shared
+                .live_dummy_parses
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .iter()
+                .filter(|(parse_limit, _)| *parse_limit == limit)
+                .map(|(_, parse)| parse.clone()),
+        );
+        build_dummy_parse_history(parses)
+    }
+
+    /// PvP matches in the current log file, with per-match (and per-round in
+    /// arenas) scoreboards
+    pub async fn pvp_matches(&self) -> Vec<PvpMatch> {
+        build_pvp_matches(&self.encounter_history().await)
+    }
+
     /// Get total size of all log files in bytes
     pub async fn log_directory_size(&self) -> u64 {
         let index = self.shared.directory_index.read().await;
@@ -167,6 +206,12 @@ impl ServiceHandle {
         self.shared.config.read().await.clone()
     }
 
+    /// Configured alacrity/latency, used by queries for players whose GCD can't be inferred
+    async fn gcd_fallback(&self) -> GcdEstimate {
+        let config = self.shared.config.read().await;
+        GcdEstimate::from_settings(config.alacrity_percent, config.latency_ms)
+    }
+
     /// Update the configuration
     pub async fn update_config(&self, config: AppConfig) -> Result<(), String> {
         let old_config = self.shared.config.read().await.clone();
@@ -179,11 +224,10 @@ impl ServiceHandle {
         let new_slots = config.overlay_settings.raid_overlay.grid_columns
             * config.overlay_settings.raid_overlay.grid_rows;
 
-        let alacrity_changed = old_config.alacrity_percent != config.alacrity_percent;
-        let latency_changed = old_config.latency_ms != config.latency_ms;
-        let new_alacrity = config.alacrity_percent;
-        let new_latency = config.latency_ms;
-
+        let dummy_parse = config.dummy_parse.clone();
+        let alacrity_percent = config.alacrity_percent;
+        let latency_ms = config.latency_ms;
+        let client_language = config.client_language;
         *self.shared.config.write().await = config.clone();
         if let Err(e) = config.save() {
             tracing::error!(error = %e, "Failed to save configuration");
@@ -194,20 +238,14 @@ impl ServiceHandle {
             self.shared.raid_registry.lock().unwrap_or_else(|p| p.into_inner()).set_max_slots(new_slots);
         }
 
-        // Update effect tracker alacrity/latency if changed
-        if alacrity_changed || latency_changed {
-            if let Some(session) = self.shared.session.read().await.as_ref() {
-                let session = session.read().await;
-                if let Some(tracker) = session.effect_tracker() {
-                    let mut tracker = tracker.lock().unwrap_or_else(|p| p.into_inner());
-                    if alacrity_changed {
-                        tracker.set_alacrity(new_alacrity);
-                    }
-                    if latency_changed {
-                        tracker.set_latency(new_latency);
-                    }
-                }
-            }
+        // Apply dummy parse, client language and fallback alacrity/latency
+        // settings to the running session
+        if let Some(session) = self.shared.session.read().await.as_ref() {
+            let mut session = session.write().await;
+            session.set_dummy_parse_limit(dummy_parse.enabled.then_some(dummy_parse.limit));
+            session.set_effect_alacrity(alacrity_percent);
+            session.set_effect_latency(latency_ms);
+            session.set_client_language(client_language);
         }
 
         if old_dir != new_dir {
@@ -299,6 +337,12 @@ impl ServiceHandle {
             false
         };
 
+        // Alacrity/latency inferred by the effect tracker from the local player's casts
+        let gcd_estimate = session.effect_tracker().and_then(|tracker| {
+            let tracker = tracker.lock().unwrap_or_else(|p| p.into_inner());
+            tracker.gcd_estimate(cache.player.id)
+        });
+
         Some(SessionInfo {
             player_name: if cache.player_initialized {
                 Some(resolve(cache.player.name).to_string())
@@ -331,6 +375,8 @@ impl ServiceHandle {
             session_end,
             duration_formatted,
             stale_session,
+            player_alacrity_percent: gcd_estimate.map(|e| e.alacrity_percent),
+            player_latency_ms: gcd_estimate.map(|e| e.latency_ms),
         })
     }
 
@@ -374,6 +420,31 @@ impl ServiceHandle {
         cache.encounter_history.summaries().to_vec()
     }
 
+    /// Personal-best ranks of the local player's boss pulls in the current log file
+    pub async fn personal_ranks(&self) -> Vec<PersonalRank> {
+        let session_guard = self.shared.session.read().await;
+        let Some(session) = session_guard.as_ref() else {
+            return Vec::new();
+        };
+        let session = session.read().await;
+        let Some(cache) = session.session_cache.as_ref() else {
+            return Vec::new();
+        };
+
+        let character = resolve(cache.player.name);
+        let history = self
+            .shared
+            .personal_history
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        cache
+            .encounter_history
+            .summaries()
+            .iter()
+            .filter_map(|summary| history.rank_summary(character, summary))
+            .collect()
+    }
+
     /// Set the Parsely link for a specific encounter
     pub async fn set_encounter_parsely_link(&self, encounter_id: u64, link: String) -> bool {
         let session_guard = self.shared.session.read().await;
@@ -388,6 +459,187 @@ impl ServiceHandle {
         cache.encounter_history.set_parsely_link(encounter_id, link)
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Encounter Annotations
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Annotation store key for a completed encounter of the current log file
+    async fn annotation_key(&self, encounter_id: u64) -> Result<String, String> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session.read().await;
+        let log_file = session
+            .active_file
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().to_string())
+            .ok_or("No log file loaded")?;
+        let cache = session.session_cache.as_ref().ok_or("No session cache")?;
+        let summary = cache
+            .encounter_history
+            .summaries()
+            .iter()
+            .find(|s| s.encounter_id == encounter_id)
+            .ok_or("Notes can only be added to completed encounters")?;
+        AnnotationStore::key(&log_file, summary)
+            .ok_or_else(|| "Encounter has no start time".to_string())
+    }
+
+    fn save_annotations(store: &AnnotationStore) -> Result<(), String> {
+        let path = default_annotations_path().ok_or("No config directory")?;
+        store
+            .save_to_disk(&path)
+            .map_err(|e| format!("Failed to save notes: {}", e))
+    }
+
+    /// Notes pinned to an encounter, in time order
+    pub async fn encounter_annotations(&self, encounter_id: u64) -> Vec<EncounterAnnotation> {
+        let Ok(key) = self.annotation_key(encounter_id).await else {
+            return Vec::new();
+        };
+        let store = self.shared.annotations.lock().unwrap_or_else(|p| p.into_inner());
+        store.get(&key).to_vec()
+    }
+
+    /// Pin a note to an encounter. Returns the saved note (with its ID).
+    pub async fn add_encounter_annotation(
+        &self,
+        encounter_id: u64,
+        annotation: EncounterAnnotation,
+    ) -> Result<EncounterAnnotation, String> {
+        if annotation.text.trim().is_empty() {
+            return Err("Note is empty".to_string());
+        }
+        let key = self.annotation_key(encounter_id).await?;
+        let mut store = self.shared.annotations.lock().unwrap_or_else(|p| p.into_inner());
+        let saved = store.add(&key, annotation);
+        Self::save_annotations(&store)?;
+        Ok(saved)
+    }
+
+    /// Change the text of an encounter note
+    pub async fn update_encounter_annotation(
+        &self,
+        encounter_id: u64,
+        annotation_id: u64,
+        text: String,
+    ) -> Result<(), String> {
+        if text.trim().is_empty() {
+            return Err("Note is empty".to_string());
+        }
+        let key = self.annotation_key(encounter_id).await?;
+        let mut store = self.shared.annotations.lock().unwrap_or_else(|p| p.into_inner());
+        if !store.update(&key, annotation_id, &text) {
+            return Err("Note not found".to_string());
+        }
+        Self::save_annotations(&store)
+    }
+
+    /// Delete an encounter note
+    pub async fn remove_encounter_annotation(
+        &self,
+        encounter_id: u64,
+        annotation_id: u64,
+    ) -> Result<(), String> {
+        let key = self.annotation_key(encounter_id).await?;
+        let mut store = self.shared.annotations.lock().unwrap_or_else(|p| p.into_inner());
+        if !store.remove(&key, annotation_id) {
+            return Err("Note not found".to_string());
+        }
+        Self::save_annotations(&store)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Encounter Reports
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Write an HTML report for one encounter (or every completed encounter of
+    /// the session when `encounter_idx` is None) as `<stem>.html`, plus a
+    /// Markdown summary as `<stem>.md`, where `<stem>` is `path` without its
+    /// extension. Returns the Markdown file's path.
+    pub async fn export_encounter_report(
+        &self,
+        encounter_idx: Option<u32>,
+        path: String,
+    ) -> Result<String, String> {
+        let (log_file, encounters) = {
+            let session_guard = self.shared.session.read().await;
+            let session = session_guard.as_ref().ok_or("No active session")?;
+            let session = session.read().await;
+            let dir = session.encounters_dir().ok_or("No encounters directory")?;
+            let log_file = session
+                .active_file
+                .as_ref()
+                .and_then(|p| p.file_name())
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let cache = session.session_cache.as_ref().ok_or("No session cache")?;
+            let store = self.shared.annotations.lock().unwrap_or_else(|p| p.into_inner());
+            let encounters: Vec<_> = cache
+                .encounter_history
+                .summaries()
+                .iter()
+                .filter(|s| encounter_idx.is_none_or(|idx| s.encounter_id == idx as u64))
+                .map(|s| {
+                    let parquet = dir.join(baras_core::storage::encounter_filename(
+                        s.encounter_id as u32,
+                    ));
+                    let notes = AnnotationStore::key(&log_file, s)
+                        .map(|key| store.get(&key).to_vec())
+                        .unwrap_or_default();
+                    (s.clone(), parquet, notes)
+                })
+                .collect();
+            (log_file, encounters)
+        };
+        if encounters.is_empty() {
+            return Err("No completed encounters to export".to_string());
+        }
+
+        let gcd_fallback = self.gcd_fallback().await;
+        let mut reports = Vec::with_capacity(encounters.len());
+        for (summary, parquet, notes) in encounters {
+            if !parquet.exists() {
+                // A session export skips encounters that were never written
+                if encounter_idx.is_none() {
+                    continue;
+                }
+                return Err(format!("Encounter file not found: {:?}", parquet));
+            }
+            self.shared.query_context.register_parquet(&parquet).await?;
+            let guard = self.shared.query_context.query().await;
+            let query = guard.query().with_gcd_fallback(gcd_fallback);
+            reports.push(EncounterReport::build(&query, summary, notes).await?);
+        }
+
+        if reports.is_empty() {
+            return Err("No encounter data to export".to_string());
+        }
+
+        let title = match (encounter_idx, reports.as_slice()) {
+            (Some(_), [report]) => report.summary.display_name.clone(),
+            _ => {
+                let date = reports[0]
+                    .summary
+                    .start_time
+                    .as_deref()
+                    .and_then(|t| t.get(..10))
+                    .unwrap_or(&log_file);
+                format!("{} - {}", reports[0].summary.area_name, date)
+            }
+        };
+
+        // Whatever extension was picked, the two files must not collide
+        let path = PathBuf::from(path);
+        let html_path = path.with_extension("html");
+        let md_path = path.with_extension("md");
+        std::fs::write(&html_path, render_html(&title, &reports))
+            .map_err(|e| format!("Failed to write report: {}", e))?;
+        std::fs::write(&md_path, render_markdown(&title, &reports))
+            .map_err(|e| format!("Failed to write summary: {}", e))?;
+        Ok(md_path.to_string_lossy().to_string())
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Raid Registry Operations
     // ─────────────────────────────────────────────────────────────────────────
@@ -427,6 +679,45 @@ impl ServiceHandle {
             .map_err(|e| e.to_string())
     }
 
+    /// Run a timer/counter hotkey action against the live session.
+    /// Alerts and audio follow through the timer manager as for log-driven timers.
+    pub async fn run_hotkey_action(&self, action: &HotkeyAction) -> Result<(), String> {
+        if !self.is_live_tailing() {
+            return Err("Not live tailing".to_string());
+        }
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or("No active session")?;
+        let mut session = session.write().await;
+
+        match action {
+            HotkeyAction::StartTimer { timer_id } => {
+                if !session.start_manual_timer(timer_id) {
+                    return Err(format!("No enabled manual timer '{}' loaded", timer_id));
+                }
+                self.shared.manual_timers_running.store(true, Ordering::Relaxed);
+            }
+            HotkeyAction::ResetTimer { timer_id } => {
+                session.reset_timer(timer_id);
+            }
+            HotkeyAction::IncrementCounter { counter_id } => {
+                session
+                    .modify_counter(counter_id, false, None)
+                    .ok_or("No current encounter")?;
+            }
+            HotkeyAction::DecrementCounter { counter_id } => {
+                session
+                    .modify_counter(counter_id, true, None)
+                    .ok_or("No current encounter")?;
+            }
+            HotkeyAction::SetCounter { counter_id, value } => {
+                session
+                    .modify_counter(counter_id, false, Some(*value))
+                    .ok_or("No current encounter")?;
+            }
+        }
+        Ok(())
+    }
+
     /// Reload effect definitions from disk and update active session
     pub async fn reload_effect_definitions(&self) -> Result<(), String> {
         self.cmd_tx
@@ -500,7 +791,8 @@ impl ServiceHandle {
         let types_ref: Option<Vec<&str>> = entity_types
             .as_ref()
             .map(|v| v.iter().map(|s| s.as_str()).collect());
-        self.shared
+        let mut rows = self
+            .shared
             .query_context
             .query()
             .await
@@ -513,7 +805,18 @@ impl ServiceHandle {
                 breakdown_mode.as_ref(),
                 duration_secs,
             )
-            .await
+            .await?;
+
+        // Names are stored in English; show them in the user's language
+        let name_language = self.shared.config.read().await.name_language;
+        for row in &mut rows {
+            row.ability_name = baras_core::game_data::display_name(
+                row.ability_id,
+                &row.ability_name,
+                name_language,
+            );
+        }
+        Ok(rows)
     }
 
     /// Query damage taken summary (damage type breakdown + mitigation stats).
@@ -673,6 +976,7 @@ impl ServiceHandle {
             .query()
             .await
             .query()
+            .with_gcd_fallback(self.gcd_fallback().await)
             .query_raid_overview(time_range.as_ref(), duration_secs)
             .await?;
 
@@ -954,13 +1258,21 @@ impl ServiceHandle {
             self.shared.query_context.register_batch(batch).await?;
         }
 
-        self.shared
+        let mut rows = self
+            .shared
             .query_context
             .query()
             .await
             .query()
             .query_effect_uptime(target_name.as_deref(), time_range.as_ref(), duration_secs)
-            .await
+            .await?;
+
+        let name_language = self.shared.config.read().await.name_language;
+        for row in &mut rows {
+            row.effect_name =
+                baras_core::game_data::display_name(row.effect_id, &row.effect_name, name_language);
+        }
+        Ok(rows)
     }
 
     /// Query individual time windows for a specific effect.
@@ -1005,6 +1317,26 @@ impl ServiceHandle {
             .await
     }
 
+    /// Parse a combat log filter, resolving `boss` against the loaded boss definitions
+    fn parse_log_filter(cache: Option<&SessionCache>, text: &str) -> Result<LogFilter, String> {
+        let boss_npc_ids = cache
+            .map(|c| {
+                c.boss_definitions()
+                    .iter()
+                    .flat_map(|def| def.boss_npc_ids())
+                    .collect()
+            })
+            .unwrap_or_default();
+        LogFilter::parse(text)
+            .map(|filter| filter.with_boss_npc_ids(boss_npc_ids))
+            .map_err(|e| e.to_string())
+    }
+
+    /// Check combat log filter syntax without running it.
+    pub fn validate_combat_log_filter(&self, text: &str) -> Result<(), String> {
+        LogFilter::parse(text).map(|_| ()).map_err(|e| e.to_string())
+    }
+
     /// Query combat log rows with pagination for virtual scrolling.
     pub async fn query_combat_log(
         &self,
@@ -1020,6 +1352,10 @@ impl ServiceHandle {
         let session_guard = self.shared.session.read().await;
         let session = session_guard.as_ref().ok_or("No active session")?;
         let session = session.read().await;
+        let search_filter = search_filter
+            .as_deref()
+            .map(|text| Self::parse_log_filter(session.session_cache.as_ref(), text))
+            .transpose()?;
 
         if let Some(idx) = encounter_idx {
             let dir = session.encounters_dir().ok_or("No encounters directory")?;
@@ -1036,7 +1372,8 @@ impl ServiceHandle {
             self.shared.query_context.register_batch(batch).await?;
         }
 
-        self.shared
+        let mut rows = self
+            .shared
             .query_context
             .query()
             .await
@@ -1046,11 +1383,23 @@ impl ServiceHandle {
                 limit,
                 source_filter.as_deref(),
                 target_filter.as_deref(),
-                search_filter.as_deref(),
+                search_filter.as_ref(),
                 time_range.as_ref(),
                 event_filters.as_ref(),
             )
-            .await
+            .await?;
+
+        let name_language = self.shared.config.read().await.name_language;
+        for row in &mut rows {
+            row.ability_name = baras_core::game_data::display_name(
+                row.ability_id,
+                &row.ability_name,
+                name_language,
+            );
+            row.effect_name =
+                baras_core::game_data::display_name(row.effect_id, &row.effect_name, name_language);
+        }
+        Ok(rows)
     }
 
     /// Get total count of combat log rows for pagination.
@@ -1066,6 +1415,10 @@ impl ServiceHandle {
         let session_guard = self.shared.session.read().await;
         let session = session_guard.as_ref().ok_or("No active session")?;
         let session = session.read().await;
+        let search_filter = search_filter
+            .as_deref()
+            .map(|text| Self::parse_log_filter(session.session_cache.as_ref(), text))
+            .transpose()?;
 
         if let Some(idx) = encounter_idx {
             let dir = session.encounters_dir().ok_or("No encounters directory")?;
@@ -1090,7 +1443,7 @@ impl ServiceHandle {
             .query_combat_log_count(
                 source_filter.as_deref(),
                 target_filter.as_deref(),
-                search_filter.as_deref(),
+                search_filter.as_ref(),
                 time_range.as_ref(),
                 event_filters.as_ref(),
             )
@@ -1110,6 +1463,10 @@ impl ServiceHandle {
         let session_guard = self.shared.session.read().await;
         let session = session_guard.as_ref().ok_or("No active session")?;
         let session = session.read().await;
+        if find_text.trim().is_empty() {
+            return Ok(vec![]);
+        }
+        let find = Self::parse_log_filter(session.session_cache.as_ref(), &find_text)?;
 
         if let Some(idx) = encounter_idx {
             let dir = session.encounters_dir().ok_or("No encounters directory")?;
@@ -1132,7 +1489,7 @@ impl ServiceHandle {
             .await
             .query()
             .query_combat_log_find(
-                &find_text,
+                &find,
                 source_filter.as_deref(),
                 target_filter.as_deref(),
                 time_range.as_ref(),
@@ -1291,6 +1648,32 @@ impl ServiceHandle {
         let session = session_guard.as_ref().ok_or("No active session")?;
         let session = session.read().await;
 
+        // Reference rotation for the discipline the player had in that encounter
+        let discipline = session.session_cache.as_ref().and_then(|cache| {
+            if let Some(idx) = encounter_idx {
+                cache
+                    .encounter_history
+                    .summaries()
+                    .iter()
+                    .find(|s| s.encounter_id == idx as u64)?
+                    .player_metrics
+                    .iter()
+                    .find(|pm| pm.name == source_name)?
+                    .discipline
+            } else {
+                cache
+                    .player_disciplines
+                    .values()
+                    .find(|p| resolve(p.name) == source_name)
+                    .and_then(|p| Discipline::from_guid(p.discipline_id))
+            }
+        });
+        let rotations = Self::rotations_dir()
+            .map(|dir| baras_core::rotations::load_rotations_from_dir(&dir))
+            .unwrap_or_default();
+        let reference =
+            discipline.and_then(|d| baras_core::rotations::find_rotation(&rotations, d));
+
         if let Some(idx) = encounter_idx {
             let dir = session.encounters_dir().ok_or("No encounters directory")?;
             let path = dir.join(baras_core::storage::encounter_filename(idx));
@@ -1306,13 +1689,236 @@ impl ServiceHandle {
             self.shared.query_context.register_batch(batch).await?;
         }
 
+        let mut analysis = self
+            .shared
+            .query_context
+            .query()
+            .await
+            .query()
+            .with_gcd_fallback(self.gcd_fallback().await)
+            .query_rotation(
+                &source_name,
+                anchor_ability_id,
+                time_range.as_ref(),
+                reference,
+            )
+            .await?;
+
+        let name_language = self.shared.config.read().await.name_language;
+        let localize = |id: i64, name: &mut String| {
+            *name = baras_core::game_data::display_name(id, name, name_language);
+        };
+        for (id, name) in &mut analysis.abilities {
+            localize(*id, name);
+        }
+        for slot in analysis.cycles.iter_mut().flat_map(|c| &mut c.slots) {
+            localize(
+                slot.gcd_ability.ability_id,
+                &mut slot.gcd_ability.ability_name,
+            );
+            for event in &mut slot.off_gcd {
+                localize(event.ability_id, &mut event.ability_name);
+            }
+        }
+        Ok(analysis)
+    }
+
+    /// Directory of user reference rotations (TOML, one or more per discipline)
+    fn rotations_dir() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("baras").join("definitions").join("rotations"))
+    }
+
+    /// Query GCD uptime ("always be casting") and downtime gaps for a player.
+    pub async fn query_gcd_uptime(
+        &self,
+        encounter_idx: Option<u32>,
+        source_name: String,
+        time_range: Option<TimeRange>,
+    ) -> Result<GcdUptimeAnalysis, String> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session.read().await;
+
+        if let Some(idx) = encounter_idx {
+            let dir = session.encounters_dir().ok_or("No encounters directory")?;
+            let path = dir.join(baras_core::storage::encounter_filename(idx));
+            if !path.exists() {
+                return Err(format!("Encounter file not found: {:?}", path));
+            }
+            self.shared.query_context.register_parquet(&path).await?;
+        } else {
+            let writer = session
+                .encounter_writer()
+                .ok_or("No live encounter buffer")?;
+            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            self.shared.query_context.register_batch(batch).await?;
+        }
+
+        self.shared
+            .query_context
+            .query()
+            .await
+            .query()
+            .with_gcd_fallback(self.gcd_fallback().await)
+            .query_gcd_uptime(&source_name, time_range.as_ref())
+            .await
+    }
+
+    /// Query cooldown usage efficiency for all players in an encounter.
+    pub async fn query_cooldown_efficiency(
+        &self,
+        encounter_idx: Option<u32>,
+        time_range: Option<TimeRange>,
+    ) -> Result<Vec<CooldownEfficiencyRow>, String> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session.read().await;
+
+        // Disciplines decide which never-pressed cooldowns a player should have had:
+        // the encounter's summary for history, the session registry when live
+        let mut player_disciplines = std::collections::HashMap::new();
+        if let Some(cache) = session.session_cache.as_ref() {
+            if let Some(idx) = encounter_idx {
+                if let Some(summary) = cache
+                    .encounter_history
+                    .summaries()
+                    .iter()
+                    .find(|s| s.encounter_id == idx as u64)
+                {
+                    for pm in &summary.player_metrics {
+                        if let Some(discipline) = pm.discipline {
+                            player_disciplines.insert(pm.name.clone(), discipline);
+                        }
+                    }
+                }
+            } else {
+                for p in cache.player_disciplines.values() {
+                    if let Some(discipline) = Discipline::from_guid(p.discipline_id) {
+                        player_disciplines.insert(resolve(p.name).to_string(), discipline);
+                    }
+                }
+            }
+        }
+
+        if let Some(idx) = encounter_idx {
+            let dir = session.encounters_dir().ok_or("No encounters directory")?;
+            let path = dir.join(baras_core::storage::encounter_filename(idx));
+            if !path.exists() {
+                return Err(format!("Encounter file not found: {:?}", path));
+            }
+            self.shared.query_context.register_parquet(&path).await?;
+        } else {
+            let writer = session
+                .encounter_writer()
+                .ok_or("No live encounter buffer")?;
+            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            self.shared.query_context.register_batch(batch).await?;
+        }
+
+        // Cooldown lengths come from the effect definitions (bundled + user overrides)
+        let definitions: Vec<_> = super::CombatService::load_effect_definitions(&self.app_handle)
+            .effects
+            .into_values()
+            .collect();
+
         self.shared
             .query_context
             .query()
             .await
             .query()
-            .query_rotation(&source_name, anchor_ability_id, time_range.as_ref())
+            .with_gcd_fallback(self.gcd_fallback().await)
+            .query_cooldown_efficiency(&definitions, &player_disciplines, time_range.as_ref())
+            .await
+    }
+
+    /// Query the healer × recipient healing matrix for an encounter.
+    pub async fn query_healing_matrix(
+        &self,
+        encounter_idx: Option<u32>,
+        time_range: Option<TimeRange>,
+    ) -> Result<HealingMatrix, String> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session.read().await;
+
+        if let Some(idx) = encounter_idx {
+            let dir = session.encounters_dir().ok_or("No encounters directory")?;
+            let path = dir.join(baras_core::storage::encounter_filename(idx));
+            if !path.exists() {
+                return Err(format!("Encounter file not found: {:?}", path));
+            }
+            self.shared.query_context.register_parquet(&path).await?;
+        } else {
+            let writer = session
+                .encounter_writer()
+                .ok_or("No live encounter buffer")?;
+            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            self.shared.query_context.register_batch(batch).await?;
+        }
+
+        self.shared
+            .query_context
+            .query()
+            .await
+            .query()
+            .query_healing_matrix(time_range.as_ref())
+            .await
+    }
+
+    /// Export a Data Explorer table or chart (or the raw events) to a CSV or
+    /// parquet file. Returns the number of rows written.
+    pub async fn export_table(
+        &self,
+        encounter_idx: Option<u32>,
+        source: ExportSource,
+        time_range: Option<TimeRange>,
+        duration_secs: Option<f32>,
+        format: ExportFormat,
+        path: String,
+    ) -> Result<usize, String> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session.read().await;
+        let search_filter = match &source {
+            ExportSource::CombatLog {
+                search_filter: Some(text),
+                ..
+            } if !text.trim().is_empty() => {
+                Some(Self::parse_log_filter(session.session_cache.as_ref(), text)?)
+            }
+            _ => None,
+        };
+
+        if let Some(idx) = encounter_idx {
+            let dir = session.encounters_dir().ok_or("No encounters directory")?;
+            let path = dir.join(baras_core::storage::encounter_filename(idx));
+            if !path.exists() {
+                return Err(format!("Encounter file not found: {:?}", path));
+            }
+            self.shared.query_context.register_parquet(&path).await?;
+        } else {
+            let writer = session
+                .encounter_writer()
+                .ok_or("No live encounter buffer")?;
+            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            self.shared.query_context.register_batch(batch).await?;
+        }
+
+        let batches = self
+            .shared
+            .query_context
+            .query()
             .await
+            .query()
+            .with_gcd_fallback(self.gcd_fallback().await)
+            .export_source(
+                &source,
+                time_range.as_ref(),
+                duration_secs,
+                search_filter.as_ref(),
+            )
+            .await?;
+        write_export(&batches, format, std::path::Path::new(&path))
     }
 
     // ─────────────────────────────────────────────────────────────────────────
@@ -1378,19 +1984,21 @@ impl ServiceHandle {
         // Get boss definitions from cache via public accessor
         let definitions = cache.boss_definitions();
 
-        // Find first boss with notes
-        for boss in definitions.iter() {
-            if let Some(notes) = &boss.notes {
-                if !notes.is_empty() {
-                    return Some(baras_overlay::NotesData {
-                        text: notes.clone(),
-                        boss_name: boss.name.clone(),
-                    });
-                }
-            }
+        // Prefer the boss being fought (narrowed to its current phase), then the
+        // first boss with notes
+        let encounter = cache.current_encounter();
+        if let Some(enc) = encounter
+            && let Some(boss) = enc
+                .active_boss_idx()
+                .and_then(|idx| enc.boss_definitions().get(idx))
+            && let Some((notes_data, _)) = super::boss_notes_data(boss, Some(enc))
+        {
+            return Some(notes_data);
         }
-
-        None
+        definitions
+            .iter()
+            .find_map(|boss| super::boss_notes_data(boss, None))
+            .map(|(notes_data, _)| notes_data)
     }
 
     /// Get list of bosses with notes status for the current area
@@ -1430,10 +2038,12 @@ impl ServiceHandle {
             .ok_or_else(|| format!("Boss '{}' not found", boss_id))?;
 
         // Send notes to overlay (even if empty, to allow clearing)
-        let notes_data = baras_overlay::NotesData {
-            text: boss.notes.clone().unwrap_or_default(),
-            boss_name: boss.name.clone(),
-        };
+        let notes_data = super::boss_notes_data(boss, None)
+            .map(|(notes_data, _)| notes_data)
+            .unwrap_or_else(|| baras_overlay::NotesData {
+                text: String::new(),
+                boss_name: boss.name.clone(),
+            });
 
         // Send via the overlay channel
         let _ = self.cmd_tx.send(super::ServiceCommand::SendNotesToOverlay(notes_data)).await;
@@ -12,27 +12,35 @@ use crate::state::SharedState;
 pub use crate::state::{RaidSlotRegistry, RegisteredPlayer};
 use baras_core::directory_watcher;
 pub use handler::*;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tauri::{AppHandle, Emitter, Manager};
 use tokio::sync::{RwLock, mpsc};
 
-use baras_core::context::{AppConfig, AppConfigExt, DirectoryIndex, ParsingSession, resolve};
-use baras_core::directory_watcher::DirectoryWatcher;
+use baras_core::boss::AreaType;
+use baras_core::context::{
+    AppConfig, AppConfigExt, DirectoryIndex, OverlayVisibilityContext, ParsingSession,
+    ProfileRuleContext, hidden_overlays, resolve, select_profile,
+};
+use baras_core::directory_watcher::{DefinitionWatcher, DirectoryWatcher};
 use baras_core::encounter::{EncounterState, PhaseType};
+use baras_core::encounter::personal_best::{PersonalRank, default_history_path};
 use baras_core::encounter::summary::classify_encounter;
-use baras_core::game_data::{Discipline, Role};
+use baras_core::game_data::{ContentType, Discipline, Role, lookup_boss};
 use baras_core::timers::FiredAlert;
 use baras_core::{
     ActiveEffect, BossEncounterDefinition, DefinitionConfig, DefinitionSet, DisplayTarget,
-    EFFECTS_DSL_VERSION, EntityType, GameSignal, PlayerMetrics, Reader, SignalHandler,
+    EFFECTS_DSL_VERSION, EncounterSummary, EntityType, GameSignal, PlayerMetrics, Reader,
+    SignalHandler,
 };
 use baras_overlay::{
     BossHealthData, ChallengeData, ChallengeEntry, Color, CooldownData, CooldownEntry, DotEntry,
     DotTarget, DotTrackerData, EffectABEntry, EffectsABData, NotesData, PersonalStats,
     PlayerContribution, PlayerRole, RaidEffect, RaidFrame, RaidFrameData, TimerData, TimerEntry,
 };
+use baras_types::formatting;
 
 use crate::audio::{AudioEvent, AudioSender, AudioService};
 use tracing::{debug, error, info, warn};
@@ -124,6 +132,9 @@ pub enum ServiceCommand {
     ReloadAreaDefinitions(i64),
     /// Start monitoring for the game process (triggered on first live event)
     StartProcessMonitor,
+    /// Record the pull that just ended for personal-best ranking
+    RecordPersonalBest,
+    SaveObservedNames,
 }
 
 /// Updates sent to the overlay system
@@ -161,6 +172,10 @@ pub enum OverlayUpdate {
     ConversationEnded,
     /// Session liveness changed (historical mode entered/exited, player logged out/in)
     NotLiveStateChanged { is_live: bool },
+    /// Profile switch rules selected a different overlay profile
+    ProfileSwitchRequested(String),
+    /// The set of overlays hidden by their visibility conditions changed
+    VisibilityConditionsChanged,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -182,6 +197,7 @@ pub enum SessionEvent {
     CombatEnded,
     AreaChanged,
     PlayerInitialized,
+    DummyParseCompleted,
 }
 
 /// Signal handler that tracks combat state and triggers metrics updates
@@ -198,6 +214,19 @@ struct CombatSignalHandler {
     local_player_id: Option<i64>,
     /// Whether we've already requested the process monitor for this tailing session
     monitor_requested: bool,
+    /// Notes text last sent while narrowed to phase/counter-tagged sections
+    /// (None while the full notes are shown)
+    phase_notes: Option<String>,
+    /// Local player's current discipline (for profile switch rules)
+    local_discipline: Option<Discipline>,
+    /// Last boss detected in the current area: (definition ID, is training dummy)
+    profile_boss: Option<(String, bool)>,
+    /// Profile last selected by the switch rules (a manual switch sticks until
+    /// the rules select a different profile)
+    auto_profile: Option<String>,
+    /// Rules couldn't be evaluated because the config was locked; retried on
+    /// the next signal
+    profile_rules_pending: bool,
 }
 
 impl CombatSignalHandler {
@@ -216,6 +245,99 @@ impl CombatSignalHandler {
             cmd_tx,
             local_player_id: None,
             monitor_requested: false,
+            phase_notes: None,
+            local_discipline: None,
+            profile_boss: None,
+            auto_profile: None,
+            profile_rules_pending: false,
+        }
+    }
+}
+
+/// Notes overlay data for a boss, narrowed to the sections tagged for the
+/// encounter's current phase/counters (full notes when none match)
+fn boss_notes_data(
+    boss: &BossEncounterDefinition,
+    encounter: Option<&baras_core::encounter::CombatEncounter>,
+) -> Option<(NotesData, bool)> {
+    let notes = boss.notes.as_deref().filter(|n| !n.is_empty())?;
+    let selected = match encounter {
+        Some(enc) => {
+            baras_core::boss::select_notes(notes, enc.current_phase.as_deref(), &enc.counters)
+        }
+        None => baras_core::boss::select_notes(notes, None, &Default::default()),
+    };
+    Some((
+        NotesData {
+            text: selected.text,
+            boss_name: boss.name.clone(),
+        },
+        selected.filtered,
+    ))
+}
+
+impl CombatSignalHandler {
+    /// Re-send the active boss's notes when the phase/counter selection changes
+    fn refresh_phase_notes(&mut self, encounter: Option<&baras_core::encounter::CombatEncounter>) {
+        let Some(enc) = encounter else {
+            return;
+        };
+        let Some(boss) = enc
+            .active_boss_idx()
+            .and_then(|idx| enc.boss_definitions().get(idx))
+        else {
+            return;
+        };
+        let Some((notes_data, filtered)) = boss_notes_data(boss, Some(enc)) else {
+            return;
+        };
+        // Counters change often; only push when the visible notes actually change
+        if filtered {
+            if self.phase_notes.as_ref() == Some(&notes_data.text) {
+                return;
+            }
+            self.phase_notes = Some(notes_data.text.clone());
+        } else if self.phase_notes.take().is_none() {
+            return;
+        }
+        let _ = self.overlay_tx.try_send(OverlayUpdate::NotesUpdated(notes_data));
+    }
+
+    /// Request a profile switch if the configured rules select a new profile
+    fn apply_profile_rules(&mut self) {
+        if !self.shared.is_live_tailing.load(Ordering::SeqCst) {
+            return;
+        }
+        // Signal handlers can't await; a busy config lock is retried on the
+        // next signal so an area or boss change is never lost
+        let Ok(config) = self.shared.config.try_read() else {
+            self.profile_rules_pending = true;
+            return;
+        };
+        self.profile_rules_pending = false;
+        if config.profile_rules.is_empty() {
+            return;
+        }
+
+        let ctx = ProfileRuleContext {
+            area_id: self.shared.current_area_id.load(Ordering::SeqCst),
+            discipline: self.local_discipline,
+            boss_id: self.profile_boss.as_ref().map(|(id, _)| id.as_str()),
+            training_dummy: self.profile_boss.as_ref().is_some_and(|(_, dummy)| *dummy),
+        };
+        let Some(profile) = select_profile(&config.profile_rules, &ctx) else {
+            return;
+        };
+        if self.auto_profile.as_deref() == Some(profile) {
+            return;
+        }
+        self.auto_profile = Some(profile.to_string());
+
+        if config.active_profile_name.as_deref() != Some(profile) {
+            info!(profile, "Switching overlay profile by rule");
+            let _ = self
+                .overlay_tx
+                .try_send(OverlayUpdate::ProfileSwitchRequested(profile.to_string()));
         }
     }
 }
@@ -226,6 +348,10 @@ impl SignalHandler for CombatSignalHandler {
         signal: &GameSignal,
         _encounter: Option<&baras_core::encounter::CombatEncounter>,
     ) {
+        if self.profile_rules_pending {
+            self.apply_profile_rules();
+        }
+
         // On first event processed, start monitoring the game process
         // (only if auto-hide when not live is enabled — no point polling otherwise)
         if !self.monitor_requested {
@@ -259,6 +385,21 @@ impl SignalHandler for CombatSignalHandler {
                 let _ = self.session_event_tx.send(SessionEvent::CombatEnded);
                 // Clear boss health and timer overlays
                 let _ = self.overlay_tx.try_send(OverlayUpdate::CombatEnded);
+                // The summary is added to history alongside this signal; rank
+                // it once the session lock is released
+                let _ = self.cmd_tx.try_send(ServiceCommand::RecordPersonalBest);
+                let _ = self.cmd_tx.try_send(ServiceCommand::SaveObservedNames);
+                // Between pulls the notes go back to the full strat
+                if self.phase_notes.is_some()
+                    && let Some(enc) = _encounter
+                    && let Some(boss) = enc
+                        .active_boss_idx()
+                        .and_then(|idx| enc.boss_definitions().get(idx))
+                    && let Some((notes_data, _)) = boss_notes_data(boss, None)
+                {
+                    self.phase_notes = None;
+                    let _ = self.overlay_tx.try_send(OverlayUpdate::NotesUpdated(notes_data));
+                }
             }
             GameSignal::DisciplineChanged {
                 entity_id,
@@ -273,8 +414,17 @@ impl SignalHandler for CombatSignalHandler {
                 // Update raid registry with discipline info for role icons
                 let mut registry = self.shared.raid_registry.lock().unwrap_or_else(|p| p.into_inner());
                 registry.update_discipline(*entity_id, *class_id, *discipline_id);
+                drop(registry);
                 // Notify frontend of player info change
                 let _ = self.session_event_tx.send(SessionEvent::PlayerInitialized);
+
+                if self.local_player_id == Some(*entity_id) {
+                    let discipline = Discipline::from_guid(*discipline_id);
+                    if discipline != self.local_discipline {
+                        self.local_discipline = discipline;
+                        self.apply_profile_rules();
+                    }
+                }
             }
             GameSignal::EffectApplied {
                 effect_id,
@@ -310,28 +460,57 @@ impl SignalHandler for CombatSignalHandler {
                     let _ = self.session_event_tx.send(SessionEvent::AreaChanged);
                     // Trigger area definition reload (will send notes or clear them)
                     let _ = self.cmd_tx.try_send(ServiceCommand::ReloadAreaDefinitions(*area_id));
+                    self.profile_boss = None;
+                    self.apply_profile_rules();
                 }
             }
             GameSignal::BossEncounterDetected {
+                definition_id,
                 definition_idx,
                 boss_name,
+                npc_id,
                 ..
             } => {
                 // Send notes for this specific boss to the overlay
                 if let Some(enc) = _encounter {
                     if let Some(def) = enc.boss_definitions().get(*definition_idx) {
-                        if let Some(notes) = &def.notes {
-                            if !notes.is_empty() {
-                                let notes_data = NotesData {
-                                    text: notes.clone(),
-                                    boss_name: boss_name.clone(),
-                                };
-                                let _ = self.overlay_tx.try_send(OverlayUpdate::NotesUpdated(notes_data));
-                            }
+                        let training_dummy = def.area_type == AreaType::TrainingDummy
+                            || lookup_boss(*npc_id)
+                                .is_some_and(|b| b.content_type == ContentType::TrainingDummy);
+                        self.profile_boss = Some((definition_id.clone(), training_dummy));
+                        self.apply_profile_rules();
+
+                        if let Some((mut notes_data, filtered)) = boss_notes_data(def, Some(enc)) {
+                            notes_data.boss_name = boss_name.clone();
+                            self.phase_notes = filtered.then(|| notes_data.text.clone());
+                            let _ = self.overlay_tx.try_send(OverlayUpdate::NotesUpdated(notes_data));
                         }
                     }
                 }
             }
+            GameSignal::DummyParseCompleted { parse, .. } => {
+                let limit = self
+                    .shared
+                    .config
+                    .try_read()
+                    .map(|c| c.dummy_parse.limit)
+                    .unwrap_or_default();
+                info!(
+                    character = %parse.character,
+                    discipline = %parse.discipline,
+                    dps = parse.dps,
+                    "Dummy parse completed"
+                );
+                self.shared
+                    .live_dummy_parses
+                    .lock()
+                    .unwrap_or_else(|p| p.into_inner())
+                    .push((limit, parse.clone()));
+                let _ = self.session_event_tx.send(SessionEvent::DummyParseCompleted);
+            }
+            GameSignal::PhaseChanged { .. } | GameSignal::CounterChanged { .. } => {
+                self.refresh_phase_notes(_encounter);
+            }
             _ => {}
         }
     }
@@ -351,6 +530,8 @@ pub struct CombatService {
     cmd_tx: mpsc::Sender<ServiceCommand>,
     tail_handle: Option<tokio::task::JoinHandle<()>>,
     directory_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Watches user definition files for hot-reload
+    definitions_watch_handle: Option<tokio::task::JoinHandle<()>>,
     metrics_handle: Option<tokio::task::JoinHandle<()>>,
     effects_handle: Option<tokio::task::JoinHandle<()>>,
     /// Effect definitions loaded at startup for overlay tracking
@@ -378,6 +559,12 @@ impl CombatService {
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
         let config = AppConfig::load();
+
+        // Merge user name tables (localized clients) over the bundled ones
+        if let Some(dir) = Self::names_dir() {
+            baras_core::game_data::load_user_name_tables(&dir);
+        }
+
         let directory_index =
             DirectoryIndex::build_index(&PathBuf::from(&config.log_directory)).unwrap_or_default();
 
@@ -434,6 +621,7 @@ impl CombatService {
             cmd_tx: cmd_tx.clone(),
             tail_handle: None,
             directory_handle: None,
+            definitions_watch_handle: None,
             metrics_handle: None,
             effects_handle: None,
             definitions,
@@ -502,9 +690,7 @@ impl CombatService {
         area_index: Arc<baras_core::boss::AreaIndex>,
         app_handle: AppHandle,
     ) {
-        use baras_core::context::{
-            FileAreaIndex, LogAreaCache, default_cache_path, extract_areas_from_file,
-        };
+        use baras_core::context::{LogAreaCache, default_cache_path, index_log_file};
         use std::collections::HashSet;
 
         tauri::async_runtime::spawn(async move {
@@ -590,15 +776,9 @@ impl CombatService {
                     .map(|d| d.as_secs())
                     .unwrap_or(0);
 
-                match extract_areas_from_file(&path, &known_area_ids) {
-                    Ok(areas) => {
-                        cache.insert(
-                            path.clone(),
-                            FileAreaIndex {
-                                modified_secs,
-                                areas,
-                            },
-                        );
+                match index_log_file(&path, &known_area_ids, modified_secs) {
+                    Ok(file_index) => {
+                        cache.insert(path.clone(), file_index);
                         scanned += 1;
                     }
                     Err(e) => {
@@ -668,6 +848,28 @@ impl CombatService {
         dirs::config_dir().map(|p| p.join("baras").join("timer_preferences.toml"))
     }
 
+    /// Get the path to the user name tables directory (localized clients)
+    fn names_dir() -> Option<std::path::PathBuf> {
+        dirs::config_dir().map(|p| p.join("baras").join("definitions").join("names"))
+    }
+
+    /// Persist names learned from the live log to the user name tables
+    async fn save_observed_names(&self) {
+        let observed = {
+            let session_guard = self.shared.session.read().await;
+            let Some(session) = session_guard.as_ref() else {
+                return;
+            };
+            session.write().await.take_observed_names()
+        };
+        if let Some(observed) = observed
+            && let Some(dir) = Self::names_dir()
+            && let Err(e) = baras_core::game_data::save_observed_names(&dir, observed)
+        {
+            warn!(error = %e, "Failed to save observed names");
+        }
+    }
+
     /// Initialize the icon cache for ability icons
     fn init_icon_cache(app_handle: &AppHandle) -> Option<Arc<baras_overlay::icons::IconCache>> {
         use baras_overlay::icons::IconCache;
@@ -748,7 +950,7 @@ impl CombatService {
     /// Version checking:
     /// - User file must have `version = N` matching EFFECTS_DSL_VERSION
     /// - Mismatched versions cause user file to be deleted (breaking DSL change)
-    fn load_effect_definitions(app_handle: &AppHandle) -> DefinitionSet {
+    pub(crate) fn load_effect_definitions(app_handle: &AppHandle) -> DefinitionSet {
         // Clean up old directory structure on first run after update
         Self::cleanup_old_effects_dir();
 
@@ -863,6 +1065,7 @@ impl CombatService {
         }
 
         self.start_watcher().await;
+        self.start_definition_watcher();
 
         loop {
             let Some(cmd) = self.cmd_rx.recv().await else {
@@ -949,6 +1152,12 @@ impl CombatService {
                 ServiceCommand::StartProcessMonitor => {
                     self.start_process_monitor();
                 }
+                ServiceCommand::RecordPersonalBest => {
+                    self.record_personal_best().await;
+                }
+                ServiceCommand::SaveObservedNames => {
+                    self.save_observed_names().await;
+                }
                 ServiceCommand::ReloadAreaDefinitions(area_id) => {
                     // Reload definitions for the new area and update notes overlay
                     if area_id == 0 {
@@ -1025,15 +1234,9 @@ impl CombatService {
         // Find the first boss with notes (or aggregate all notes)
         // For now, send notes from first boss that has them
         for boss in bosses {
-            if let Some(notes) = &boss.notes {
-                if !notes.is_empty() {
-                    let notes_data = NotesData {
-                        text: notes.clone(),
-                        boss_name: boss.name.clone(),
-                    };
-                    let _ = self.overlay_tx.try_send(OverlayUpdate::NotesUpdated(notes_data));
-                    return;
-                }
+            if let Some((notes_data, _)) = boss_notes_data(boss, None) {
+                let _ = self.overlay_tx.try_send(OverlayUpdate::NotesUpdated(notes_data));
+                return;
             }
         }
         
@@ -1279,6 +1482,42 @@ impl CombatService {
         let _ = self.app_handle.emit("session-updated", "WatcherStarted");
     }
 
+    /// Watch the user definitions directory and hot-reload edited TOML files.
+    /// Files that fail to parse are reported to the frontend instead of loaded.
+    fn start_definition_watcher(&mut self) {
+        let Some(dir) = dirs::config_dir().map(|p| p.join("baras").join("definitions")) else {
+            return;
+        };
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            warn!(directory = %dir.display(), error = %e, "Failed to create definitions directory");
+            return;
+        }
+        let mut watcher = match DefinitionWatcher::new(&dir) {
+            Ok(w) => w,
+            Err(e) => {
+                warn!(directory = %dir.display(), error = %e, "Failed to watch definitions directory");
+                return;
+            }
+        };
+
+        let cmd_tx = self.cmd_tx.clone();
+        let app_handle = self.app_handle.clone();
+        let handle = tokio::spawn(async move {
+            while let Some(changes) = watcher.next_changes().await {
+                let (commands, errors) = directory::check_definition_changes(&changes);
+                for e in errors {
+                    let _ = app_handle.emit("definitions-error", e);
+                }
+                for cmd in commands {
+                    if cmd_tx.send(cmd).await.is_err() {
+                        return; // Service shut down
+                    }
+                }
+            }
+        });
+        self.definitions_watch_handle = Some(handle);
+    }
+
     async fn start_tailing(&mut self, path: PathBuf) {
         info!(path = %path.display(), "Starting to tail log file");
         self.stop_tailing().await;
@@ -1300,6 +1539,13 @@ impl CombatService {
         let (session_event_tx, session_event_rx) = std::sync::mpsc::channel::<SessionEvent>();
 
         let mut session = ParsingSession::new(path.clone(), self.definitions.clone());
+        {
+            let config = self.shared.config.read().await;
+            session.set_dummy_parse_limit(
+                config.dummy_parse.enabled.then_some(config.dummy_parse.limit),
+            );
+            session.set_client_language(config.client_language);
+        }
 
         // Load timer preferences into the session's timer manager (Live mode only)
         if let Some(prefs_path) = Self::timer_preferences_path() {
@@ -1459,6 +1705,15 @@ impl CombatService {
                         if let Some(cache) = &mut session_guard.session_cache {
                             // Restore player, area, disciplines, and encounter history
                             let generation_count = cache.restore_from_worker_output(&parse_result);
+
+                            // Rank later pulls against the boss pulls in this file
+                            if cache.player_initialized {
+                                record_personal_history(
+                                    &self.shared,
+                                    resolve(cache.player.name),
+                                    &parse_result.encounters,
+                                );
+                            }
                             
                             debug!(
                                 area_id = parse_result.area.area_id,
@@ -1531,14 +1786,20 @@ impl CombatService {
                             }
                         }
 
-                        // Sync player context to effect tracker so discipline-scoped
-                        // effects work immediately (tracker missed historical signals)
+                        // Sync player context to effect tracker and timer manager so
+                        // discipline/role-scoped definitions work immediately
+                        // (they missed historical signals)
                         if let Some((player_id, discipline_id)) = player_context {
                             if let Some(tracker) = session_guard.effect_tracker() {
                                 if let Ok(mut tracker) = tracker.lock() {
                                     tracker.set_player_context(player_id, discipline_id);
                                 }
                             }
+                            if let Some(timer_mgr) = session_guard.timer_manager() {
+                                if let Ok(mut timer_mgr) = timer_mgr.lock() {
+                                    timer_mgr.set_player_context(player_id, discipline_id);
+                                }
+                            }
                         }
 
                         // Enable live parquet writing (continues from where subprocess left off)
@@ -1656,7 +1917,7 @@ impl CombatService {
         // Trigger initial metrics send after file processing
         let _ = trigger_tx.try_send(MetricsTrigger::InitialLoad);
 
-        // Set alacrity/latency from config for duration calculations
+        // Configured alacrity/latency for durations until they can be inferred from casts
         {
             let session_guard = session.read().await;
             let config = self.shared.config.read().await;
@@ -1736,6 +1997,7 @@ impl CombatService {
         let effects_handle = tokio::spawn(async move {
             // Track previous state to avoid redundant updates
             let mut last_raid_effect_count: usize = 0;
+            let mut last_raid_hp: Vec<u8> = Vec::new();
             let _last_effects_count: usize = 0;
 
             // Track previous state for new overlays to avoid redundant updates
@@ -1744,6 +2006,9 @@ impl CombatService {
             let mut last_cooldowns_count: usize = 0;
             let mut last_dot_tracker_count: usize = 0;
 
+            // Last time the loop saw combat (for "hide after N seconds out of combat")
+            let mut last_in_combat = std::time::Instant::now();
+
             loop {
                 // Check which overlays are active to determine sleep interval
                 let raid_active = shared.raid_overlay_active.load(Ordering::Relaxed);
@@ -1764,7 +2029,8 @@ impl CombatService {
                     || effects_b_active
                     || cooldowns_active
                     || dot_tracker_active;
-                let needs_audio = is_live && (in_combat || raid_active);
+                let manual_timers = shared.manual_timers_running.load(Ordering::Relaxed);
+                let needs_audio = is_live && (in_combat || raid_active || manual_timers);
 
                 // Adaptive sleep: fast when active, slow when idle
                 // 30ms matches tail polling for consistent ~60ms max latency
@@ -1775,6 +2041,32 @@ impl CombatService {
                 };
                 tokio::time::sleep(std::time::Duration::from_millis(sleep_ms)).await;
 
+                // Per-overlay visibility conditions (checked before the idle skip,
+                // since hidden overlays are exactly the ones not marked active)
+                if in_combat {
+                    last_in_combat = std::time::Instant::now();
+                }
+                let conditions = shared
+                    .config
+                    .read()
+                    .await
+                    .overlay_settings
+                    .visibility_conditions
+                    .clone();
+                let hidden = if conditions.is_empty() {
+                    HashSet::new()
+                } else {
+                    let ctx = build_visibility_context(&shared, last_in_combat).await;
+                    hidden_overlays(&conditions, &ctx)
+                };
+                if shared.set_conditionally_hidden(hidden)
+                    && overlay_tx
+                        .try_send(OverlayUpdate::VisibilityConditionsChanged)
+                        .is_err()
+                {
+                    warn!("Overlay channel full, dropped visibility conditions update");
+                }
+
                 // Skip processing if nothing needs updating
                 if !any_overlay_active && !needs_audio {
                     continue;
@@ -1785,13 +2077,25 @@ impl CombatService {
                     let rearranging = shared.rearrange_mode.load(Ordering::Relaxed);
                     if let Some(data) = build_raid_frame_data(&shared, rearranging, icon_cache.as_ref()).await {
                         let effect_count: usize = data.frames.iter().map(|f| f.effects.len()).sum();
+                        // Whole percents are enough to redraw HP bars without sending every tick
+                        let hp: Vec<u8> = data
+                            .frames
+                            .iter()
+                            .map(|f| (f.hp_percent * 100.0).round() as u8)
+                            .collect();
                         // Always send in rearrange mode, otherwise only when effects exist/changed
-                        if rearranging || effect_count > 0 || last_raid_effect_count > 0 {
+                        // or someone's HP moved
+                        if rearranging
+                            || effect_count > 0
+                            || last_raid_effect_count > 0
+                            || hp != last_raid_hp
+                        {
                             if overlay_tx.try_send(OverlayUpdate::EffectsUpdated(data)).is_err() {
                                 warn!("Overlay channel full, dropped raid effects update");
                             }
                         }
                         last_raid_effect_count = effect_count;
+                        last_raid_hp = hp;
                     } else if rearranging {
                         // In rearrange mode, send empty data to keep overlay rendering
                         if overlay_tx.try_send(OverlayUpdate::EffectsUpdated(
@@ -1800,8 +2104,10 @@ impl CombatService {
                             warn!("Overlay channel full, dropped raid effects clear");
                         }
                         last_raid_effect_count = 0;
+                        last_raid_hp.clear();
                     } else {
                         last_raid_effect_count = 0;
+                        last_raid_hp.clear();
                     }
                 }
                 // Effects A: only send if there are effects or effects just cleared
@@ -1925,8 +2231,11 @@ impl CombatService {
                     if let Some((timers_a, timers_b, countdowns, alerts)) =
                         build_timer_data_with_audio(&shared).await
                     {
-                        // Send timer overlay data (only when in combat)
-                        if in_combat && timer_active {
+                        // Send timer overlay data when in combat, or out of combat while
+                        // hotkey-started timers run (plus one final update to clear them)
+                        let has_timers =
+                            !timers_a.entries.is_empty() || !timers_b.entries.is_empty();
+                        if timer_active && (in_combat || has_timers || manual_timers) {
                             if overlay_tx.try_send(OverlayUpdate::TimersAUpdated(timers_a)).is_err() {
                                 warn!("Overlay channel full, dropped timers A update");
                             }
@@ -1934,16 +2243,17 @@ impl CombatService {
                                 warn!("Overlay channel full, dropped timers B update");
                             }
                         }
+                        if manual_timers && !in_combat && !has_timers {
+                            shared.manual_timers_running.store(false, Ordering::Relaxed);
+                        }
 
-                        // Send countdown audio events (only when in combat)
-                        if in_combat {
-                            for (name, seconds, voice_pack) in countdowns {
-                                let _ = audio_tx.try_send(AudioEvent::Countdown {
-                                    timer_name: name,
-                                    seconds,
-                                    voice_pack,
-                                });
-                            }
+                        // Send countdown audio events (only checked in combat or for manual timers)
+                        for (name, seconds, voice_pack) in countdowns {
+                            let _ = audio_tx.try_send(AudioEvent::Countdown {
+                                timer_name: name,
+                                seconds,
+                                voice_pack,
+                            });
                         }
 
                         // Send text alerts to overlay (only those with alert_text_enabled and not stale)
@@ -2003,6 +2313,7 @@ impl CombatService {
             let _ = handle.await;
         }
 
+        self.save_observed_names().await;
         *self.shared.session.write().await = None;
     }
 
@@ -2046,6 +2357,71 @@ impl CombatService {
         }
     }
 
+    /// Record the last finished pull in the personal history and announce
+    /// any personal best it set (live tailing only)
+    async fn record_personal_best(&mut self) {
+        let (character, summary) = {
+            let session_guard = self.shared.session.read().await;
+            let Some(session) = session_guard.as_ref() else {
+                return;
+            };
+            let session = session.read().await;
+            let Some(cache) = session.session_cache.as_ref() else {
+                return;
+            };
+            let Some(summary) = cache.encounter_history.summaries().last().cloned() else {
+                return;
+            };
+            (resolve(cache.player.name).to_string(), summary)
+        };
+
+        let Some(rank) =
+            record_personal_history(&self.shared, &character, std::slice::from_ref(&summary)).pop()
+        else {
+            return;
+        };
+        let bests = rank.new_bests();
+        if bests.is_empty() || !self.shared.is_live_tailing.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let european = self.shared.config.read().await.european_number_format;
+        let improvements: Vec<String> = bests
+            .iter()
+            .map(|(label, metric)| {
+                format!(
+                    "{} {} (was {})",
+                    label,
+                    formatting::format_compact(metric.value, european),
+                    formatting::format_compact(metric.best, european)
+                )
+            })
+            .collect();
+        let text = format!(
+            "New personal best on {}: {}",
+            summary.boss_name.as_deref().unwrap_or_default(),
+            improvements.join(", ")
+        );
+        info!(character = %character, "{}", text);
+
+        let alert = FiredAlert {
+            id: "personal_best".to_string(),
+            name: "New personal best".to_string(),
+            text: text.clone(),
+            color: Some([255, 215, 0, 255]),
+            timestamp: chrono::Local::now().naive_local(),
+            alert_text_enabled: true,
+            audio_enabled: true,
+            audio_file: None,
+        };
+        let _ = self.overlay_tx.try_send(OverlayUpdate::AlertsFired(vec![alert]));
+        let _ = self.audio_tx.try_send(AudioEvent::Alert {
+            text: "New personal best".to_string(),
+            custom_sound: None,
+        });
+        let _ = self.app_handle.emit("personal-best", text);
+    }
+
     async fn refresh_index(&mut self) {
         let log_dir = self.shared.config.read().await.log_directory.clone();
         if let Ok(index) = DirectoryIndex::build_index(&PathBuf::from(&log_dir)) {
@@ -2054,6 +2430,31 @@ impl CombatService {
     }
 }
 
+/// Record the character's boss pulls from these summaries in the personal
+/// history (saved to disk if anything new was recorded). Returns the ranks
+/// of the newly recorded pulls.
+fn record_personal_history(
+    shared: &SharedState,
+    character: &str,
+    summaries: &[EncounterSummary],
+) -> Vec<PersonalRank> {
+    let mut history = shared
+        .personal_history
+        .lock()
+        .unwrap_or_else(|p| p.into_inner());
+    let ranks: Vec<PersonalRank> = summaries
+        .iter()
+        .filter_map(|summary| history.record(character, summary))
+        .collect();
+    if !ranks.is_empty()
+        && let Some(path) = default_history_path()
+        && let Err(e) = history.save_to_disk(&path)
+    {
+        warn!(error = %e, "Failed to save personal history");
+    }
+    ranks
+}
+
 /// Calculate unified combat data for all overlays
 async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData> {
     let session_guard = shared.session.read().await;
@@ -2112,6 +2513,12 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
                 .summaries()
                 .last()
                 .map(|s| s.display_name.clone())
+        } else if encounter_type == PhaseType::PvP {
+            // PvP fights are numbered per match, never by boss
+            let area_entered_line = encounter
+                .area_entered_line
+                .or(cache.current_area.entered_at_line);
+            Some(cache.encounter_history.peek_pvp_name(area_entered_line))
         } else if let Some(def) = encounter.active_boss_definition() {
             // Definition is active - use definition name with pull count
             let pull_count = cache.encounter_history.peek_pull_count(&def.name);
@@ -2252,6 +2659,42 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
             })
             .unwrap_or(0.0);
 
+        // Rank the pull against the player's own history (boss pulls only)
+        let personal_rank = {
+            let history = shared
+                .personal_history
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            if is_finalized {
+                let character = resolve(player_info.name);
+                cache
+                    .encounter_history
+                    .summaries()
+                    .last()
+                    .and_then(|s| history.rank_summary(character, s))
+                    .map(Box::new)
+            } else {
+                let boss_name = encounter
+                    .active_boss_definition()
+                    .map(|def| def.name.clone())
+                    .or_else(|| boss_info.map(|b| b.boss.to_string()));
+                // Same difficulty as the summary will get
+                let pull_difficulty = encounter.difficulty_name.clone().or(difficulty.clone());
+                let player = metrics.iter().find(|m| m.entity_id == player_entity_id);
+                match (encounter_type, boss_name, player) {
+                    (PhaseType::PvP | PhaseType::DummyParse, _, _) => None,
+                    (_, Some(boss), Some(player)) => {
+                        Some(Box::new(history.rank_live(
+                            &boss,
+                            pull_difficulty.as_deref(),
+                            player,
+                        )))
+                    }
+                    _ => None,
+                }
+            }
+        };
+
         Some(CombatData {
             metrics,
             player_entity_id,
@@ -2263,6 +2706,7 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
             challenges,
             current_phase,
             phase_time_secs,
+            personal_rank,
         })
     } else if let Some(summary) = cache.encounter_history.summaries().last() {
         // Fallback to historical summary for initial hydration when no live encounter exists
@@ -2283,6 +2727,12 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
             challenges: None,
             current_phase: None,
             phase_time_secs: 0.0,
+            personal_rank: shared
+                .personal_history
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .rank_summary(resolve(player_info.name), summary)
+                .map(Box::new),
         })
     } else {
         None
@@ -2331,6 +2781,12 @@ async fn build_raid_frame_data(
         .map(|c| c.player.id)
         .unwrap_or(0);
 
+    // Last known HP comes from the current encounter's player table
+    let encounter = session
+        .session_cache
+        .as_ref()
+        .and_then(|c| c.current_encounter());
+
     // Process new targets queue - these are entities that JUST received an effect from local player
     // The registry handles duplicate rejection via try_register
     for target in tracker.take_new_targets() {
@@ -2392,7 +2848,10 @@ async fn build_raid_frame_data(
                 slot,
                 player_id: Some(player.entity_id),
                 name: player.name.clone(),
-                hp_percent: 1.0,
+                hp_percent: encounter
+                    .and_then(|enc| enc.players.get(&player.entity_id))
+                    .map(|p| p.hp_percent() / 100.0)
+                    .unwrap_or(1.0),
                 role,
                 class_icon,
                 effects,
@@ -2404,6 +2863,45 @@ async fn build_raid_frame_data(
     Some(RaidFrameData { frames })
 }
 
+/// Snapshot of the live game state for overlay visibility conditions
+async fn build_visibility_context(
+    shared: &Arc<SharedState>,
+    last_in_combat: std::time::Instant,
+) -> OverlayVisibilityContext {
+    let in_combat = shared.in_combat.load(Ordering::SeqCst);
+    let mut ctx = OverlayVisibilityContext {
+        in_combat,
+        secs_out_of_combat: last_in_combat.elapsed().as_secs_f32(),
+        area_id: shared.current_area_id.load(Ordering::SeqCst),
+        ..Default::default()
+    };
+
+    let session_guard = shared.session.read().await;
+    let Some(session) = session_guard.as_ref() else {
+        return ctx;
+    };
+    let session = session.read().await;
+    let Some(cache) = session.session_cache.as_ref() else {
+        return ctx;
+    };
+
+    if cache.player_initialized {
+        ctx.discipline = cache
+            .player_disciplines
+            .get(&cache.player.id)
+            .and_then(|p| Discipline::from_guid(p.discipline_id));
+    }
+    if in_combat
+        && let Some(def) = cache
+            .current_encounter()
+            .and_then(|enc| enc.active_boss_definition())
+    {
+        ctx.boss_encounter = true;
+        ctx.training_dummy = def.area_type == AreaType::TrainingDummy;
+    }
+    ctx
+}
+
 /// Build boss health data from the current encounter
 async fn build_boss_health_data(shared: &Arc<SharedState>) -> Option<BossHealthData> {
     let session_guard = shared.session.read().await;
@@ -2417,7 +2915,11 @@ async fn build_boss_health_data(shared: &Arc<SharedState>) -> Option<BossHealthD
         return Some(BossHealthData::default());
     }
 
-    let entries = cache.get_boss_health();
+    let name_language = shared.config.read().await.name_language;
+    let mut entries = cache.get_boss_health();
+    for entry in &mut entries {
+        entry.name = baras_core::game_data::display_name(entry.class_id, &entry.name, name_language);
+    }
     Some(BossHealthData { entries })
 }
 
@@ -2452,9 +2954,10 @@ async fn build_timer_data_with_audio(
         alerts.extend(tracker.take_fired_alerts());
     }
 
-    // If not in combat, return only alerts (no countdown checks)
+    // If not in combat, return only alerts (no countdown checks).
+    // CombatEnded clears all timers, so anything still running was started by a hotkey.
     let in_combat = shared.in_combat.load(Ordering::SeqCst);
-    if !in_combat {
+    if !in_combat && timer_mgr.active_timers().is_empty() {
         return Some((
             TimerData::default(),
             TimerData::default(),
@@ -2540,7 +3043,7 @@ async fn process_effect_audio(shared: &std::sync::Arc<SharedState>) -> EffectAud
 
     for effect in tracker.active_effects_mut() {
         // Check for text alert on expiration (independent of audio settings)
-        if let Some(text) = effect.check_expiration_alert().map(|s| s.to_string()) {
+        if let Some(text) = effect.check_expiration_alert() {
             text_alerts.push(FiredAlert {
                 id: effect.definition_id.clone(),
                 name: effect.name.clone(),
@@ -2967,6 +3470,8 @@ pub struct CombatData {
     pub current_phase: Option<String>,
     /// Time spent in the current phase (seconds)
     pub phase_time_secs: f32,
+    /// Current boss pull against the player's own history
+    pub personal_rank: Option<Box<PersonalRank>>,
 }
 
 impl CombatData {
@@ -3006,6 +3511,7 @@ impl CombatData {
             total_shield_absorbed: player.total_shield_absorbed,
             current_phase: self.current_phase.clone(),
             phase_time_secs: self.phase_time_secs,
+            personal_rank: self.personal_rank.clone(),
         })
     }
 }
@@ -3026,4 +3532,8 @@ pub struct SessionInfo {
     pub duration_formatted: Option<String>,
     /// True if the log file's last event is older than 30 minutes (no active session)
     pub stale_session: bool,
+    /// Local player's alacrity inferred from activation spacing (None until enough casts)
+    pub player_alacrity_percent: Option<f32>,
+    /// Local player's latency inferred from activation spacing (None until enough casts)
+    pub player_latency_ms: Option<u16>,
 }
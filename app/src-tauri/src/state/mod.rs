@@ -8,11 +8,15 @@ mod raid_registry;
 
 pub use raid_registry::{RaidSlotRegistry, RegisteredPlayer};
 
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 
 use baras_core::context::{AppConfig, DirectoryIndex, LogAreaCache, ParsingSession};
+use baras_core::encounter::dummy::{DummyParse, DummyParseLimit};
+use baras_core::encounter::annotations::{AnnotationStore, default_annotations_path};
+use baras_core::encounter::personal_best::{PersonalHistory, default_history_path};
 use baras_core::query::QueryContext;
 
 /// State shared between the combat service and Tauri commands.
@@ -58,6 +62,8 @@ pub struct SharedState {
     pub dot_tracker_overlay_active: AtomicBool,
     /// Whether raid frame rearrange mode is active (bypasses rendering gates)
     pub rearrange_mode: AtomicBool,
+    /// Whether hotkey-started timers may be running out of combat (keeps timer audio polling)
+    pub manual_timers_running: AtomicBool,
 
     // ─── Conversation auto-hide state ───────────────────────────────────────
     /// Whether overlays are temporarily hidden due to conversation
@@ -71,11 +77,25 @@ pub struct SharedState {
     /// Whether overlays were visible before not-live hide triggered (for restore)
     pub overlays_visible_before_not_live: AtomicBool,
 
+    // ─── Conditional visibility state ────────────────────────────────────────
+    /// Config keys of overlays hidden because their visibility conditions fail
+    pub conditionally_hidden: Mutex<HashSet<String>>,
+
     /// Shared query context for DataFusion queries (reuses SessionContext)
     pub query_context: QueryContext,
 
     /// Cache of area indexes for log files (persisted to disk)
     pub area_cache: RwLock<LogAreaCache>,
+
+    /// Dummy parses completed live this run, with the limit they were cut at
+    /// (the file being tailed isn't in the area cache yet)
+    pub live_dummy_parses: Mutex<Vec<(DummyParseLimit, DummyParse)>>,
+
+    /// Recorded boss pulls for personal-best ranking (persisted to disk)
+    pub personal_history: Mutex<PersonalHistory>,
+
+    /// Notes pinned to encounters (persisted to disk)
+    pub annotations: Mutex<AnnotationStore>,
 }
 
 impl SharedState {
@@ -99,16 +119,29 @@ impl SharedState {
             cooldowns_overlay_active: AtomicBool::new(false),
             dot_tracker_overlay_active: AtomicBool::new(false),
             rearrange_mode: AtomicBool::new(false),
+            manual_timers_running: AtomicBool::new(false),
             // Conversation auto-hide state
             conversation_hiding_active: AtomicBool::new(false),
             overlays_visible_before_conversation: AtomicBool::new(false),
             // Not-live auto-hide state
             not_live_hiding_active: AtomicBool::new(false),
             overlays_visible_before_not_live: AtomicBool::new(false),
+            conditionally_hidden: Mutex::new(HashSet::new()),
             // Shared query context for DataFusion (reuses SessionContext across queries)
             query_context: QueryContext::new(),
             // Area cache - loaded from disk later in service startup
             area_cache: RwLock::new(LogAreaCache::new()),
+            live_dummy_parses: Mutex::new(Vec::new()),
+            personal_history: Mutex::new(
+                default_history_path()
+                    .map(|path| PersonalHistory::load_from_disk(&path))
+                    .unwrap_or_default(),
+            ),
+            annotations: Mutex::new(
+                default_annotations_path()
+                    .map(|path| AnnotationStore::load_from_disk(&path))
+                    .unwrap_or_default(),
+            ),
         }
     }
 
@@ -167,6 +200,27 @@ impl SharedState {
         was_visible
     }
 
+    /// Replace the set of conditionally hidden overlays. Returns whether it changed.
+    pub fn set_conditionally_hidden(&self, hidden: HashSet<String>) -> bool {
+        let mut current = self
+            .conditionally_hidden
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        if *current == hidden {
+            return false;
+        }
+        *current = hidden;
+        true
+    }
+
+    /// Whether an overlay is hidden by its visibility conditions
+    pub fn is_conditionally_hidden(&self, key: &str) -> bool {
+        self.conditionally_hidden
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .contains(key)
+    }
+
     /// Execute a function with mutable access to the current session.
     /// Returns `None` if no session is active.
     pub async fn with_session<F, T>(&self, f: F) -> Option<T>
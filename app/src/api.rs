@@ -7,7 +7,10 @@ use serde::Serialize;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::prelude::*;
 
-use crate::types::{AppConfig, OverlayStatus, OverlayType, SessionInfo};
+use crate::types::{
+    AppConfig, DummyParseHistory, OverlayStatus, OverlayType, PersonalRank, PvpMatch,
+    SessionInfo,
+};
 use crate::utils::js_set;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -403,11 +406,29 @@ pub async fn get_encounter_history()
     from_js(result)
 }
 
+/// Get training dummy parse history per character and discipline
+pub async fn get_dummy_parse_history() -> Option<Vec<DummyParseHistory>> {
+    let result = invoke("get_dummy_parse_history", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Get personal-best ranks for the boss pulls in the current log file
+pub async fn get_personal_ranks() -> Option<Vec<PersonalRank>> {
+    let result = invoke("get_personal_ranks", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Get PvP matches in the current log file with their scoreboards
+pub async fn get_pvp_matches() -> Option<Vec<PvpMatch>> {
+    let result = invoke("get_pvp_matches", JsValue::NULL).await;
+    from_js(result)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Unified Encounter Item Commands (NEW - replaces type-specific commands)
 // ─────────────────────────────────────────────────────────────────────────────
 
-use crate::types::{BossWithPath, EncounterItem};
+use crate::types::{BossWithPath, EncounterItem, LintIssue};
 
 /// Fetch all bosses for an area file with full encounter data
 pub async fn fetch_area_bosses(file_path: &str) -> Option<Vec<BossWithPath>> {
@@ -416,6 +437,21 @@ pub async fn fetch_area_bosses(file_path: &str) -> Option<Vec<BossWithPath>> {
     from_js(result)
 }
 
+/// Statically check boss definitions in an area file (optionally one boss)
+pub async fn lint_encounter_definitions(
+    file_path: &str,
+    boss_id: Option<&str>,
+) -> Result<Vec<LintIssue>, String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "filePath", &JsValue::from_str(file_path));
+    match boss_id {
+        Some(id) => js_set(&obj, "bossId", &JsValue::from_str(id)),
+        None => js_set(&obj, "bossId", &JsValue::NULL),
+    }
+    let result = try_invoke("lint_encounter_definitions", obj.into()).await?;
+    from_js(result).ok_or_else(|| "Failed to parse lint results".to_string())
+}
+
 /// Create a new encounter item (timer, phase, counter, challenge, or entity)
 pub async fn create_encounter_item(
     boss_id: &str,
@@ -593,6 +629,28 @@ pub async fn save_file_dialog(default_name: &str) -> Option<String> {
     result.as_string()
 }
 
+/// Open a native save dialog for a CSV or parquet export, returns the file path or None
+pub async fn save_table_dialog(default_name: &str, format: ExportFormat) -> Option<String> {
+    let options = js_sys::Object::new();
+    js_set(&options, "defaultPath", &JsValue::from_str(default_name));
+
+    let filter = js_sys::Object::new();
+    let name = match format {
+        ExportFormat::Csv => "CSV files",
+        ExportFormat::Parquet => "Parquet files",
+    };
+    js_set(&filter, "name", &JsValue::from_str(name));
+    let exts = js_sys::Array::new();
+    exts.push(&JsValue::from_str(format.extension()));
+    js_set(&filter, "extensions", &exts);
+    let filters = js_sys::Array::new();
+    filters.push(&filter);
+    js_set(&options, "filters", &filters);
+
+    let result = save_dialog(options.into()).await;
+    result.as_string()
+}
+
 /// Open a native file dialog for .toml files, returns the file path or None
 pub async fn open_toml_file_dialog() -> Option<String> {
     let options = js_sys::Object::new();
@@ -821,6 +879,88 @@ pub async fn set_encounter_parsely_link(encounter_id: u64, link: &str) -> Result
     from_js(result).ok_or_else(|| "Failed to parse response".to_string())
 }
 
+/// Get the notes pinned to an encounter
+pub async fn get_encounter_annotations(encounter_id: u64) -> Vec<EncounterAnnotation> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "encounterId", &JsValue::from_f64(encounter_id as f64));
+    let result = invoke("get_encounter_annotations", obj.into()).await;
+    from_js(result).unwrap_or_default()
+}
+
+/// Pin a note to an encounter. Returns the saved note.
+pub async fn add_encounter_annotation(
+    encounter_id: u64,
+    annotation: &EncounterAnnotation,
+) -> Result<EncounterAnnotation, String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "encounterId", &JsValue::from_f64(encounter_id as f64));
+    let annotation_js = serde_wasm_bindgen::to_value(annotation).unwrap_or(JsValue::NULL);
+    js_set(&obj, "annotation", &annotation_js);
+    let result = try_invoke("add_encounter_annotation", obj.into()).await?;
+    from_js(result).ok_or_else(|| "Failed to parse response".to_string())
+}
+
+/// Change the text of an encounter note
+pub async fn update_encounter_annotation(
+    encounter_id: u64,
+    annotation_id: u64,
+    text: &str,
+) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "encounterId", &JsValue::from_f64(encounter_id as f64));
+    js_set(&obj, "annotationId", &JsValue::from_f64(annotation_id as f64));
+    js_set(&obj, "text", &JsValue::from_str(text));
+    try_invoke("update_encounter_annotation", obj.into()).await?;
+    Ok(())
+}
+
+/// Delete an encounter note
+pub async fn remove_encounter_annotation(
+    encounter_id: u64,
+    annotation_id: u64,
+) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "encounterId", &JsValue::from_f64(encounter_id as f64));
+    js_set(&obj, "annotationId", &JsValue::from_f64(annotation_id as f64));
+    try_invoke("remove_encounter_annotation", obj.into()).await?;
+    Ok(())
+}
+
+/// Write an HTML report (and a Markdown summary next to it) for one encounter,
+/// or every encounter of the session when `encounter_idx` is None.
+/// Returns the Markdown file's path.
+pub async fn export_encounter_report(
+    encounter_idx: Option<u32>,
+    path: &str,
+) -> Result<String, String> {
+    let obj = js_sys::Object::new();
+    match encounter_idx {
+        Some(idx) => js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64)),
+        None => js_set(&obj, "encounterIdx", &JsValue::NULL),
+    }
+    js_set(&obj, "path", &JsValue::from_str(path));
+    let result = try_invoke("export_encounter_report", obj.into()).await?;
+    from_js(result).ok_or_else(|| "Failed to parse export result".to_string())
+}
+
+/// Open a native save dialog for an HTML report, returns the file path or None
+pub async fn save_report_dialog(default_name: &str) -> Option<String> {
+    let options = js_sys::Object::new();
+    js_set(&options, "defaultPath", &JsValue::from_str(default_name));
+
+    let filter = js_sys::Object::new();
+    js_set(&filter, "name", &JsValue::from_str("HTML files"));
+    let exts = js_sys::Array::new();
+    exts.push(&JsValue::from_str("html"));
+    js_set(&filter, "extensions", &exts);
+    let filters = js_sys::Array::new();
+    filters.push(&filter);
+    js_set(&options, "filters", &filters);
+
+    let result = save_dialog(options.into()).await;
+    result.as_string()
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Audio File Picker
 // ─────────────────────────────────────────────────────────────────────────────
@@ -866,10 +1006,12 @@ pub async fn install_update() -> Result<(), String> {
 // Re-export query types from shared types crate
 pub use baras_types::{
     AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow,
-    DamageTakenSummary, DataTab, EffectChartData, EffectWindow, EncounterTimeline,
-    EntityBreakdown, GcdSlot, GroupedEntityNames, HpPoint, NpcHealthRow, PhaseSegment,
-    PlayerDeath, RaidOverviewRow, RotationAnalysis, RotationCycle, RotationEvent, TimeRange,
-    TimeSeriesPoint,
+    CooldownEfficiencyRow, DamageTakenSummary, DataTab, EffectChartData, EffectWindow,
+    EncounterAnnotation, EncounterTimeline, EntityBreakdown, ExportFormat, ExportSource,
+    GcdGapContext, GcdGapKind, GcdSlot, GcdUptimeAnalysis, GroupedEntityNames, HealingMatrix,
+    HpPoint, NpcHealthRow, PhaseSegment, PlayerDeath, RaidOverviewRow, RotationAnalysis,
+    RotationCycle, RotationDeviation, RotationDeviationKind, RotationEvent, TimeRange,
+    TimeSeriesMetric, TimeSeriesPoint,
 };
 
 /// Query ability breakdown for an encounter and data tab.
@@ -1319,6 +1461,12 @@ pub async fn query_combat_log_count(
     from_js(result)
 }
 
+/// Check combat log filter syntax. Returns the syntax error, if any.
+pub async fn validate_combat_log_filter(filter: &str) -> Result<(), String> {
+    try_invoke("validate_combat_log_filter", build_args("filter", &filter)).await?;
+    Ok(())
+}
+
 /// Find matching rows in combat log (returns position and row_idx).
 pub async fn query_combat_log_find(
     encounter_idx: Option<u32>,
@@ -1456,6 +1604,106 @@ pub async fn query_rotation(
     from_js(result)
 }
 
+/// Query GCD uptime ("always be casting") and downtime gaps for a player.
+pub async fn query_gcd_uptime(
+    encounter_idx: Option<u32>,
+    source_name: &str,
+    time_range: Option<&TimeRange>,
+) -> Option<GcdUptimeAnalysis> {
+    let obj = js_sys::Object::new();
+    if let Some(idx) = encounter_idx {
+        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    } else {
+        js_set(&obj, "encounterIdx", &JsValue::NULL);
+    }
+    js_set(&obj, "sourceName", &JsValue::from_str(source_name));
+    if let Some(tr) = time_range {
+        let tr_js = serde_wasm_bindgen::to_value(tr).unwrap_or(JsValue::NULL);
+        js_set(&obj, "timeRange", &tr_js);
+    } else {
+        js_set(&obj, "timeRange", &JsValue::NULL);
+    }
+    let result = invoke("query_gcd_uptime", obj.into()).await;
+    from_js(result)
+}
+
+/// Query the healer × recipient healing matrix for an encounter.
+pub async fn query_healing_matrix(
+    encounter_idx: Option<u32>,
+    time_range: Option<&TimeRange>,
+) -> Option<HealingMatrix> {
+    let obj = js_sys::Object::new();
+    if let Some(idx) = encounter_idx {
+        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    } else {
+        js_set(&obj, "encounterIdx", &JsValue::NULL);
+    }
+    if let Some(tr) = time_range {
+        let tr_js = serde_wasm_bindgen::to_value(tr).unwrap_or(JsValue::NULL);
+        js_set(&obj, "timeRange", &tr_js);
+    } else {
+        js_set(&obj, "timeRange", &JsValue::NULL);
+    }
+    let result = invoke("query_healing_matrix", obj.into()).await;
+    from_js(result)
+}
+
+/// Export a Data Explorer table or chart to CSV or parquet. Returns rows written.
+pub async fn export_table(
+    encounter_idx: Option<u32>,
+    source: &ExportSource,
+    time_range: Option<&TimeRange>,
+    duration_secs: Option<f32>,
+    format: ExportFormat,
+    path: &str,
+) -> Result<usize, String> {
+    let obj = js_sys::Object::new();
+    if let Some(idx) = encounter_idx {
+        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    } else {
+        js_set(&obj, "encounterIdx", &JsValue::NULL);
+    }
+    let source_js = serde_wasm_bindgen::to_value(source).unwrap_or(JsValue::NULL);
+    js_set(&obj, "source", &source_js);
+    if let Some(tr) = time_range {
+        let tr_js = serde_wasm_bindgen::to_value(tr).unwrap_or(JsValue::NULL);
+        js_set(&obj, "timeRange", &tr_js);
+    } else {
+        js_set(&obj, "timeRange", &JsValue::NULL);
+    }
+    if let Some(d) = duration_secs {
+        js_set(&obj, "durationSecs", &JsValue::from_f64(d as f64));
+    } else {
+        js_set(&obj, "durationSecs", &JsValue::NULL);
+    }
+    let format_js = serde_wasm_bindgen::to_value(&format).unwrap_or(JsValue::NULL);
+    js_set(&obj, "format", &format_js);
+    js_set(&obj, "path", &JsValue::from_str(path));
+    let result = try_invoke("export_table", obj.into()).await?;
+    from_js(result).ok_or_else(|| "Failed to parse export result".to_string())
+}
+
+/// Query cooldown usage efficiency for all players in an encounter.
+pub async fn query_cooldown_efficiency(
+    encounter_idx: Option<u32>,
+    time_range: Option<&TimeRange>,
+) -> Option<Vec<CooldownEfficiencyRow>> {
+    let obj = js_sys::Object::new();
+    if let Some(idx) = encounter_idx {
+        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    } else {
+        js_set(&obj, "encounterIdx", &JsValue::NULL);
+    }
+    if let Some(tr) = time_range {
+        let tr_js = serde_wasm_bindgen::to_value(tr).unwrap_or(JsValue::NULL);
+        js_set(&obj, "timeRange", &tr_js);
+    } else {
+        js_set(&obj, "timeRange", &JsValue::NULL);
+    }
+    let result = invoke("query_cooldown_efficiency", obj.into()).await;
+    from_js(result)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Changelog Commands
 // ─────────────────────────────────────────────────────────────────────────────
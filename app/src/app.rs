@@ -7,13 +7,13 @@ use wasm_bindgen_futures::spawn_local;
 
 use crate::api::{self, BossNotesInfo};
 use crate::components::{
-    DataExplorerPanel, EffectEditorPanel, EncounterEditorPanel, HistoryPanel,
-    HotkeyInput, ParselyUploadModal, SettingsPanel, ToastFrame, ToastSeverity, use_parsely_upload,
+    ActionHotkeysEditor, DataExplorerPanel, DummyParsePanel, EffectEditorPanel, EncounterEditorPanel, HistoryPanel,
+    HotkeyInput, ParselyUploadModal, PvpMatchPanel, SettingsPanel, ToastFrame, ToastSeverity, use_parsely_upload,
     use_parsely_upload_provider, use_toast, use_toast_provider,
 };
 use crate::types::{
-    CombatLogSessionState, DataExplorerState, EffectsEditorState, EncounterBuilderState,
-    LogFileInfo, MainTab, MetricType, OverlaySettings, OverlayStatus, OverlayType,
+    ActionHotkey, CombatLogSessionState, DataExplorerState, EffectsEditorState, EncounterBuilderState,
+    GameLanguage, LogFileInfo, MainTab, MetricType, OverlaySettings, OverlayStatus, OverlayType,
     SessionInfo, UiSessionState, UpdateInfo, ViewMode,
 };
 
@@ -89,6 +89,7 @@ pub fn App() -> Element {
     let mut hotkey_visibility = use_signal(String::new);
     let mut hotkey_move_mode = use_signal(String::new);
     let mut hotkey_rearrange = use_signal(String::new);
+    let mut hotkey_actions = use_signal(Vec::<ActionHotkey>::new);
     let mut hotkey_save_status = use_signal(String::new);
 
     // Log management state
@@ -103,6 +104,8 @@ pub fn App() -> Element {
     // Application settings
     let mut minimize_to_tray = use_signal(|| true);
     let mut european_number_format = use_signal(|| false);
+    let mut name_language = use_signal(GameLanguage::default);
+    let mut client_language = use_signal(|| None::<GameLanguage>);
     let mut app_version = use_signal(String::new);
 
     // Update state
@@ -122,6 +125,7 @@ pub fn App() -> Element {
     // Profile state
     let mut profile_names = use_signal(Vec::<String>::new);
     let mut active_profile = use_signal(|| None::<String>);
+    let mut auto_switched_profile = use_signal(|| None::<String>);
 
     // Parsely settings
     let mut parsely_username = use_signal(String::new);
@@ -146,6 +150,7 @@ pub fn App() -> Element {
             if let Some(v) = config.hotkeys.toggle_rearrange_mode {
                 hotkey_rearrange.set(v);
             }
+            hotkey_actions.set(config.hotkeys.actions);
             profile_names.set(config.profiles.iter().map(|p| p.name.clone()).collect());
             active_profile.set(config.active_profile_name);
             auto_delete_empty.set(config.auto_delete_empty_files);
@@ -155,6 +160,8 @@ pub fn App() -> Element {
             hide_small_log_files.set(config.hide_small_log_files);
             minimize_to_tray.set(config.minimize_to_tray);
             european_number_format.set(config.european_number_format);
+            name_language.set(config.name_language);
+            client_language.set(config.client_language);
             parsely_username.set(config.parsely.username);
             parsely_password.set(config.parsely.password);
             parsely_guild.set(config.parsely.guild);
@@ -369,6 +376,73 @@ pub fn App() -> Element {
         closure.forget();
     });
 
+    // Listen for hot-reload failures of hand-edited definition files
+    let mut definitions_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Some(msg) = payload.as_string()
+            {
+                definitions_toast.show(
+                    format!("Definitions not reloaded: {}", msg),
+                    ToastSeverity::Normal,
+                );
+            }
+        });
+        api::tauri_listen("definitions-error", &closure).await;
+        closure.forget();
+    });
+
+    // Listen for new personal bests on boss pulls
+    let mut personal_best_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Some(msg) = payload.as_string()
+            {
+                personal_best_toast.show(msg, ToastSeverity::Normal);
+            }
+        });
+        api::tauri_listen("personal-best", &closure).await;
+        closure.forget();
+    });
+
+    // Listen for automatic profile switches (profile switch rules)
+    let mut profile_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Some(name) = payload.as_string()
+            {
+                profile_toast.show(format!("Switched to profile '{}'", name), ToastSeverity::Normal);
+                let _ = auto_switched_profile.try_write().map(|mut w| *w = Some(name));
+            }
+        });
+        api::tauri_listen("profile-switched", &closure).await;
+        closure.forget();
+    });
+
+    // Sync header controls with the profile loaded by a switch rule
+    use_effect(move || {
+        let Some(name) = auto_switched_profile() else {
+            return;
+        };
+        active_profile.set(Some(name));
+        spawn(async move {
+            if let Some(cfg) = api::get_config().await {
+                overlay_settings.set(cfg.overlay_settings);
+            }
+            if let Some(status) = api::get_overlay_status().await {
+                apply_status(&status, &mut metric_overlays_enabled, &mut personal_enabled,
+                    &mut raid_enabled, &mut boss_health_enabled, &mut timers_enabled,
+                    &mut timers_b_enabled, &mut challenges_enabled, &mut alerts_enabled,
+                    &mut effects_a_enabled, &mut effects_b_enabled,
+                    &mut cooldowns_enabled, &mut dot_tracker_enabled, &mut notes_enabled,
+                    &mut overlays_visible, &mut move_mode, &mut rearrange_mode);
+            }
+        });
+    });
+
     // Listen for overlay status changes (from hotkeys or other sources)
     // This ensures UI buttons stay in sync when overlay state changes
     use_future(move || async move {
@@ -829,7 +903,10 @@ pub fn App() -> Element {
                                 // Right side: player stats + Parsely upload
                                 div { class: "session-toolbar-right",
                                     // Player stats for effect duration calculations
-                                    PlayerStatsBar {}
+                                    PlayerStatsBar {
+                                        alacrity_percent: info.player_alacrity_percent,
+                                        latency_ms: info.player_latency_ms,
+                                    }
 
                                     // Parsely upload button
                                     if !current_file.is_empty() {
@@ -996,6 +1073,10 @@ pub fn App() -> Element {
                     }
                     }
 
+                    DummyParsePanel { european: ui_state.read().european_number_format }
+
+                    PvpMatchPanel { european: ui_state.read().european_number_format }
+
                     div { class: "history-container-large", HistoryPanel { state: ui_state } }
                 }
 
@@ -1666,6 +1747,74 @@ pub fn App() -> Element {
                                     i { class: "fa-solid fa-triangle-exclamation" }
                                     strong { " Editor inputs still use '.' for decimals." }
                                 }
+                                div { class: "setting-row",
+                                    label { "Game name language" }
+                                    select {
+                                        value: name_language().code(),
+                                        onchange: move |e| {
+                                            let Some(lang) = GameLanguage::all()
+                                                .iter()
+                                                .copied()
+                                                .find(|l| l.code() == e.value())
+                                            else {
+                                                return;
+                                            };
+                                            name_language.set(lang);
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                if let Some(mut cfg) = api::get_config().await {
+                                                    cfg.name_language = lang;
+                                                    if let Err(err) = api::update_config(&cfg).await {
+                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                    }
+                                                }
+                                            });
+                                        },
+                                        for lang in GameLanguage::all() {
+                                            option {
+                                                value: lang.code(),
+                                                selected: *lang == name_language(),
+                                                "{lang.label()}"
+                                            }
+                                        }
+                                    }
+                                }
+                                p { class: "hint", "Language for ability and boss names, learned from your own logs. Logs from German and French clients are matched by game ID, so timers and definitions work in any client language." }
+                                div { class: "setting-row",
+                                    label { "Game client language" }
+                                    select {
+                                        value: client_language().map(|l| l.code()).unwrap_or("auto"),
+                                        onchange: move |e| {
+                                            let lang = GameLanguage::all()
+                                                .iter()
+                                                .copied()
+                                                .find(|l| l.code() == e.value());
+                                            client_language.set(lang);
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                if let Some(mut cfg) = api::get_config().await {
+                                                    cfg.client_language = lang;
+                                                    if let Err(err) = api::update_config(&cfg).await {
+                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                    }
+                                                }
+                                            });
+                                        },
+                                        option {
+                                            value: "auto",
+                                            selected: client_language().is_none(),
+                                            "Detect from log"
+                                        }
+                                        for lang in GameLanguage::all() {
+                                            option {
+                                                value: lang.code(),
+                                                selected: client_language() == Some(*lang),
+                                                "{lang.label()}"
+                                            }
+                                        }
+                                    }
+                                }
+                                p { class: "hint", "Names are only learned from logs whose client language is known. Set this if your client isn't detected; English logs are always recognized." }
                             }
 
                             div { class: "settings-section",
@@ -1702,17 +1851,29 @@ pub fn App() -> Element {
                                         }
                                     }
                                 }
+                                h4 { "Timer & Counter Hotkeys" }
+                                p { class: "hint", "Start timers with a manual trigger, reset timers, or change boss counters. Alerts and audio play as usual." }
+                                ActionHotkeysEditor {
+                                    actions: hotkey_actions(),
+                                    on_change: move |list| hotkey_actions.set(list),
+                                }
                                 div { class: "settings-footer",
                                     button {
                                         class: "btn btn-save",
                                         onclick: move |_| {
                                             let v = hotkey_visibility(); let m = hotkey_move_mode(); let r = hotkey_rearrange();
+                                            // Drop incomplete bindings rather than registering them
+                                            let actions: Vec<ActionHotkey> = hotkey_actions()
+                                                .into_iter()
+                                                .filter(|a| !a.key.is_empty() && !a.action.target_id().is_empty())
+                                                .collect();
                                             let mut toast = use_toast();
                                             spawn(async move {
                                                 if let Some(mut cfg) = api::get_config().await {
                                                     cfg.hotkeys.toggle_visibility = if v.is_empty() { None } else { Some(v) };
                                                     cfg.hotkeys.toggle_move_mode = if m.is_empty() { None } else { Some(m) };
                                                     cfg.hotkeys.toggle_rearrange_mode = if r.is_empty() { None } else { Some(r) };
+                                                    cfg.hotkeys.actions = actions;
                                                     if let Err(err) = api::update_config(&cfg).await {
                                                         toast.show(format!("Failed to save hotkeys: {}", err), ToastSeverity::Normal);
                                                     } else {
@@ -2189,9 +2350,11 @@ pub fn App() -> Element {
 // Player Stats Bar
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Inline bar for alacrity and latency settings
+/// Inline bar for alacrity and latency settings. The configured values are used
+/// until they can be inferred from the local player's casts; inferred values are
+/// shown next to them once available.
 #[component]
-fn PlayerStatsBar() -> Element {
+fn PlayerStatsBar(alacrity_percent: Option<f32>, latency_ms: Option<u16>) -> Element {
     let mut alacrity = use_signal(|| 0.0f32);
     let mut latency = use_signal(|| 0u16);
     let mut loaded = use_signal(|| false);
@@ -2233,7 +2396,7 @@ fn PlayerStatsBar() -> Element {
                 label { "Alacrity %" }
                 input {
                     r#type: "text",
-                    title: "Your alacrity percentage for GCD calculations",
+                    title: "Your alacrity percentage, used until it can be inferred from your casts",
                     value: "{alacrity():.1}",
                     onchange: move |e| {
                         if let Ok(val) = e.value().parse::<f32>() {
@@ -2242,12 +2405,19 @@ fn PlayerStatsBar() -> Element {
                         }
                     }
                 }
+                if let Some(inferred) = alacrity_percent {
+                    span {
+                        class: "stat-value",
+                        title: "Inferred from your casts",
+                        "({inferred:.1})"
+                    }
+                }
             }
             div { class: "stat-input",
                 label { "Latency (ms)" }
                 input {
                     r#type: "text",
-                    title: "Your network latency in milliseconds for ability timing",
+                    title: "Your network latency in milliseconds, used until it can be inferred from your casts",
                     value: "{latency()}",
                     onchange: move |e| {
                         if let Ok(val) = e.value().parse::<u16>() {
@@ -2256,10 +2426,17 @@ fn PlayerStatsBar() -> Element {
                         }
                     }
                 }
+                if let Some(inferred) = latency_ms {
+                    span {
+                        class: "stat-value",
+                        title: "Inferred from your casts",
+                        "({inferred})"
+                    }
+                }
             }
             span {
                 class: "stats-help-icon",
-                title: "Alacrity and Latency affect duration of certain HoTs and effects",
+                title: "Alacrity and Latency affect duration of certain HoTs and effects. Once enough of your GCD casts have been seen, the values inferred from them (in parentheses) are used instead.",
                 i { class: "fa-solid fa-circle-question" }
             }
         }
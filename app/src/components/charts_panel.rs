@@ -1,6 +1,7 @@
 //! Charts Panel Component
 //!
-//! Displays time series charts (DPS, HPS, DTPS) with effect highlighting.
+//! Displays time series charts (DPS, HPS, DTPS) with effect highlighting and
+//! encounter notes.
 //! Uses ECharts for visualization via wasm-bindgen JS interop.
 
 use dioxus::prelude::*;
@@ -8,9 +9,13 @@ use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local as spawn;
 
-use crate::api::{self, EffectChartData, EffectWindow, HpPoint, TimeRange, TimeSeriesPoint};
+use crate::api::{
+    self, EffectChartData, EffectWindow, EncounterAnnotation, ExportSource, HpPoint, TimeRange,
+    TimeSeriesMetric, TimeSeriesPoint,
+};
 use crate::components::ability_icon::AbilityIcon;
 use crate::components::class_icons::get_class_icon;
+use crate::components::table_export::{TableExportButton, file_stem};
 use crate::utils::js_set;
 use baras_types::formatting;
 
@@ -118,6 +123,34 @@ fn merge_effect_windows(mut windows: Vec<EffectWindow>) -> Vec<EffectWindow> {
     merged
 }
 
+/// Vertical marker lines for encounter notes (set even if empty so ECharts
+/// clears removed notes)
+fn build_annotation_mark_line(annotations: &[EncounterAnnotation]) -> JsValue {
+    let mark_line = js_sys::Object::new();
+    js_set(&mark_line, "symbol", &JsValue::from_str("none"));
+    js_set(&mark_line, "silent", &JsValue::FALSE);
+    let line_style = js_sys::Object::new();
+    js_set(&line_style, "color", &JsValue::from_str("#f1c40f"));
+    js_set(&line_style, "type", &JsValue::from_str("dashed"));
+    js_set(&line_style, "width", &JsValue::from_f64(1.0));
+    js_set(&mark_line, "lineStyle", &line_style);
+    let label = js_sys::Object::new();
+    js_set(&label, "color", &JsValue::from_str("#f1c40f"));
+    js_set(&label, "fontSize", &JsValue::from_f64(10.0));
+    js_set(&label, "formatter", &JsValue::from_str("{b}"));
+    js_set(&mark_line, "label", &label);
+
+    let data = js_sys::Array::new();
+    for note in annotations {
+        let line = js_sys::Object::new();
+        js_set(&line, "xAxis", &JsValue::from_f64(note.time_secs as f64));
+        js_set(&line, "name", &JsValue::from_str(&note.text));
+        data.push(&line);
+    }
+    js_set(&mark_line, "data", &data);
+    mark_line.into()
+}
+
 fn build_time_series_option(
     data: &[TimeSeriesPoint],
     secondary_data: Option<&[TimeSeriesPoint]>,
@@ -127,6 +160,7 @@ fn build_time_series_option(
     fill_color: &str,
     effect_windows: &[(i64, EffectWindow, &str)], // (effect_id, window, color)
     y_axis_name: &str,
+    annotations: &[EncounterAnnotation],
 ) -> JsValue {
     let obj = js_sys::Object::new();
 
@@ -337,6 +371,7 @@ fn build_time_series_option(
     }
     js_set(&mark_area, "data", &mark_data);
     js_set(&series, "markArea", &mark_area);
+    js_set(&series, "markLine", &build_annotation_mark_line(annotations));
 
     series_arr.push(&series);
 
@@ -424,6 +459,7 @@ fn build_time_series_option(
 fn build_hp_chart_option(
     data: &[HpPoint],
     effect_windows: &[(i64, EffectWindow, &str)],
+    annotations: &[EncounterAnnotation],
 ) -> JsValue {
     let obj = js_sys::Object::new();
 
@@ -588,6 +624,7 @@ fn build_hp_chart_option(
     }
     js_set(&mark_area, "data", &mark_data);
     js_set(&series, "markArea", &mark_area);
+    js_set(&series, "markLine", &build_annotation_mark_line(annotations));
 
     series_arr.push(&series);
     js_set(&obj, "series", &series_arr);
@@ -617,6 +654,9 @@ pub struct ChartsPanelProps {
     /// Local player name for default selection
     #[props(default)]
     pub local_player: Option<String>,
+    /// Encounter notes (drawn as marker lines)
+    #[props(default)]
+    pub annotations: Vec<EncounterAnnotation>,
     /// Whether entity sidebar is collapsed
     #[props(default)]
     pub entity_collapsed: bool,
@@ -644,6 +684,12 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
         encounter_idx_signal.set(props.encounter_idx);
     }
 
+    // Mirror annotations prop so charts redraw when notes change
+    let mut annotations_signal = use_signal(|| props.annotations.clone());
+    if *annotations_signal.read() != props.annotations {
+        annotations_signal.set(props.annotations.clone());
+    }
+
     // Entity selection (default to none - show aggregated data)
     let mut selected_entity = use_signal(|| None::<String>);
     let mut entities = use_signal(Vec::<String>::new);
@@ -866,6 +912,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
         let dtps = dtps_data.read().clone();
         let hp = hp_data.read().clone();
         let windows = effect_windows.read().clone();
+        let notes = annotations_signal.read().clone();
 
         // Dispose hidden charts immediately to prevent overlap
         if !show_dps_val {
@@ -898,6 +945,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                     "rgba(231, 76, 60, 0.15)",
                     &windows,
                     "DPS",
+                    &notes,
                 );
                 set_chart_option(&chart, &option);
             }
@@ -916,6 +964,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                     "rgba(46, 204, 113, 0.15)",
                     &windows,
                     "HPS",
+                    &notes,
                 );
                 set_chart_option(&chart, &option);
             }
@@ -933,6 +982,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                     "rgba(230, 126, 34, 0.15)",
                     &windows,
                     "DTPS",
+                    &notes,
                 );
                 set_chart_option(&chart, &option);
             }
@@ -941,7 +991,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                 && !hp.is_empty()
                 && let Some(chart) = init_chart("chart-hp")
             {
-                let option = build_hp_chart_option(&hp, &windows);
+                let option = build_hp_chart_option(&hp, &windows, &notes);
                 set_chart_option(&chart, &option);
             }
 
@@ -979,6 +1029,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
     let entity_list = entities.read().clone();
     let active = active_effects.read().clone();
     let passive = passive_effects.read().clone();
+    let export_entity = selected_entity.read().clone();
     let current_effects = selected_effects.read().clone();
 
     let dps_empty = dps_data.read().is_empty();
@@ -1040,37 +1091,93 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                     div { class: "sidebar-section",
                         h4 { "Charts" }
                         div { class: "chart-toggles",
-                            label {
-                                input {
-                                    r#type: "checkbox",
-                                    checked: *show_dps.read(),
-                                    onchange: move |e| show_dps.set(e.checked())
+                            div { class: "chart-toggle-row",
+                                label {
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: *show_dps.read(),
+                                        onchange: move |e| show_dps.set(e.checked())
+                                    }
+                                    span { class: "toggle-dps", "DPS" }
+                                }
+                                if let Some(entity) = export_entity.clone() {
+                                    TableExportButton {
+                                        encounter_idx: props.encounter_idx,
+                                        source: ExportSource::TimeSeries {
+                                            metric: TimeSeriesMetric::Dps,
+                                            entity_name: Some(entity.clone()),
+                                            bucket_ms,
+                                        },
+                                        time_range: props.time_range,
+                                        file_stem: file_stem(&format!("{} dps", entity)),
+                                    }
                                 }
-                                span { class: "toggle-dps", "DPS" }
                             }
-                            label {
-                                input {
-                                    r#type: "checkbox",
-                                    checked: *show_hps.read(),
-                                    onchange: move |e| show_hps.set(e.checked())
+                            div { class: "chart-toggle-row",
+                                label {
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: *show_hps.read(),
+                                        onchange: move |e| show_hps.set(e.checked())
+                                    }
+                                    span { class: "toggle-hps", "HPS" }
+                                }
+                                if let Some(entity) = export_entity.clone() {
+                                    TableExportButton {
+                                        encounter_idx: props.encounter_idx,
+                                        source: ExportSource::TimeSeries {
+                                            metric: TimeSeriesMetric::Hps,
+                                            entity_name: Some(entity.clone()),
+                                            bucket_ms,
+                                        },
+                                        time_range: props.time_range,
+                                        file_stem: file_stem(&format!("{} hps", entity)),
+                                    }
                                 }
-                                span { class: "toggle-hps", "HPS" }
                             }
-                            label {
-                                input {
-                                    r#type: "checkbox",
-                                    checked: *show_dtps.read(),
-                                    onchange: move |e| show_dtps.set(e.checked())
+                            div { class: "chart-toggle-row",
+                                label {
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: *show_dtps.read(),
+                                        onchange: move |e| show_dtps.set(e.checked())
+                                    }
+                                    span { class: "toggle-dtps", "DTPS" }
+                                }
+                                if let Some(entity) = export_entity.clone() {
+                                    TableExportButton {
+                                        encounter_idx: props.encounter_idx,
+                                        source: ExportSource::TimeSeries {
+                                            metric: TimeSeriesMetric::Dtps,
+                                            entity_name: Some(entity.clone()),
+                                            bucket_ms,
+                                        },
+                                        time_range: props.time_range,
+                                        file_stem: file_stem(&format!("{} dtps", entity)),
+                                    }
                                 }
-                                span { class: "toggle-dtps", "DTPS" }
                             }
-                            label {
-                                input {
-                                    r#type: "checkbox",
-                                    checked: *show_hp.read(),
-                                    onchange: move |e| show_hp.set(e.checked())
+                            div { class: "chart-toggle-row",
+                                label {
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: *show_hp.read(),
+                                        onchange: move |e| show_hp.set(e.checked())
+                                    }
+                                    span { class: "toggle-hp", "HP%" }
+                                }
+                                if let Some(entity) = export_entity.clone() {
+                                    TableExportButton {
+                                        encounter_idx: props.encounter_idx,
+                                        source: ExportSource::TimeSeries {
+                                            metric: TimeSeriesMetric::Hp,
+                                            entity_name: Some(entity.clone()),
+                                            bucket_ms,
+                                        },
+                                        time_range: props.time_range,
+                                        file_stem: file_stem(&format!("{} hp", entity)),
+                                    }
                                 }
-                                span { class: "toggle-hp", "HP%" }
                             }
                         }
                     }
@@ -1129,7 +1236,18 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                 div { class: "effects-row",
                     // Abilities (active effects triggered by ability cast)
                     div { class: "effects-section",
-                        h4 { "Abilities" }
+                        h4 {
+                            "Abilities"
+                            if let Some(entity) = export_entity.clone() {
+                                TableExportButton {
+                                    encounter_idx: props.encounter_idx,
+                                    source: ExportSource::EffectUptime { target_name: Some(entity.clone()) },
+                                    time_range: props.time_range,
+                                    duration_secs: Some(props.duration_secs),
+                                    file_stem: file_stem(&format!("{} effect uptime", entity)),
+                                }
+                            }
+                        }
                         if active.is_empty() {
                             div { class: "effects-empty", "No abilities" }
                         } else {
@@ -5,8 +5,12 @@
 use dioxus::prelude::*;
 use wasm_bindgen::JsCast;
 
-use crate::api::{self, CombatLogFilters, CombatLogFindMatch, CombatLogRow, GroupedEntityNames, TimeRange};
+use crate::api::{
+    self, CombatLogFilters, CombatLogFindMatch, CombatLogRow, EncounterAnnotation, ExportSource,
+    GroupedEntityNames, TimeRange,
+};
 use crate::components::ability_icon::AbilityIcon;
+use crate::components::table_export::TableExportButton;
 use crate::types::CombatLogSessionState;
 use baras_types::formatting;
 
@@ -17,6 +21,13 @@ const OVERSCAN: usize = 10;
 /// Page size for data fetching.
 const PAGE_SIZE: u64 = 200;
 
+/// Tooltip for the filter and find inputs
+const FILTER_HELP: &str = "Words match names. Fields: source:, target: (name, player, companion, \
+npc, friendly, boss), ability:, effect:, type: (damage, heal, action, apply, remove, death, \
+interrupt, other), dmgtype:, phase:, time:1:30-2:00. Compare amount, effective, absorbed, \
+overheal, threat, time, source_hp, target_hp with > >= < <= = != (100k, 1.5m). Flags: crit, \
+reflect. Combine with AND, OR, NOT/! and parentheses.";
+
 // Effect type IDs for mapping to readable names
 const EFFECT_TYPE_APPLYEFFECT: i64 = 836045448945477;
 const EFFECT_TYPE_REMOVEEFFECT: i64 = 836045448945478;
@@ -57,6 +68,12 @@ pub struct CombatLogProps {
     /// Optional callback to update the parent's time range (e.g. from context menu)
     #[props(default)]
     pub on_range_change: Option<EventHandler<TimeRange>>,
+    /// Notes pinned to the encounter (shown on their rows)
+    #[props(default)]
+    pub annotations: Vec<EncounterAnnotation>,
+    /// Pin a note to a row (from the context menu)
+    #[props(default)]
+    pub on_add_annotation: Option<EventHandler<EncounterAnnotation>>,
     /// European number format (swaps `.` and `,`)
     #[props(default)]
     pub european: bool,
@@ -134,9 +151,13 @@ fn format_damage_type(dmg_type: &str) -> &str {
 }
 
 /// Get CSS class for row based on content.
-fn row_class(row: &CombatLogRow, highlighted_row_idx: Option<u64>) -> String {
+fn row_class(row: &CombatLogRow, highlighted_row_idx: Option<u64>, annotated: bool) -> String {
     let mut classes = vec!["log-row"];
 
+    if annotated {
+        classes.push("log-annotated");
+    }
+
     // Row background tint based on effect type
     if row.effect_id == EFFECT_DAMAGE {
         classes.push("log-damage-row");
@@ -247,6 +268,9 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
     // Context menu state for right-click "Set as range start/end"
     let mut context_menu_pos = use_signal(|| None::<(f64, f64)>);
     let mut context_menu_time = use_signal(|| 0.0f32);
+    let mut context_menu_row = use_signal(|| 0u64);
+    // Note being written for a row: (time, row_idx, position, text)
+    let mut note_draft = use_signal(|| None::<(f32, u64, (f64, f64), String)>);
 
     // Data state
     let mut rows = use_signal(Vec::<CombatLogRow>::new);
@@ -283,8 +307,10 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
     let mut container_height = use_signal(|| 500.0f64);
     let mut loaded_offset = use_signal(|| 0u64);
 
-    // Debounced search
+    // Debounced search, plus syntax errors from the filter language
     let mut search_debounce = use_signal(String::new);
+    let mut search_error = use_signal(|| None::<String>);
+    let mut find_error = use_signal(|| None::<String>);
 
     // Track previous encounter to detect changes (for resetting state on encounter switch)
     let mut prev_encounter_idx = use_signal(|| props.encounter_idx);
@@ -307,6 +333,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
             target_filter.set(None);
             search_text.set(String::new());
             search_debounce.set(String::new());
+            search_error.set(None);
 
             // Reset event type filters to defaults
             filter_damage.set(true);
@@ -321,6 +348,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
             // Reset find feature state
             find_text.set(String::new());
             find_debounce.set(String::new());
+            find_error.set(None);
             find_matches.set(vec![]);
             find_current_idx.set(0);
             highlighted_row.set(None);
@@ -452,8 +480,16 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
             let text = search_text.read().clone();
             spawn(async move {
                 gloo_timers::future::TimeoutFuture::new(300).await;
-                if *search_text.read() == text {
-                    search_debounce.set(text);
+                if *search_text.read() != text {
+                    return;
+                }
+                // Keep the last valid filter applied while the text has a syntax error
+                match api::validate_combat_log_filter(&text).await {
+                    Ok(()) => {
+                        search_error.set(None);
+                        search_debounce.set(text);
+                    }
+                    Err(e) => search_error.set(Some(e)),
                 }
             });
         }
@@ -465,8 +501,15 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
             let text = find_text.read().clone();
             spawn(async move {
                 gloo_timers::future::TimeoutFuture::new(300).await;
-                if *find_text.read() == text {
-                    find_debounce.set(text);
+                if *find_text.read() != text {
+                    return;
+                }
+                match api::validate_combat_log_filter(&text).await {
+                    Ok(()) => {
+                        find_error.set(None);
+                        find_debounce.set(text);
+                    }
+                    Err(e) => find_error.set(Some(e)),
                 }
             });
         }
@@ -705,12 +748,16 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
 
                 // Search input (filter)
                 input {
-                    class: "log-search",
+                    class: if search_error.read().is_some() { "log-search invalid" } else { "log-search" },
                     r#type: "text",
-                    placeholder: "Filter... (use OR)",
+                    placeholder: "Filter... (e.g. crit amount>100k source:npc)",
+                    title: "{FILTER_HELP}",
                     value: "{search_text}",
                     oninput: move |e| search_text.set(e.value()),
                 }
+                if let Some(err) = search_error.read().as_ref() {
+                    span { class: "log-filter-error", title: "{err}", "{err}" }
+                }
 
                 // Clear all filters button
                 button {
@@ -730,12 +777,27 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
                     "Clear Filters"
                 }
 
+                // Export the filtered rows (every column, not just the visible ones)
+                TableExportButton {
+                    encounter_idx: Some(props.encounter_idx),
+                    source: ExportSource::CombatLog {
+                        source_filter: source_filter.read().clone(),
+                        target_filter: target_filter.read().clone(),
+                        search_filter: Some(search_debounce.read().clone())
+                            .filter(|text| !text.trim().is_empty()),
+                        event_filters: build_event_filters(),
+                    },
+                    time_range: props.time_range,
+                    file_stem: "combat_log".to_string(),
+                }
+
                 // Find group (searches all data via backend)
                 div { class: "log-find-group",
                     input {
-                        class: "log-find-input",
+                        class: if find_error.read().is_some() { "log-find-input invalid" } else { "log-find-input" },
                         r#type: "text",
                         placeholder: "Find...",
+                        title: if let Some(err) = find_error.read().as_ref() { err.clone() } else { FILTER_HELP.to_string() },
                         value: "{find_text}",
                         oninput: move |e| find_text.set(e.value()),
                     }
@@ -1103,18 +1165,28 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
                         for row in visible_rows.iter() {
                             {
                             let row_time = row.time_secs;
+                            let row_idx = row.row_idx;
+                            let row_note = props
+                                .annotations
+                                .iter()
+                                .find(|a| a.row_idx == Some(row.row_idx))
+                                .map(|a| a.text.clone());
                             rsx! {
                             div {
                                 key: "{row.row_idx}",
-                                class: "{row_class(&row, highlighted_row_idx)}",
+                                class: "{row_class(&row, highlighted_row_idx, row_note.is_some())}",
                                 oncontextmenu: move |e: MouseEvent| {
-                                    if props.on_range_change.is_some() {
+                                    if props.on_range_change.is_some() || props.on_add_annotation.is_some() {
                                         e.prevent_default();
                                         context_menu_pos.set(Some((e.client_coordinates().x, e.client_coordinates().y)));
                                         context_menu_time.set(row_time);
+                                        context_menu_row.set(row_idx);
                                     }
                                 },
                                 div { class: "log-cell log-time", style: "width: {col_time}px; min-width: {col_time}px;",
+                                    if let Some(note) = row_note.as_ref() {
+                                        i { class: "fa-solid fa-thumbtack log-note-pin", title: "{note}" }
+                                    }
                                     if absolute_time {
                                         "{format_time_absolute(row.timestamp_ms)}"
                                     } else {
@@ -1184,9 +1256,9 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
                 }
             }
 
-            // Context menu for "Set as range start/end"
+            // Context menu for "Set as range start/end" and "Add note"
             if let Some((x, y)) = *context_menu_pos.read() {
-                if props.on_range_change.is_some() {
+                if props.on_range_change.is_some() || props.on_add_annotation.is_some() {
                     // Transparent backdrop for click-outside dismissal
                     div {
                         style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; z-index: 999;",
@@ -1195,31 +1267,83 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
                     div {
                         class: "log-context-menu",
                         style: "position: fixed; left: {x}px; top: {y}px; z-index: 1000;",
-                        div {
-                            class: "context-menu-item",
-                            onclick: move |_| {
-                                let t = *context_menu_time.read();
-                                if let Some(ref handler) = props.on_range_change {
-                                    handler.call(TimeRange::new(t, props.time_range.end));
-                                }
-                                context_menu_pos.set(None);
-                            },
-                            "Set as range start"
+                        if props.on_range_change.is_some() {
+                            div {
+                                class: "context-menu-item",
+                                onclick: move |_| {
+                                    let t = *context_menu_time.read();
+                                    if let Some(ref handler) = props.on_range_change {
+                                        handler.call(TimeRange::new(t, props.time_range.end));
+                                    }
+                                    context_menu_pos.set(None);
+                                },
+                                "Set as range start"
+                            }
+                            div {
+                                class: "context-menu-item",
+                                onclick: move |_| {
+                                    let t = *context_menu_time.read();
+                                    if let Some(ref handler) = props.on_range_change {
+                                        handler.call(TimeRange::new(props.time_range.start, t));
+                                    }
+                                    context_menu_pos.set(None);
+                                },
+                                "Set as range end"
+                            }
                         }
-                        div {
-                            class: "context-menu-item",
-                            onclick: move |_| {
-                                let t = *context_menu_time.read();
-                                if let Some(ref handler) = props.on_range_change {
-                                    handler.call(TimeRange::new(props.time_range.start, t));
-                                }
-                                context_menu_pos.set(None);
-                            },
-                            "Set as range end"
+                        if props.on_add_annotation.is_some() {
+                            div {
+                                class: "context-menu-item",
+                                onclick: move |_| {
+                                    let t = *context_menu_time.read();
+                                    let row_idx = *context_menu_row.read();
+                                    note_draft.set(Some((t, row_idx, (x, y), String::new())));
+                                    context_menu_pos.set(None);
+                                },
+                                "Add note..."
+                            }
                         }
                     }
                 }
             }
+
+            // Note editor for a row
+            if let Some((time_secs, row_idx, (x, y), text)) = note_draft.read().clone() {
+                div {
+                    style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; z-index: 999;",
+                    onclick: move |_| note_draft.set(None),
+                }
+                div {
+                    class: "log-note-editor",
+                    style: "position: fixed; left: {x}px; top: {y}px; z-index: 1000;",
+                    span { class: "note-time", "{format_time_relative(time_secs)}" }
+                    input {
+                        r#type: "text",
+                        placeholder: "Note...",
+                        maxlength: 500,
+                        value: "{text}",
+                        autofocus: true,
+                        oninput: move |e| note_draft.set(Some((time_secs, row_idx, (x, y), e.value()))),
+                        onkeydown: move |e| match e.key() {
+                            Key::Enter => {
+                                if !text.trim().is_empty()
+                                    && let Some(ref handler) = props.on_add_annotation
+                                {
+                                    handler.call(EncounterAnnotation {
+                                        id: 0,
+                                        time_secs,
+                                        row_idx: Some(row_idx),
+                                        text: text.clone(),
+                                    });
+                                }
+                                note_draft.set(None);
+                            }
+                            Key::Escape => note_draft.set(None),
+                            _ => {}
+                        },
+                    }
+                }
+            }
         }
     }
 }
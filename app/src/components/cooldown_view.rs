@@ -0,0 +1,171 @@
+//! Cooldown efficiency component.
+//!
+//! Shows, per player, how many times each tracked cooldown was used versus
+//! how many times it could have been used, with a timeline of idle windows.
+
+use dioxus::prelude::*;
+
+use crate::api;
+use crate::api::{CooldownEfficiencyRow, TimeRange};
+use crate::components::ability_icon::AbilityIcon;
+use baras_types::formatting;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CooldownViewProps {
+    pub encounter_idx: Option<u32>,
+    pub time_range: TimeRange,
+    /// Encounter duration (used as the timeline end when no range is selected)
+    pub duration_secs: f32,
+    /// European number format (swaps `.` and `,`)
+    #[props(default)]
+    pub european: bool,
+}
+
+/// CSS class for an efficiency percentage (good / okay / poor).
+fn efficiency_class(pct: f32) -> &'static str {
+    if pct >= 90.0 {
+        "cd-eff good"
+    } else if pct >= 60.0 {
+        "cd-eff okay"
+    } else {
+        "cd-eff poor"
+    }
+}
+
+#[component]
+pub fn CooldownView(props: CooldownViewProps) -> Element {
+    let eu = props.european;
+
+    let mut rows = use_signal(Vec::<CooldownEfficiencyRow>::new);
+    let mut loading = use_signal(|| false);
+
+    // Track time_range so the query re-runs on phase/time filter changes
+    let mut tracked_time_range = use_signal(|| props.time_range);
+    if *tracked_time_range.read() != props.time_range {
+        tracked_time_range.set(props.time_range);
+    }
+
+    let enc_idx = props.encounter_idx;
+
+    use_effect(move || {
+        let tr = tracked_time_range();
+        let tr_opt = if tr.start == 0.0 && tr.end == 0.0 {
+            None
+        } else {
+            Some(tr)
+        };
+
+        loading.set(true);
+        spawn(async move {
+            let result = api::query_cooldown_efficiency(enc_idx, tr_opt.as_ref()).await;
+            rows.set(result.unwrap_or_default());
+            loading.set(false);
+        });
+    });
+
+    let tr = props.time_range;
+    let (window_start, window_end) = if tr.start == 0.0 && tr.end == 0.0 {
+        (0.0, props.duration_secs)
+    } else {
+        (tr.start, tr.end)
+    };
+    let window_len = (window_end - window_start).max(0.001);
+    let pos_pct = move |t: f32| ((t - window_start) / window_len * 100.0).clamp(0.0, 100.0);
+
+    // Group rows by player, keeping the backend's sort order
+    let mut players: Vec<(String, Vec<CooldownEfficiencyRow>)> = Vec::new();
+    for row in rows.read().iter() {
+        match players.last_mut() {
+            Some((name, list)) if *name == row.player_name => list.push(row.clone()),
+            _ => players.push((row.player_name.clone(), vec![row.clone()])),
+        }
+    }
+
+    rsx! {
+        div { class: "cooldown-view",
+            if loading() && players.is_empty() {
+                div { class: "rotation-placeholder", "Loading..." }
+            } else if players.is_empty() {
+                div { class: "rotation-placeholder",
+                    "No tracked cooldowns were used in this encounter."
+                }
+            }
+            for (player, list) in players.iter() {
+                {
+                    let uses: i64 = list.iter().map(|r| r.uses).sum();
+                    let possible: i64 = list.iter().map(|r| r.possible_uses).sum();
+                    rsx! {
+                        div { class: "cooldown-player", key: "{player}",
+                            div { class: "cooldown-player-header",
+                                span { class: "cooldown-player-name", "{player}" }
+                                span { class: "cooldown-player-total",
+                                    "{uses} / {possible} uses ({formatting::format_pct_ratio(uses, possible, eu)})"
+                                }
+                            }
+                            table { class: "cooldown-table",
+                                thead {
+                                    tr {
+                                        th { class: "name-col", "Cooldown" }
+                                        th { class: "num", "CD" }
+                                        th { class: "num", "Uses" }
+                                        th { class: "num", "Possible" }
+                                        th { class: "num", "Efficiency" }
+                                        th { class: "num", "Idle" }
+                                        th { class: "timeline-col", "Timeline" }
+                                    }
+                                }
+                                tbody {
+                                    for row in list.iter() {
+                                        tr { key: "{row.cooldown_id}",
+                                            td { class: "name-col",
+                                                span { class: "name-with-icon",
+                                                    if let Some(icon_id) = row.icon_ability_id {
+                                                        AbilityIcon {
+                                                            ability_id: icon_id,
+                                                            size: 20,
+                                                            fallback: row.cooldown_name.clone(),
+                                                        }
+                                                    }
+                                                    "{row.cooldown_name}"
+                                                }
+                                            }
+                                            td { class: "num", "{formatting::format_duration_f32(row.cooldown_secs)}" }
+                                            td { class: "num", "{row.uses}" }
+                                            td { class: "num", "{row.possible_uses}" }
+                                            td { class: "num",
+                                                span { class: efficiency_class(row.efficiency_pct),
+                                                    "{formatting::format_pct_f32(row.efficiency_pct, eu)}"
+                                                }
+                                            }
+                                            td { class: "num", "{formatting::format_duration_f32(row.idle_secs)}" }
+                                            td { class: "timeline-col",
+                                                div { class: "cooldown-timeline",
+                                                    for (i, gap) in row.idle_gaps.iter().enumerate() {
+                                                        div {
+                                                            key: "g{i}",
+                                                            class: "cooldown-idle",
+                                                            title: "Ready but unused: {formatting::format_duration_f32(gap.start_secs)} - {formatting::format_duration_f32(gap.end_secs)}",
+                                                            style: "left: {pos_pct(gap.start_secs)}%; width: {pos_pct(gap.end_secs) - pos_pct(gap.start_secs)}%;",
+                                                        }
+                                                    }
+                                                    for (i, t) in row.use_times.iter().enumerate() {
+                                                        div {
+                                                            key: "u{i}",
+                                                            class: "cooldown-use",
+                                                            title: "Used at {formatting::format_duration_f32(*t)}",
+                                                            style: "left: {pos_pct(*t)}%;",
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -9,16 +9,20 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local as spawn;
 
 use crate::api::{
-    self, AbilityBreakdown, DamageTakenSummary, EncounterTimeline, EntityBreakdown,
+    self, AbilityBreakdown, DamageTakenSummary, EncounterAnnotation, EncounterTimeline,
+    EntityBreakdown, ExportSource,
     NpcHealthRow, PlayerDeath, RaidOverviewRow, TimeRange,
 };
 use crate::components::ability_icon::AbilityIcon;
 use crate::components::charts_panel::ChartsPanel;
 use crate::components::class_icons::{get_class_icon, get_role_icon};
 use crate::components::combat_log::CombatLog;
+use crate::components::cooldown_view::CooldownView;
+use crate::components::healing_matrix::HealingMatrixView;
 use crate::components::history_panel::EncounterSummary;
 use crate::components::phase_timeline::PhaseTimelineFilter;
 use crate::components::rotation_view::RotationView;
+use crate::components::table_export::{TableExportButton, file_stem};
 use crate::components::{ToastSeverity, use_toast};
 use crate::types::{BreakdownMode, CombatLogSessionState, DataTab, SortColumn, SortDirection, UiSessionState, ViewMode};
 use crate::utils::js_set;
@@ -278,8 +282,6 @@ fn parse_hsl(color: &str) -> Option<(f64, f64, f64)> {
 // Helper Functions
 // ─────────────────────────────────────────────────────────────────────────────
 
-
-
 /// Group encounters into sections by area (based on is_phase_start flag or area change)
 fn group_by_area(
     encounters: &[EncounterSummary],
@@ -423,6 +425,9 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
     // Timeline state
     let mut timeline = use_signal(|| None::<EncounterTimeline>);
 
+    // Notes pinned to the selected encounter
+    let mut annotations = use_signal(Vec::<EncounterAnnotation>::new);
+
     // Overview data
     let mut overview_data = use_signal(Vec::<RaidOverviewRow>::new);
     let mut player_deaths = use_signal(Vec::<PlayerDeath>::new);
@@ -642,6 +647,7 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
         let _ = npc_health.try_write().map(|mut w| *w = Vec::new());
         let _ = last_overview_fetch.try_write().map(|mut w| *w = None);
         let _ = timeline.try_write().map(|mut w| *w = None);
+        let _ = annotations.try_write().map(|mut w| *w = Vec::new());
         // Only reset time_range and selected_source if encounter actually changed (not on initial mount restore)
         if is_encounter_change {
             let _ = selected_source.try_write().map(|mut w| *w = None);
@@ -686,6 +692,13 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                     let _ = timeline_state
                         .try_write()
                         .map(|mut w| *w = LoadState::Loaded);
+
+                    if let Some(idx) = idx {
+                        let notes = api::get_encounter_annotations(idx as u64).await;
+                        if *load_generation.peek() == generation {
+                            let _ = annotations.try_write().map(|mut w| *w = notes);
+                        }
+                    }
                 }
                 None => {
                     // None can mean: no encounters directory, file not found, or other backend issues
@@ -700,6 +713,80 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
         });
     });
 
+    // Note handlers (historical encounters only)
+    let mut toast = use_toast();
+    let add_annotation = move |note: EncounterAnnotation| {
+        let Some(idx) = *selected_encounter.peek() else {
+            return;
+        };
+        spawn(async move {
+            match api::add_encounter_annotation(idx as u64, &note).await {
+                Ok(saved) => {
+                    if *selected_encounter.peek() == Some(idx) {
+                        let mut notes = annotations.write();
+                        let pos = notes.partition_point(|a| a.time_secs <= saved.time_secs);
+                        notes.insert(pos, saved);
+                    }
+                }
+                Err(err) => toast.show(format!("Failed to add note: {}", err), ToastSeverity::Normal),
+            }
+        });
+    };
+    let edit_annotation = move |(id, text): (u64, String)| {
+        let Some(idx) = *selected_encounter.peek() else {
+            return;
+        };
+        spawn(async move {
+            match api::update_encounter_annotation(idx as u64, id, &text).await {
+                Ok(()) => {
+                    if let Some(note) = annotations.write().iter_mut().find(|a| a.id == id) {
+                        note.text = text.trim().to_string();
+                    }
+                }
+                Err(err) => toast.show(format!("Failed to save note: {}", err), ToastSeverity::Normal),
+            }
+        });
+    };
+    let remove_annotation = move |id: u64| {
+        let Some(idx) = *selected_encounter.peek() else {
+            return;
+        };
+        spawn(async move {
+            match api::remove_encounter_annotation(idx as u64, id).await {
+                Ok(()) => annotations.write().retain(|a| a.id != id),
+                Err(err) => toast.show(format!("Failed to delete note: {}", err), ToastSeverity::Normal),
+            }
+        });
+    };
+
+    // Report export (one encounter, or the whole session when None)
+    let mut exporting = use_signal(|| false);
+    let export_report = move |encounter_idx: Option<u32>| {
+        if *exporting.peek() {
+            return;
+        }
+        let default_name = match encounter_idx.and_then(|idx| {
+            encounters.peek().iter().find(|e| e.encounter_id as u32 == idx).cloned()
+        }) {
+            Some(enc) => format!("{}.html", file_stem(&enc.display_name)),
+            None => "raid_report.html".to_string(),
+        };
+        spawn(async move {
+            let Some(path) = api::save_report_dialog(&default_name).await else {
+                return;
+            };
+            exporting.set(true);
+            match api::export_encounter_report(encounter_idx, &path).await {
+                Ok(summary_path) => toast.show(
+                    format!("Report saved (Markdown summary: {})", summary_path),
+                    ToastSeverity::Normal,
+                ),
+                Err(err) => toast.show(format!("Failed to export report: {}", err), ToastSeverity::Normal),
+            }
+            exporting.set(false);
+        });
+    };
+
     // Load overview data when timeline is loaded and view_mode/time_range changes
     // Overview data provides class icons for all views + full data for Overview tab
     use_effect(move || {
@@ -1327,6 +1414,15 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                 " Encounters"
                             }
                         }
+                        if !*sidebar_collapsed.read() && !encounters().is_empty() {
+                            button {
+                                class: "sidebar-collapse-btn sidebar-report-btn",
+                                title: "Export a report of every encounter (HTML + Markdown)",
+                                disabled: exporting(),
+                                onclick: move |_| export_report(None),
+                                i { class: "fa-solid fa-file-export" }
+                            }
+                        }
                         button {
                             class: "sidebar-collapse-btn",
                             title: if *sidebar_collapsed.read() { "Expand encounters" } else { "Collapse encounters" },
@@ -1466,7 +1562,11 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                             range: time_range(),
                             on_range_change: move |new_range: TimeRange| {
                                 time_range.set(new_range);
-                            }
+                            },
+                            annotations: annotations(),
+                            on_add_annotation: selected_encounter().map(|_| EventHandler::new(add_annotation)),
+                            on_edit_annotation: selected_encounter().map(|_| EventHandler::new(edit_annotation)),
+                            on_remove_annotation: selected_encounter().map(|_| EventHandler::new(remove_annotation)),
                         }
                     }
 
@@ -1525,6 +1625,23 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                             onclick: move |_| view_mode.set(ViewMode::Rotation),
                             "Rotation"
                         }
+                        button {
+                            class: if matches!(view_mode(), ViewMode::Cooldowns) { "data-tab active" } else { "data-tab" },
+                            onclick: move |_| view_mode.set(ViewMode::Cooldowns),
+                            "Cooldowns"
+                        }
+                        button {
+                            class: if matches!(view_mode(), ViewMode::HealingMatrix) { "data-tab active" } else { "data-tab" },
+                            onclick: move |_| view_mode.set(ViewMode::HealingMatrix),
+                            "Heal Matrix"
+                        }
+                        button {
+                            class: "panel-fullscreen-btn panel-report-btn",
+                            title: "Export a report of this encounter (HTML + Markdown)",
+                            disabled: exporting(),
+                            onclick: move |_| export_report(*selected_encounter.peek()),
+                            i { class: if exporting() { "fa-solid fa-spinner fa-spin" } else { "fa-solid fa-file-export" } }
+                        }
                         button {
                             class: "panel-fullscreen-btn",
                             title: if *overview_fullscreen.read() { "Exit fullscreen" } else { "Expand to fullscreen" },
@@ -1564,6 +1681,8 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                 on_range_change: move |new_range: TimeRange| {
                                     time_range.set(new_range);
                                 },
+                                annotations: annotations(),
+                                on_add_annotation: add_annotation,
                                 european: eu,
                             }
                         }
@@ -1584,6 +1703,7 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                         duration_secs: duration,
                                         time_range: tr,
                                         local_player: local_player_name.read().clone(),
+                                        annotations: annotations(),
                                         entity_collapsed: *entity_collapsed.read(),
                                         on_toggle_entity: move |_| { let v = *entity_collapsed.read(); entity_collapsed.set(!v); },
                                         european: eu,
@@ -1591,6 +1711,25 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                 }
                             }
                         }
+                    } else if matches!(view_mode(), ViewMode::Cooldowns) {
+                        // Cooldown efficiency per player
+                        if let Some(tl) = timeline.read().as_ref() {
+                            CooldownView {
+                                key: "{selected_encounter():?}",
+                                encounter_idx: *selected_encounter.read(),
+                                time_range: time_range(),
+                                duration_secs: tl.duration_secs,
+                                european: eu,
+                            }
+                        }
+                    } else if matches!(view_mode(), ViewMode::HealingMatrix) {
+                        // Healer × recipient heatmap
+                        HealingMatrixView {
+                            key: "{selected_encounter():?}",
+                            encounter_idx: *selected_encounter.read(),
+                            time_range: time_range(),
+                            european: eu,
+                        }
                     } else if matches!(view_mode(), ViewMode::Overview) {
                         // Raid Overview - Donut Charts + Table
                         // Uses memoized overview_table_data - charts initialized via use_effect above
@@ -1604,6 +1743,12 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                             h4 { class: "death-tracker-title",
                                                 i { class: "fa-solid fa-skull" }
                                                 " Deaths ({deaths.len()})"
+                                                TableExportButton {
+                                                    encounter_idx: *selected_encounter.read(),
+                                                    source: ExportSource::PlayerDeaths,
+                                                    time_range: time_range(),
+                                                    file_stem: "deaths",
+                                                }
                                             }
                                             div { class: "death-list",
                                                 for death in deaths.iter() {
@@ -1643,13 +1788,29 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                     table { class: "overview-table",
                                         thead {
                                             tr {
-                                                th { class: "name-col", "Name" }
+                                                th { class: "name-col",
+                                                    "Name"
+                                                    {
+                                                        let tr = time_range();
+                                                        let full = timeline.read().as_ref().map(|t| t.duration_secs);
+                                                        let duration = if tr.start != 0.0 || tr.end != 0.0 { Some(tr.end - tr.start) } else { full };
+                                                        rsx! {
+                                                            TableExportButton {
+                                                                encounter_idx: *selected_encounter.read(),
+                                                                source: ExportSource::RaidOverview,
+                                                                time_range: tr,
+                                                                duration_secs: duration,
+                                                                file_stem: "raid_overview",
+                                                            }
+                                                        }
+                                                    }
+                                                }
                                                 th { class: "section-header", colspan: "2", "Damage Dealt" }
                                                 th { class: "section-header", colspan: "2", "Threat" }
                                                 th { class: "section-header", colspan: "3", "Damage Taken" }
                                                 th { class: "section-header", colspan: "4", "Healing" }
                                                 th { class: "section-header", colspan: "2", "Shielding" }
-                                                th { class: "section-header", colspan: "1", "Activity" }
+                                                th { class: "section-header", colspan: "2", "Activity" }
                                             }
                                             tr { class: "sub-header",
                                                 th {}
@@ -1667,6 +1828,7 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                                 th { class: "num", "Total" }
                                                 th { class: "num", "SPS" }
                                                 th { class: "num", "APM" }
+                                                th { class: "num", title: "Share of the fight spent on the GCD (always be casting)", "ABC" }
                                             }
                                         }
                                         tbody {
@@ -1715,6 +1877,9 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                                     td { class: "num shield", "{format_number(row.shielding_given_total)}" }
                                                     td { class: "num shield", "{format_number(row.sps)}" }
                                                     td { class: "num apm", "{formatting::format_decimal_f64(row.apm, 1, eu)}" }
+                                                    td { class: "num apm",
+                                                        if row.entity_type == "Player" { "{format_pct(row.abc_pct)}" }
+                                                    }
                                                 }
                                             }
                                         }
@@ -1735,6 +1900,7 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                                 td { class: "num shield", "{format_number(table_data.total_shielding)}" }
                                                 td { class: "num shield", "{format_number(table_data.total_sps)}" }
                                                 td { class: "num apm" }
+                                                td { class: "num apm" }
                                             }
                                         }
                                     }
@@ -1760,6 +1926,12 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                                     h4 { class: "npc-health-title",
                                                         i { class: "fa-solid fa-heart-pulse" }
                                                         " NPC Health ({npcs.len()})"
+                                                        TableExportButton {
+                                                            encounter_idx: *selected_encounter.read(),
+                                                            source: ExportSource::NpcHealth,
+                                                            time_range: time_range(),
+                                                            file_stem: "npc_health",
+                                                        }
                                                     }
                                                     div { class: "npc-health-grid",
                                                         for chunk in chunks.iter() {
@@ -2053,6 +2225,42 @@ pub fn DataExplorerPanel(mut props: DataExplorerProps) -> Element {
                                             }
                                         }
                                     }
+                                    {
+                                        let source = selected_source();
+                                        let entity_types = source.is_none().then(|| {
+                                            if *show_players_only.read() {
+                                                vec!["Player".to_string(), "Companion".to_string()]
+                                            } else {
+                                                vec!["Npc".to_string()]
+                                            }
+                                        });
+                                        let tab_name = match current_tab {
+                                            DataTab::Damage => "damage",
+                                            DataTab::Healing => "healing",
+                                            DataTab::DamageTaken => "damage_taken",
+                                            DataTab::HealingTaken => "healing_taken",
+                                            DataTab::Charts => "charts",
+                                        };
+                                        let stem = file_stem(&format!(
+                                            "{} {}",
+                                            tab_name,
+                                            source.as_deref().unwrap_or("all")
+                                        ));
+                                        rsx! {
+                                            TableExportButton {
+                                                encounter_idx: *selected_encounter.read(),
+                                                source: ExportSource::Breakdown {
+                                                    tab: current_tab,
+                                                    entity_name: source,
+                                                    entity_types,
+                                                    breakdown_mode: Some(*breakdown_mode.read()),
+                                                },
+                                                time_range: time_range(),
+                                                duration_secs: timeline.read().as_ref().map(|t| t.duration_secs),
+                                                file_stem: stem,
+                                            }
+                                        }
+                                    }
                                 }
                                 // Table with dynamic columns (sortable)
                                 {
@@ -0,0 +1,221 @@
+//! Training dummy parses component.
+//!
+//! Fixed-length dummy parses per character and discipline, with the newest
+//! parse compared to the best one. Parses come from the log file area index
+//! plus any completed live this run.
+
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use crate::api;
+use crate::components::{ToastSeverity, use_toast};
+use crate::types::{DummyParseHistory, DummyParseLimit, DummyParseSettings};
+use baras_types::formatting;
+
+/// Number of older parses shown under each character/discipline
+const MAX_LISTED_PARSES: usize = 10;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DummyParsePanelProps {
+    /// European number format (swaps `.` and `,`)
+    #[props(default)]
+    pub european: bool,
+}
+
+#[component]
+pub fn DummyParsePanel(props: DummyParsePanelProps) -> Element {
+    let eu = props.european;
+
+    let mut settings = use_signal(DummyParseSettings::default);
+    let mut history = use_signal(Vec::<DummyParseHistory>::new);
+    let mut expanded = use_signal(|| None::<String>);
+
+    let reload = move || {
+        spawn(async move {
+            if let Some(h) = api::get_dummy_parse_history().await {
+                let _ = history.try_write().map(|mut w| *w = h);
+            }
+        });
+    };
+
+    use_future(move || async move {
+        if let Some(config) = api::get_config().await {
+            settings.set(config.dummy_parse);
+        }
+        reload();
+    });
+
+    // Refresh when a live parse completes or the area index finishes a scan
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && payload
+                    .as_string()
+                    .is_some_and(|p| p.contains("DummyParseCompleted"))
+            {
+                reload();
+            }
+        });
+        api::tauri_listen("session-updated", &closure).await;
+        closure.forget();
+
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |_: JsValue| reload());
+        api::tauri_listen("log-files-changed", &closure).await;
+        closure.forget();
+    });
+
+    let mut save = move |new_settings: DummyParseSettings| {
+        settings.set(new_settings.clone());
+        let mut toast = use_toast();
+        spawn(async move {
+            if let Some(mut cfg) = api::get_config().await {
+                cfg.dummy_parse = new_settings;
+                if let Err(err) = api::update_config(&cfg).await {
+                    toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                }
+            }
+            reload();
+        });
+    };
+
+    let current = settings();
+    let (mode, amount) = match current.limit {
+        DummyParseLimit::Duration { secs } => ("duration", secs as f64 / 60.0),
+        DummyParseLimit::Damage { hp } => ("damage", hp as f64 / 1_000_000.0),
+    };
+
+    rsx! {
+        div { class: "dummy-parse-panel",
+            div { class: "dummy-parse-header",
+                h4 {
+                    i { class: "fa-solid fa-bullseye" }
+                    " Training Dummy Parses"
+                }
+                div { class: "dummy-parse-controls",
+                    label {
+                        input {
+                            r#type: "checkbox",
+                            checked: current.enabled,
+                            onchange: {
+                                let current = current.clone();
+                                move |e: Event<FormData>| {
+                                    save(DummyParseSettings { enabled: e.checked(), ..current.clone() });
+                                }
+                            },
+                        }
+                        " Track"
+                    }
+                    select {
+                        value: mode,
+                        onchange: {
+                            let current = current.clone();
+                            move |e: Event<FormData>| {
+                                let limit = if e.value() == "damage" {
+                                    DummyParseLimit::Damage { hp: 1_000_000 }
+                                } else {
+                                    DummyParseLimit::Duration { secs: 180 }
+                                };
+                                save(DummyParseSettings { limit, ..current.clone() });
+                            }
+                        },
+                        option { value: "duration", "Duration (min)" }
+                        option { value: "damage", "Damage (M HP)" }
+                    }
+                    input {
+                        r#type: "number",
+                        class: "dummy-parse-amount",
+                        min: "0.5",
+                        step: "0.5",
+                        value: "{amount}",
+                        onchange: {
+                            let current = current.clone();
+                            move |e: Event<FormData>| {
+                                let Ok(value) = e.value().parse::<f64>() else {
+                                    return;
+                                };
+                                if value <= 0.0 {
+                                    return;
+                                }
+                                let limit = match current.limit {
+                                    DummyParseLimit::Duration { .. } => DummyParseLimit::Duration {
+                                        secs: (value * 60.0).round() as u32,
+                                    },
+                                    DummyParseLimit::Damage { .. } => DummyParseLimit::Damage {
+                                        hp: (value * 1_000_000.0).round() as i64,
+                                    },
+                                };
+                                save(DummyParseSettings { limit, ..current.clone() });
+                            }
+                        },
+                    }
+                }
+            }
+
+            if history.read().is_empty() {
+                p { class: "hint",
+                    "No {current.limit.label(eu)} parses yet. A parse starts on your first hit on a training dummy and stops automatically at the limit."
+                }
+            } else {
+                table { class: "dummy-parse-table",
+                    thead {
+                        tr {
+                            th { "Character" }
+                            th { "Discipline" }
+                            th { class: "num", "Last DPS" }
+                            th { class: "num", "Best DPS" }
+                            th { class: "num", "Last vs Best" }
+                            th { class: "num", "Parses" }
+                        }
+                    }
+                    tbody {
+                        for group in history.read().iter() {
+                            {
+                                let key = format!("{}|{}", group.character, group.discipline);
+                                let is_open = expanded().as_deref() == Some(key.as_str());
+                                let last_dps = group.parses.first().map(|p| p.dps).unwrap_or_default();
+                                let delta_class = if group.last_vs_best_pct >= 100.0 {
+                                    "num dummy-parse-best"
+                                } else {
+                                    "num"
+                                };
+                                rsx! {
+                                    tr {
+                                        key: "{key}",
+                                        class: "dummy-parse-group",
+                                        onclick: {
+                                            let key = key.clone();
+                                            move |_| {
+                                                let next = if is_open { None } else { Some(key.clone()) };
+                                                expanded.set(next);
+                                            }
+                                        },
+                                        td {
+                                            i { class: if is_open { "fa-solid fa-chevron-down" } else { "fa-solid fa-chevron-right" } }
+                                            " {group.character}"
+                                        }
+                                        td { "{group.discipline}" }
+                                        td { class: "num", "{formatting::format_compact_f64(last_dps, eu)}" }
+                                        td { class: "num", "{formatting::format_compact_f64(group.best_dps, eu)}" }
+                                        td { class: delta_class, "{formatting::format_pct(group.last_vs_best_pct, eu)}" }
+                                        td { class: "num", "{group.parses.len()}" }
+                                    }
+                                    if is_open {
+                                        for parse in group.parses.iter().take(MAX_LISTED_PARSES) {
+                                            tr { key: "{key}-{parse.started_at}", class: "dummy-parse-row",
+                                                td { colspan: "2", "{parse.started_at}" }
+                                                td { class: "num", "{formatting::format_compact_f64(parse.dps, eu)}" }
+                                                td { class: "num", "{formatting::format_compact(parse.damage, eu)}" }
+                                                td { class: "num", "{formatting::format_duration_f32(parse.duration_secs)}" }
+                                                td {}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1597,7 +1597,7 @@ fn EffectEditForm(
                                         "Alert Text"
                                         span {
                                             class: "help-icon",
-                                            title: "Text shown in the alert notification. Leave blank for no alert",
+                                            title: "Text shown in the alert notification. Leave blank for no alert. Placeholders: {{source_name}}, {{target_name}}, {{stacks}}, {{phase}}, {{hp}}, {{counter:id}}",
                                             "?"
                                         }
                                     }
@@ -1907,7 +1907,7 @@ const ALL_DISCIPLINES: &[(&str, &[&str])] = &[
 ];
 
 #[component]
-fn DisciplineSelector(
+pub fn DisciplineSelector(
     selected: Vec<String>,
     on_change: EventHandler<Vec<String>>,
 ) -> Element {
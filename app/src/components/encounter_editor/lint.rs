@@ -0,0 +1,94 @@
+//! Definition lint panel
+//!
+//! Runs the core definition linter against the saved boss file and lists
+//! broken references, scope mismatches and unreachable phases.
+
+use dioxus::prelude::*;
+
+use crate::api;
+use crate::types::{BossWithPath, LintIssue, LintSeverity};
+
+/// Collapsible summary of lint findings for one boss
+#[component]
+pub fn LintPanel(boss_with_path: BossWithPath) -> Element {
+    let mut issues = use_signal(Vec::<LintIssue>::new);
+    let mut error = use_signal(|| None::<String>);
+    let mut expanded = use_signal(|| false);
+
+    // Props are not reactive on their own - mirror them into a signal so the
+    // lint re-runs after every save that replaces the boss.
+    let mut tracked = use_signal(|| boss_with_path.clone());
+    if *tracked.peek() != boss_with_path {
+        tracked.set(boss_with_path.clone());
+    }
+
+    use_effect(move || {
+        let bwp = tracked();
+        spawn(async move {
+            match api::lint_encounter_definitions(&bwp.file_path, Some(&bwp.boss.id)).await {
+                Ok(found) => {
+                    issues.set(found);
+                    error.set(None);
+                }
+                Err(e) => {
+                    issues.set(Vec::new());
+                    error.set(Some(e));
+                }
+            }
+        });
+    });
+
+    let list = issues();
+    let errors = list
+        .iter()
+        .filter(|i| i.severity == LintSeverity::Error)
+        .count();
+    let warnings = list.len() - errors;
+
+    if let Some(err) = error() {
+        return rsx! {
+            div { class: "lint-panel lint-error",
+                span { class: "lint-summary", "Lint failed: {err}" }
+            }
+        };
+    }
+    if list.is_empty() {
+        return rsx! {};
+    }
+
+    let panel_class = if errors > 0 {
+        "lint-panel lint-error"
+    } else {
+        "lint-panel lint-warning"
+    };
+
+    rsx! {
+        div { class: "{panel_class}",
+            button {
+                class: "lint-summary",
+                onclick: move |_| expanded.set(!expanded()),
+                span { if expanded() { "▾ " } else { "▸ " } }
+                if errors > 0 {
+                    "{errors} error(s)"
+                    if warnings > 0 { ", " }
+                }
+                if warnings > 0 {
+                    "{warnings} warning(s)"
+                }
+            }
+            if expanded() {
+                ul { class: "lint-list",
+                    for (idx, issue) in list.iter().enumerate() {
+                        li {
+                            key: "{idx}",
+                            class: if issue.severity == LintSeverity::Error { "lint-item error" } else { "lint-item warning" },
+                            span { class: "lint-scope", "{issue.scope}" }
+                            span { class: "lint-name", "{issue.item}" }
+                            span { class: "lint-message", "{issue.message}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
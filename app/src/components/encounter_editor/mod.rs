@@ -7,6 +7,7 @@ mod challenges;
 mod conditions;
 mod counters;
 mod entities;
+mod lint;
 mod new_forms;
 mod notes;
 mod phases;
@@ -93,7 +93,11 @@ pub fn NotesTab(
                 code { "1. numbered lists" }
                 ", "
                 code { "---" }
-                " dividers"
+                " dividers. Tag a header with "
+                code { "{{phase: burn}}" }
+                " or "
+                code { "{{counter: adds_killed >= 2}}" }
+                " to show that section only while it matches during the fight."
             }
 
             // Textarea for notes
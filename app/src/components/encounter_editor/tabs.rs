@@ -13,6 +13,7 @@ use crate::types::{
 use super::challenges::ChallengesTab;
 use super::counters::CountersTab;
 use super::entities::EntitiesTab;
+use super::lint::LintPanel;
 use super::notes::NotesTab;
 use super::phases::PhasesTab;
 use super::timers::TimersTab;
@@ -156,6 +157,9 @@ pub fn BossTabs(
 
     rsx! {
         div { class: "boss-tabs",
+            // Definition lint findings (hidden when clean)
+            LintPanel { boss_with_path: boss_with_path.clone() }
+
             // Tab bar with counts
             div { class: "tab-nav",
                 for tab in BossTab::all() {
@@ -12,6 +12,8 @@ use crate::types::{
 };
 use crate::utils::parse_hex_color;
 
+use crate::components::effect_editor::DisciplineSelector;
+
 use super::InlineNameCreator;
 use super::conditions::CounterConditionEditor;
 use super::tabs::EncounterData;
@@ -40,6 +42,8 @@ fn default_timer(name: String) -> BossTimerDefinition {
             "veteran".to_string(),
             "master".to_string(),
         ],
+        roles: vec![],
+        disciplines: vec![],
         enabled: true,
         can_be_refreshed: false,
         repeats: 0,
@@ -53,6 +57,18 @@ fn default_timer(name: String) -> BossTimerDefinition {
     }
 }
 
+/// Display name for a serialized discipline variant ("KineticCombat" -> "Kinetic Combat")
+fn discipline_label(variant: &str) -> String {
+    let mut label = String::with_capacity(variant.len() + 2);
+    for (i, c) in variant.chars().enumerate() {
+        if i > 0 && c.is_ascii_uppercase() {
+            label.push(' ');
+        }
+        label.push(c);
+    }
+    label
+}
+
 #[component]
 pub fn TimersTab(
     boss_with_path: BossWithPath,
@@ -489,6 +505,60 @@ fn TimerEditForm(
                                 }
                             }
 
+                            div { class: "form-row-hz",
+                                label { class: "flex items-center",
+                                    "Roles"
+                                    span {
+                                        class: "help-icon",
+                                        title: "Only show this timer when your local player's discipline has one of the selected roles. If none are selected, the timer applies to all roles.",
+                                        "?"
+                                    }
+                                }
+                                div { class: "flex gap-xs",
+                                    for (role, role_label) in [("Tank", "tank"), ("Healer", "healer"), ("Dps", "dps")] {
+                                        {
+                                            let role_str = role.to_string();
+                                            let is_active = draft().roles.contains(&role_str);
+
+                                            rsx! {
+                                                button {
+                                                    class: if is_active { "toggle-btn active" } else { "toggle-btn" },
+                                                    onclick: move |_| {
+                                                        let mut d = draft();
+                                                        if d.roles.contains(&role_str) {
+                                                            d.roles.retain(|x| x != &role_str);
+                                                        } else {
+                                                            d.roles.push(role_str.clone());
+                                                        }
+                                                        draft.set(d);
+                                                    },
+                                                    "{role_label}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            div { class: "form-row-hz",
+                                label { class: "flex items-center",
+                                    "Disciplines"
+                                    span {
+                                        class: "help-icon",
+                                        title: "Only show this timer when your local player is one of the selected disciplines. If empty, the timer applies to all disciplines.",
+                                        "?"
+                                    }
+                                }
+                                DisciplineSelector {
+                                    selected: draft().disciplines.iter().map(|d| discipline_label(d)).collect::<Vec<_>>(),
+                                    on_change: move |names: Vec<String>| {
+                                        let mut d = draft();
+                                        d.disciplines = names.iter().map(|n| n.replace(' ', "")).collect();
+                                        draft.set(d);
+                                    }
+                                }
+                            }
+
                             // ─── Display fields ────────────────────────────────
                             div { class: "form-row-hz mt-sm",
                                 label { "Color" }
@@ -870,7 +940,7 @@ fn TimerEditForm(
                                     "Alert Text"
                                     span {
                                         class: "help-icon",
-                                        title: "Text shown in the alert notification. Defaults to timer name",
+                                        title: "Text shown in the alert notification. Defaults to timer name. Placeholders: {{source_name}}, {{target_name}}, {{stacks}}, {{phase}}, {{hp}}, {{counter:id}}",
                                         "?"
                                     }
                                 }
@@ -0,0 +1,217 @@
+//! Healing matrix component.
+//!
+//! Heatmap of healing from each healer (rows) to each recipient (columns),
+//! switchable between effective, raw, overheal and shield absorption.
+
+use dioxus::prelude::*;
+
+use crate::api;
+use crate::api::{HealingMatrix, TimeRange};
+use baras_types::HealingMatrixCell;
+use baras_types::formatting;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct HealingMatrixViewProps {
+    pub encounter_idx: Option<u32>,
+    pub time_range: TimeRange,
+    /// European number format (swaps `.` and `,`)
+    #[props(default)]
+    pub european: bool,
+}
+
+/// Which value the heatmap colours by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatrixMetric {
+    EffectivePlusShield,
+    Effective,
+    Raw,
+    Overheal,
+    Shield,
+}
+
+impl MatrixMetric {
+    const ALL: [MatrixMetric; 5] = [
+        MatrixMetric::EffectivePlusShield,
+        MatrixMetric::Effective,
+        MatrixMetric::Raw,
+        MatrixMetric::Overheal,
+        MatrixMetric::Shield,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            MatrixMetric::EffectivePlusShield => "Effective + Shields",
+            MatrixMetric::Effective => "Effective Healing",
+            MatrixMetric::Raw => "Raw Healing",
+            MatrixMetric::Overheal => "Overhealing",
+            MatrixMetric::Shield => "Shield Absorbed",
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            MatrixMetric::EffectivePlusShield => "total",
+            MatrixMetric::Effective => "effective",
+            MatrixMetric::Raw => "raw",
+            MatrixMetric::Overheal => "overheal",
+            MatrixMetric::Shield => "shield",
+        }
+    }
+
+    fn from_key(key: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|m| m.key() == key)
+            .unwrap_or(MatrixMetric::EffectivePlusShield)
+    }
+
+    fn value(self, cell: &HealingMatrixCell) -> f64 {
+        match self {
+            MatrixMetric::EffectivePlusShield => cell.effective_heal + cell.shield_absorbed,
+            MatrixMetric::Effective => cell.effective_heal,
+            MatrixMetric::Raw => cell.raw_heal,
+            MatrixMetric::Overheal => cell.overheal,
+            MatrixMetric::Shield => cell.shield_absorbed,
+        }
+    }
+}
+
+#[component]
+pub fn HealingMatrixView(props: HealingMatrixViewProps) -> Element {
+    let eu = props.european;
+
+    let mut matrix = use_signal(HealingMatrix::default);
+    let mut loading = use_signal(|| false);
+    let mut metric = use_signal(|| MatrixMetric::EffectivePlusShield);
+
+    // Track time_range so the query re-runs on phase/time filter changes
+    let mut tracked_time_range = use_signal(|| props.time_range);
+    if *tracked_time_range.read() != props.time_range {
+        tracked_time_range.set(props.time_range);
+    }
+
+    let enc_idx = props.encounter_idx;
+
+    use_effect(move || {
+        let tr = tracked_time_range();
+        let tr_opt = if tr.start == 0.0 && tr.end == 0.0 {
+            None
+        } else {
+            Some(tr)
+        };
+
+        loading.set(true);
+        spawn(async move {
+            let result = api::query_healing_matrix(enc_idx, tr_opt.as_ref()).await;
+            matrix.set(result.unwrap_or_default());
+            loading.set(false);
+        });
+    });
+
+    let data = matrix.read();
+    let m = metric();
+    let value_at = |healer: &str, recipient: &str| -> Option<&HealingMatrixCell> {
+        data.cells
+            .iter()
+            .find(|c| c.source_name == healer && c.target_name == recipient)
+    };
+    let max_value = data.cells.iter().map(|c| m.value(c)).fold(0.0_f64, f64::max);
+    let healer_total = |healer: &str| -> f64 {
+        data.cells
+            .iter()
+            .filter(|c| c.source_name == healer)
+            .map(|c| m.value(c))
+            .sum()
+    };
+    let recipient_total = |recipient: &str| -> f64 {
+        data.cells
+            .iter()
+            .filter(|c| c.target_name == recipient)
+            .map(|c| m.value(c))
+            .sum()
+    };
+
+    rsx! {
+        div { class: "healing-matrix",
+            div { class: "healing-matrix-controls",
+                label { "Metric" }
+                select {
+                    value: "{m.key()}",
+                    onchange: move |e| metric.set(MatrixMetric::from_key(&e.value())),
+                    for option in MatrixMetric::ALL {
+                        option { value: "{option.key()}", "{option.label()}" }
+                    }
+                }
+            }
+            if loading() && data.cells.is_empty() {
+                div { class: "rotation-placeholder", "Loading..." }
+            } else if data.cells.is_empty() {
+                div { class: "rotation-placeholder", "No healing was done in this encounter." }
+            } else {
+                div { class: "healing-matrix-scroll",
+                    table { class: "healing-matrix-table",
+                        thead {
+                            tr {
+                                th { class: "corner", "Healer \\ Target" }
+                                for recipient in data.recipients.iter() {
+                                    th { key: "{recipient}", class: "recipient", title: "{recipient}",
+                                        span { "{recipient}" }
+                                    }
+                                }
+                                th { class: "total", "Total" }
+                            }
+                        }
+                        tbody {
+                            for healer in data.healers.iter() {
+                                tr { key: "{healer}",
+                                    th { class: "healer", "{healer}" }
+                                    for recipient in data.recipients.iter() {
+                                        {
+                                            match value_at(healer, recipient) {
+                                                Some(cell) if m.value(cell) > 0.0 => {
+                                                    let value = m.value(cell);
+                                                    let intensity = if max_value > 0.0 { value / max_value } else { 0.0 };
+                                                    let tooltip = format!(
+                                                        "{healer} → {recipient}\nEffective: {}\nRaw: {}\nOverheal: {}\nShield: {}",
+                                                        formatting::format_compact_f64(cell.effective_heal, eu),
+                                                        formatting::format_compact_f64(cell.raw_heal, eu),
+                                                        formatting::format_compact_f64(cell.overheal, eu),
+                                                        formatting::format_compact_f64(cell.shield_absorbed, eu),
+                                                    );
+                                                    rsx! {
+                                                        td {
+                                                            key: "{recipient}",
+                                                            class: "cell",
+                                                            title: "{tooltip}",
+                                                            style: "--heat: {intensity:.3};",
+                                                            "{formatting::format_compact_f64(value, eu)}"
+                                                        }
+                                                    }
+                                                }
+                                                _ => rsx! {
+                                                    td { key: "{recipient}", class: "cell empty" }
+                                                },
+                                            }
+                                        }
+                                    }
+                                    td { class: "total", "{formatting::format_compact_f64(healer_total(healer), eu)}" }
+                                }
+                            }
+                        }
+                        tfoot {
+                            tr {
+                                th { class: "healer", "Total" }
+                                for recipient in data.recipients.iter() {
+                                    td { key: "{recipient}", class: "total",
+                                        "{formatting::format_compact_f64(recipient_total(recipient), eu)}"
+                                    }
+                                }
+                                td { class: "total" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
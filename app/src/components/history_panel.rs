@@ -11,6 +11,7 @@ use wasm_bindgen_futures::spawn_local as spawn;
 use crate::api;
 use crate::components::class_icons::{get_class_icon, get_role_icon};
 use crate::components::{ToastSeverity, use_parsely_upload, use_toast};
+use crate::types::PersonalRank;
 use baras_types::formatting;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -145,6 +146,31 @@ fn group_by_area(
     sections
 }
 
+/// Badge for a boss pull ranked against the player's own history:
+/// (class, label, tooltip). `None` when there's nothing to compare against.
+fn personal_rank_badge(rank: &PersonalRank) -> Option<(&'static str, String, String)> {
+    if rank.compared_pulls == 0 {
+        return None;
+    }
+    let headline = rank.headline();
+    let (metric, top) = headline.first()?;
+    let title = format!(
+        "{} vs {} earlier pulls (best {})",
+        metric,
+        rank.compared_pulls,
+        formatting::format_compact(top.best, false)
+    );
+    if !rank.new_bests().is_empty() {
+        Some(("personal-rank-badge best", "PB".to_string(), title))
+    } else {
+        Some((
+            "personal-rank-badge",
+            format!("{:.0}%", top.percentile),
+            title,
+        ))
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Components
 // ─────────────────────────────────────────────────────────────────────────────
@@ -177,6 +203,16 @@ pub fn HistoryPanel(mut props: HistoryPanelProps) -> Element {
     
     // Track upload state per encounter_id
     let mut upload_states = use_signal(HashMap::<u64, UploadState>::new);
+    // Personal-best ranks by encounter_id (boss pulls by the local player)
+    let mut ranks = use_signal(HashMap::<u64, PersonalRank>::new);
+    let reload_ranks = move || {
+        spawn(async move {
+            if let Some(list) = api::get_personal_ranks().await {
+                let by_id = list.into_iter().map(|r| (r.encounter_id, r)).collect();
+                let _ = ranks.try_write().map(|mut w| *w = by_id);
+            }
+        });
+    };
     // Get parsely upload manager for event handlers
     let mut parsely_upload = use_parsely_upload();
 
@@ -190,6 +226,7 @@ pub fn HistoryPanel(mut props: HistoryPanelProps) -> Element {
             encounters.set(history);
         }
         loading.set(false);
+        reload_ranks();
     });
 
     // Listen for session updates (refresh on combat end, file change, etc.)
@@ -215,6 +252,7 @@ pub fn HistoryPanel(mut props: HistoryPanelProps) -> Element {
                         }
                         let _ = encounters.try_write().map(|mut w| *w = history);
                     }
+                    reload_ranks();
                 });
             }
         });
@@ -389,6 +427,10 @@ pub fn HistoryPanel(mut props: HistoryPanelProps) -> Element {
                                                     let row_class = if is_expanded { "expanded" } else { "" };
                                                     let success_class = if enc.success { "success" } else { "wipe" };
                                                     let npc_list = enc.npc_names.join(", ");
+                                                    let rank_badge = ranks
+                                                        .read()
+                                                        .get(&enc_id)
+                                                        .and_then(personal_rank_badge);
                                                     
                                                     // Check if already uploaded (persisted in backend)
                                                     let persisted_link = enc.parsely_link.clone();
@@ -418,6 +460,9 @@ pub fn HistoryPanel(mut props: HistoryPanelProps) -> Element {
                                                             td { class: "col-name",
                                                                 div { class: "encounter-info",
                                                                     span { class: "encounter-name", "{enc.display_name}" }
+                                                                    if let Some((badge_class, label, title)) = rank_badge {
+                                                                        span { class: "{badge_class}", title: "{title}", "{label}" }
+                                                                    }
                                                                     if !npc_list.is_empty() {
                                                                         span { class: "encounter-npcs", "{npc_list}" }
                                                                     }
@@ -5,6 +5,8 @@
 
 use dioxus::prelude::*;
 
+use crate::types::{ActionHotkey, HotkeyAction};
+
 /// Props for the HotkeyInput component
 #[derive(Props, Clone, PartialEq)]
 pub struct HotkeyInputProps {
@@ -176,3 +178,143 @@ pub fn HotkeyInput(props: HotkeyInputProps) -> Element {
         }
     }
 }
+
+/// Kind of action in the action hotkey editor's dropdown
+fn action_kind(action: &HotkeyAction) -> &'static str {
+    match action {
+        HotkeyAction::StartTimer { .. } => "start_timer",
+        HotkeyAction::ResetTimer { .. } => "reset_timer",
+        HotkeyAction::IncrementCounter { .. } => "increment_counter",
+        HotkeyAction::DecrementCounter { .. } => "decrement_counter",
+        HotkeyAction::SetCounter { .. } => "set_counter",
+    }
+}
+
+/// Rebuild an action as another kind, keeping its target ID
+fn action_with_kind(kind: &str, id: String, value: u32) -> HotkeyAction {
+    match kind {
+        "reset_timer" => HotkeyAction::ResetTimer { timer_id: id },
+        "increment_counter" => HotkeyAction::IncrementCounter { counter_id: id },
+        "decrement_counter" => HotkeyAction::DecrementCounter { counter_id: id },
+        "set_counter" => HotkeyAction::SetCounter {
+            counter_id: id,
+            value,
+        },
+        _ => HotkeyAction::StartTimer { timer_id: id },
+    }
+}
+
+/// Editable list of hotkeys bound to manual timers and boss counters
+#[component]
+pub fn ActionHotkeysEditor(
+    actions: Vec<ActionHotkey>,
+    on_change: EventHandler<Vec<ActionHotkey>>,
+) -> Element {
+    rsx! {
+        div { class: "action-hotkeys",
+            for (idx, binding) in actions.iter().cloned().enumerate() {
+                {
+                    let kind = action_kind(&binding.action);
+                    let target = binding.action.target_id().to_string();
+                    let value = match binding.action {
+                        HotkeyAction::SetCounter { value, .. } => value,
+                        _ => 0,
+                    };
+                    let placeholder = if kind.ends_with("timer") { "timer id" } else { "counter id" };
+                    rsx! {
+                        div { class: "action-hotkey-row", key: "{idx}",
+                            HotkeyInput {
+                                value: binding.key.clone(),
+                                on_change: {
+                                    let actions = actions.clone();
+                                    move |v: String| {
+                                        let mut list = actions.clone();
+                                        list[idx].key = v;
+                                        on_change.call(list);
+                                    }
+                                },
+                            }
+                            select {
+                                value: "{kind}",
+                                onchange: {
+                                    let actions = actions.clone();
+                                    let target = target.clone();
+                                    move |e: Event<FormData>| {
+                                        let mut list = actions.clone();
+                                        list[idx].action = action_with_kind(&e.value(), target.clone(), value);
+                                        on_change.call(list);
+                                    }
+                                },
+                                option { value: "start_timer", "Start timer" }
+                                option { value: "reset_timer", "Reset timer" }
+                                option { value: "increment_counter", "Counter +1" }
+                                option { value: "decrement_counter", "Counter -1" }
+                                option { value: "set_counter", "Set counter" }
+                            }
+                            input {
+                                r#type: "text",
+                                placeholder: "{placeholder}",
+                                value: "{target}",
+                                onchange: {
+                                    let actions = actions.clone();
+                                    move |e: Event<FormData>| {
+                                        let mut list = actions.clone();
+                                        list[idx].action = action_with_kind(kind, e.value().trim().to_string(), value);
+                                        on_change.call(list);
+                                    }
+                                }
+                            }
+                            if kind == "set_counter" {
+                                input {
+                                    r#type: "number",
+                                    class: "input-small",
+                                    min: "0",
+                                    value: "{value}",
+                                    onchange: {
+                                        let actions = actions.clone();
+                                        let target = target.clone();
+                                        move |e: Event<FormData>| {
+                                            let mut list = actions.clone();
+                                            let value = e.value().parse().unwrap_or(0);
+                                            list[idx].action = action_with_kind(kind, target.clone(), value);
+                                            on_change.call(list);
+                                        }
+                                    }
+                                }
+                            }
+                            button {
+                                class: "btn btn-small",
+                                title: "Remove",
+                                onclick: {
+                                    let actions = actions.clone();
+                                    move |_| {
+                                        let mut list = actions.clone();
+                                        list.remove(idx);
+                                        on_change.call(list);
+                                    }
+                                },
+                                i { class: "fa-solid fa-xmark" }
+                            }
+                        }
+                    }
+                }
+            }
+            button {
+                class: "btn btn-small",
+                onclick: {
+                    let actions = actions.clone();
+                    move |_| {
+                        let mut list = actions.clone();
+                        list.push(ActionHotkey {
+                            key: String::new(),
+                            action: HotkeyAction::StartTimer { timer_id: String::new() },
+                        });
+                        on_change.call(list);
+                    }
+                },
+                i { class: "fa-solid fa-plus" }
+                " Add Timer/Counter Hotkey"
+            }
+        }
+    }
+}
@@ -7,22 +7,29 @@ pub mod ability_icon;
 pub mod charts_panel;
 pub mod class_icons;
 pub mod combat_log;
+pub mod cooldown_view;
+pub mod healing_matrix;
 pub mod data_explorer;
+pub mod dummy_parses;
 pub mod effect_editor;
 pub mod encounter_editor;
 pub mod history_panel;
 pub mod hotkey_input;
 pub mod parsely_upload_modal;
 pub mod phase_timeline;
+pub mod pvp_matches;
 pub mod rotation_view;
 pub mod settings_panel;
+pub mod table_export;
 pub mod toast;
 
 pub use data_explorer::DataExplorerPanel;
+pub use dummy_parses::DummyParsePanel;
 pub use effect_editor::EffectEditorPanel;
 pub use encounter_editor::EncounterEditorPanel;
 pub use history_panel::HistoryPanel;
-pub use hotkey_input::HotkeyInput;
+pub use hotkey_input::{ActionHotkeysEditor, HotkeyInput};
 pub use parsely_upload_modal::{ParselyUploadModal, use_parsely_upload, use_parsely_upload_provider};
+pub use pvp_matches::PvpMatchPanel;
 pub use settings_panel::SettingsPanel;
 pub use toast::{ToastFrame, ToastSeverity, use_toast, use_toast_provider};
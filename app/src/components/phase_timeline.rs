@@ -1,11 +1,14 @@
 //! Phase Timeline Filter Component
 //!
-//! A timeline bar showing encounter duration with phase segments.
+//! A timeline bar showing encounter duration with phase segments and pinned notes.
 //! Allows selecting a phase or dragging an arbitrary time range.
 
 use dioxus::prelude::*;
 
-use crate::api::{EncounterTimeline, PhaseSegment, TimeRange};
+use crate::api::{EncounterAnnotation, EncounterTimeline, PhaseSegment, TimeRange};
+
+/// Seconds shown either side of a note when jumping to it
+const NOTE_WINDOW_SECS: f32 = 10.0;
 
 fn format_time(secs: f32) -> String {
     let mins = (secs / 60.0) as i32;
@@ -48,6 +51,18 @@ pub struct PhaseTimelineProps {
     pub range: TimeRange,
     /// Callback when range changes
     pub on_range_change: EventHandler<TimeRange>,
+    /// Notes pinned to the encounter
+    #[props(default)]
+    pub annotations: Vec<EncounterAnnotation>,
+    /// Add a note (None when notes can't be added, e.g. live encounter)
+    #[props(default)]
+    pub on_add_annotation: Option<EventHandler<EncounterAnnotation>>,
+    /// Change a note's text (id, text)
+    #[props(default)]
+    pub on_edit_annotation: Option<EventHandler<(u64, String)>>,
+    /// Delete a note by id
+    #[props(default)]
+    pub on_remove_annotation: Option<EventHandler<u64>>,
 }
 
 #[component]
@@ -65,6 +80,9 @@ pub fn PhaseTimelineFilter(props: PhaseTimelineProps) -> Element {
     let mut editing_start = use_signal(|| None::<String>);
     let mut editing_end = use_signal(|| None::<String>);
 
+    // Note being written: None = closed, Some((id, time, text)) with id 0 for a new note
+    let mut note_draft = use_signal(|| None::<(u64, f32, String)>);
+
     // Calculate percentage position for a time value
     let time_to_pct = |t: f32| -> f32 {
         if duration > 0.0 {
@@ -81,6 +99,37 @@ pub fn PhaseTimelineFilter(props: PhaseTimelineProps) -> Element {
             .call(TimeRange::new(phase.start_secs, phase.end_secs));
     };
 
+    // Jump to a note: select a short window around it
+    let jump_to_note = move |time_secs: f32| {
+        props.on_range_change.call(TimeRange::new(
+            (time_secs - NOTE_WINDOW_SECS).max(0.0),
+            (time_secs + NOTE_WINDOW_SECS).min(duration),
+        ));
+    };
+
+    // Save the open note draft
+    let mut save_note = move || {
+        let Some((id, time_secs, text)) = note_draft.read().clone() else {
+            return;
+        };
+        if text.trim().is_empty() {
+            return;
+        }
+        if id == 0 {
+            if let Some(ref handler) = props.on_add_annotation {
+                handler.call(EncounterAnnotation {
+                    id: 0,
+                    time_secs,
+                    row_idx: None,
+                    text,
+                });
+            }
+        } else if let Some(ref handler) = props.on_edit_annotation {
+            handler.call((id, text));
+        }
+        note_draft.set(None);
+    };
+
     // Handle reset to full range
     let reset_range = move |_| {
         committed_range.set(None);
@@ -324,6 +373,28 @@ pub fn PhaseTimelineFilter(props: PhaseTimelineProps) -> Element {
                             }
                         }
                     }
+
+                    // Note markers
+                    for note in props.annotations.iter() {
+                        {
+                            let left = time_to_pct(note.time_secs);
+                            let time_secs = note.time_secs;
+                            rsx! {
+                                div {
+                                    key: "{note.id}",
+                                    class: "phase-timeline-note",
+                                    style: "left: {left}%;",
+                                    title: "{format_time(note.time_secs)} {note.text}",
+                                    onmousedown: move |e| e.stop_propagation(),
+                                    onclick: move |e| {
+                                        e.stop_propagation();
+                                        jump_to_note(time_secs);
+                                    },
+                                    i { class: "fa-solid fa-thumbtack" }
+                                }
+                            }
+                        }
+                    }
                 }
             }
 
@@ -454,6 +525,79 @@ pub fn PhaseTimelineFilter(props: PhaseTimelineProps) -> Element {
                         "✕"
                     }
                 }
+
+                if props.on_add_annotation.is_some() && note_draft.read().is_none() {
+                    button {
+                        class: "phase-timeline-add-note",
+                        title: "Pin a note at the start of the selected range",
+                        onclick: move |_| note_draft.set(Some((0, range.start, String::new()))),
+                        i { class: "fa-solid fa-thumbtack" }
+                        " Note"
+                    }
+                }
+            }
+
+            // Note editor (new note or edit)
+            if let Some((id, time_secs, text)) = note_draft.read().clone() {
+                div { class: "phase-timeline-note-editor",
+                    span { class: "note-time", "{format_time(time_secs)}" }
+                    input {
+                        r#type: "text",
+                        placeholder: "Note...",
+                        maxlength: 500,
+                        value: "{text}",
+                        autofocus: true,
+                        oninput: move |e| note_draft.set(Some((id, time_secs, e.value()))),
+                        onkeydown: move |e| match e.key() {
+                            Key::Enter => save_note(),
+                            Key::Escape => note_draft.set(None),
+                            _ => {}
+                        },
+                    }
+                    button { onclick: move |_| save_note(), "Save" }
+                    button { onclick: move |_| note_draft.set(None), "Cancel" }
+                }
+            }
+
+            // Pinned notes
+            if !props.annotations.is_empty() {
+                div { class: "phase-timeline-notes",
+                    for note in props.annotations.iter() {
+                        {
+                            let note_id = note.id;
+                            let time_secs = note.time_secs;
+                            let text = note.text.clone();
+                            rsx! {
+                                div { key: "{note.id}", class: "phase-timeline-note-item",
+                                    button {
+                                        class: "note-time",
+                                        title: "Jump to note",
+                                        onclick: move |_| jump_to_note(time_secs),
+                                        i { class: "fa-solid fa-thumbtack" }
+                                        " {format_time(note.time_secs)}"
+                                    }
+                                    span { class: "note-text", "{note.text}" }
+                                    if props.on_edit_annotation.is_some() {
+                                        button {
+                                            class: "note-action",
+                                            title: "Edit note",
+                                            onclick: move |_| note_draft.set(Some((note_id, time_secs, text.clone()))),
+                                            i { class: "fa-solid fa-pen" }
+                                        }
+                                    }
+                                    if let Some(handler) = props.on_remove_annotation {
+                                        button {
+                                            class: "note-action",
+                                            title: "Delete note",
+                                            onclick: move |_| handler.call(note_id),
+                                            i { class: "fa-solid fa-xmark" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
             }
 
             // Phase legend chips (compact)
@@ -0,0 +1,145 @@
+//! PvP match scoreboards component.
+//!
+//! Warzone and arena matches from the current log file, each with a
+//! scoreboard for damage, healing, protection and kills/deaths/assists.
+//! Arena matches can be viewed per round.
+
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use crate::api;
+use crate::types::{PvpMatch, PvpScoreboardEntry};
+use baras_types::formatting;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PvpMatchPanelProps {
+    /// European number format (swaps `.` and `,`)
+    #[props(default)]
+    pub european: bool,
+}
+
+#[component]
+pub fn PvpMatchPanel(props: PvpMatchPanelProps) -> Element {
+    let eu = props.european;
+
+    let mut matches = use_signal(Vec::<PvpMatch>::new);
+    // (match index, round number; None = whole match)
+    let mut selected = use_signal(|| (0usize, None::<u32>));
+
+    let reload = move || {
+        spawn(async move {
+            if let Some(m) = api::get_pvp_matches().await {
+                let newest = m.len().saturating_sub(1);
+                let _ = matches.try_write().map(|mut w| *w = m);
+                selected.set((newest, None));
+            }
+        });
+    };
+
+    use_future(move || async move {
+        reload();
+    });
+
+    // Refresh when a fight ends or another file is loaded
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && payload.as_string().is_some_and(|p| {
+                    p.contains("CombatEnded") || p.contains("FileLoaded")
+                })
+            {
+                reload();
+            }
+        });
+        api::tauri_listen("session-updated", &closure).await;
+        closure.forget();
+    });
+
+    let all = matches.read();
+    if all.is_empty() {
+        return rsx! {};
+    }
+    let (match_idx, round_no) = selected();
+    let Some(current) = all.get(match_idx).or(all.last()) else {
+        return rsx! {};
+    };
+    let round = round_no.and_then(|n| current.rounds.iter().find(|r| r.number == n));
+    let scoreboard: &[PvpScoreboardEntry] = match round {
+        Some(round) => &round.scoreboard,
+        None => &current.scoreboard,
+    };
+    let duration = round.map_or(current.duration_seconds, |r| r.duration_seconds);
+
+    rsx! {
+        div { class: "pvp-match-panel",
+            div { class: "pvp-match-header",
+                h4 {
+                    i { class: "fa-solid fa-shield-halved" }
+                    " PvP Matches"
+                }
+                div { class: "pvp-match-controls",
+                    select {
+                        value: "{match_idx}",
+                        onchange: move |e: Event<FormData>| {
+                            if let Ok(idx) = e.value().parse::<usize>() {
+                                selected.set((idx, None));
+                            }
+                        },
+                        for (idx, m) in all.iter().enumerate() {
+                            option {
+                                key: "{idx}",
+                                value: "{idx}",
+                                "{idx + 1}. {m.area_name}"
+                            }
+                        }
+                    }
+                    if !current.rounds.is_empty() {
+                        select {
+                            value: round_no.map(|n| n.to_string()).unwrap_or_default(),
+                            onchange: move |e: Event<FormData>| {
+                                selected.set((match_idx, e.value().parse::<u32>().ok()));
+                            },
+                            option { value: "", "All rounds" }
+                            for r in current.rounds.iter() {
+                                option { key: "{r.number}", value: "{r.number}", "Round {r.number}" }
+                            }
+                        }
+                    }
+                    span { class: "hint", "{formatting::format_duration(duration)} in combat" }
+                }
+            }
+
+            table { class: "pvp-scoreboard",
+                thead {
+                    tr {
+                        th { "Player" }
+                        th { "Discipline" }
+                        th { class: "num", "Damage" }
+                        th { class: "num", "Healing" }
+                        th { class: "num", title: "Shielding plus estimated taunt mitigation", "Protection" }
+                        th { class: "num", "K" }
+                        th { class: "num", "D" }
+                        th { class: "num", "A" }
+                    }
+                }
+                tbody {
+                    for entry in scoreboard.iter() {
+                        tr { key: "{entry.name}",
+                            td { "{entry.name}" }
+                            td { class: "pvp-discipline", {entry.discipline_name.clone().unwrap_or_default()} }
+                            td { class: "num", "{formatting::format_compact(entry.damage, eu)}" }
+                            td { class: "num", "{formatting::format_compact(entry.healing, eu)}" }
+                            td { class: "num", "{formatting::format_compact(entry.protection, eu)}" }
+                            td { class: "num", "{entry.kills}" }
+                            td { class: "num", "{entry.deaths}" }
+                            td { class: "num", "{entry.assists}" }
+                        }
+                    }
+                }
+            }
+            p { class: "hint",
+                "Only events involving you are logged, so other players' numbers are partial."
+            }
+        }
+    }
+}
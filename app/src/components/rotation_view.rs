@@ -2,11 +2,17 @@
 //!
 //! Displays ability rotation cycles split by an anchor ability,
 //! with GCD abilities in a horizontal row and off-GCD weaves stacked above.
+//! A GCD uptime summary above the cycles lists downtime gaps and what was
+//! logged around them. If a reference rotation is defined for the player's
+//! discipline, each cycle is scored and its deviations are listed.
 
 use dioxus::prelude::*;
 
 use crate::api;
-use crate::api::{RotationAnalysis, TimeRange};
+use crate::api::{
+    GcdGapContext, GcdGapKind, GcdUptimeAnalysis, RotationAnalysis, RotationDeviation,
+    RotationDeviationKind, TimeRange,
+};
 use crate::components::ability_icon::AbilityIcon;
 use baras_types::formatting;
 
@@ -23,6 +29,35 @@ pub struct RotationViewProps {
     pub european: bool,
 }
 
+/// Short label for a logged event inside a GCD gap.
+fn gap_context_label(ctx: &GcdGapContext) -> String {
+    match ctx.kind {
+        GcdGapKind::Death => "Died".to_string(),
+        GcdGapKind::Revived => "Revived".to_string(),
+        GcdGapKind::PhaseTransition if ctx.detail.is_empty() => "Phase ended".to_string(),
+        GcdGapKind::PhaseTransition => format!("Phase: {}", ctx.detail),
+        GcdGapKind::Interrupted => format!("Interrupted: {}", ctx.detail),
+        GcdGapKind::Cancelled => format!("Cancelled: {}", ctx.detail),
+        GcdGapKind::TargetCleared => "Target lost".to_string(),
+    }
+}
+
+/// Short description of a deviation from the reference rotation.
+fn deviation_label(dev: &RotationDeviation) -> String {
+    let expected = dev.expected.as_deref().unwrap_or("?");
+    let actual = dev.actual.as_deref().unwrap_or("?");
+    match dev.kind {
+        RotationDeviationKind::Priority => format!("{} while {} was available", actual, expected),
+        RotationDeviationKind::OutOfOrder => {
+            format!("{} instead of {} ({})", actual, expected, dev.detail)
+        }
+        RotationDeviationKind::Missing => format!("Skipped {}", expected),
+        RotationDeviationKind::DotClipped => format!("{} clipped: {}", actual, dev.detail),
+        RotationDeviationKind::DotDropped => format!("{} dropped: {}", actual, dev.detail),
+        RotationDeviationKind::NotUsed => format!("{} never used", expected),
+    }
+}
+
 #[component]
 pub fn RotationView(props: RotationViewProps) -> Element {
     let eu = props.european;
@@ -32,6 +67,7 @@ pub fn RotationView(props: RotationViewProps) -> Element {
     let mut available_abilities = use_signal(|| Vec::<(i64, String)>::new());
     let mut selected_anchor = use_signal(|| None::<i64>);
     let mut rotation = use_signal(|| None::<RotationAnalysis>);
+    let mut gcd_uptime = use_signal(|| None::<GcdUptimeAnalysis>);
     let mut loading = use_signal(|| false);
 
     // Context menu state for right-click "Set as range start/end"
@@ -77,6 +113,27 @@ pub fn RotationView(props: RotationViewProps) -> Element {
         });
     });
 
+    // GCD uptime follows source and time range (no anchor needed)
+    use_effect(move || {
+        let tr = tracked_time_range();
+        let source = tracked_source.read().clone();
+
+        let Some(source_name) = source else {
+            gcd_uptime.set(None);
+            return;
+        };
+        let tr_opt = if tr.start == 0.0 && tr.end == 0.0 {
+            None
+        } else {
+            Some(tr)
+        };
+
+        spawn(async move {
+            let result = api::query_gcd_uptime(enc_idx, &source_name, tr_opt.as_ref()).await;
+            gcd_uptime.set(result);
+        });
+    });
+
     // Query rotation when Create is clicked (rotation_active) or time_range changes
     use_effect(move || {
         let tr = tracked_time_range();
@@ -150,6 +207,93 @@ pub fn RotationView(props: RotationViewProps) -> Element {
                 }
             }
 
+            // GCD uptime summary and downtime gaps
+            if let Some(ref uptime) = gcd_uptime() {
+                div { class: "gcd-uptime",
+                    div { class: "gcd-uptime-stats",
+                        span { class: "cycle-stat",
+                            span { class: "cycle-stat-label", "ABC " }
+                            "{formatting::format_pct_f32(uptime.abc_pct, eu)}"
+                        }
+                        span { class: "cycle-stat",
+                            span { class: "cycle-stat-label", "Active " }
+                            "{formatting::format_duration_f32(uptime.active_secs)} / {formatting::format_duration_f32(uptime.window_secs)}"
+                        }
+                        span { class: "cycle-stat",
+                            span { class: "cycle-stat-label", "GCDs " }
+                            "{uptime.gcd_count}"
+                        }
+                        span { class: "cycle-stat",
+                            title: if uptime.gcd_inferred {
+                                format!(
+                                    "Inferred from cast spacing: {}% alacrity, ~{} ms latency",
+                                    formatting::format_decimal(uptime.alacrity_percent, 1, eu),
+                                    uptime.latency_ms,
+                                )
+                            } else {
+                                format!(
+                                    "Not enough back-to-back casts to infer alacrity; using the configured {}%",
+                                    formatting::format_decimal(uptime.alacrity_percent, 1, eu),
+                                )
+                            },
+                            span { class: "cycle-stat-label", "GCD " }
+                            "{formatting::format_decimal(uptime.gcd_secs, 1, eu)}s"
+                            if !uptime.gcd_inferred { "?" }
+                        }
+                        span { class: "cycle-stat",
+                            span { class: "cycle-stat-label", "Gaps " }
+                            "{uptime.gaps.len()}"
+                        }
+                    }
+                    if !uptime.gaps.is_empty() {
+                        table { class: "gcd-gap-table",
+                            thead {
+                                tr {
+                                    th { "Time" }
+                                    th { class: "num", "Length" }
+                                    th { "Between" }
+                                    th { "Context" }
+                                }
+                            }
+                            tbody {
+                                for (i, gap) in uptime.gaps.iter().enumerate() {
+                                    {
+                                    let gap_range = TimeRange::new(gap.start_secs, gap.end_secs);
+                                    rsx! {
+                                    tr { key: "{i}",
+                                        class: if props.on_range_change.is_some() { "gcd-gap-row clickable" } else { "gcd-gap-row" },
+                                        title: if props.on_range_change.is_some() { "Click to zoom to this gap" } else { "" },
+                                        onclick: move |_| {
+                                            if let Some(ref handler) = props.on_range_change {
+                                                handler.call(gap_range);
+                                            }
+                                        },
+                                        td { "{formatting::format_duration_f32(gap.start_secs)} - {formatting::format_duration_f32(gap.end_secs)}" }
+                                        td { class: "num", "{formatting::format_decimal(gap.end_secs - gap.start_secs, 1, eu)}s" }
+                                        td {
+                                            "{gap.before_ability.as_deref().unwrap_or(\"(start)\")} → {gap.after_ability.as_deref().unwrap_or(\"(end)\")}"
+                                        }
+                                        td {
+                                            if gap.context.is_empty() {
+                                                span { class: "gcd-gap-unexplained", "Nothing logged (movement / mechanics?)" }
+                                            }
+                                            for (k, ctx) in gap.context.iter().enumerate() {
+                                                span { key: "{k}", class: "gcd-gap-tag",
+                                                    title: "{formatting::format_duration_f32(ctx.time_secs)}",
+                                                    "{gap_context_label(ctx)}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // Rotation cycles
             if let Some(ref analysis) = rotation() {
                 if analysis.cycles.is_empty() {
@@ -157,12 +301,44 @@ pub fn RotationView(props: RotationViewProps) -> Element {
                         "No rotation data found for the selected anchor ability."
                     }
                 } else {
+                    // Reference rotation summary
+                    if let Some(ref check) = analysis.check {
+                        div { class: "rotation-check-summary",
+                            span { class: "cycle-stat",
+                                span { class: "cycle-stat-label", "Reference " }
+                                "{check.name}"
+                            }
+                            span { class: "cycle-stat",
+                                span { class: "cycle-stat-label", "Score " }
+                                "{formatting::format_pct_f32(check.score_pct, eu)}"
+                            }
+                            span { class: "cycle-stat",
+                                title: "Estimated from your average damage and healing per cast",
+                                span { class: "cycle-stat-label", "Est. lost " }
+                                "{format_number(check.lost_damage)}"
+                            }
+                        }
+                    } else {
+                        div { class: "rotation-check-hint",
+                            "No reference rotation for this discipline. Add a TOML file to the rotations folder in your BARAS definitions directory to score cycles."
+                        }
+                    }
                     div { class: "rotation-cycles",
                         for (i, cycle) in analysis.cycles.iter().enumerate() {
+                            {
+                            let score = analysis.check.as_ref().and_then(|c| c.cycles.get(i));
+                            rsx! {
                             div { class: "rotation-cycle",
                                 key: "{i}",
                                 // Per-cycle stats
                                 div { class: "rotation-cycle-stats",
+                                    if let Some(score) = score {
+                                        span {
+                                            class: if score.deviations.is_empty() { "cycle-stat score clean" } else { "cycle-stat score" },
+                                            span { class: "cycle-stat-label", "Score " }
+                                            "{formatting::format_pct_f32(score.score_pct, eu)}"
+                                        }
+                                    }
                                     if cycle.total_damage > 0.0 && cycle.duration_secs > 0.0 {
                                         span { class: "cycle-stat dps",
                                             span { class: "cycle-stat-label", "DPS " }
@@ -189,8 +365,13 @@ pub fn RotationView(props: RotationViewProps) -> Element {
                                     for (j, slot) in cycle.slots.iter().enumerate() {
                                         {
                                         let gcd_time = slot.gcd_ability.time_secs;
+                                        let slot_deviation = score
+                                            .and_then(|s| s.deviations.iter().find(|d| d.slot_index == Some(j)));
                                         rsx! {
-                                        div { class: "gcd-slot", key: "{j}",
+                                        div {
+                                            class: if slot_deviation.is_some() { "gcd-slot deviation" } else { "gcd-slot" },
+                                            title: slot_deviation.map(deviation_label).unwrap_or_default(),
+                                            key: "{j}",
                                             // Off-GCD weaves stacked above (reversed: last weave nearest GCD)
                                             for (k, weave) in slot.off_gcd.iter().rev().enumerate() {
                                                 {
@@ -239,6 +420,26 @@ pub fn RotationView(props: RotationViewProps) -> Element {
                                         }
                                     }
                                 }
+                                // Deviations from the reference rotation
+                                if let Some(score) = score.filter(|s| !s.deviations.is_empty()) {
+                                    ul { class: "rotation-deviations",
+                                        for (k, dev) in score.deviations.iter().enumerate() {
+                                            li { key: "{k}",
+                                                span { class: "rotation-deviation-time",
+                                                    "{formatting::format_duration_f32(dev.time_secs)}"
+                                                }
+                                                " {deviation_label(dev)}"
+                                                if dev.lost_damage > 0.0 {
+                                                    span { class: "rotation-deviation-lost",
+                                                        " (-{format_number(dev.lost_damage)})"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            }
                             }
                         }
                     }
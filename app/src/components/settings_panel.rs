@@ -8,12 +8,14 @@ use gloo_timers::future::TimeoutFuture;
 use std::collections::HashMap;
 
 use crate::api;
+use crate::components::effect_editor::DisciplineSelector;
 use crate::components::{ToastSeverity, use_toast};
 use crate::types::{
     AlertsOverlayConfig, BossHealthConfig, ChallengeLayout, CooldownTrackerConfig,
     DotTrackerConfig, EffectsAConfig, EffectsBConfig, MAX_PROFILES, MetricType,
-    NotesOverlayConfig, OverlayAppearanceConfig, OverlaySettings, PersonalOverlayConfig,
-    PersonalStat, RaidOverlaySettings, TimerOverlayConfig,
+    NotesOverlayConfig, OverlayAppearanceConfig, OverlaySettings, OverlayVisibilityConditions,
+    PersonalOverlayConfig,
+    PersonalStat, ProfileArea, ProfileSwitchRule, RaidOverlaySettings, Role, TimerOverlayConfig,
 };
 use crate::utils::{color_to_hex, parse_hex_color};
 
@@ -66,6 +68,8 @@ pub fn SettingsPanel(
     let personal_label_font_color_hex =
         color_to_hex(&current_settings.personal_overlay.label_color);
     let boss_bar_hex = color_to_hex(&current_settings.boss_health.bar_color);
+    let raid_hp_hex = color_to_hex(&current_settings.raid_overlay.hp_bar_color);
+    let raid_low_hp_hex = color_to_hex(&current_settings.raid_overlay.low_hp_color);
 
     // Save settings to backend
     let save_to_backend = move |_| {
@@ -113,6 +117,8 @@ pub fn SettingsPanel(
                 config.overlay_settings.dot_tracker_opacity = new_settings.dot_tracker_opacity;
                 config.overlay_settings.notes_overlay = new_settings.notes_overlay.clone();
                 config.overlay_settings.notes_opacity = new_settings.notes_opacity;
+                config.overlay_settings.visibility_conditions =
+                    new_settings.visibility_conditions.clone();
                 config.overlay_settings.positions = existing_positions;
                 config.overlay_settings.enabled = existing_enabled;
 
@@ -319,6 +325,8 @@ pub fn SettingsPanel(
                     if !profile_status().is_empty() {
                         p { class: "profile-status compact", "{profile_status}" }
                     }
+
+                    ProfileRulesEditor { profile_names: profile_names() }
                 }
             }
 
@@ -1926,6 +1934,73 @@ pub fn SettingsPanel(
                             }
                             p { class: "hint", "Display ability icons instead of colored squares (requires icon pack)" }
 
+                            h4 { "Health" }
+
+                            div { class: "setting-row",
+                                label { "Show HP Bars" }
+                                input {
+                                    r#type: "checkbox",
+                                    checked: current_settings.raid_overlay.show_hp_bars,
+                                    onchange: move |e: Event<FormData>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.raid_overlay.show_hp_bars = e.checked();
+                                        update_draft(new_settings);
+                                    }
+                                }
+                            }
+                            p { class: "hint", "Last known HP from the combat log, updated as players appear in log lines" }
+
+                            div { class: "setting-row",
+                                label { "HP Bar Color" }
+                                input {
+                                    r#type: "color",
+                                    value: "{raid_hp_hex}",
+                                    class: "color-picker",
+                                    oninput: move |e: Event<FormData>| {
+                                        if let Some(color) = parse_hex_color(&e.value()) {
+                                            let mut new_settings = draft_settings();
+                                            new_settings.raid_overlay.hp_bar_color = color;
+                                            update_draft(new_settings);
+                                        }
+                                    }
+                                }
+                            }
+
+                            div { class: "setting-row",
+                                label { "Low HP Color" }
+                                input {
+                                    r#type: "color",
+                                    value: "{raid_low_hp_hex}",
+                                    class: "color-picker",
+                                    oninput: move |e: Event<FormData>| {
+                                        if let Some(color) = parse_hex_color(&e.value()) {
+                                            let mut new_settings = draft_settings();
+                                            new_settings.raid_overlay.low_hp_color = color;
+                                            update_draft(new_settings);
+                                        }
+                                    }
+                                }
+                            }
+
+                            div { class: "setting-row",
+                                label { "Low HP Threshold" }
+                                input {
+                                    r#type: "range",
+                                    min: "5",
+                                    max: "75",
+                                    step: "5",
+                                    value: "{current_settings.raid_overlay.low_hp_threshold}",
+                                    oninput: move |e| {
+                                        if let Ok(val) = e.value().parse::<u8>() {
+                                            let mut new_settings = draft_settings();
+                                            new_settings.raid_overlay.low_hp_threshold = val.clamp(5, 75);
+                                            update_draft(new_settings);
+                                        }
+                                    }
+                                }
+                                span { class: "value", "{current_settings.raid_overlay.low_hp_threshold}%" }
+                            }
+
                             div { class: "setting-row reset-row",
                                 button {
                                     class: "btn btn-reset",
@@ -2333,6 +2408,23 @@ pub fn SettingsPanel(
                 }
             }
 
+            // Visibility conditions (all overlay types)
+            OverlayVisibilityEditor {
+                conditions: current_settings.visibility_conditions.get(&tab).cloned().unwrap_or_default(),
+                on_change: {
+                    let tab = tab.clone();
+                    move |conditions: OverlayVisibilityConditions| {
+                        let mut new_settings = draft_settings();
+                        if conditions.is_unconditional() {
+                            new_settings.visibility_conditions.remove(&tab);
+                        } else {
+                            new_settings.visibility_conditions.insert(tab.clone(), conditions);
+                        }
+                        update_draft(new_settings);
+                    }
+                }
+            }
+
             } // End settings-content
 
             // ─────────────────────────────────────────────────────────────────
@@ -2353,6 +2445,299 @@ pub fn SettingsPanel(
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Visibility Conditions
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Conditions that limit when the selected overlay is shown
+#[component]
+fn OverlayVisibilityEditor(
+    conditions: OverlayVisibilityConditions,
+    on_change: EventHandler<OverlayVisibilityConditions>,
+) -> Element {
+    let hide_after = conditions
+        .hide_after_combat_secs
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        details { class: "settings-section collapsible",
+            open: !conditions.is_unconditional(),
+            summary { class: "collapsible-summary",
+                i { class: "fa-solid fa-eye summary-icon" }
+                "Visibility"
+                span {
+                    class: "help-icon",
+                    title: "Only show this overlay when all selected conditions hold. Conditions are ignored in move mode so the overlay can be positioned.",
+                    "?"
+                }
+            }
+            div { class: "collapsible-content",
+                div { class: "setting-row",
+                    label { "Only In Combat" }
+                    input {
+                        r#type: "checkbox",
+                        checked: conditions.in_combat_only,
+                        onchange: {
+                            let conditions = conditions.clone();
+                            move |e: Event<FormData>| {
+                                let mut c = conditions.clone();
+                                c.in_combat_only = e.checked();
+                                on_change.call(c);
+                            }
+                        }
+                    }
+                }
+                div { class: "setting-row",
+                    label { "Only During Boss Encounters" }
+                    input {
+                        r#type: "checkbox",
+                        checked: conditions.boss_encounters_only,
+                        onchange: {
+                            let conditions = conditions.clone();
+                            move |e: Event<FormData>| {
+                                let mut c = conditions.clone();
+                                c.boss_encounters_only = e.checked();
+                                on_change.call(c);
+                            }
+                        }
+                    }
+                }
+                div { class: "setting-row",
+                    label { "Hide After Combat (sec)" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        placeholder: "never",
+                        value: "{hide_after}",
+                        onchange: {
+                            let conditions = conditions.clone();
+                            move |e: Event<FormData>| {
+                                let mut c = conditions.clone();
+                                c.hide_after_combat_secs = e.value().trim().parse::<u32>().ok();
+                                on_change.call(c);
+                            }
+                        }
+                    }
+                }
+                div { class: "setting-row",
+                    label { "Areas" }
+                    div { class: "flex gap-xs",
+                        for area in ProfileArea::all().iter().copied() {
+                            button {
+                                class: if conditions.areas.contains(&area) { "toggle-btn active" } else { "toggle-btn" },
+                                onclick: {
+                                    let conditions = conditions.clone();
+                                    move |_| {
+                                        let mut c = conditions.clone();
+                                        if c.areas.contains(&area) {
+                                            c.areas.retain(|a| *a != area);
+                                        } else {
+                                            c.areas.push(area);
+                                        }
+                                        on_change.call(c);
+                                    }
+                                },
+                                "{area.label()}"
+                            }
+                        }
+                    }
+                }
+                div { class: "setting-row",
+                    label { "Roles" }
+                    div { class: "flex gap-xs",
+                        for (role, role_label) in [(Role::Tank, "tank"), (Role::Healer, "healer"), (Role::Dps, "dps")] {
+                            button {
+                                class: if conditions.roles.contains(&role) { "toggle-btn active" } else { "toggle-btn" },
+                                onclick: {
+                                    let conditions = conditions.clone();
+                                    move |_| {
+                                        let mut c = conditions.clone();
+                                        if c.roles.contains(&role) {
+                                            c.roles.retain(|r| *r != role);
+                                        } else {
+                                            c.roles.push(role);
+                                        }
+                                        on_change.call(c);
+                                    }
+                                },
+                                "{role_label}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Profile Switch Rules
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Editor for rules that load a profile automatically by area, role and boss.
+/// Changes are saved immediately.
+#[component]
+fn ProfileRulesEditor(profile_names: Vec<String>) -> Element {
+    let mut rules = use_signal(Vec::<ProfileSwitchRule>::new);
+    let mut toast = use_toast();
+
+    use_future(move || async move {
+        if let Some(config) = api::get_config().await {
+            rules.set(config.profile_rules);
+        }
+    });
+
+    let mut persist = move |new_rules: Vec<ProfileSwitchRule>| {
+        rules.set(new_rules.clone());
+        spawn(async move {
+            if let Some(mut config) = api::get_config().await {
+                config.profile_rules = new_rules;
+                if let Err(err) = api::update_config(&config).await {
+                    toast.show(format!("Failed to save profile rules: {}", err), ToastSeverity::Normal);
+                }
+            }
+        });
+    };
+
+    let current = rules();
+    let default_profile = profile_names.first().cloned().unwrap_or_default();
+
+    rsx! {
+        div { class: "profile-rules",
+            div { class: "flex items-center",
+                span { class: "text-sm", "Automatic switching" }
+                span {
+                    class: "help-icon",
+                    title: "Load a profile automatically when you enter an area, change discipline or engage a boss. Rules are checked top to bottom and the first match wins. Empty conditions match anything, so a rule without conditions works as a fallback.",
+                    "?"
+                }
+            }
+
+            for (idx, rule) in current.iter().enumerate() {
+                {
+                    let rule = rule.clone();
+                    let bosses_text = rule.bosses.join(", ");
+                    rsx! {
+                        div { key: "{idx}", class: "profile-rule",
+                            div { class: "flex items-center gap-xs",
+                                input {
+                                    r#type: "checkbox",
+                                    title: "Enabled",
+                                    checked: rule.enabled,
+                                    onchange: move |e| {
+                                        let mut r = rules();
+                                        r[idx].enabled = e.checked();
+                                        persist(r);
+                                    }
+                                }
+                                select {
+                                    class: "select",
+                                    value: "{rule.profile_name}",
+                                    onchange: move |e| {
+                                        let mut r = rules();
+                                        r[idx].profile_name = e.value();
+                                        persist(r);
+                                    },
+                                    for name in profile_names.iter() {
+                                        option {
+                                            value: "{name}",
+                                            selected: *name == rule.profile_name,
+                                            "{name}"
+                                        }
+                                    }
+                                }
+                                button {
+                                    class: "btn btn-small btn-delete ml-auto",
+                                    title: "Remove rule",
+                                    onclick: move |_| {
+                                        let mut r = rules();
+                                        r.remove(idx);
+                                        persist(r);
+                                    },
+                                    "×"
+                                }
+                            }
+                            div { class: "flex gap-xs",
+                                for area in ProfileArea::all().iter().copied() {
+                                    button {
+                                        class: if rule.areas.contains(&area) { "toggle-btn active" } else { "toggle-btn" },
+                                        onclick: move |_| {
+                                            let mut r = rules();
+                                            if r[idx].areas.contains(&area) {
+                                                r[idx].areas.retain(|a| *a != area);
+                                            } else {
+                                                r[idx].areas.push(area);
+                                            }
+                                            persist(r);
+                                        },
+                                        "{area.label()}"
+                                    }
+                                }
+                            }
+                            div { class: "flex gap-xs",
+                                for (role, role_label) in [(Role::Tank, "tank"), (Role::Healer, "healer"), (Role::Dps, "dps")] {
+                                    button {
+                                        class: if rule.roles.contains(&role) { "toggle-btn active" } else { "toggle-btn" },
+                                        onclick: move |_| {
+                                            let mut r = rules();
+                                            if r[idx].roles.contains(&role) {
+                                                r[idx].roles.retain(|x| *x != role);
+                                            } else {
+                                                r[idx].roles.push(role);
+                                            }
+                                            persist(r);
+                                        },
+                                        "{role_label}"
+                                    }
+                                }
+                                DisciplineSelector {
+                                    selected: rule.disciplines.clone(),
+                                    on_change: move |names: Vec<String>| {
+                                        let mut r = rules();
+                                        r[idx].disciplines = names;
+                                        persist(r);
+                                    }
+                                }
+                            }
+                            input {
+                                r#type: "text",
+                                class: "profile-name-input",
+                                placeholder: "Boss IDs (comma separated, any boss if empty)",
+                                value: "{bosses_text}",
+                                onchange: move |e| {
+                                    let mut r = rules();
+                                    r[idx].bosses = e
+                                        .value()
+                                        .split(',')
+                                        .map(|b| b.trim().to_string())
+                                        .filter(|b| !b.is_empty())
+                                        .collect();
+                                    persist(r);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            button {
+                class: "btn btn-small btn-save",
+                disabled: profile_names.is_empty(),
+                onclick: move |_| {
+                    let mut r = rules();
+                    r.push(ProfileSwitchRule {
+                        profile_name: default_profile.clone(),
+                        ..Default::default()
+                    });
+                    persist(r);
+                },
+                "+ Rule"
+            }
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Simple Sub-Components (these work with simple props)
 // ─────────────────────────────────────────────────────────────────────────────
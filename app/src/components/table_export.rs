@@ -0,0 +1,88 @@
+//! Export button for Data Explorer tables and charts
+//!
+//! Saves the rows behind a view, with its time range and filters applied,
+//! as CSV (spreadsheets) or parquet (pandas, polars, DuckDB).
+
+use dioxus::prelude::*;
+
+use crate::api::{self, ExportFormat, ExportSource, TimeRange};
+use crate::components::{ToastSeverity, use_toast};
+
+/// File-system friendly name: lowercase words joined by underscores
+pub fn file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect::<String>()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[component]
+pub fn TableExportButton(
+    encounter_idx: Option<u32>,
+    source: ExportSource,
+    /// Current time range ((0, 0) means the whole encounter)
+    time_range: TimeRange,
+    /// Duration used for per-second rates (same as the on-screen query)
+    #[props(default)]
+    duration_secs: Option<f32>,
+    /// Suggested file name, without extension
+    file_stem: String,
+) -> Element {
+    let mut menu_open = use_signal(|| false);
+    let mut busy = use_signal(|| false);
+    let mut toast = use_toast();
+
+    let mut export = move |format: ExportFormat| {
+        menu_open.set(false);
+        let source = source.clone();
+        let default_name = format!("{}.{}", file_stem, format.extension());
+        let tr = (time_range.start != 0.0 || time_range.end != 0.0).then_some(time_range);
+        spawn(async move {
+            let Some(path) = api::save_table_dialog(&default_name, format).await else {
+                return;
+            };
+            busy.set(true);
+            match api::export_table(encounter_idx, &source, tr.as_ref(), duration_secs, format, &path)
+                .await
+            {
+                Ok(rows) => toast.show(format!("Exported {} rows to {}", rows, path), ToastSeverity::Normal),
+                Err(err) => toast.show(format!("Export failed: {}", err), ToastSeverity::Normal),
+            }
+            busy.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "table-export",
+            button {
+                class: "table-export-btn",
+                title: "Export these rows (CSV or parquet)",
+                disabled: busy(),
+                onclick: move |_| { let open = *menu_open.read(); menu_open.set(!open); },
+                if busy() {
+                    i { class: "fa-solid fa-spinner fa-spin" }
+                } else {
+                    i { class: "fa-solid fa-download" }
+                }
+            }
+            if menu_open() {
+                div { class: "table-export-menu",
+                    button {
+                        onclick: {
+                            let mut export = export.clone();
+                            move |_| export(ExportFormat::Csv)
+                        },
+                        "CSV"
+                    }
+                    button {
+                        onclick: move |_| export(ExportFormat::Parquet),
+                        "Parquet"
+                    }
+                }
+            }
+        }
+    }
+}
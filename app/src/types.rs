@@ -13,6 +13,7 @@ pub use baras_types::{
     // Selectors (unified ID-or-Name matching)
     AbilitySelector,
     // Config types
+    ActionHotkey,
     AlertsOverlayConfig,
     AppConfig,
     BossHealthConfig,
@@ -26,6 +27,9 @@ pub use baras_types::{
     DataExplorerState,
     DataTab,
     DotTrackerConfig,
+    DummyParseHistory,
+    DummyParseLimit,
+    DummyParseSettings,
     EffectSelector,
     EffectsAConfig,
     EffectsBConfig,
@@ -33,14 +37,25 @@ pub use baras_types::{
     EncounterBuilderState,
     EntityFilter,
     EntitySelector,
+    GameLanguage,
+    HotkeyAction,
+    LintIssue,
+    LintSeverity,
     MainTab,
     NotesOverlayConfig,
     OverlayAppearanceConfig,
     OverlaySettings,
+    OverlayVisibilityConditions,
     PersonalOverlayConfig,
+    PersonalRank,
     PersonalStat,
+    ProfileArea,
+    ProfileSwitchRule,
+    PvpMatch,
+    PvpScoreboardEntry,
     RaidOverlaySettings,
     RefreshAbility,
+    Role,
     SortColumn,
     SortDirection,
     TimerOverlayConfig,
@@ -78,6 +93,12 @@ pub struct SessionInfo {
     /// True if the log file's last event is older than 30 minutes (no active session)
     #[serde(default)]
     pub stale_session: bool,
+    /// Local player's alacrity inferred from activation spacing (None until enough casts)
+    #[serde(default)]
+    pub player_alacrity_percent: Option<f32>,
+    /// Local player's latency inferred from activation spacing (None until enough casts)
+    #[serde(default)]
+    pub player_latency_ms: Option<u16>,
 }
 
 /// Overlay status response from backend
@@ -366,6 +387,12 @@ pub struct BossTimerDefinition {
     pub counter_condition: Option<CounterCondition>,
     #[serde(default)]
     pub difficulties: Vec<String>,
+    /// Roles as serialized by the backend ("Tank", "Healer", "Dps"); empty = all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    /// Discipline variant names (e.g. "KineticCombat"); empty = all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disciplines: Vec<String>,
     #[serde(default = "default_enabled")]
     pub enabled: bool,
     #[serde(default)]
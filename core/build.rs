@@ -9,6 +9,7 @@ fn main() {
 
     generate_off_gcd_set(&out_dir);
     generate_attack_types_map(&out_dir);
+    generate_ability_names_map(&out_dir);
 
     println!("cargo:rerun-if-changed=data/off_gcd.json");
     println!("cargo:rerun-if-changed=data/attack_types.csv");
@@ -70,3 +71,41 @@ fn generate_attack_types_map(out_dir: &str) {
     writeln!(file, "pub static ATTACK_TYPES: phf::Map<i64, &'static str> = {};", builder.build())
         .unwrap();
 }
+
+fn generate_ability_names_map(out_dir: &str) {
+    let csv = fs::read_to_string("data/attack_types.csv").expect("failed to read attack_types.csv");
+
+    // The English name is the last column and may be quoted (it can contain commas)
+    let mut entries = BTreeMap::new();
+    for line in csv.lines().skip(1) {
+        let fields: Vec<&str> = line.splitn(4, ',').collect();
+        if fields.len() < 4 {
+            continue;
+        }
+        let id: i64 = match fields[0].trim().parse() {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        let name = fields[3].trim().trim_matches('"');
+        if name.is_empty() {
+            continue;
+        }
+        entries.entry(id).or_insert(name.to_string());
+    }
+
+    let path = Path::new(out_dir).join("ability_names.rs");
+    let mut file = BufWriter::new(fs::File::create(&path).unwrap());
+
+    let mut builder = phf_codegen::Map::new();
+    let quoted: Vec<_> = entries.iter().map(|(id, name)| (*id, format!("{:?}", name))).collect();
+    for (id, name) in &quoted {
+        builder.entry(*id, name);
+    }
+
+    writeln!(
+        file,
+        "pub static ENGLISH_ABILITY_NAMES: phf::Map<i64, &'static str> = {};",
+        builder.build()
+    )
+    .unwrap();
+}
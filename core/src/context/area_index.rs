@@ -2,6 +2,7 @@
 //!
 //! Lightweight indexing of AreaEntered events in combat log files.
 //! Enables filtering log files by operation/area name in the file browser.
+//! The same pass records training dummy sessions for the dummy parse history.
 
 use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
@@ -12,6 +13,11 @@ use std::time::SystemTime;
 use encoding_rs::WINDOWS_1252;
 use serde::{Deserialize, Serialize};
 
+use super::parse_log_filename;
+use crate::combat_log::LogParser;
+use crate::encounter::dummy::{DummySession, DummySessionRecorder};
+use crate::game_data::training_dummy_ids;
+
 /// A single area visit entry extracted from a log file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileAreaEntry {
@@ -65,6 +71,9 @@ pub struct FileAreaIndex {
     pub modified_secs: u64,
     /// Areas visited in this file (deduplicated by area_id + difficulty_id)
     pub areas: Vec<FileAreaEntry>,
+    /// Training dummy sessions in this file
+    #[serde(default)]
+    pub dummy_sessions: Vec<DummySession>,
 }
 
 /// Cache of area indexes for all log files
@@ -76,7 +85,7 @@ pub struct LogAreaCache {
     pub entries: HashMap<PathBuf, FileAreaIndex>,
 }
 
-const CACHE_VERSION: u32 = 1;
+const CACHE_VERSION: u32 = 2;
 
 impl LogAreaCache {
     pub fn new() -> Self {
@@ -164,28 +173,46 @@ const MARKER_BYTES: &[u8] = b"{836045448953664}";
 /// The AreaEntered effect type ID as string - for parsing decoded lines
 const AREA_ENTERED_MARKER: &str = "{836045448953664}";
 
-/// Extract AreaEntered events from a log file efficiently.
+/// The DisciplineChanged effect type ID as bytes (dummy sessions need the discipline)
+const DISCIPLINE_MARKER_BYTES: &[u8] = b"{836045448953665}";
+
+/// Index a log file: areas visited and training dummy sessions.
 ///
-/// Reads file as raw bytes line-by-line, pattern matches on ASCII marker,
+/// Reads file as raw bytes line-by-line, pattern matches on ASCII markers,
 /// then decodes only matching lines with Windows-1252 encoding.
 /// Only returns areas that exist in `known_area_ids` (from definition files).
 ///
 /// # Arguments
 /// * `path` - Path to the combat log file
 /// * `known_area_ids` - Set of area IDs that have definitions (only these are indexed)
-///
-/// # Returns
-/// Deduplicated list of areas visited in the file
-pub fn extract_areas_from_file(
+/// * `modified_secs` - File modification time stored for cache invalidation
+pub fn index_log_file(
     path: &Path,
     known_area_ids: &HashSet<i64>,
-) -> IoResult<Vec<FileAreaEntry>> {
+    modified_secs: u64,
+) -> IoResult<FileAreaIndex> {
     let file = File::open(path)?;
     let mut reader = BufReader::new(file);
 
     let mut areas = Vec::new();
     let mut seen: HashSet<(i64, i64)> = HashSet::new(); // (area_id, difficulty_id)
     let mut line_buf = Vec::new();
+    let mut line_number = 0u64;
+
+    // Dummy hits are parsed in full, so only lines naming a dummy (or a
+    // discipline change) are decoded
+    let dummy_markers: Vec<Vec<u8>> = training_dummy_ids()
+        .into_iter()
+        .map(|id| format!("{{{}}}", id).into_bytes())
+        .collect();
+    let session_date = path
+        .file_name()
+        .and_then(|f| f.to_str())
+        .and_then(parse_log_filename)
+        .map(|(_, dt)| dt)
+        .unwrap_or_default();
+    let parser = LogParser::new(session_date);
+    let mut dummies = DummySessionRecorder::default();
 
     // Read line by line as raw bytes (handles Windows-1252 encoding properly)
     loop {
@@ -194,13 +221,21 @@ pub fn extract_areas_from_file(
         if bytes_read == 0 {
             break; // EOF
         }
+        line_number += 1;
+
+        if contains(&line_buf, DISCIPLINE_MARKER_BYTES)
+            || dummy_markers.iter().any(|m| contains(&line_buf, m))
+        {
+            let (line, _, _) = WINDOWS_1252.decode(&line_buf);
+            if let Some(event) = parser.parse_line(line_number, line.trim_end()) {
+                dummies.record_event(&event);
+            }
+            continue;
+        }
 
         // Fast path: check if marker exists in raw bytes (ASCII matching)
         // This avoids decoding lines that don't contain AreaEntered events
-        if !line_buf
-            .windows(MARKER_BYTES.len())
-            .any(|w| w == MARKER_BYTES)
-        {
+        if !contains(&line_buf, MARKER_BYTES) {
             continue;
         }
 
@@ -219,7 +254,15 @@ pub fn extract_areas_from_file(
         }
     }
 
-    Ok(areas)
+    Ok(FileAreaIndex {
+        modified_secs,
+        areas,
+        dummy_sessions: dummies.finish(),
+    })
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
 }
 
 /// Parse an AreaEntered line to extract area information.
@@ -7,10 +7,12 @@ use super::error::ConfigError;
 
 // Re-export all shared types
 pub use baras_types::{
-    overlay_colors, AlertsOverlayConfig, AppConfig, BossHealthConfig, ChallengeColumns,
-    ChallengeLayout, ChallengeOverlayConfig, Color, HotkeySettings, OverlayAppearanceConfig,
-    OverlayPositionConfig, OverlayProfile, OverlaySettings, PersonalOverlayConfig, PersonalStat,
-    PersonalStatCategory, RaidOverlaySettings, TimerOverlayConfig, MAX_PROFILES,
+    overlay_colors, ActionHotkey, AlertsOverlayConfig, AppConfig, BossHealthConfig,
+    ChallengeColumns, ChallengeLayout, ChallengeOverlayConfig, Color, HotkeyAction,
+    HotkeySettings, OverlayAppearanceConfig,
+    OverlayPositionConfig, OverlayProfile, OverlaySettings, OverlayVisibilityConditions,
+    PersonalOverlayConfig, PersonalStat, PersonalStatCategory, ProfileArea, ProfileSwitchRule,
+    RaidOverlaySettings, TimerOverlayConfig, MAX_PROFILES,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -140,6 +142,7 @@ impl AppConfigExt for AppConfig {
         if self.active_profile_name.as_deref() == Some(name) {
             self.active_profile_name = None;
         }
+        self.profile_rules.retain(|r| r.profile_name != name);
         Ok(())
     }
 
@@ -155,6 +158,10 @@ impl AppConfigExt for AppConfig {
             .ok_or("Profile not found")?;
         profile.name = new_name.clone();
 
+        for rule in self.profile_rules.iter_mut().filter(|r| r.profile_name == old_name) {
+            rule.profile_name = new_name.clone();
+        }
+
         if self.active_profile_name.as_deref() == Some(old_name) {
             self.active_profile_name = Some(new_name);
         }
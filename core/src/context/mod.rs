@@ -4,22 +4,27 @@ mod config;
 mod error;
 mod interner;
 mod log_files;
+mod overlay_visibility;
 mod parser;
+mod profile_rules;
 pub mod watcher;
 
 pub use error::{ConfigError, WatcherError};
 
 pub use area_index::{
-    FileAreaEntry, FileAreaIndex, LogAreaCache, default_cache_path, extract_areas_from_file,
+    FileAreaEntry, FileAreaIndex, LogAreaCache, default_cache_path, index_log_file,
 };
 pub use background_tasks::BackgroundTasks;
 pub use config::{
-    AlertsOverlayConfig, AppConfig, AppConfigExt, BossHealthConfig, ChallengeColumns,
-    ChallengeLayout, ChallengeOverlayConfig, Color, HotkeySettings, MAX_PROFILES,
+    ActionHotkey, AlertsOverlayConfig, AppConfig, AppConfigExt, BossHealthConfig,
+    ChallengeColumns, ChallengeLayout, ChallengeOverlayConfig, Color, HotkeyAction,
+    HotkeySettings, MAX_PROFILES,
     OverlayAppearanceConfig, OverlayPositionConfig, OverlayProfile, OverlaySettings,
-    PersonalOverlayConfig, PersonalStat, PersonalStatCategory, RaidOverlaySettings,
-    TimerOverlayConfig, overlay_colors,
+    OverlayVisibilityConditions, PersonalOverlayConfig, PersonalStat, PersonalStatCategory,
+    ProfileArea, ProfileSwitchRule, RaidOverlaySettings, TimerOverlayConfig, overlay_colors,
 };
 pub use interner::{IStr, empty_istr, intern, resolve};
 pub use log_files::{DirectoryIndex, parse_log_filename};
+pub use overlay_visibility::{OverlayVisibilityContext, hidden_overlays};
 pub use parser::{DefinitionLoader, ParseResult, ParsingSession, parse_file, resolve_log_path};
+pub use profile_rules::{ProfileRuleContext, select_profile};
@@ -0,0 +1,134 @@
+//! Conditional overlay visibility
+//!
+//! Evaluates the per-overlay `visibility_conditions` from
+//! [`OverlaySettings`](super::OverlaySettings) against the live game state.
+
+use std::collections::{HashMap, HashSet};
+
+use super::config::OverlayVisibilityConditions;
+use super::profile_rules::{area_matches, roles_match};
+use crate::game_data::Discipline;
+
+/// Game state that overlay visibility conditions are evaluated against
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OverlayVisibilityContext {
+    pub in_combat: bool,
+    /// Seconds since the last combat ended (or since tailing started)
+    pub secs_out_of_combat: f32,
+    /// A boss encounter is active in the current combat
+    pub boss_encounter: bool,
+    pub area_id: i64,
+    /// The active boss is a training dummy
+    pub training_dummy: bool,
+    pub discipline: Option<Discipline>,
+}
+
+impl OverlayVisibilityContext {
+    /// Whether an overlay with these conditions should be shown
+    pub fn allows(&self, conditions: &OverlayVisibilityConditions) -> bool {
+        if conditions.in_combat_only && !self.in_combat {
+            return false;
+        }
+        if conditions.boss_encounters_only && !self.boss_encounter {
+            return false;
+        }
+        if let Some(secs) = conditions.hide_after_combat_secs
+            && !self.in_combat
+            && self.secs_out_of_combat >= secs as f32
+        {
+            return false;
+        }
+        let areas_ok = conditions.areas.is_empty()
+            || conditions
+                .areas
+                .iter()
+                .any(|a| area_matches(*a, self.area_id, self.training_dummy));
+        areas_ok && roles_match(&conditions.roles, self.discipline)
+    }
+}
+
+/// Config keys of the overlays whose visibility conditions currently fail,
+/// given [`OverlaySettings::visibility_conditions`](super::OverlaySettings)
+pub fn hidden_overlays(
+    conditions: &HashMap<String, OverlayVisibilityConditions>,
+    ctx: &OverlayVisibilityContext,
+) -> HashSet<String> {
+    conditions
+        .iter()
+        .filter(|(_, conditions)| !ctx.allows(conditions))
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::ProfileArea;
+    use crate::game_data::Role;
+
+    #[test]
+    fn test_hidden_overlays_follow_combat_and_area() {
+        let mut conditions = HashMap::new();
+        conditions.insert(
+            "dps".to_string(),
+            OverlayVisibilityConditions {
+                areas: vec![ProfileArea::Operation, ProfileArea::TrainingDummy],
+                hide_after_combat_secs: Some(10),
+                ..Default::default()
+            },
+        );
+        conditions.insert(
+            "boss_health".to_string(),
+            OverlayVisibilityConditions {
+                boss_encounters_only: true,
+                ..Default::default()
+            },
+        );
+        conditions.insert(
+            "raid".to_string(),
+            OverlayVisibilityConditions {
+                roles: vec![Role::Healer],
+                ..Default::default()
+            },
+        );
+
+        // Fighting a dummy in the fleet as a healer, shortly after combat
+        let mut ctx = OverlayVisibilityContext {
+            secs_out_of_combat: 4.0,
+            area_id: 1,
+            training_dummy: true,
+            discipline: Some(Discipline::Seer),
+            ..Default::default()
+        };
+        assert_eq!(
+            hidden_overlays(&conditions, &ctx),
+            HashSet::from(["boss_health".to_string()])
+        );
+
+        // Out of combat for a while, no longer on the dummy, now DPS
+        ctx.secs_out_of_combat = 12.0;
+        ctx.training_dummy = false;
+        ctx.discipline = Some(Discipline::Madness);
+        assert_eq!(
+            hidden_overlays(&conditions, &ctx),
+            HashSet::from([
+                "dps".to_string(),
+                "boss_health".to_string(),
+                "raid".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_in_combat_only() {
+        let conditions = OverlayVisibilityConditions {
+            in_combat_only: true,
+            ..Default::default()
+        };
+        let mut ctx = OverlayVisibilityContext::default();
+        assert!(!ctx.allows(&conditions));
+        ctx.in_combat = true;
+        assert!(ctx.allows(&conditions));
+        assert!(ctx.allows(&OverlayVisibilityConditions::default()));
+    }
+}
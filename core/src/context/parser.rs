@@ -9,7 +9,8 @@ use crate::combat_log::{CombatEvent, Reader};
 use crate::context::{AppConfig, parse_log_filename};
 use crate::dsl::BossEncounterDefinition;
 use crate::effects::{DefinitionSet, EffectTracker};
-use crate::game_data::effect_type_id;
+use crate::encounter::dummy::DummyParseLimit;
+use crate::game_data::{GameLanguage, ObservedNames, effect_type_id};
 use crate::signal_processor::{EventProcessor, GameSignal, SignalHandler};
 use crate::state::SessionCache;
 use crate::storage::{EncounterWriter, EventMetadata, encounter_filename};
@@ -150,6 +151,29 @@ impl ParsingSession {
         self.definition_loader = Some(loader);
     }
 
+    /// Set the training dummy parse length (`None` disables dummy parses)
+    pub fn set_dummy_parse_limit(&mut self, limit: Option<DummyParseLimit>) {
+        if let Some(cache) = self.session_cache.as_mut() {
+            cache.dummy_parse.limit = limit;
+        }
+    }
+
+    /// Set the game client language declared by the user (`None` to only
+    /// detect it from the log)
+    pub fn set_client_language(&mut self, language: Option<GameLanguage>) {
+        if let Some(cache) = self.session_cache.as_mut() {
+            cache.client_language = language;
+        }
+    }
+
+    /// Take the localized names first seen in this log since the last call
+    pub fn take_observed_names(&mut self) -> Option<ObservedNames> {
+        self.session_cache
+            .as_mut()
+            .and_then(|cache| cache.log_names.as_mut())
+            .and_then(|names| names.take_observed())
+    }
+
     /// Register a signal handler to receive game signals
     pub fn add_signal_handler(&mut self, handler: Box<dyn SignalHandler + Send + Sync>) {
         self.signal_handlers.push(handler);
@@ -369,6 +393,62 @@ impl ParsingSession {
         }
     }
 
+    /// Start a manual timer (e.g. from a hotkey). Returns false if no enabled
+    /// manual timer with this ID is loaded. No-op in Historical mode.
+    pub fn start_manual_timer(&mut self, timer_id: &str) -> bool {
+        let Some(timer_mgr) = &self.timer_manager else {
+            return false;
+        };
+        let encounter = self
+            .session_cache
+            .as_ref()
+            .and_then(|c| c.current_encounter());
+        let started = timer_mgr
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .start_manual_timer(timer_id, encounter);
+        if started {
+            self.process_timer_counter_triggers(chrono::Local::now().naive_local());
+        }
+        started
+    }
+
+    /// Stop a running timer (e.g. from a hotkey). Returns false if it wasn't running.
+    pub fn reset_timer(&mut self, timer_id: &str) -> bool {
+        let Some(timer_mgr) = &self.timer_manager else {
+            return false;
+        };
+        timer_mgr
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .reset_timer(timer_id)
+    }
+
+    /// Change a counter on the current encounter (e.g. from a hotkey) and dispatch
+    /// the resulting `CounterChanged` signal so counter-triggered timers fire.
+    /// Returns the new value, or None if there is no current encounter.
+    pub fn modify_counter(
+        &mut self,
+        counter_id: &str,
+        decrement: bool,
+        set_value: Option<u32>,
+    ) -> Option<u32> {
+        let cache = self.session_cache.as_mut()?;
+        let encounter = cache.current_encounter_mut()?;
+        let (old_value, new_value) = encounter.modify_counter(counter_id, decrement, set_value);
+        if old_value != new_value {
+            let timestamp = chrono::Local::now().naive_local();
+            self.dispatch_signals(&[GameSignal::CounterChanged {
+                counter_id: counter_id.to_string(),
+                old_value,
+                new_value,
+                timestamp,
+            }]);
+            self.process_timer_counter_triggers(timestamp);
+        }
+        Some(new_value)
+    }
+
     /// Get a shared reference to the effect tracker for overlay queries.
     /// Returns None in Historical mode.
     pub fn effect_tracker(&self) -> Option<Arc<Mutex<EffectTracker>>> {
@@ -431,7 +511,8 @@ impl ParsingSession {
         }
     }
 
-    /// Set player alacrity for effect duration calculations.
+    /// Set configured player alacrity for effect duration calculations
+    /// (used until it can be inferred from casts).
     /// No-op in Historical mode (session has no effect tracker).
     pub fn set_effect_alacrity(&self, alacrity_percent: f32) {
         if let Some(tracker) = &self.effect_tracker {
@@ -441,7 +522,8 @@ impl ParsingSession {
         }
     }
 
-    /// Set player latency for effect duration calculations.
+    /// Set configured player latency for effect duration calculations
+    /// (used until it can be inferred from casts).
     /// No-op in Historical mode (session has no effect tracker).
     pub fn set_effect_latency(&self, latency_ms: u16) {
         if let Some(tracker) = &self.effect_tracker {
@@ -0,0 +1,182 @@
+//! Automatic overlay profile selection
+//!
+//! Matches the `profile_rules` from [`AppConfig`](super::AppConfig) against the
+//! current area, the local player's discipline and the engaged boss.
+
+use super::config::{ProfileArea, ProfileSwitchRule};
+use crate::game_data::{Discipline, Role, is_flashpoint, is_operation, is_pvp_area};
+
+/// Game state that profile switch rules are evaluated against
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProfileRuleContext<'a> {
+    pub area_id: i64,
+    pub discipline: Option<Discipline>,
+    /// Definition ID of the most recently detected boss in this area
+    pub boss_id: Option<&'a str>,
+    /// The detected boss is a training dummy
+    pub training_dummy: bool,
+}
+
+impl ProfileRuleContext<'_> {
+    /// Whether every non-empty condition of the rule holds
+    pub fn matches(&self, rule: &ProfileSwitchRule) -> bool {
+        let areas_ok = rule.areas.is_empty()
+            || rule
+                .areas
+                .iter()
+                .any(|a| area_matches(*a, self.area_id, self.training_dummy));
+
+        let roles_ok = roles_match(&rule.roles, self.discipline);
+
+        let disciplines_ok = rule.disciplines.is_empty()
+            || self.discipline.is_some_and(|d| {
+                rule.disciplines
+                    .iter()
+                    .any(|name| Discipline::from_name(name) == Some(d))
+            });
+
+        let bosses_ok = rule.bosses.is_empty()
+            || self
+                .boss_id
+                .is_some_and(|id| rule.bosses.iter().any(|b| b == id));
+
+        areas_ok && roles_ok && disciplines_ok && bosses_ok
+    }
+}
+
+/// Profile chosen by the first enabled rule that matches, if any
+pub fn select_profile<'r>(
+    rules: &'r [ProfileSwitchRule],
+    ctx: &ProfileRuleContext<'_>,
+) -> Option<&'r str> {
+    rules
+        .iter()
+        .filter(|r| r.enabled && !r.profile_name.is_empty())
+        .find(|r| ctx.matches(r))
+        .map(|r| r.profile_name.as_str())
+}
+
+/// Whether the area (or the training dummy being fought) is of the given kind
+pub(super) fn area_matches(area: ProfileArea, area_id: i64, training_dummy: bool) -> bool {
+    match area {
+        ProfileArea::Operation => is_operation(area_id),
+        ProfileArea::Flashpoint => is_flashpoint(area_id),
+        ProfileArea::Pvp => is_pvp_area(area_id),
+        ProfileArea::TrainingDummy => training_dummy,
+        ProfileArea::OpenWorld => {
+            !is_operation(area_id) && !is_flashpoint(area_id) && !is_pvp_area(area_id)
+        }
+    }
+}
+
+/// Whether the discipline's role is one of `roles` (empty matches anything,
+/// an unknown discipline matches only an empty list)
+pub(super) fn roles_match(roles: &[Role], discipline: Option<Discipline>) -> bool {
+    roles.is_empty() || discipline.is_some_and(|d| roles.contains(&d.role()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPERATION_AREA: i64 = 833571547775670;
+
+    fn rule(profile: &str) -> ProfileSwitchRule {
+        ProfileSwitchRule {
+            profile_name: profile.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let rules = vec![
+            ProfileSwitchRule {
+                bosses: vec!["apex_vanguard".to_string()],
+                ..rule("Apex")
+            },
+            ProfileSwitchRule {
+                areas: vec![ProfileArea::Operation],
+                roles: vec![Role::Healer],
+                ..rule("Raid Healer")
+            },
+            ProfileSwitchRule {
+                areas: vec![ProfileArea::Operation],
+                ..rule("Raid")
+            },
+            ProfileSwitchRule {
+                areas: vec![ProfileArea::TrainingDummy],
+                disciplines: vec!["Kinetic Combat".to_string()],
+                ..rule("Dummy Tank")
+            },
+            rule("Default"),
+        ];
+
+        let mut ctx = ProfileRuleContext {
+            area_id: OPERATION_AREA,
+            discipline: Some(Discipline::Corruption),
+            ..Default::default()
+        };
+        assert_eq!(select_profile(&rules, &ctx), Some("Raid Healer"));
+
+        ctx.discipline = Some(Discipline::Lightning);
+        assert_eq!(select_profile(&rules, &ctx), Some("Raid"));
+
+        ctx.boss_id = Some("apex_vanguard");
+        assert_eq!(select_profile(&rules, &ctx), Some("Apex"));
+
+        let ctx = ProfileRuleContext {
+            area_id: 1,
+            discipline: Some(Discipline::KineticCombat),
+            boss_id: Some("training_dummy"),
+            training_dummy: true,
+        };
+        assert_eq!(select_profile(&rules, &ctx), Some("Dummy Tank"));
+
+        let ctx = ProfileRuleContext::default();
+        assert_eq!(select_profile(&rules, &ctx), Some("Default"));
+    }
+
+    #[test]
+    fn test_disabled_and_unknown_discipline_rules_skipped() {
+        let rules = vec![
+            ProfileSwitchRule {
+                enabled: false,
+                ..rule("Disabled")
+            },
+            ProfileSwitchRule {
+                roles: vec![Role::Tank],
+                ..rule("Tank")
+            },
+        ];
+
+        // Role rules never match before the discipline is known
+        let ctx = ProfileRuleContext::default();
+        assert_eq!(select_profile(&rules, &ctx), None);
+
+        let ctx = ProfileRuleContext {
+            discipline: Some(Discipline::Darkness),
+            ..Default::default()
+        };
+        assert_eq!(select_profile(&rules, &ctx), Some("Tank"));
+    }
+
+    #[test]
+    fn test_rule_default_matches_serde_default() {
+        let parsed: ProfileSwitchRule =
+            toml::from_str("profile_name = \"Heals\"\nroles = [\"Healer\"]").unwrap();
+        assert!(parsed.enabled);
+        assert_eq!(parsed.roles, vec![Role::Healer]);
+        assert_eq!(
+            parsed,
+            ProfileSwitchRule {
+                roles: vec![Role::Healer],
+                ..rule("Heals")
+            }
+        );
+        assert!(
+            toml::from_str::<ProfileSwitchRule>("profile_name = \"X\"\nroles = [\"Healz\"]")
+                .is_err()
+        );
+    }
+}
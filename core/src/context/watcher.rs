@@ -1,6 +1,7 @@
 use crate::context::DirectoryIndex;
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tokio::sync::mpsc::{self, Receiver};
 
 pub enum DirectoryEvent {
@@ -98,6 +99,110 @@ impl DirectoryWatcher {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Definition Watcher
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// How long to keep collecting changes after the first one. Editors often
+/// save as truncate + write or write-to-temp + rename.
+const DEFINITION_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A debounced batch of changed user definition files
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DefinitionChanges {
+    /// Changed or removed encounter TOML files
+    pub encounters: Vec<PathBuf>,
+    /// The user effects file changed or was removed
+    pub effects: Option<PathBuf>,
+}
+
+impl DefinitionChanges {
+    pub fn is_empty(&self) -> bool {
+        self.encounters.is_empty() && self.effects.is_none()
+    }
+}
+
+/// Watches the user definitions directory (encounters + effects.toml)
+/// so hand-edited TOML can be hot-reloaded mid-session.
+pub struct DefinitionWatcher {
+    _watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<Event>>,
+    encounters_dir: PathBuf,
+    effects_path: PathBuf,
+}
+
+impl DefinitionWatcher {
+    /// Watch `definitions_dir` recursively. `encounters/` and `effects.toml`
+    /// inside it are reported; everything else is ignored.
+    pub fn new(definitions_dir: &Path) -> notify::Result<Self> {
+        let (tx, rx) = mpsc::channel(100);
+
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                // Bursts beyond the buffer are fine to drop: the batch already
+                // contains a change that will trigger a full reload
+                let _ = tx.try_send(res);
+            },
+            Config::default(),
+        )?;
+
+        watcher.watch(definitions_dir, RecursiveMode::Recursive)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+            encounters_dir: definitions_dir.join("encounters"),
+            effects_path: definitions_dir.join("effects.toml"),
+        })
+    }
+
+    /// Wait for the next batch of relevant changes. Returns None when the
+    /// watcher is closed.
+    pub async fn next_changes(&mut self) -> Option<DefinitionChanges> {
+        loop {
+            let mut changes = DefinitionChanges::default();
+            let first = self.rx.recv().await?;
+            self.collect(first, &mut changes);
+
+            let deadline = tokio::time::Instant::now() + DEFINITION_DEBOUNCE;
+            while let Ok(Some(res)) = tokio::time::timeout_at(deadline, self.rx.recv()).await {
+                self.collect(res, &mut changes);
+            }
+
+            if !changes.is_empty() {
+                return Some(changes);
+            }
+        }
+    }
+
+    fn collect(&self, res: notify::Result<Event>, changes: &mut DefinitionChanges) {
+        let event = match res {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::warn!(error = %e, "Definition watcher error");
+                return;
+            }
+        };
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            return;
+        }
+        for path in event.paths {
+            if path.extension().is_none_or(|ext| ext != "toml") {
+                continue;
+            }
+            if path == self.effects_path {
+                changes.effects = Some(path);
+            } else if path.starts_with(&self.encounters_dir) && !changes.encounters.contains(&path)
+            {
+                changes.encounters.push(path);
+            }
+        }
+    }
+}
+
 fn is_combat_log(path: &Path) -> bool {
     path.file_name()
         .and_then(|n| n.to_str())
@@ -112,3 +217,33 @@ pub fn build_index(dir: &Path) -> Result<(DirectoryIndex, Option<PathBuf>), Stri
     let newest = index.newest_file().map(|f| f.path.clone());
     Ok((index, newest))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_definition_watcher_batches_edits_for_reload() {
+        let dir = std::env::temp_dir().join(format!("baras-defs-watch-{}", std::process::id()));
+        let encounters = dir.join("encounters");
+        std::fs::create_dir_all(&encounters).unwrap();
+
+        let mut watcher = DefinitionWatcher::new(&dir).unwrap();
+
+        // Saving a file twice plus unrelated files lands in one debounced batch
+        let boss = encounters.join("boss.toml");
+        std::fs::write(&boss, "# draft").unwrap();
+        std::fs::write(&boss, "# saved").unwrap();
+        std::fs::write(dir.join("effects.toml"), "version = 1").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let changes = tokio::time::timeout(Duration::from_secs(5), watcher.next_changes())
+            .await
+            .expect("no definition changes reported")
+            .unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(changes.encounters, vec![boss]);
+        assert_eq!(changes.effects, Some(dir.join("effects.toml")));
+    }
+}
@@ -0,0 +1,151 @@
+//! Alert text templating
+//!
+//! Timer and effect `alert_text` may contain placeholders that are filled from
+//! the triggering signal and the encounter state when the alert fires:
+//!
+//! | Placeholder      | Value                                                    |
+//! |------------------|----------------------------------------------------------|
+//! | `{source_name}`  | Name of the signal's source entity                       |
+//! | `{target_name}`  | Name of the signal's target (the NPC for death/HP/spawn) |
+//! | `{stacks}`       | Effect charges/stacks carried by the signal              |
+//! | `{phase}`        | Current boss phase ID                                    |
+//! | `{hp}`           | HP percent from the signal, else the active boss's HP    |
+//! | `{counter:<id>}` | Current value of an encounter counter                    |
+//!
+//! Known placeholders without a value render empty; unknown placeholders are
+//! left as written so literal braces survive.
+
+use hashbrown::HashMap;
+
+/// Values available to alert text placeholders
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlertContext<'a> {
+    pub source_name: Option<&'a str>,
+    pub target_name: Option<&'a str>,
+    pub stacks: Option<u8>,
+    pub hp_percent: Option<f32>,
+    pub phase: Option<&'a str>,
+    pub counters: Option<&'a HashMap<String, u32>>,
+}
+
+impl AlertContext<'_> {
+    /// Value for a placeholder key, or `None` if the key is not a known placeholder
+    fn value(&self, key: &str) -> Option<String> {
+        if let Some(counter_id) = key.strip_prefix("counter:") {
+            let value = self
+                .counters
+                .and_then(|c| c.get(counter_id.trim()))
+                .copied()
+                .unwrap_or(0);
+            return Some(value.to_string());
+        }
+        let value = match key {
+            "source_name" => self.source_name.unwrap_or_default().to_string(),
+            "target_name" => self.target_name.unwrap_or_default().to_string(),
+            "stacks" => self.stacks.map(|s| s.to_string()).unwrap_or_default(),
+            "phase" => self.phase.unwrap_or_default().to_string(),
+            "hp" => self
+                .hp_percent
+                .map(|hp| format!("{:.0}", hp))
+                .unwrap_or_default(),
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+/// Fill `{placeholder}` references in alert text from the given context
+pub fn render_alert_text(template: &str, ctx: &AlertContext<'_>) -> String {
+    if !template.contains('{') {
+        return template.to_string();
+    }
+
+    let mut out = String::with_capacity(template.len() + 16);
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let Some(close) = after.find('}') else {
+            out.push_str(&rest[open..]);
+            return out;
+        };
+        let key = &after[..close];
+        if key.contains('{') {
+            // "{ {target_name}" - emit the stray brace and rescan from the next one
+            out.push('{');
+            rest = after;
+            continue;
+        }
+        match ctx.value(key.trim()) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[open..open + close + 2]),
+        }
+        rest = &after[close + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_signal_and_encounter_placeholders() {
+        let mut counters = HashMap::new();
+        counters.insert("adds_killed".to_string(), 3);
+        let ctx = AlertContext {
+            source_name: Some("Dread Master Brontes"),
+            target_name: Some("Kira"),
+            stacks: Some(4),
+            hp_percent: Some(42.6),
+            phase: Some("burn"),
+            counters: Some(&counters),
+        };
+
+        assert_eq!(
+            render_alert_text("Bomb on {target_name}", &ctx),
+            "Bomb on Kira"
+        );
+        assert_eq!(
+            render_alert_text("{source_name} @ {hp}% ({phase})", &ctx),
+            "Dread Master Brontes @ 43% (burn)"
+        );
+        assert_eq!(
+            render_alert_text(
+                "{stacks} stacks, {counter:adds_killed} adds, {counter:other}",
+                &ctx
+            ),
+            "4 stacks, 3 adds, 0"
+        );
+    }
+
+    #[test]
+    fn test_render_missing_and_unknown_placeholders() {
+        let ctx = AlertContext::default();
+
+        assert_eq!(render_alert_text("Swap now", &ctx), "Swap now");
+        assert_eq!(
+            render_alert_text("Bomb on {target_name}!", &ctx),
+            "Bomb on !"
+        );
+        assert_eq!(
+            render_alert_text("{unknown} stays", &ctx),
+            "{unknown} stays"
+        );
+        assert_eq!(
+            render_alert_text("unclosed {target_name", &ctx),
+            "unclosed {target_name"
+        );
+        assert_eq!(
+            render_alert_text(
+                "{ {stacks}",
+                &AlertContext {
+                    stacks: Some(2),
+                    ..ctx
+                }
+            ),
+            "{ 2"
+        );
+    }
+}
@@ -10,6 +10,7 @@ use super::{
     ChallengeDefinition, CounterCondition, CounterDefinition, CounterTrigger, PhaseDefinition,
 };
 use crate::dsl::audio::AudioConfig;
+use crate::game_data::{Discipline, Role};
 use baras_types::AlertTrigger;
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -290,6 +291,14 @@ pub struct BossTimerDefinition {
     #[serde(default, skip_serializing_if = "crate::serde_defaults::is_empty_vec")]
     pub difficulties: Vec<String>,
 
+    /// Roles this timer is shown to (empty = all roles)
+    #[serde(default, skip_serializing_if = "crate::serde_defaults::is_empty_vec")]
+    pub roles: Vec<Role>,
+
+    /// Disciplines this timer is shown to (empty = all disciplines)
+    #[serde(default, skip_serializing_if = "crate::serde_defaults::is_empty_vec")]
+    pub disciplines: Vec<Discipline>,
+
     /// Whether timer is enabled
     #[serde(default = "crate::serde_defaults::default_true")]
     pub enabled: bool,
@@ -377,6 +386,8 @@ impl BossTimerDefinition {
             encounters: vec![area_name.to_string()], // Kept for logging/legacy
             boss: Some(boss_name.to_string()),
             difficulties: self.difficulties.clone(),
+            roles: self.roles.clone(),
+            disciplines: self.disciplines.clone(),
             phases: self.phases.clone(),
             counter_condition: self.counter_condition.clone(),
             // Boss timers default to single-instance (per_target = false)
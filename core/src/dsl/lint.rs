@@ -0,0 +1,606 @@
+//! Static checks for encounter and effect definitions.
+//!
+//! Catches mistakes that otherwise only surface (silently) during a raid:
+//! dangling phase/counter/timer references, `chains_to` cycles, triggers used by
+//! a system that ignores them, phases that can never start, duplicate IDs, and
+//! selectors naming entities missing from the boss roster.
+
+use std::collections::{HashMap, HashSet};
+
+use super::{BossEncounterDefinition, ChallengeCondition, EntitySelector, Trigger, TriggerScope};
+use crate::effects::EffectDefinition;
+use baras_types::EntityFilter;
+
+pub use baras_types::{LintIssue, LintSeverity};
+
+/// Trigger types the effect tracker responds to.
+const EFFECT_TRIGGER_TYPES: &[&str] = &[
+    "effect_applied",
+    "effect_removed",
+    "ability_cast",
+    "damage_taken",
+    "healing_taken",
+];
+
+/// Collects issues for one definition scope.
+struct Linter {
+    scope: String,
+    issues: Vec<LintIssue>,
+}
+
+impl Linter {
+    fn new(scope: &str) -> Self {
+        Self {
+            scope: scope.to_string(),
+            issues: Vec::new(),
+        }
+    }
+
+    fn error(&mut self, item: &str, message: String) {
+        self.push(LintSeverity::Error, item, message);
+    }
+
+    fn warning(&mut self, item: &str, message: String) {
+        self.push(LintSeverity::Warning, item, message);
+    }
+
+    fn push(&mut self, severity: LintSeverity, item: &str, message: String) {
+        self.issues.push(LintIssue {
+            severity,
+            scope: self.scope.clone(),
+            item: item.to_string(),
+            message,
+        });
+    }
+
+    /// Report IDs that appear more than once (case-sensitive, as matched at runtime).
+    fn check_duplicates<'a>(&mut self, kind: &str, ids: impl IntoIterator<Item = &'a str>) {
+        let mut seen = HashSet::new();
+        let mut reported = HashSet::new();
+        for id in ids {
+            if !seen.insert(id) && reported.insert(id) {
+                self.error(&format!("{kind} '{id}'"), format!("duplicate {kind} ID"));
+            }
+        }
+    }
+}
+
+/// IDs a boss definition can reference.
+struct KnownIds<'a> {
+    phases: HashSet<&'a str>,
+    counters: HashSet<&'a str>,
+    timers: HashSet<&'a str>,
+    boss: &'a BossEncounterDefinition,
+}
+
+impl KnownIds<'_> {
+    /// Whether a selector name resolves to a roster entity.
+    fn has_entity(&self, name: &str) -> bool {
+        self.boss.entity_by_name(name).is_some()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Boss Encounter Lint
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Statically check a boss encounter definition.
+pub fn lint_boss(boss: &BossEncounterDefinition) -> Vec<LintIssue> {
+    let mut lint = Linter::new(&boss.id);
+    let known = KnownIds {
+        phases: boss.phases.iter().map(|p| p.id.as_str()).collect(),
+        counters: boss.counters.iter().map(|c| c.id.as_str()).collect(),
+        timers: boss.timers.iter().map(|t| t.id.as_str()).collect(),
+        boss,
+    };
+
+    // ─── Duplicate IDs ───────────────────────────────────────────────────────
+    lint.check_duplicates("phase", boss.phases.iter().map(|p| p.id.as_str()));
+    lint.check_duplicates("counter", boss.counters.iter().map(|c| c.id.as_str()));
+    lint.check_duplicates("timer", boss.timers.iter().map(|t| t.id.as_str()));
+    lint.check_duplicates("challenge", boss.challenges.iter().map(|c| c.id.as_str()));
+    let entity_names: Vec<String> = boss
+        .entities
+        .iter()
+        .map(|e| e.name.to_lowercase())
+        .collect();
+    lint.check_duplicates("entity", entity_names.iter().map(String::as_str));
+
+    // ─── Timers ──────────────────────────────────────────────────────────────
+    for timer in &boss.timers {
+        let item = format!("timer '{}'", timer.id);
+        check_trigger(
+            &mut lint,
+            &known,
+            &item,
+            "trigger",
+            &timer.trigger,
+            TriggerScope::TIMER,
+        );
+        if let Some(cancel) = &timer.cancel_trigger {
+            check_trigger(
+                &mut lint,
+                &known,
+                &item,
+                "cancel_trigger",
+                cancel,
+                TriggerScope::TIMER,
+            );
+        }
+        for phase in &timer.phases {
+            check_ref(&mut lint, &item, "phase", phase, &known.phases);
+        }
+        if let Some(cond) = &timer.counter_condition {
+            check_ref(
+                &mut lint,
+                &item,
+                "counter",
+                &cond.counter_id,
+                &known.counters,
+            );
+        }
+        if let Some(next) = &timer.chains_to {
+            check_ref(&mut lint, &item, "timer", next, &known.timers);
+        }
+    }
+    check_chain_cycles(&mut lint, boss);
+
+    // ─── Phases ──────────────────────────────────────────────────────────────
+    for phase in &boss.phases {
+        let item = format!("phase '{}'", phase.id);
+        check_trigger(
+            &mut lint,
+            &known,
+            &item,
+            "start_trigger",
+            &phase.start_trigger,
+            TriggerScope::PHASE,
+        );
+        if let Some(end) = &phase.end_trigger {
+            check_trigger(
+                &mut lint,
+                &known,
+                &item,
+                "end_trigger",
+                end,
+                TriggerScope::PHASE,
+            );
+        }
+        if let Some(prev) = &phase.preceded_by {
+            check_ref(&mut lint, &item, "phase", prev, &known.phases);
+        }
+        if let Some(cond) = &phase.counter_condition {
+            check_ref(
+                &mut lint,
+                &item,
+                "counter",
+                &cond.counter_id,
+                &known.counters,
+            );
+        }
+        for counter in &phase.resets_counters {
+            check_ref(&mut lint, &item, "counter", counter, &known.counters);
+        }
+    }
+    for phase_id in unreachable_phases(boss) {
+        lint.error(
+            &format!("phase '{phase_id}'"),
+            "phase can never start (its start trigger or preceded_by depends only on \
+             phases that never start, or uses a trigger phases ignore)"
+                .to_string(),
+        );
+    }
+
+    // ─── Counters ────────────────────────────────────────────────────────────
+    for counter in &boss.counters {
+        let item = format!("counter '{}'", counter.id);
+        let scope = TriggerScope::COUNTER;
+        check_trigger(
+            &mut lint,
+            &known,
+            &item,
+            "increment_on",
+            &counter.increment_on,
+            scope,
+        );
+        if let Some(dec) = &counter.decrement_on {
+            check_trigger(&mut lint, &known, &item, "decrement_on", dec, scope);
+        }
+        check_trigger(
+            &mut lint,
+            &known,
+            &item,
+            "reset_on",
+            &counter.reset_on,
+            scope,
+        );
+    }
+
+    // ─── Challenges ──────────────────────────────────────────────────────────
+    for challenge in &boss.challenges {
+        let item = format!("challenge '{}'", challenge.id);
+        for cond in &challenge.conditions {
+            match cond {
+                ChallengeCondition::Phase { phase_ids } => {
+                    for phase in phase_ids {
+                        check_ref(&mut lint, &item, "phase", phase, &known.phases);
+                    }
+                }
+                ChallengeCondition::Counter { counter_id, .. } => {
+                    check_ref(&mut lint, &item, "counter", counter_id, &known.counters);
+                }
+                ChallengeCondition::Source { matcher } | ChallengeCondition::Target { matcher } => {
+                    check_filter(&mut lint, &known, &item, matcher);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // ─── Victory Trigger ─────────────────────────────────────────────────────
+    if let Some(victory) = &boss.victory_trigger {
+        check_trigger(
+            &mut lint,
+            &known,
+            "victory_trigger",
+            "trigger",
+            victory,
+            TriggerScope::ALL,
+        );
+    }
+
+    lint.issues
+}
+
+/// Report a reference to an ID that isn't defined in this encounter.
+fn check_ref(lint: &mut Linter, item: &str, kind: &str, id: &str, known: &HashSet<&str>) {
+    if !known.contains(id) {
+        lint.error(item, format!("references unknown {kind} '{id}'"));
+    }
+}
+
+/// Whether a system actually evaluates a trigger at runtime. The counter engine
+/// also reacts to timer and counter events beyond the declared editor scope.
+fn evaluated_by(trigger: &Trigger, system: TriggerScope) -> bool {
+    let counter_event = matches!(
+        trigger,
+        Trigger::CounterReaches { .. } | Trigger::TimerExpires { .. } | Trigger::TimerStarted { .. }
+    );
+    trigger.scope().contains(system) || (counter_event && system == TriggerScope::COUNTER)
+}
+
+/// Check a trigger (recursing into `any_of`) for scope and reference problems.
+fn check_trigger(
+    lint: &mut Linter,
+    known: &KnownIds,
+    item: &str,
+    field: &str,
+    trigger: &Trigger,
+    system: TriggerScope,
+) {
+    if matches!(trigger, Trigger::CombatEnd) && system != TriggerScope::COUNTER {
+        // Timers and phases are torn down at combat end regardless
+        lint.warning(
+            item,
+            format!("{field}: 'combat_end' has no effect here (cleared at combat end anyway)"),
+        );
+    } else if !evaluated_by(trigger, system) {
+        lint.error(
+            item,
+            format!(
+                "{field}: '{}' is ignored by {}",
+                trigger.type_name(),
+                system_name(system)
+            ),
+        );
+    }
+
+    match trigger {
+        Trigger::PhaseEntered { phase_id } | Trigger::PhaseEnded { phase_id } => {
+            check_ref(lint, item, "phase", phase_id, &known.phases);
+        }
+        Trigger::CounterReaches { counter_id, .. } => {
+            check_ref(lint, item, "counter", counter_id, &known.counters);
+        }
+        Trigger::TimerExpires { timer_id } | Trigger::TimerStarted { timer_id } => {
+            check_ref(lint, item, "timer", timer_id, &known.timers);
+        }
+        Trigger::BossHpBelow { selector, .. }
+        | Trigger::BossHpAbove { selector, .. }
+        | Trigger::NpcAppears { selector }
+        | Trigger::EntityDeath { selector } => {
+            check_selectors(lint, known, item, selector);
+        }
+        Trigger::TargetSet { selector, .. } => check_selectors(lint, known, item, selector),
+        Trigger::AnyOf { conditions } => {
+            for cond in conditions {
+                check_trigger(lint, known, item, field, cond, system);
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(filter) = trigger.source_filter() {
+        check_filter(lint, known, item, filter);
+    }
+    if let Some(filter) = trigger.target_filter() {
+        check_filter(lint, known, item, filter);
+    }
+}
+
+fn check_filter(lint: &mut Linter, known: &KnownIds, item: &str, filter: &EntityFilter) {
+    if let EntityFilter::Selector(selectors) = filter {
+        check_selectors(lint, known, item, selectors);
+    }
+}
+
+/// Name selectors should match a roster entity; otherwise they silently fall
+/// back to matching the NPC's in-game name.
+fn check_selectors(lint: &mut Linter, known: &KnownIds, item: &str, selectors: &[EntitySelector]) {
+    for selector in selectors {
+        if let EntitySelector::Name(name) = selector
+            && !known.has_entity(name)
+        {
+            lint.warning(
+                item,
+                format!("selector '{name}' is not in the entity roster (falls back to log name)"),
+            );
+        }
+    }
+}
+
+fn system_name(system: TriggerScope) -> &'static str {
+    if system == TriggerScope::TIMER {
+        "timers"
+    } else if system == TriggerScope::PHASE {
+        "phases"
+    } else if system == TriggerScope::COUNTER {
+        "counters"
+    } else {
+        "this system"
+    }
+}
+
+/// Report each unbounded `chains_to` cycle once.
+///
+/// Loops are a deliberate pattern for repeating mechanics; they're only a
+/// problem when no timer in the loop has a cancel trigger or phase restriction
+/// to stop it, in which case it runs until combat ends.
+fn check_chain_cycles(lint: &mut Linter, boss: &BossEncounterDefinition) {
+    let next: HashMap<&str, &str> = boss
+        .timers
+        .iter()
+        .filter_map(|t| t.chains_to.as_deref().map(|n| (t.id.as_str(), n)))
+        .collect();
+    let bounded: HashSet<&str> = boss
+        .timers
+        .iter()
+        .filter(|t| t.cancel_trigger.is_some() || !t.phases.is_empty())
+        .map(|t| t.id.as_str())
+        .collect();
+
+    let mut reported: HashSet<&str> = HashSet::new();
+    for start in boss.timers.iter().map(|t| t.id.as_str()) {
+        let mut path: Vec<&str> = vec![start];
+        let mut current = start;
+        while let Some(&n) = next.get(current) {
+            if let Some(pos) = path.iter().position(|&p| p == n) {
+                let cycle = &path[pos..];
+                let is_new = cycle.iter().all(|id| !reported.contains(id));
+                reported.extend(cycle.iter().copied());
+                if is_new && !cycle.iter().any(|id| bounded.contains(id)) {
+                    let mut chain = cycle.join(" -> ");
+                    chain.push_str(&format!(" -> {n}"));
+                    lint.error(
+                        &format!("timer '{n}'"),
+                        format!("chains_to forms a cycle that never stops: {chain}"),
+                    );
+                }
+                break;
+            }
+            path.push(n);
+            current = n;
+        }
+    }
+}
+
+/// Phases whose start can never fire.
+///
+/// A phase is reachable when its start trigger can fire without depending on
+/// other phases, or depends (via `phase_ended`) on a reachable phase, and its
+/// `preceded_by` phase (if any) is itself reachable.
+fn unreachable_phases(boss: &BossEncounterDefinition) -> Vec<&str> {
+    let mut reachable: HashSet<&str> = HashSet::new();
+    loop {
+        let before = reachable.len();
+        for phase in &boss.phases {
+            if reachable.contains(phase.id.as_str()) {
+                continue;
+            }
+            let preceded_ok = phase
+                .preceded_by
+                .as_deref()
+                .is_none_or(|p| p != phase.id && reachable.contains(p));
+            if preceded_ok && can_fire_for_phase(&phase.start_trigger, &reachable) {
+                reachable.insert(phase.id.as_str());
+            }
+        }
+        if reachable.len() == before {
+            break;
+        }
+    }
+
+    boss.phases
+        .iter()
+        .map(|p| p.id.as_str())
+        .filter(|id| !reachable.contains(id))
+        .collect()
+}
+
+fn can_fire_for_phase(trigger: &Trigger, reachable: &HashSet<&str>) -> bool {
+    match trigger {
+        Trigger::AnyOf { conditions } => {
+            conditions.iter().any(|c| can_fire_for_phase(c, reachable))
+        }
+        _ if !trigger.valid_for_phase() => false,
+        Trigger::PhaseEnded { phase_id } => reachable.contains(phase_id.as_str()),
+        _ => true,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Effect Definition Lint
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Statically check effect definitions.
+///
+/// `known_timer_ids` are the timer IDs that `on_apply_trigger_timer` /
+/// `on_expire_trigger_timer` may name.
+pub fn lint_effects(
+    effects: &[EffectDefinition],
+    known_timer_ids: &HashSet<String>,
+) -> Vec<LintIssue> {
+    let mut lint = Linter::new("effects");
+    lint.check_duplicates("effect", effects.iter().map(|e| e.id.as_str()));
+
+    for effect in effects {
+        let item = format!("effect '{}'", effect.id);
+        let kind = effect.trigger.type_name();
+        if !EFFECT_TRIGGER_TYPES.contains(&kind) {
+            lint.error(&item, format!("trigger: '{kind}' is ignored by effects"));
+        }
+
+        let empty_selectors = match &effect.trigger {
+            Trigger::EffectApplied { effects, .. } | Trigger::EffectRemoved { effects, .. } => {
+                effects.is_empty()
+            }
+            Trigger::AbilityCast { abilities, .. }
+            | Trigger::DamageTaken { abilities, .. }
+            | Trigger::HealingTaken { abilities, .. } => abilities.is_empty(),
+            _ => false,
+        };
+        if empty_selectors {
+            lint.warning(
+                &item,
+                "trigger has no selectors and will never match".to_string(),
+            );
+        }
+
+        for timer in [
+            &effect.on_apply_trigger_timer,
+            &effect.on_expire_trigger_timer,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !known_timer_ids.contains(timer) {
+                lint.error(&item, format!("references unknown timer '{timer}'"));
+            }
+        }
+    }
+
+    lint.issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boss(toml_src: &str) -> BossEncounterDefinition {
+        let config: super::super::BossConfig = toml::from_str(toml_src).unwrap();
+        config.bosses.into_iter().next().unwrap()
+    }
+
+    fn messages(issues: &[LintIssue]) -> Vec<String> {
+        issues
+            .iter()
+            .map(|i| format!("{}: {}", i.item, i.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_clean_definition_has_no_issues() {
+        let b = boss(
+            r#"
+            [[boss]]
+            id = "clean"
+            entities = [{ name = "Boss", ids = [1], is_boss = true }]
+
+            [[boss.phase]]
+            id = "p1"
+            name = "P1"
+            start_trigger = { type = "combat_start" }
+
+            [[boss.phase]]
+            id = "p2"
+            name = "P2"
+            start_trigger = { type = "boss_hp_below", hp_percent = 50.0, selector = ["Boss"] }
+
+            [[boss.timer]]
+            id = "a"
+            name = "A"
+            trigger = { type = "phase_entered", phase_id = "p2" }
+            chains_to = "b"
+
+            [[boss.timer]]
+            id = "b"
+            name = "B"
+            trigger = { type = "manual" }
+            "#,
+        );
+        assert_eq!(messages(&lint_boss(&b)), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_reports_dangling_refs_scope_and_cycles() {
+        let b = boss(
+            r#"
+            [[boss]]
+            id = "broken"
+            entities = [{ name = "Boss", ids = [1] }]
+
+            [[boss.phase]]
+            id = "p1"
+            name = "P1"
+            start_trigger = { type = "timer_expires", timer_id = "a" }
+
+            [[boss.phase]]
+            id = "p2"
+            name = "P2"
+            start_trigger = { type = "phase_ended", phase_id = "p1" }
+
+            [[boss.timer]]
+            id = "a"
+            name = "A"
+            trigger = { type = "counter_reaches", counter_id = "missing", value = 2 }
+            chains_to = "b"
+
+            [[boss.timer]]
+            id = "b"
+            name = "B"
+            trigger = { type = "entity_death", selector = ["Add"] }
+            chains_to = "a"
+
+            [[boss.timer]]
+            id = "b"
+            name = "B again"
+            trigger = { type = "combat_start" }
+            "#,
+        );
+        let msgs = messages(&lint_boss(&b));
+        let has = |needle: &str| msgs.iter().any(|m| m.contains(needle));
+        assert!(has("duplicate timer ID"), "{msgs:?}");
+        assert!(has("references unknown counter 'missing'"), "{msgs:?}");
+        assert!(
+            has("chains_to forms a cycle that never stops: a -> b -> a"),
+            "{msgs:?}"
+        );
+        assert!(has("'timer_expires' is ignored by phases"), "{msgs:?}");
+        assert!(has("phase 'p1': phase can never start"), "{msgs:?}");
+        assert!(has("phase 'p2': phase can never start"), "{msgs:?}");
+        assert!(
+            has("selector 'Add' is not in the entity roster"),
+            "{msgs:?}"
+        );
+    }
+}
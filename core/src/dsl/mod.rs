@@ -3,34 +3,43 @@
 //! This module contains stateless definitions parsed from TOML config files.
 //! These are the building blocks for defining boss encounters, timers, effects, etc.
 //!
+//! - **alert_template**: Placeholder rendering for alert text
 //! - **audio**: Audio configuration for timers/alerts
 //! - **definition**: Boss encounter definitions (entities, phases, counters, timers, challenges)
 //! - **challenge**: Challenge metric tracking definitions
 //! - **counter**: Counter definitions for tracking occurrences
 //! - **entity_filter**: Entity matching/filtering
+//! - **lint**: Static checks for definitions (dangling references, cycles, scope misuse)
 //! - **loader**: TOML loading and saving
+//! - **notes**: Phase/counter-tagged sections in encounter notes
 //! - **phase**: Phase definitions for boss encounters
 //! - **triggers**: Unified trigger system
 //!
 //! Note: Runtime state (phases, counters, HP) is tracked in `CombatEncounter`
 //! which consolidates all encounter-scoped state.
 
+mod alert_template;
 mod audio;
 mod challenge;
 mod counter;
 mod definition;
 mod entity_filter;
 mod error;
+mod lint;
 mod loader;
+mod notes;
 mod phase;
 pub mod triggers;
 
+pub use alert_template::*;
 pub use audio::*;
 pub use challenge::*;
 pub use counter::*;
 pub use definition::*;
 pub use entity_filter::*;
 pub use error::DslError;
+pub use lint::*;
 pub use loader::*;
+pub use notes::*;
 pub use phase::*;
 pub use triggers::*;
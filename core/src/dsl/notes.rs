@@ -0,0 +1,217 @@
+//! Phase-aware encounter notes
+//!
+//! Boss notes are Markdown. A heading may carry trailing tags that limit its
+//! section to a phase or counter state:
+//!
+//! ```text
+//! ## Burn {phase: burn, burn_2}
+//! ## Adds {counter: adds_killed >= 2}
+//! ## Final burn {phase: burn_2} {counter: pylons < 4}
+//! ```
+//!
+//! A section runs until the next heading of the same or a higher level.
+//! While at least one tagged section matches the encounter state, only the
+//! untagged content and the matching sections are shown; otherwise the whole
+//! notes are shown. Tags are always stripped from the rendered text.
+
+use hashbrown::HashMap;
+
+use super::{ComparisonOp, CounterCondition};
+
+/// Notes text selected for the current encounter state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectedNotes {
+    /// Markdown text to display (tags stripped)
+    pub text: String,
+    /// True when only part of the notes matched the current phase/counters
+    pub filtered: bool,
+}
+
+/// Conditions attached to a tagged heading
+#[derive(Debug, Clone, Default, PartialEq)]
+struct SectionTags {
+    phases: Vec<String>,
+    counters: Vec<CounterCondition>,
+}
+
+impl SectionTags {
+    fn is_empty(&self) -> bool {
+        self.phases.is_empty() && self.counters.is_empty()
+    }
+
+    fn matches(&self, phase: Option<&str>, counters: &HashMap<String, u32>) -> bool {
+        let phase_ok =
+            self.phases.is_empty() || phase.is_some_and(|p| self.phases.iter().any(|t| t == p));
+        phase_ok
+            && self.counters.iter().all(|c| {
+                let value = counters.get(&c.counter_id).copied().unwrap_or(0);
+                c.operator.evaluate(value, c.value)
+            })
+    }
+}
+
+/// Pick the notes to show for the current phase and counter values
+pub fn select_notes(
+    notes: &str,
+    phase: Option<&str>,
+    counters: &HashMap<String, u32>,
+) -> SelectedNotes {
+    // (line, tags of the innermost tagged section containing it)
+    let mut lines: Vec<(String, Option<usize>)> = Vec::new();
+    let mut tags: Vec<SectionTags> = Vec::new();
+    // Open tagged sections: (heading level, index into `tags`)
+    let mut open: Vec<(usize, usize)> = Vec::new();
+
+    for line in notes.lines() {
+        if let Some(level) = heading_level(line) {
+            open.retain(|(l, _)| *l < level);
+            let (heading, section_tags) = split_tags(line);
+            if !section_tags.is_empty() {
+                tags.push(section_tags);
+                open.push((level, tags.len() - 1));
+            }
+            lines.push((heading, open.last().map(|(_, idx)| *idx)));
+        } else {
+            lines.push((line.to_string(), open.last().map(|(_, idx)| *idx)));
+        }
+    }
+
+    let matched: Vec<bool> = tags.iter().map(|t| t.matches(phase, counters)).collect();
+    let filtered = matched.iter().any(|m| *m);
+
+    let text = lines
+        .into_iter()
+        .filter(|(_, section)| !filtered || section.is_none_or(|idx| matched[idx]))
+        .map(|(line, _)| line)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    SelectedNotes { text, filtered }
+}
+
+/// Markdown heading level (number of leading '#'), if the line is a heading
+fn heading_level(line: &str) -> Option<usize> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    (level > 0 && trimmed[level..].starts_with(' ')).then_some(level)
+}
+
+/// Split trailing `{phase: ...}` / `{counter: ...}` tags off a heading.
+/// Unrecognized brace groups are left in the heading text.
+fn split_tags(line: &str) -> (String, SectionTags) {
+    let mut tags = SectionTags::default();
+    let mut heading = line.trim_end();
+
+    while heading.ends_with('}') {
+        let Some(open) = heading.rfind('{') else {
+            break;
+        };
+        let inner = &heading[open + 1..heading.len() - 1];
+        let Some((key, value)) = inner.split_once(':') else {
+            break;
+        };
+        match key.trim() {
+            "phase" | "phases" => tags.phases.extend(
+                value
+                    .split(',')
+                    .map(|p| p.trim().to_string())
+                    .filter(|p| !p.is_empty()),
+            ),
+            "counter" => match parse_counter_condition(value) {
+                Some(cond) => tags.counters.push(cond),
+                None => break,
+            },
+            _ => break,
+        }
+        heading = heading[..open].trim_end();
+    }
+
+    (heading.to_string(), tags)
+}
+
+/// Parse `counter_id <op> value`, e.g. `adds_killed >= 2`
+fn parse_counter_condition(text: &str) -> Option<CounterCondition> {
+    const OPS: [(&str, ComparisonOp); 7] = [
+        (">=", ComparisonOp::Gte),
+        ("<=", ComparisonOp::Lte),
+        ("!=", ComparisonOp::Ne),
+        ("==", ComparisonOp::Eq),
+        (">", ComparisonOp::Gt),
+        ("<", ComparisonOp::Lt),
+        ("=", ComparisonOp::Eq),
+    ];
+    let (pos, symbol, operator) = OPS
+        .iter()
+        .filter_map(|(symbol, op)| text.find(symbol).map(|pos| (pos, *symbol, *op)))
+        .min_by_key(|(pos, symbol, _)| (*pos, std::cmp::Reverse(symbol.len())))?;
+
+    let counter_id = text[..pos].trim();
+    let value = text[pos + symbol.len()..].trim().parse().ok()?;
+    (!counter_id.is_empty()).then(|| CounterCondition {
+        counter_id: counter_id.to_string(),
+        operator,
+        value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str = "\
+Tanks swap at 3 stacks.
+## Opener {phase: p1}
+- Stack on boss
+### Healers
+- Shield tank
+## Burn {phase: burn, burn_2}
+- Pop cooldowns
+## Adds {counter: adds_killed >= 2}
+- Kill the shield droid
+## Loot
+Need before greed";
+
+    #[test]
+    fn test_select_phase_section() {
+        let selected = select_notes(NOTES, Some("p1"), &HashMap::new());
+        assert!(selected.filtered);
+        assert_eq!(
+            selected.text,
+            "Tanks swap at 3 stacks.\n## Opener\n- Stack on boss\n### Healers\n- Shield tank\n## Loot\nNeed before greed"
+        );
+
+        let selected = select_notes(NOTES, Some("burn_2"), &HashMap::new());
+        assert!(selected.text.contains("## Burn\n- Pop cooldowns"));
+        assert!(!selected.text.contains("Opener"));
+    }
+
+    #[test]
+    fn test_select_counter_section_and_fallback() {
+        let mut counters = HashMap::new();
+        counters.insert("adds_killed".to_string(), 2);
+        let selected = select_notes(NOTES, Some("unknown"), &counters);
+        assert!(selected.filtered);
+        assert!(selected.text.contains("## Adds\n- Kill the shield droid"));
+        assert!(!selected.text.contains("Burn"));
+
+        // Nothing matches: full notes with tags stripped
+        let selected = select_notes(NOTES, None, &HashMap::new());
+        assert!(!selected.filtered);
+        assert!(selected.text.contains("## Opener\n"));
+        assert!(selected.text.contains("## Burn\n"));
+        assert!(!selected.text.contains('{'));
+    }
+
+    #[test]
+    fn test_split_tags_keeps_unrecognized_braces() {
+        let (heading, tags) = split_tags("## Orbs {left/right}");
+        assert_eq!(heading, "## Orbs {left/right}");
+        assert!(tags.is_empty());
+
+        let (heading, tags) = split_tags("## Final {phase: burn} {counter: pylons<4}");
+        assert_eq!(heading, "## Final");
+        assert_eq!(tags.phases, vec!["burn"]);
+        assert_eq!(tags.counters[0].operator, ComparisonOp::Lt);
+        assert_eq!(tags.counters[0].value, 4);
+    }
+}
@@ -199,7 +199,7 @@ pub enum Trigger {
     TimeElapsed { secs: f32 },
 
     // ─── System-specific ───────────────────────────────────────────────────
-    /// Manual/debug trigger. [T only]
+    /// Started from a user hotkey. [T only]
     Manual,
 
     /// Never triggers. [C only]
@@ -250,6 +250,34 @@ impl Trigger {
         }
     }
 
+    /// TOML type tag for this trigger (e.g. "ability_cast").
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::CombatStart => "combat_start",
+            Self::CombatEnd => "combat_end",
+            Self::AbilityCast { .. } => "ability_cast",
+            Self::EffectApplied { .. } => "effect_applied",
+            Self::EffectRemoved { .. } => "effect_removed",
+            Self::DamageTaken { .. } => "damage_taken",
+            Self::HealingTaken { .. } => "healing_taken",
+            Self::BossHpBelow { .. } => "boss_hp_below",
+            Self::BossHpAbove { .. } => "boss_hp_above",
+            Self::NpcAppears { .. } => "npc_appears",
+            Self::EntityDeath { .. } => "entity_death",
+            Self::TargetSet { .. } => "target_set",
+            Self::PhaseEntered { .. } => "phase_entered",
+            Self::PhaseEnded { .. } => "phase_ended",
+            Self::AnyPhaseChange => "any_phase_change",
+            Self::CounterReaches { .. } => "counter_reaches",
+            Self::TimerExpires { .. } => "timer_expires",
+            Self::TimerStarted { .. } => "timer_started",
+            Self::TimeElapsed { .. } => "time_elapsed",
+            Self::Manual => "manual",
+            Self::Never => "never",
+            Self::AnyOf { .. } => "any_of",
+        }
+    }
+
     /// Check if this trigger is valid for use as a timer trigger.
     pub const fn valid_for_timer(&self) -> bool {
         self.scope().contains(TriggerScope::TIMER)
@@ -17,7 +17,8 @@ use std::time::{Duration, Instant};
 use chrono::NaiveDateTime;
 
 use super::DisplayTarget;
-use crate::context::IStr;
+use crate::context::{IStr, resolve};
+use crate::dsl::{AlertContext, render_alert_text};
 
 /// An active effect instance on a specific entity
 ///
@@ -523,11 +524,28 @@ impl ActiveEffect {
         false
     }
 
+    /// Placeholder values for this effect's alert text (source, target, stacks)
+    pub fn alert_context(&self) -> AlertContext<'static> {
+        AlertContext {
+            source_name: Some(resolve(self.source_name)),
+            target_name: Some(resolve(self.target_name)),
+            stacks: Some(self.stacks),
+            ..Default::default()
+        }
+    }
+
+    /// Alert text with placeholders filled from this effect
+    fn render_alert_text(&self) -> Option<String> {
+        self.alert_text
+            .as_deref()
+            .map(|template| render_alert_text(template, &self.alert_context()))
+    }
+
     /// Check if alert should fire on expiration
     ///
     /// Returns Some(text) when alert should fire, None otherwise.
     /// Uses same window as audio expiration [0, 0.3)
-    pub fn check_expiration_alert(&mut self) -> Option<&str> {
+    pub fn check_expiration_alert(&mut self) -> Option<String> {
         if !self.alert_on_expire {
             return None;
         }
@@ -539,14 +557,14 @@ impl ActiveEffect {
         // Fire if effect was removed early (charges depleted, cleansed, etc.)
         if self.removed_at.is_some() {
             self.on_end_alert_fired = true;
-            return self.alert_text.as_deref();
+            return self.render_alert_text();
         }
 
         let remaining = self.remaining_base_secs_realtime();
 
         if (0.0..0.3).contains(&remaining) {
             self.on_end_alert_fired = true;
-            return self.alert_text.as_deref();
+            return self.render_alert_text();
         }
 
         None
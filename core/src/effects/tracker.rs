@@ -10,11 +10,11 @@ use std::time::Duration;
 use chrono::NaiveDateTime;
 
 use crate::combat_log::EntityType;
-use crate::context::IStr;
-use crate::dsl::EntityDefinition;
+use crate::context::{IStr, resolve};
+use crate::dsl::{AlertContext, EntityDefinition, render_alert_text};
 use crate::dsl::{EntityFilter, EntityFilterMatching};
 use crate::encounter::CombatEncounter;
-use crate::game_data::Discipline;
+use crate::game_data::{Discipline, GcdEstimate, GcdEstimator};
 use crate::signal_processor::{GameSignal, SignalHandler};
 
 use crate::timers::FiredAlert;
@@ -386,13 +386,12 @@ pub struct EffectTracker {
     /// Local player's current discipline (for discipline-scoped effects)
     local_player_discipline: Option<Discipline>,
 
-    /// Player's alacrity percentage (e.g., 15.4 for 15.4%)
+    /// Per-player alacrity/latency inferred from activation spacing.
     /// Used to adjust durations for effects with is_affected_by_alacrity = true
-    alacrity_percent: f32,
+    gcd_estimator: GcdEstimator,
 
-    /// Player's network latency in milliseconds
-    /// Added to effect durations to compensate for network delay
-    latency_ms: u16,
+    /// Configured alacrity/latency, used for casters without an inferred estimate
+    gcd_fallback: GcdEstimate,
 
     /// Queue of targets that received effects from local player.
     /// Drained by the service to attempt registration in the raid registry.
@@ -437,8 +436,8 @@ impl EffectTracker {
             current_game_time: None,
             local_player_id: None,
             local_player_discipline: None,
-            alacrity_percent: 0.0,
-            latency_ms: 0,
+            gcd_estimator: GcdEstimator::new(),
+            gcd_fallback: GcdEstimate::default(),
             new_targets: Vec::new(),
             pending_aoe_refresh: None,
             aoe_collecting: None,
@@ -460,12 +459,16 @@ impl EffectTracker {
     /// If `alert_text` is `None`, only audio fires (no text on screen) — the
     /// `text` field is still populated (with the definition name) for the audio
     /// TTS fallback, but `alert_text_enabled` is `false` so nothing is shown.
-    fn build_instant_alert(def: &EffectDefinition, timestamp: NaiveDateTime) -> FiredAlert {
+    fn build_instant_alert(
+        def: &EffectDefinition,
+        alert_ctx: &AlertContext<'_>,
+        timestamp: NaiveDateTime,
+    ) -> FiredAlert {
         let has_text = def.alert_text.is_some();
-        let text = def
-            .alert_text
-            .clone()
-            .unwrap_or_else(|| def.name.clone());
+        let text = match &def.alert_text {
+            Some(template) => render_alert_text(template, alert_ctx),
+            None => def.name.clone(),
+        };
         FiredAlert {
             id: def.id.clone(),
             name: def.name.clone(),
@@ -478,39 +481,45 @@ impl EffectTracker {
         }
     }
 
-    /// Set the player's alacrity percentage for duration calculations
+    /// Set the local player's identity and discipline
     pub fn set_player_context(&mut self, player_id: i64, discipline_id: i64) {
         self.local_player_id = Some(player_id);
         self.local_player_discipline = Discipline::from_guid(discipline_id);
     }
 
+    /// Set the configured alacrity percentage (fallback until it can be inferred)
     pub fn set_alacrity(&mut self, alacrity_percent: f32) {
-        self.alacrity_percent = alacrity_percent;
+        self.gcd_fallback =
+            GcdEstimate::from_settings(alacrity_percent, self.gcd_fallback.latency_ms);
     }
 
-    /// Set the player's network latency for duration calculations
+    /// Set the configured network latency (fallback until it can be inferred)
     pub fn set_latency(&mut self, latency_ms: u16) {
-        self.latency_ms = latency_ms;
+        self.gcd_fallback =
+            GcdEstimate::from_settings(self.gcd_fallback.alacrity_percent, latency_ms);
     }
 
-    /// Calculate effective duration for a definition, applying alacrity and latency if configured
-    /// For cooldowns with cooldown_ready_secs, adds the ready period to the total duration
+    /// Inferred GCD tier, alacrity and latency for a player (None until enough casts are seen)
+    pub fn gcd_estimate(&self, player_id: i64) -> Option<GcdEstimate> {
+        self.gcd_estimator.estimate(player_id)
+    }
+
+    /// Calculate effective duration for a definition, applying the caster's inferred
+    /// alacrity and latency (or the configured ones until enough casts are seen).
+    /// For cooldowns with cooldown_ready_secs, adds the ready period to the total duration.
     ///
     /// Formula: (base_duration / (1 + alacrity)) + latency + cooldown_ready_secs
-    fn effective_duration(&self, def: &super::EffectDefinition) -> Option<Duration> {
+    fn effective_duration(&self, def: &super::EffectDefinition, caster_id: i64) -> Option<Duration> {
         def.duration_secs.map(|base_secs| {
-            // Apply alacrity reduction if enabled for this effect
-            let adjusted = if def.is_affected_by_alacrity && self.alacrity_percent > 0.0 {
-                base_secs / (1.0 + self.alacrity_percent / 100.0)
+            // Apply alacrity reduction and latency compensation if enabled for this effect
+            let with_latency = if def.is_affected_by_alacrity {
+                self.gcd_estimator
+                    .estimate(caster_id)
+                    .unwrap_or(self.gcd_fallback)
+                    .adjust_duration(base_secs)
             } else {
                 base_secs
             };
-            // Add latency compensation for effects affected by alacrity (network-sensitive)
-            let with_latency = if def.is_affected_by_alacrity && self.latency_ms > 0 {
-                adjusted + (self.latency_ms as f32 / 1000.0)
-            } else {
-                adjusted
-            };
             // Add cooldown_ready_secs to extend the total duration for the ready state
             let total = with_latency + def.cooldown_ready_secs;
             Duration::from_secs_f32(total)
@@ -728,7 +737,8 @@ impl EffectTracker {
 
         // Collect effects that just ended (duration expired or removed by signal).
         // Include audio info so alerts fire reliably before GC.
-        let mut ended_effects: Vec<(String, Option<String>, bool)> = Vec::new();
+        let mut ended_effects: Vec<(String, Option<String>, bool, AlertContext<'static>)> =
+            Vec::new();
 
         for effect in self.active_effects.values_mut() {
             // Handle duration-expired effects.
@@ -770,12 +780,13 @@ impl EffectTracker {
                     effect.definition_id.clone(),
                     effect.audio_file.clone(),
                     should_play_audio,
+                    effect.alert_context(),
                 ));
             }
         }
 
         // Fire OnExpire alerts (with audio for early removals)
-        for (def_id, audio_file, audio_enabled) in ended_effects {
+        for (def_id, audio_file, audio_enabled, alert_ctx) in ended_effects {
             if let Some(def) = self.definitions.effects.get(&def_id)
                 && def.alert_on == AlertTrigger::OnExpire
                 && let Some(text) = &def.alert_text
@@ -783,7 +794,7 @@ impl EffectTracker {
                 self.fired_alerts.push(FiredAlert {
                     id: def_id,
                     name: def.name.clone(),
-                    text: text.clone(),
+                    text: render_alert_text(text, &alert_ctx),
                     color: def.color,
                     timestamp: current_time,
                     alert_text_enabled: true,
@@ -821,6 +832,14 @@ impl EffectTracker {
         charges: Option<u8>,
         encounter: Option<&crate::encounter::CombatEncounter>,
     ) {
+        let alert_ctx = AlertContext {
+            source_name: Some(resolve(source_name)),
+            target_name: Some(resolve(target_name)),
+            stacks: charges,
+            ..encounter
+                .map(CombatEncounter::alert_context)
+                .unwrap_or_default()
+        };
         self.current_game_time = Some(timestamp);
 
         // Note: GC is handled by tick() - don't duplicate here to reduce work per signal
@@ -862,13 +881,13 @@ impl EffectTracker {
         for def in matching_defs {
             // Instant alerts: fire and skip — no ActiveEffect created
             if def.is_alert {
-                pending_alerts.push(Self::build_instant_alert(def, timestamp));
+                pending_alerts.push(Self::build_instant_alert(def, &alert_ctx, timestamp));
                 continue;
             }
 
             let key = EffectKey::new(&def.id, target_id);
 
-            let duration = self.effective_duration(def);
+            let duration = self.effective_duration(def, source_id);
 
             // Pre-compute DotTracker validation before mutable borrow of active_effects
             let dot_tracker_valid = def.display_target != DisplayTarget::DotTracker
@@ -903,7 +922,7 @@ impl EffectTracker {
                     pending_alerts.push(FiredAlert {
                         id: def.id.clone(),
                         name: def.name.clone(),
-                        text: text.clone(),
+                        text: render_alert_text(text, &alert_ctx),
                         color: def.color,
                         timestamp,
                         alert_text_enabled: true,
@@ -954,7 +973,7 @@ impl EffectTracker {
                     pending_alerts.push(FiredAlert {
                         id: def.id.clone(),
                         name: def.name.clone(),
-                        text: text.clone(),
+                        text: render_alert_text(text, &alert_ctx),
                         color: def.color,
                         timestamp,
                         alert_text_enabled: true,
@@ -1064,7 +1083,7 @@ impl EffectTracker {
                     id: def.id.clone(),
                     name: def.name.clone(),
                     display_text: def.display_text().to_string(),
-                    duration: self.effective_duration(def),
+                    duration: self.effective_duration(def, source_id),
                     color: def.effective_color(),
                     display_target: def.display_target,
                     icon_ability_id: def.icon_ability_id.unwrap_or(action_id as u64),
@@ -1251,7 +1270,11 @@ impl EffectTracker {
             .definitions
             .find_refreshable_by(collecting.ability_id as u64, None)
             .into_iter()
-            .map(|def| (def.id.clone(), self.effective_duration(def)))
+            .map(|def| {
+                // AoE refreshes are only tracked for the local player's casts
+                let caster = self.local_player_id.unwrap_or_default();
+                (def.id.clone(), self.effective_duration(def, caster))
+            })
             .collect();
 
         // Refresh effects on all collected targets
@@ -1280,6 +1303,13 @@ impl EffectTracker {
         timestamp: NaiveDateTime,
         encounter: Option<&crate::encounter::CombatEncounter>,
     ) {
+        let alert_ctx = AlertContext {
+            source_name: Some(resolve(source_name)),
+            target_name: Some(resolve(target_name)),
+            ..encounter
+                .map(CombatEncounter::alert_context)
+                .unwrap_or_default()
+        };
         let local_player_id = self.local_player_id;
         let ability_name_str = crate::context::resolve(ability_name);
 
@@ -1340,7 +1370,7 @@ impl EffectTracker {
 
             // Instant alerts: fire and skip — no ActiveEffect created
             if def.is_alert {
-                self.fired_alerts.push(Self::build_instant_alert(def, timestamp));
+                self.fired_alerts.push(Self::build_instant_alert(def, &alert_ctx, timestamp));
                 continue;
             }
 
@@ -1362,7 +1392,7 @@ impl EffectTracker {
 
             let key = EffectKey::new(&def.id, effect_target_id);
 
-            let duration = self.effective_duration(def);
+            let duration = self.effective_duration(def, source_id);
 
             if let Some(existing) = self.active_effects.get_mut(&key) {
                 // Refresh existing effect (same trigger ability was cast again)
@@ -1385,7 +1415,7 @@ impl EffectTracker {
                     self.fired_alerts.push(FiredAlert {
                         id: def.id.clone(),
                         name: def.name.clone(),
-                        text: text.clone(),
+                        text: render_alert_text(text, &alert_ctx),
                         color: def.color,
                         timestamp,
                         alert_text_enabled: true,
@@ -1430,7 +1460,7 @@ impl EffectTracker {
                     self.fired_alerts.push(FiredAlert {
                         id: def.id.clone(),
                         name: def.name.clone(),
-                        text: text.clone(),
+                        text: render_alert_text(text, &alert_ctx),
                         color: def.color,
                         timestamp,
                         alert_text_enabled: true,
@@ -1460,6 +1490,13 @@ impl EffectTracker {
         encounter: Option<&crate::encounter::CombatEncounter>,
         trigger_check: fn(&EffectDefinition) -> bool,
     ) {
+        let alert_ctx = AlertContext {
+            source_name: Some(resolve(source_name)),
+            target_name: Some(resolve(target_name)),
+            ..encounter
+                .map(CombatEncounter::alert_context)
+                .unwrap_or_default()
+        };
         self.current_game_time = Some(timestamp);
         let ability_name_str = crate::context::resolve(ability_name);
 
@@ -1498,12 +1535,12 @@ impl EffectTracker {
 
             // Instant alerts: fire and skip — no ActiveEffect created
             if def.is_alert {
-                self.fired_alerts.push(Self::build_instant_alert(def, timestamp));
+                self.fired_alerts.push(Self::build_instant_alert(def, &alert_ctx, timestamp));
                 continue;
             }
 
             let key = EffectKey::new(&def.id, target_id);
-            let duration = self.effective_duration(def);
+            let duration = self.effective_duration(def, source_id);
 
             if let Some(existing) = self.active_effects.get_mut(&key) {
                 existing.refresh(timestamp, duration);
@@ -1515,7 +1552,7 @@ impl EffectTracker {
                     self.fired_alerts.push(FiredAlert {
                         id: def.id.clone(),
                         name: def.name.clone(),
-                        text: text.clone(),
+                        text: render_alert_text(text, &alert_ctx),
                         color: def.color,
                         timestamp,
                         alert_text_enabled: true,
@@ -1559,7 +1596,7 @@ impl EffectTracker {
                     self.fired_alerts.push(FiredAlert {
                         id: def.id.clone(),
                         name: def.name.clone(),
-                        text: text.clone(),
+                        text: render_alert_text(text, &alert_ctx),
                         color: def.color,
                         timestamp,
                         alert_text_enabled: true,
@@ -1587,6 +1624,13 @@ impl EffectTracker {
         timestamp: NaiveDateTime,
         encounter: Option<&crate::encounter::CombatEncounter>,
     ) {
+        let alert_ctx = AlertContext {
+            source_name: Some(resolve(source_name)),
+            target_name: Some(resolve(target_name)),
+            ..encounter
+                .map(CombatEncounter::alert_context)
+                .unwrap_or_default()
+        };
         self.current_game_time = Some(timestamp);
         let local_player_id = self.local_player_id;
 
@@ -1644,12 +1688,12 @@ impl EffectTracker {
             {
                 // Instant alerts: fire and skip — no ActiveEffect created
                 if def.is_alert {
-                    self.fired_alerts.push(Self::build_instant_alert(def, timestamp));
+                    self.fired_alerts.push(Self::build_instant_alert(def, &alert_ctx, timestamp));
                     continue;
                 }
 
                 // Create new effect when the game effect is removed (cooldown tracking)
-                let duration = self.effective_duration(def);
+                let duration = self.effective_duration(def, source_id);
                 let display_text = def.display_text().to_string();
                 let icon_ability_id = def.icon_ability_id.unwrap_or(effect_id as u64);
                 let effect = ActiveEffect::new(
@@ -1685,7 +1729,7 @@ impl EffectTracker {
                     self.fired_alerts.push(FiredAlert {
                         id: def.id.clone(),
                         name: def.name.clone(),
-                        text: text.clone(),
+                        text: render_alert_text(text, &alert_ctx),
                         color: def.color,
                         timestamp,
                         alert_text_enabled: true,
@@ -1723,7 +1767,13 @@ impl EffectTracker {
 
             // Calculate duration before borrowing active_effects mutably
             let duration = if def.is_refreshed_on_modify {
-                self.effective_duration(def)
+                let caster = self
+                    .active_effects
+                    .get(&key)
+                    .map(|e| e.source_entity_id)
+                    .or(self.local_player_id)
+                    .unwrap_or_default();
+                self.effective_duration(def, caster)
             } else {
                 None
             };
@@ -1985,6 +2035,12 @@ impl SignalHandler for EffectTracker {
             } => {
                 self.current_game_time = Some(*timestamp);
 
+                // Feed activation spacing into the per-player alacrity/latency estimate
+                if *source_entity_type == EntityType::Player {
+                    self.gcd_estimator
+                        .record_activation(*source_id, *ability_id, *timestamp);
+                }
+
                 // Handle AbilityCast-triggered effects (procs, cooldowns)
                 // This works for any source, not just local player
                 self.handle_ability_cast(
@@ -0,0 +1,165 @@
+//! Notes pinned to encounters.
+//!
+//! Encounter data is rebuilt from the log file every time it's loaded, so
+//! notes are kept in their own file, keyed by the log file name and the
+//! encounter's start time, and found again whenever the same encounter is
+//! parsed.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Result as IoResult;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::summary::EncounterSummary;
+
+pub use baras_types::EncounterAnnotation;
+
+const ANNOTATIONS_VERSION: u32 = 1;
+
+/// Longest note kept (longer text is cut)
+pub const MAX_ANNOTATION_LEN: usize = 500;
+
+/// Notes for every annotated encounter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotationStore {
+    version: u32,
+    entries: HashMap<String, Vec<EncounterAnnotation>>,
+}
+
+impl Default for AnnotationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnnotationStore {
+    pub fn new() -> Self {
+        Self {
+            version: ANNOTATIONS_VERSION,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Load from disk, starting empty if missing or from another version
+    pub fn load_from_disk(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(content) => match serde_json::from_str::<Self>(&content) {
+                Ok(store) if store.version == ANNOTATIONS_VERSION => store,
+                Ok(_) => {
+                    tracing::info!("Annotations version mismatch, starting fresh");
+                    Self::new()
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to parse annotations, starting fresh");
+                    Self::new()
+                }
+            },
+            Err(_) => Self::new(),
+        }
+    }
+
+    pub fn save_to_disk(&self, path: &Path) -> IoResult<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)
+    }
+
+    /// Store key for an encounter of a log file. `None` if the encounter has
+    /// no start time to identify it by.
+    pub fn key(log_file: &str, summary: &EncounterSummary) -> Option<String> {
+        let start_time = summary.start_time.as_deref()?;
+        Some(format!("{}|{}", log_file, start_time))
+    }
+
+    /// Notes for an encounter, in time order
+    pub fn get(&self, key: &str) -> &[EncounterAnnotation] {
+        self.entries.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Add a note, assigning it an ID. Returns the stored note.
+    pub fn add(&mut self, key: &str, mut annotation: EncounterAnnotation) -> EncounterAnnotation {
+        let notes = self.entries.entry(key.to_string()).or_default();
+        annotation.id = notes.iter().map(|a| a.id).max().unwrap_or(0) + 1;
+        annotation.text = clean_text(&annotation.text);
+        let pos = notes.partition_point(|a| a.time_secs <= annotation.time_secs);
+        notes.insert(pos, annotation.clone());
+        annotation
+    }
+
+    /// Replace a note's text. Returns false if there's no such note.
+    pub fn update(&mut self, key: &str, id: u64, text: &str) -> bool {
+        let Some(note) = self
+            .entries
+            .get_mut(key)
+            .and_then(|notes| notes.iter_mut().find(|a| a.id == id))
+        else {
+            return false;
+        };
+        note.text = clean_text(text);
+        true
+    }
+
+    /// Remove a note. Returns false if there's no such note.
+    pub fn remove(&mut self, key: &str, id: u64) -> bool {
+        let Some(notes) = self.entries.get_mut(key) else {
+            return false;
+        };
+        let before = notes.len();
+        notes.retain(|a| a.id != id);
+        let removed = notes.len() != before;
+        if notes.is_empty() {
+            self.entries.remove(key);
+        }
+        removed
+    }
+}
+
+fn clean_text(text: &str) -> String {
+    text.trim().chars().take(MAX_ANNOTATION_LEN).collect()
+}
+
+/// Default location of the annotations file
+pub fn default_annotations_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("annotations.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(time_secs: f32, text: &str) -> EncounterAnnotation {
+        EncounterAnnotation {
+            time_secs,
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_notes_stay_sorted_and_keep_ids() {
+        let mut store = AnnotationStore::new();
+        let key = "combat_2025-12-01.txt|2025-12-01T20:00:00";
+
+        let late = store.add(key, note(90.0, "  Heal CD missed here "));
+        let early = store.add(key, note(12.5, "Add spawned early"));
+        assert_eq!((late.id, early.id), (1, 2));
+        assert_eq!(late.text, "Heal CD missed here");
+
+        let times: Vec<f32> = store.get(key).iter().map(|a| a.time_secs).collect();
+        assert_eq!(times, vec![12.5, 90.0]);
+
+        assert!(store.update(key, 1, "Kolto missed"));
+        assert_eq!(store.get(key)[1].text, "Kolto missed");
+
+        assert!(store.remove(key, 2));
+        assert!(!store.remove(key, 2));
+        assert_eq!(store.add(key, note(30.0, "Wipe call")).id, 2);
+
+        assert!(store.remove(key, 1) && store.remove(key, 2));
+        assert!(store.get(key).is_empty());
+        assert!(store.entries.is_empty());
+    }
+}
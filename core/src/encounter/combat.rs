@@ -17,15 +17,18 @@ use hashbrown::{HashMap, HashSet};
 use crate::combat_log::{CombatEvent, Entity, EntityType};
 use crate::context::IStr;
 use crate::dsl::{BossEncounterDefinition, CounterCondition, CounterDefinition};
-use crate::game_data::{Difficulty, Discipline, SHIELD_EFFECT_IDS, defense_type, effect_id};
+use crate::game_data::{
+    Difficulty, Discipline, SHIELD_EFFECT_IDS, defense_type, effect_id, is_pvp_area,
+};
 use crate::{effect_type_id, is_boss};
 
 use super::challenge::ChallengeTracker;
 use super::effect_instance::EffectInstance;
 use super::entity_info::{NpcInfo, PlayerInfo};
 use super::metrics::MetricAccumulator;
+use super::pvp::PvpTracker;
 use super::{EncounterState, OverlayHealthEntry};
-use crate::dsl::ChallengeContext;
+use crate::dsl::{AlertContext, ChallengeContext};
 
 /// Processing mode for the encounter
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -133,6 +136,8 @@ pub struct CombatEncounter {
     pub accumulated_data: HashMap<i64, MetricAccumulator>,
     /// Challenge metrics for boss encounters
     pub challenge_tracker: ChallengeTracker,
+    /// Kill/death/assist attribution (PvP areas only)
+    pub pvp: PvpTracker,
 
     // ─── Line Number Tracking (for per-encounter Parsely uploads) ────────────
     /// Line number of the first event accumulated for this encounter
@@ -188,6 +193,7 @@ impl CombatEncounter {
             // Metrics
             accumulated_data: HashMap::new(),
             challenge_tracker: ChallengeTracker::new(),
+            pvp: PvpTracker::default(),
 
             // Line number tracking
             first_event_line: None,
@@ -366,6 +372,7 @@ impl CombatEncounter {
                 current: npc.current_hp,
                 max: npc.max_hp,
                 first_seen_at: npc.first_seen_at,
+                class_id: npc.class_id,
             })
             .collect();
 
@@ -561,6 +568,19 @@ impl CombatEncounter {
         }
     }
 
+    /// Build the encounter part of an alert text context (phase, counters, boss HP)
+    pub fn alert_context(&self) -> AlertContext<'_> {
+        AlertContext {
+            phase: self.current_phase.as_deref(),
+            counters: Some(&self.counters),
+            hp_percent: self
+                .active_boss
+                .as_ref()
+                .and_then(|b| self.get_npc_hp_pct(b.entity_id)),
+            ..Default::default()
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Entity State
     // ═══════════════════════════════════════════════════════════════════════
@@ -708,13 +728,22 @@ impl CombatEncounter {
 
         match entity.entity_type {
             EntityType::Player => {
+                // Every line carries the entity's HP, so the last one seen is
+                // the player's current HP (raid frames read it)
+                let (current_hp, max_hp) = entity.health;
                 self.players
                     .entry(entity.log_id)
-                    .and_modify(|p| p.last_seen_at = Some(timestamp))
+                    .and_modify(|p| {
+                        p.last_seen_at = Some(timestamp);
+                        p.current_hp = current_hp;
+                        p.max_hp = max_hp;
+                    })
                     .or_insert_with(|| PlayerInfo {
                         id: entity.log_id,
                         name: entity.name,
                         last_seen_at: Some(timestamp),
+                        current_hp,
+                        max_hp,
                         ..Default::default()
                     });
             }
@@ -817,6 +846,7 @@ impl CombatEncounter {
                 applied_at: event.timestamp,
                 is_shield,
                 removed_at: None,
+                absorbed: 0,
             });
     }
 
@@ -856,6 +886,10 @@ impl CombatEncounter {
         let is_natural_shield = defense_type == defense_type::SHIELD
             && event.details.dmg_effective == event.details.dmg_amount;
 
+        if self.area_id.is_some_and(is_pvp_area) {
+            self.pvp.process(event);
+        }
+
         // Source accumulation
         {
             let source = self
@@ -1058,3 +1092,58 @@ impl CombatEncounter {
         Some(stats)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat_log::{Action, Details, Effect};
+    use crate::context::intern;
+    use chrono::NaiveDate;
+
+    fn event(source: Entity, target: Entity) -> CombatEvent {
+        CombatEvent {
+            line_number: 0,
+            timestamp: NaiveDate::from_ymd_opt(2025, 12, 10)
+                .unwrap()
+                .and_hms_opt(20, 0, 0)
+                .unwrap(),
+            source_entity: source,
+            target_entity: target,
+            effect: Effect {
+                type_id: effect_type_id::APPLYEFFECT,
+                effect_id: effect_id::HEAL,
+                ..Default::default()
+            },
+            details: Details::default(),
+            action: Action::default(),
+        }
+    }
+
+    fn player(id: i64, health: (i32, i32)) -> Entity {
+        Entity {
+            name: intern("Healer"),
+            log_id: id,
+            entity_type: EntityType::Player,
+            health,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tracks_last_known_player_hp() {
+        let mut enc = CombatEncounter::new(1, ProcessingMode::Live);
+        enc.track_event_entities(&event(
+            player(1, (40_000, 50_000)),
+            player(2, (10_000, 40_000)),
+        ));
+        assert_eq!(enc.players[&1].hp_percent(), 80.0);
+        assert_eq!(enc.players[&2].hp_percent(), 25.0);
+
+        // Later lines overwrite, zero-health lines are ignored, dead reads as 0
+        enc.track_event_entities(&event(player(2, (0, 40_000)), player(1, (50_000, 50_000))));
+        assert_eq!(enc.players[&1].hp_percent(), 100.0);
+        assert_eq!(enc.players[&2].hp_percent(), 25.0);
+        enc.players.get_mut(&2).unwrap().is_dead = true;
+        assert_eq!(enc.players[&2].hp_percent(), 0.0);
+    }
+}
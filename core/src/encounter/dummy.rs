@@ -0,0 +1,374 @@
+//! Training dummy parses.
+//!
+//! Damage a player deals to training dummies is recorded into sessions: one
+//! session per character and stretch of hits without a pause longer than
+//! [`SESSION_GAP_SECS`]. A session keeps damage per second from its first hit,
+//! so any parse length ([`DummyParseLimit`]) can be cut from it later. Sessions
+//! are recorded while indexing log files (for the parse history) and live (to
+//! finish the current parse as soon as its limit is reached).
+
+use chrono::NaiveDateTime;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::combat_log::{CombatEvent, EntityType};
+use crate::context::resolve;
+use crate::game_data::{effect_id, effect_type_id, is_training_dummy};
+
+pub use baras_types::{DummyParse, DummyParseHistory, DummyParseLimit};
+
+/// A pause in hits longer than this starts a new session
+pub const SESSION_GAP_SECS: i64 = 10;
+
+/// Damage dealt to training dummies by one character without a long pause
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DummySession {
+    pub character: String,
+    pub discipline: String,
+    /// Time of the first hit
+    pub started_at: NaiveDateTime,
+    /// Damage per second since the first hit
+    pub damage_per_sec: Vec<i64>,
+}
+
+impl DummySession {
+    fn new(character: &str, discipline: &str, started_at: NaiveDateTime) -> Self {
+        Self {
+            character: character.to_string(),
+            discipline: discipline.to_string(),
+            started_at,
+            damage_per_sec: Vec::new(),
+        }
+    }
+
+    fn add_hit(&mut self, timestamp: NaiveDateTime, amount: i64) {
+        let second = (timestamp - self.started_at).num_milliseconds().max(0) as usize / 1000;
+        if self.damage_per_sec.len() <= second {
+            self.damage_per_sec.resize(second + 1, 0);
+        }
+        self.damage_per_sec[second] += amount;
+    }
+
+    /// Cut a parse of the given length from this session.
+    /// Returns `None` if the session didn't reach the limit.
+    pub fn parse(&self, limit: DummyParseLimit) -> Option<DummyParse> {
+        let (duration_secs, damage) = match limit {
+            DummyParseLimit::Duration { secs } => {
+                let secs = secs as usize;
+                if secs == 0 || self.damage_per_sec.len() < secs {
+                    return None;
+                }
+                (secs as f32, self.damage_per_sec[..secs].iter().sum::<i64>())
+            }
+            DummyParseLimit::Damage { hp } => {
+                let mut total = 0;
+                let (second, before, bucket) =
+                    self.damage_per_sec
+                        .iter()
+                        .enumerate()
+                        .find_map(|(i, dmg)| {
+                            let before = total;
+                            total += dmg;
+                            (hp > 0 && total >= hp).then_some((i, before, *dmg))
+                        })?;
+                // Interpolate inside the second the limit was crossed
+                let fraction = (hp - before) as f32 / bucket.max(1) as f32;
+                (second as f32 + fraction, hp)
+            }
+        };
+
+        Some(DummyParse {
+            character: self.character.clone(),
+            discipline: self.discipline.clone(),
+            started_at: self.started_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            duration_secs,
+            damage,
+            dps: damage as f64 / duration_secs.max(1.0) as f64,
+        })
+    }
+}
+
+/// Damage dealt by a player to a training dummy, if the event is one
+pub fn dummy_hit_amount(event: &CombatEvent) -> Option<i64> {
+    (event.effect.type_id == effect_type_id::APPLYEFFECT
+        && event.effect.effect_id == effect_id::DAMAGE
+        && event.source_entity.entity_type == EntityType::Player
+        && event.details.dmg_amount > 0
+        && is_training_dummy(event.target_entity.class_id))
+    .then_some(event.details.dmg_amount as i64)
+}
+
+#[derive(Debug, Clone)]
+struct OpenSession {
+    session: DummySession,
+    last_hit: NaiveDateTime,
+}
+
+/// Splits dummy hits into sessions, keeping one open session per character so
+/// players hitting dummies side by side don't end each other's sessions
+#[derive(Debug, Clone, Default)]
+pub struct DummySessionRecorder {
+    /// Character name -> session still being recorded
+    open: HashMap<String, OpenSession>,
+    finished: Vec<DummySession>,
+    /// Player log ID -> discipline name (for [`Self::record_event`])
+    disciplines: HashMap<i64, String>,
+}
+
+impl DummySessionRecorder {
+    /// Record an event from a log scan. Disciplines come from each player's
+    /// latest `DisciplineChanged` event.
+    pub fn record_event(&mut self, event: &CombatEvent) {
+        if event.effect.type_id == effect_type_id::DISCIPLINECHANGED {
+            self.disciplines.insert(
+                event.source_entity.log_id,
+                resolve(event.effect.discipline_name).to_string(),
+            );
+        } else if let Some(amount) = dummy_hit_amount(event) {
+            let discipline = self
+                .disciplines
+                .get(&event.source_entity.log_id)
+                .cloned()
+                .unwrap_or_default();
+            self.record(
+                event.timestamp,
+                amount,
+                resolve(event.source_entity.name),
+                &discipline,
+            );
+        }
+    }
+
+    /// Record a hit. Returns true if it started a new session.
+    pub fn record(
+        &mut self,
+        timestamp: NaiveDateTime,
+        amount: i64,
+        character: &str,
+        discipline: &str,
+    ) -> bool {
+        let continues = self.open.get(character).is_some_and(|open| {
+            open.session.discipline == discipline
+                && (timestamp - open.last_hit).num_seconds() <= SESSION_GAP_SECS
+        });
+        if !continues {
+            if let Some(ended) = self.open.remove(character) {
+                self.finished.push(ended.session);
+            }
+            self.open.insert(
+                character.to_string(),
+                OpenSession {
+                    session: DummySession::new(character, discipline, timestamp),
+                    last_hit: timestamp,
+                },
+            );
+        }
+        if let Some(open) = self.open.get_mut(character) {
+            open.session.add_hit(timestamp, amount);
+            open.last_hit = timestamp;
+        }
+        !continues
+    }
+
+    /// The session still being recorded for a character
+    pub fn current(&self, character: &str) -> Option<&DummySession> {
+        self.open.get(character).map(|open| &open.session)
+    }
+
+    /// All recorded sessions, oldest first
+    pub fn finish(mut self) -> Vec<DummySession> {
+        self.finished
+            .extend(self.open.into_values().map(|open| open.session));
+        self.finished.sort_by_key(|s| s.started_at);
+        self.finished
+    }
+}
+
+/// Live dummy parse for the local player: starts on the first hit and
+/// completes once the limit is reached. Later hits in the same session are
+/// ignored until the player pauses and starts over.
+#[derive(Debug, Clone, Default)]
+pub struct DummyParseTracker {
+    /// Parse length (`None` disables tracking)
+    pub limit: Option<DummyParseLimit>,
+    recorder: DummySessionRecorder,
+    completed: bool,
+}
+
+impl DummyParseTracker {
+    /// Record a local player hit. Returns the parse when it completes.
+    pub fn process(
+        &mut self,
+        event: &CombatEvent,
+        character: &str,
+        discipline: &str,
+    ) -> Option<DummyParse> {
+        let limit = self.limit?;
+        let amount = dummy_hit_amount(event)?;
+        if self
+            .recorder
+            .record(event.timestamp, amount, character, discipline)
+        {
+            self.completed = false;
+            // Earlier sessions are only kept by log scans
+            self.recorder.finished.clear();
+        }
+        if self.completed {
+            return None;
+        }
+
+        let session = self.recorder.current(character)?;
+        // A duration parse is only complete once a hit lands past the limit,
+        // so the last second is fully counted
+        if let DummyParseLimit::Duration { secs } = limit
+            && session.damage_per_sec.len() <= secs as usize
+        {
+            return None;
+        }
+        let parse = session.parse(limit)?;
+        self.completed = true;
+        Some(parse)
+    }
+}
+
+/// Group parses by character and discipline (newest first within each
+/// group) and compare each group's newest parse to its best.
+/// Parses with the same character and start time are counted once.
+pub fn build_dummy_parse_history(mut parses: Vec<DummyParse>) -> Vec<DummyParseHistory> {
+    parses.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    parses.dedup_by(|a, b| a.character == b.character && a.started_at == b.started_at);
+
+    let mut history: Vec<DummyParseHistory> = Vec::new();
+    for parse in parses {
+        match history
+            .iter_mut()
+            .find(|h| h.character == parse.character && h.discipline == parse.discipline)
+        {
+            Some(group) => group.parses.push(parse),
+            None => history.push(DummyParseHistory {
+                character: parse.character.clone(),
+                discipline: parse.discipline.clone(),
+                parses: vec![parse],
+                best_dps: 0.0,
+                last_vs_best_pct: 0.0,
+            }),
+        }
+    }
+
+    for group in &mut history {
+        group.best_dps = group.parses.iter().map(|p| p.dps).fold(0.0, f64::max);
+        if let Some(last) = group.parses.first()
+            && group.best_dps > 0.0
+        {
+            group.last_vs_best_pct = last.dps / group.best_dps * 100.0;
+        }
+    }
+    history
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(secs: f64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 12, 10)
+            .unwrap()
+            .and_hms_opt(20, 0, 0)
+            .unwrap()
+            + chrono::Duration::milliseconds((secs * 1000.0) as i64)
+    }
+
+    #[test]
+    fn test_sessions_split_on_pause_and_discipline() {
+        let mut recorder = DummySessionRecorder::default();
+        assert!(recorder.record(at(0.0), 100, "Jerran", "Lightning"));
+        assert!(!recorder.record(at(1.5), 100, "Jerran", "Lightning"));
+        // Long pause starts a new session
+        assert!(recorder.record(at(30.0), 100, "Jerran", "Lightning"));
+        // Respec starts a new session
+        assert!(recorder.record(at(31.0), 100, "Jerran", "Madness"));
+
+        let sessions = recorder.finish();
+        assert_eq!(sessions.len(), 3);
+        assert_eq!(sessions[0].damage_per_sec, vec![100, 100]);
+        assert_eq!(sessions[2].discipline, "Madness");
+    }
+
+    #[test]
+    fn test_interleaved_characters_keep_their_sessions() {
+        let mut recorder = DummySessionRecorder::default();
+        assert!(recorder.record(at(0.0), 100, "Jerran", "Lightning"));
+        assert!(recorder.record(at(0.5), 200, "Kessa", "Marksmanship"));
+        for sec in 1..6 {
+            assert!(!recorder.record(at(sec as f64), 100, "Jerran", "Lightning"));
+            assert!(!recorder.record(at(sec as f64 + 0.5), 200, "Kessa", "Marksmanship"));
+        }
+        assert_eq!(recorder.current("Kessa").unwrap().damage_per_sec.len(), 6);
+
+        let sessions = recorder.finish();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].character, "Jerran");
+        assert_eq!(sessions[0].damage_per_sec, vec![100; 6]);
+        assert_eq!(sessions[1].character, "Kessa");
+        assert_eq!(sessions[1].damage_per_sec, vec![200; 6]);
+        assert!(
+            sessions[1]
+                .parse(DummyParseLimit::Duration { secs: 5 })
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        let mut recorder = DummySessionRecorder::default();
+        for sec in 0..10 {
+            recorder.record(at(sec as f64 + 0.5), 1000, "Jerran", "Lightning");
+        }
+        let session = recorder.finish().remove(0);
+
+        let timed = session
+            .parse(DummyParseLimit::Duration { secs: 5 })
+            .unwrap();
+        assert_eq!(timed.damage, 5000);
+        assert_eq!(timed.dps, 1000.0);
+        assert!(
+            session
+                .parse(DummyParseLimit::Duration { secs: 20 })
+                .is_none()
+        );
+
+        // 2500 damage is reached halfway through the third second
+        let hp = session.parse(DummyParseLimit::Damage { hp: 2500 }).unwrap();
+        assert_eq!(hp.duration_secs, 2.5);
+        assert_eq!(hp.dps, 1000.0);
+        assert!(
+            session
+                .parse(DummyParseLimit::Damage { hp: 20_000 })
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_history_compares_last_to_best() {
+        let parse = |started_at: &str, dps: f64| DummyParse {
+            character: "Jerran".to_string(),
+            discipline: "Lightning".to_string(),
+            started_at: started_at.to_string(),
+            duration_secs: 180.0,
+            damage: (dps * 180.0) as i64,
+            dps,
+        };
+        let history = build_dummy_parse_history(vec![
+            parse("2025-12-01 20:00:00", 20_000.0),
+            parse("2025-12-08 20:00:00", 18_000.0),
+            parse("2025-12-01 20:00:00", 20_000.0),
+        ]);
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].parses.len(), 2);
+        assert_eq!(history[0].parses[0].started_at, "2025-12-08 20:00:00");
+        assert_eq!(history[0].best_dps, 20_000.0);
+        assert_eq!(history[0].last_vs_best_pct, 90.0);
+    }
+}
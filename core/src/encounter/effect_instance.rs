@@ -8,4 +8,6 @@ pub struct EffectInstance {
     pub applied_at: NaiveDateTime,
     pub removed_at: Option<NaiveDateTime>,
     pub is_shield: bool,
+    /// Damage absorbed so far (shields only), used to track remaining capacity
+    pub absorbed: i64,
 }
@@ -16,6 +16,9 @@ pub struct PlayerInfo {
     pub current_target_id: i64,
     /// Last time this player was seen in an event (for filtering stale players)
     pub last_seen_at: Option<NaiveDateTime>,
+    /// Last known HP from the log (max 0 = not seen with HP yet)
+    pub current_hp: i32,
+    pub max_hp: i32,
 }
 
 impl Default for PlayerInfo {
@@ -32,6 +35,22 @@ impl Default for PlayerInfo {
             received_revive_immunity: false,
             current_target_id: 0,
             last_seen_at: None,
+            current_hp: 0,
+            max_hp: 0,
+        }
+    }
+}
+
+impl PlayerInfo {
+    /// Last known HP as a percentage (0 when dead, 100 when unknown)
+    #[inline]
+    pub fn hp_percent(&self) -> f32 {
+        if self.is_dead {
+            0.0
+        } else if self.max_hp > 0 {
+            (self.current_hp as f32 / self.max_hp as f32 * 100.0).clamp(0.0, 100.0)
+        } else {
+            100.0
         }
     }
 }
@@ -1,8 +1,12 @@
+pub mod annotations;
 pub mod challenge;
 pub mod combat;
+pub mod dummy;
 pub mod effect_instance;
 pub mod entity_info;
 pub mod metrics;
+pub mod personal_best;
+pub mod pvp;
 pub mod shielding;
 pub mod summary;
 
@@ -44,6 +48,9 @@ pub struct OverlayHealthEntry {
     /// Used for sorting by encounter order (not serialized)
     #[serde(skip)]
     pub first_seen_at: Option<NaiveDateTime>,
+    /// NPC class ID, used to show the name in the user's language (not serialized)
+    #[serde(skip)]
+    pub class_id: i64,
 }
 
 impl OverlayHealthEntry {
@@ -0,0 +1,332 @@
+//! Personal-best tracking against a character's own history.
+//!
+//! Boss pulls are recorded per character, boss, difficulty and discipline as
+//! encounter summaries are produced (live or when a log file is loaded), and
+//! persisted across sessions. A pull is ranked by the share of the other
+//! recorded pulls it matched or beat.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Result as IoResult;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::PhaseType;
+use super::metrics::PlayerMetrics;
+use super::summary::EncounterSummary;
+use crate::game_data::Role;
+
+pub use baras_types::{MetricRank, PersonalRank};
+
+const HISTORY_VERSION: u32 = 1;
+
+/// Pulls shorter than this aren't recorded (early resets skew the numbers)
+pub const MIN_PULL_SECS: i64 = 20;
+/// Pulls kept per character/boss/difficulty/discipline (oldest dropped first,
+/// personal bests are always kept)
+pub const MAX_PULLS_PER_KEY: usize = 500;
+
+/// One recorded pull
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRecord {
+    /// Encounter start time (identifies the pull when a file is loaded again)
+    pub start_time: String,
+    pub dps: i64,
+    pub hps: i64,
+    pub ehps: i64,
+}
+
+/// Recorded boss pulls by character, boss, difficulty and discipline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalHistory {
+    version: u32,
+    entries: HashMap<String, Vec<PullRecord>>,
+}
+
+impl Default for PersonalHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PersonalHistory {
+    pub fn new() -> Self {
+        Self {
+            version: HISTORY_VERSION,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Load from disk, starting empty if missing or from another version
+    pub fn load_from_disk(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(content) => match serde_json::from_str::<Self>(&content) {
+                Ok(history) if history.version == HISTORY_VERSION => history,
+                Ok(_) => {
+                    tracing::info!("Personal history version mismatch, starting fresh");
+                    Self::new()
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to parse personal history, starting fresh");
+                    Self::new()
+                }
+            },
+            Err(_) => Self::new(),
+        }
+    }
+
+    pub fn save_to_disk(&self, path: &Path) -> IoResult<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)
+    }
+
+    /// History key for a character's pulls on a boss
+    pub fn key(character: &str, boss: &str, difficulty: &str, discipline: &str) -> String {
+        format!("{}|{}|{}|{}", character, boss, difficulty, discipline)
+    }
+
+    /// Key and record for the character's part in a summary, if it's a
+    /// rankable boss pull (PvP and dummy parses are tracked elsewhere)
+    fn summary_record(
+        character: &str,
+        summary: &EncounterSummary,
+    ) -> Option<(String, PullRecord, bool)> {
+        if matches!(
+            summary.encounter_type,
+            PhaseType::PvP | PhaseType::DummyParse
+        ) || summary.duration_seconds < MIN_PULL_SECS
+        {
+            return None;
+        }
+        let boss = summary.boss_name.as_deref()?;
+        let metrics = summary
+            .player_metrics
+            .iter()
+            .find(|m| m.name == character)?;
+        Some(Self::metrics_record(
+            boss,
+            summary.difficulty.as_deref(),
+            metrics,
+            summary.start_time.as_deref().unwrap_or_default(),
+        ))
+    }
+
+    fn metrics_record(
+        boss: &str,
+        difficulty: Option<&str>,
+        metrics: &PlayerMetrics,
+        start_time: &str,
+    ) -> (String, PullRecord, bool) {
+        let key = Self::key(
+            &metrics.name,
+            boss,
+            difficulty.unwrap_or_default(),
+            metrics.discipline_name.as_deref().unwrap_or_default(),
+        );
+        let healer = metrics.discipline.is_some_and(|d| d.role() == Role::Healer);
+        let record = PullRecord {
+            start_time: start_time.to_string(),
+            dps: metrics.dps,
+            hps: metrics.hps,
+            ehps: metrics.ehps,
+        };
+        (key, record, healer)
+    }
+
+    /// Rank values against the recorded pulls for a key, leaving out the
+    /// pull that started at `start_time` (the pull itself, if recorded)
+    pub fn rank(
+        &self,
+        key: &str,
+        start_time: &str,
+        dps: i64,
+        hps: i64,
+        ehps: i64,
+        healer: bool,
+    ) -> PersonalRank {
+        let others: Vec<&PullRecord> = self
+            .entries
+            .get(key)
+            .map(|pulls| {
+                pulls
+                    .iter()
+                    .filter(|p| p.start_time != start_time)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let metric = |value: i64, get: fn(&PullRecord) -> i64| {
+            let best = others.iter().map(|p| get(p)).max().unwrap_or(0);
+            let percentile = if others.is_empty() {
+                100.0
+            } else {
+                let beaten = others.iter().filter(|p| get(p) <= value).count();
+                beaten as f32 / others.len() as f32 * 100.0
+            };
+            MetricRank {
+                value,
+                best,
+                percentile,
+                is_best: !others.is_empty() && value > 0 && value > best,
+            }
+        };
+
+        PersonalRank {
+            encounter_id: 0,
+            compared_pulls: others.len() as u32,
+            healer,
+            dps: metric(dps, |p| p.dps),
+            hps: metric(hps, |p| p.hps),
+            ehps: metric(ehps, |p| p.ehps),
+        }
+    }
+
+    /// Rank the character's part in a summary against their other pulls
+    pub fn rank_summary(
+        &self,
+        character: &str,
+        summary: &EncounterSummary,
+    ) -> Option<PersonalRank> {
+        let (key, record, healer) = Self::summary_record(character, summary)?;
+        let mut rank = self.rank(
+            &key,
+            &record.start_time,
+            record.dps,
+            record.hps,
+            record.ehps,
+            healer,
+        );
+        rank.encounter_id = summary.encounter_id;
+        Some(rank)
+    }
+
+    /// Rank a boss pull still in progress against the player's recorded pulls
+    pub fn rank_live(
+        &self,
+        boss: &str,
+        difficulty: Option<&str>,
+        metrics: &PlayerMetrics,
+    ) -> PersonalRank {
+        let (key, record, healer) = Self::metrics_record(boss, difficulty, metrics, "");
+        self.rank(&key, "", record.dps, record.hps, record.ehps, healer)
+    }
+
+    /// Record the character's part in a summary and rank it against the
+    /// earlier pulls. Returns `None` if the summary isn't a rankable pull for
+    /// the character, or was already recorded (same start time).
+    pub fn record(&mut self, character: &str, summary: &EncounterSummary) -> Option<PersonalRank> {
+        let rank = self.rank_summary(character, summary)?;
+        let (key, record, _) = Self::summary_record(character, summary)?;
+        let pulls = self.entries.entry(key).or_default();
+        if pulls.iter().any(|p| p.start_time == record.start_time) {
+            return None;
+        }
+        pulls.push(record);
+        if pulls.len() > MAX_PULLS_PER_KEY {
+            // Drop the oldest pull that isn't the best in any metric
+            let best = |get: fn(&PullRecord) -> i64| {
+                pulls
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(_, p)| get(p))
+                    .map(|(i, _)| i)
+            };
+            let keep = [best(|p| p.dps), best(|p| p.hps), best(|p| p.ehps)];
+            if let Some(oldest) = (0..pulls.len()).find(|i| !keep.contains(&Some(*i))) {
+                pulls.remove(oldest);
+            }
+        }
+        Some(rank)
+    }
+}
+
+/// Default location of the personal history file
+pub fn default_history_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("personal_history.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pull(start: &str, dps: i64) -> EncounterSummary {
+        EncounterSummary {
+            encounter_id: 0,
+            display_name: "Brontes - 1".to_string(),
+            encounter_type: PhaseType::Raid,
+            start_time: Some(start.to_string()),
+            end_time: None,
+            duration_seconds: 300,
+            success: true,
+            area_name: "Dread Fortress".to_string(),
+            difficulty: Some("Veteran 8".to_string()),
+            boss_name: Some("Brontes".to_string()),
+            player_metrics: vec![PlayerMetrics {
+                name: "Jerran".to_string(),
+                discipline_name: Some("Lightning".to_string()),
+                dps,
+                ..Default::default()
+            }],
+            is_phase_start: false,
+            npc_names: Vec::new(),
+            area_entered_line: None,
+            event_start_line: None,
+            event_end_line: None,
+            challenges: Vec::new(),
+            pvp_stats: Vec::new(),
+            parsely_link: None,
+        }
+    }
+
+    #[test]
+    fn test_record_ranks_against_earlier_pulls() {
+        let mut history = PersonalHistory::new();
+        let first = history
+            .record("Jerran", &pull("2025-12-01T20:00:00", 20_000))
+            .unwrap();
+        assert_eq!(first.compared_pulls, 0);
+        assert!(!first.dps.is_best);
+
+        history.record("Jerran", &pull("2025-12-02T20:00:00", 18_000));
+        let best = history
+            .record("Jerran", &pull("2025-12-03T20:00:00", 21_000))
+            .unwrap();
+        assert!(best.dps.is_best);
+        assert_eq!(best.dps.best, 20_000);
+        assert_eq!(best.dps.percentile, 100.0);
+
+        // Loading the same pull again doesn't record it twice
+        let again = pull("2025-12-02T20:00:00", 18_000);
+        assert!(history.record("Jerran", &again).is_none());
+        let again = history.rank_summary("Jerran", &again).unwrap();
+        assert_eq!(again.compared_pulls, 2);
+        assert_eq!(again.dps.percentile, 0.0);
+
+        // Other characters and trash aren't ranked
+        assert!(
+            history
+                .record("Someone", &pull("2025-12-04T20:00:00", 1))
+                .is_none()
+        );
+        let mut trash = pull("2025-12-04T20:00:00", 1);
+        trash.boss_name = None;
+        assert!(history.record("Jerran", &trash).is_none());
+    }
+
+    #[test]
+    fn test_eviction_keeps_personal_best() {
+        let mut history = PersonalHistory::new();
+        history.record("Jerran", &pull("pull-0000", 30_000));
+        for i in 1..=MAX_PULLS_PER_KEY {
+            history.record("Jerran", &pull(&format!("pull-{i:04}"), 10_000 + i as i64));
+        }
+
+        let pulls = history.entries.values().next().unwrap();
+        assert_eq!(pulls.len(), MAX_PULLS_PER_KEY);
+        assert_eq!(pulls[0].start_time, "pull-0000");
+        assert_eq!(pulls[1].start_time, "pull-0002");
+    }
+}
@@ -0,0 +1,356 @@
+//! PvP warzone and arena analysis.
+//!
+//! While an encounter takes place in a PvP area, [`PvpTracker`] attributes
+//! player deaths to kills and assists and estimates the damage prevented by
+//! taunts. Matches are rebuilt from the encounter summaries afterwards: one
+//! match per visit to a PvP area, split into rounds in arenas.
+
+use chrono::{Duration, NaiveDateTime};
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::PhaseType;
+use super::summary::EncounterSummary;
+use crate::combat_log::{CombatEvent, Entity, EntityType};
+use crate::context::resolve;
+use crate::game_data::{effect_id, is_arena};
+
+pub use baras_types::{PvpMatch, PvpRound, PvpScoreboardEntry};
+
+/// Players who damaged a victim within this window before the death get an assist
+pub const ASSIST_WINDOW_SECS: i64 = 10;
+/// How long a taunt reduces the taunted player's damage against others
+pub const TAUNT_DURATION_SECS: i64 = 6;
+/// Damage reduction of a taunted player against anyone but the taunter
+pub const TAUNT_DAMAGE_REDUCTION: f64 = 0.3;
+/// A pause in combat longer than this between arena encounters starts a new round
+pub const ARENA_ROUND_GAP_SECS: i64 = 10;
+
+/// Kill/death/assist and taunt numbers for one player in one encounter
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PvpPlayerStats {
+    pub entity_id: i64,
+    pub name: String,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    /// Estimated damage prevented by this player's taunts
+    pub taunt_mitigation: i64,
+}
+
+/// Per-encounter PvP attribution.
+///
+/// A death is credited as a kill to the last player who damaged the victim,
+/// and as an assist to every other player who damaged them within
+/// [`ASSIST_WINDOW_SECS`].
+#[derive(Debug, Clone, Default)]
+pub struct PvpTracker {
+    /// Victim -> recent hits as (time, attacker), oldest first
+    recent_hits: HashMap<i64, Vec<(NaiveDateTime, i64)>>,
+    /// Taunted player -> (taunter, taunt expiry)
+    taunts: HashMap<i64, (i64, NaiveDateTime)>,
+    stats: HashMap<i64, PvpPlayerStats>,
+}
+
+impl PvpTracker {
+    pub fn process(&mut self, event: &CombatEvent) {
+        let source = &event.source_entity;
+        let target = &event.target_entity;
+
+        if event.effect.effect_id == effect_id::DEATH {
+            if target.entity_type == EntityType::Player {
+                self.record_death(target, event.timestamp);
+            }
+            return;
+        }
+
+        let player_vs_player = source.entity_type == EntityType::Player
+            && target.entity_type == EntityType::Player
+            && source.log_id != target.log_id;
+        if !player_vs_player {
+            return;
+        }
+
+        if event.effect.effect_id == effect_id::TAUNT {
+            self.player(source);
+            self.taunts.insert(
+                target.log_id,
+                (
+                    source.log_id,
+                    event.timestamp + Duration::seconds(TAUNT_DURATION_SECS),
+                ),
+            );
+            return;
+        }
+
+        if event.details.dmg_amount <= 0 {
+            return;
+        }
+        self.player(source);
+        let hits = self.recent_hits.entry(target.log_id).or_default();
+        hits.retain(|(at, _)| (event.timestamp - *at).num_seconds() <= ASSIST_WINDOW_SECS);
+        hits.push((event.timestamp, source.log_id));
+
+        // Damage from a taunted player to someone other than the taunter was
+        // reduced; credit the taunter with what was prevented
+        if let Some(&(taunter, expires)) = self.taunts.get(&source.log_id)
+            && event.timestamp <= expires
+            && taunter != target.log_id
+        {
+            let amount = event.details.dmg_amount as f64;
+            let prevented = amount / (1.0 - TAUNT_DAMAGE_REDUCTION) * TAUNT_DAMAGE_REDUCTION;
+            if let Some(stats) = self.stats.get_mut(&taunter) {
+                stats.taunt_mitigation += prevented.round() as i64;
+            }
+        }
+    }
+
+    fn record_death(&mut self, victim: &Entity, timestamp: NaiveDateTime) {
+        self.player(victim).deaths += 1;
+
+        let hits = self.recent_hits.remove(&victim.log_id).unwrap_or_default();
+        let mut attackers: Vec<i64> = hits
+            .iter()
+            .rev()
+            .filter(|(at, _)| (timestamp - *at).num_seconds() <= ASSIST_WINDOW_SECS)
+            .map(|(_, attacker)| *attacker)
+            .collect();
+        // Last attacker first, each attacker once
+        let mut seen = Vec::new();
+        attackers.retain(|id| {
+            let first = !seen.contains(id);
+            seen.push(*id);
+            first
+        });
+
+        let mut attackers = attackers.into_iter();
+        if let Some(killer) = attackers.next()
+            && let Some(stats) = self.stats.get_mut(&killer)
+        {
+            stats.kills += 1;
+        }
+        for assist in attackers {
+            if let Some(stats) = self.stats.get_mut(&assist) {
+                stats.assists += 1;
+            }
+        }
+    }
+
+    fn player(&mut self, entity: &Entity) -> &mut PvpPlayerStats {
+        self.stats
+            .entry(entity.log_id)
+            .or_insert_with(|| PvpPlayerStats {
+                entity_id: entity.log_id,
+                name: resolve(entity.name).to_string(),
+                ..Default::default()
+            })
+    }
+
+    /// Stats for every player seen, most kills first
+    pub fn snapshot(&self) -> Vec<PvpPlayerStats> {
+        let mut stats: Vec<_> = self.stats.values().cloned().collect();
+        stats.sort_by(|a, b| b.kills.cmp(&a.kills).then_with(|| a.name.cmp(&b.name)));
+        stats
+    }
+}
+
+/// Group PvP encounters into matches (one per visit to a PvP area) with
+/// scoreboards, splitting arena matches into rounds.
+pub fn build_pvp_matches(summaries: &[EncounterSummary]) -> Vec<PvpMatch> {
+    let mut matches: Vec<PvpMatch> = Vec::new();
+    let mut match_key = None;
+    let mut last_end: Option<NaiveDateTime> = None;
+
+    for summary in summaries {
+        if summary.encounter_type != PhaseType::PvP {
+            continue;
+        }
+        let key = (summary.area_entered_line, summary.area_name.clone());
+        if match_key.as_ref() != Some(&key) || summary.is_phase_start {
+            matches.push(PvpMatch {
+                area_name: summary.area_name.clone(),
+                is_arena: is_arena(&summary.area_name),
+                start_time: summary.start_time.clone(),
+                duration_seconds: 0,
+                encounter_ids: Vec::new(),
+                rounds: Vec::new(),
+                scoreboard: Vec::new(),
+            });
+            match_key = Some(key);
+            last_end = None;
+        }
+        let Some(pvp_match) = matches.last_mut() else {
+            continue;
+        };
+
+        pvp_match.duration_seconds += summary.duration_seconds;
+        pvp_match.encounter_ids.push(summary.encounter_id);
+        add_to_scoreboard(&mut pvp_match.scoreboard, summary);
+
+        if pvp_match.is_arena {
+            let start = parse_time(summary.start_time.as_deref());
+            let new_round = match (last_end, start) {
+                (Some(end), Some(start)) => (start - end).num_seconds() > ARENA_ROUND_GAP_SECS,
+                _ => true,
+            };
+            if new_round || pvp_match.rounds.is_empty() {
+                let number = pvp_match.rounds.len() as u32 + 1;
+                pvp_match.rounds.push(PvpRound {
+                    number,
+                    start_time: summary.start_time.clone(),
+                    duration_seconds: 0,
+                    scoreboard: Vec::new(),
+                });
+            }
+            if let Some(round) = pvp_match.rounds.last_mut() {
+                round.duration_seconds += summary.duration_seconds;
+                add_to_scoreboard(&mut round.scoreboard, summary);
+            }
+            last_end = parse_time(summary.end_time.as_deref()).or(start);
+        }
+    }
+
+    for pvp_match in &mut matches {
+        finish_scoreboard(&mut pvp_match.scoreboard);
+        for round in &mut pvp_match.rounds {
+            finish_scoreboard(&mut round.scoreboard);
+        }
+    }
+    matches
+}
+
+fn parse_time(time: Option<&str>) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(time?, "%Y-%m-%dT%H:%M:%S").ok()
+}
+
+fn scoreboard_entry<'a>(
+    scoreboard: &'a mut Vec<PvpScoreboardEntry>,
+    name: &str,
+) -> &'a mut PvpScoreboardEntry {
+    let idx = match scoreboard.iter().position(|e| e.name == name) {
+        Some(idx) => idx,
+        None => {
+            scoreboard.push(PvpScoreboardEntry {
+                name: name.to_string(),
+                ..Default::default()
+            });
+            scoreboard.len() - 1
+        }
+    };
+    &mut scoreboard[idx]
+}
+
+fn add_to_scoreboard(scoreboard: &mut Vec<PvpScoreboardEntry>, summary: &EncounterSummary) {
+    for metrics in &summary.player_metrics {
+        let entry = scoreboard_entry(scoreboard, &metrics.name);
+        entry.damage += metrics.total_damage;
+        entry.healing += metrics.total_healing;
+        entry.shielding += metrics.total_shielding;
+        if metrics.discipline_name.is_some() {
+            entry.discipline_name = metrics.discipline_name.clone();
+        }
+    }
+    for stats in &summary.pvp_stats {
+        let entry = scoreboard_entry(scoreboard, &stats.name);
+        entry.kills += stats.kills;
+        entry.deaths += stats.deaths;
+        entry.assists += stats.assists;
+        entry.taunt_mitigation += stats.taunt_mitigation;
+    }
+}
+
+fn finish_scoreboard(scoreboard: &mut [PvpScoreboardEntry]) {
+    for entry in scoreboard.iter_mut() {
+        entry.protection = entry.shielding + entry.taunt_mitigation;
+    }
+    scoreboard.sort_by(|a, b| b.damage.cmp(&a.damage).then_with(|| a.name.cmp(&b.name)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat_log::{Action, Details, Effect};
+    use crate::context::intern;
+    use crate::game_data::effect_type_id;
+    use chrono::NaiveDate;
+
+    fn at(secs: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 12, 10)
+            .unwrap()
+            .and_hms_opt(20, 0, 0)
+            .unwrap()
+            + Duration::seconds(secs)
+    }
+
+    fn player(id: i64, name: &str) -> Entity {
+        Entity {
+            name: intern(name),
+            log_id: id,
+            entity_type: EntityType::Player,
+            ..Default::default()
+        }
+    }
+
+    fn event(secs: i64, source: &Entity, target: &Entity, effect: i64, dmg: i32) -> CombatEvent {
+        CombatEvent {
+            line_number: 0,
+            timestamp: at(secs),
+            source_entity: source.clone(),
+            target_entity: target.clone(),
+            effect: Effect {
+                type_id: effect_type_id::APPLYEFFECT,
+                effect_id: effect,
+                ..Default::default()
+            },
+            details: Details {
+                dmg_amount: dmg,
+                ..Default::default()
+            },
+            action: Action::default(),
+        }
+    }
+
+    #[test]
+    fn test_kill_and_assist_attribution() {
+        let (tank, dps, stale, victim) = (
+            player(1, "Tank"),
+            player(2, "Dps"),
+            player(3, "Stale"),
+            player(4, "Victim"),
+        );
+        let mut tracker = PvpTracker::default();
+        tracker.process(&event(0, &stale, &victim, effect_id::DAMAGE, 1000));
+        tracker.process(&event(20, &tank, &victim, effect_id::DAMAGE, 1000));
+        tracker.process(&event(22, &dps, &victim, effect_id::DAMAGE, 5000));
+        tracker.process(&event(23, &victim, &victim, effect_id::DEATH, 0));
+
+        let stats: HashMap<_, _> = tracker
+            .snapshot()
+            .into_iter()
+            .map(|s| (s.name.clone(), s))
+            .collect();
+        assert_eq!(stats["Dps"].kills, 1);
+        assert_eq!(stats["Tank"].assists, 1);
+        assert_eq!(stats["Stale"].assists, 0);
+        assert_eq!(stats["Victim"].deaths, 1);
+    }
+
+    #[test]
+    fn test_taunt_mitigation_credits_taunter() {
+        let (tank, enemy, healer) = (player(1, "Tank"), player(2, "Enemy"), player(3, "Healer"));
+        let mut tracker = PvpTracker::default();
+        tracker.process(&event(0, &tank, &enemy, effect_id::TAUNT, 0));
+        // Reduced hit on the healer: 700 dealt, 300 prevented
+        tracker.process(&event(2, &enemy, &healer, effect_id::DAMAGE, 700));
+        // Hits on the taunter and after expiry don't count
+        tracker.process(&event(3, &enemy, &tank, effect_id::DAMAGE, 700));
+        tracker.process(&event(10, &enemy, &healer, effect_id::DAMAGE, 700));
+
+        let tank_stats = tracker
+            .snapshot()
+            .into_iter()
+            .find(|s| s.name == "Tank")
+            .unwrap();
+        assert_eq!(tank_stats.taunt_mitigation, 300);
+    }
+}
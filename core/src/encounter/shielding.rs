@@ -1,13 +1,15 @@
-//! Simple FIFO shield attribution.
+//! Capacity-aware FIFO shield attribution.
 //!
 //! The combat log's `dmg_absorbed` shows the TOTAL absorbed by all active shields.
-//! We use simple FIFO attribution: credit all absorbed damage to whoever applied
-//! the first (oldest) active shield.
+//! Shields absorb in application order (oldest first). Each limited shield has an
+//! estimated capacity (see `game_data::shield_absorbs`); once the oldest shield's
+//! estimate is used up, the rest of the absorption spills to the next one.
+//! Unlimited (percentage/charge based) shields take whatever reaches them.
 //!
 //! If no shields are active, we check for recently closed shields (500ms grace window)
 //! to handle timing edge cases.
 
-use super::CombatEncounter;
+use super::{CombatEncounter, EffectInstance};
 use crate::combat_log::CombatEvent;
 use crate::game_data::get_shield_info;
 use chrono::NaiveDateTime;
@@ -29,14 +31,39 @@ pub struct ShieldContext {
     pub estimated_max: i64,
 }
 
-/// Lightweight shield reference for attribution without borrowing issues
-struct ActiveShield {
-    source_id: i64,
+/// Split an absorbed amount across stacked shields in FIFO order.
+///
+/// `remaining` holds each shield's remaining estimated capacity (`None` for
+/// unlimited shields). Returns the amount credited to each shield. Absorption
+/// beyond every estimate goes to the newest shield, since the log shows it was
+/// still absorbing.
+pub fn split_absorption(absorbed: i64, remaining: &[Option<i64>]) -> Vec<i64> {
+    let mut credits = vec![0; remaining.len()];
+    let mut left = absorbed.max(0);
+    for (credit, capacity) in credits.iter_mut().zip(remaining) {
+        if left == 0 {
+            break;
+        }
+        let take = capacity.map_or(left, |c| c.clamp(0, left));
+        *credit = take;
+        left -= take;
+    }
+    if left > 0
+        && let Some(last) = credits.last_mut()
+    {
+        *last += left;
+    }
+    credits
+}
+
+/// Estimated capacity of a shield effect (`None` if unlimited/unknown).
+fn shield_capacity(effect_id: i64) -> Option<i64> {
+    get_shield_info(effect_id).and_then(|info| info.estimated_absorb())
 }
 
 impl CombatEncounter {
     /// Process a damage event that has absorption.
-    /// Credits all absorbed damage to the first (oldest) active shield using FIFO.
+    /// Splits absorbed damage across active shields by remaining capacity (FIFO).
     pub fn attribute_shield_absorption(&mut self, event: &CombatEvent) {
         let absorbed = event.details.dmg_absorbed as i64;
         if absorbed == 0 {
@@ -44,35 +71,53 @@ impl CombatEncounter {
         }
 
         let target_id = event.target_entity.log_id;
+        let credits = self.consume_active_shields(target_id, absorbed, event.timestamp);
 
-        // Find the first active shield (FIFO - oldest applied)
-        if let Some(first_shield) = self.get_first_active_shield(target_id, event.timestamp) {
-            self.credit_shielding(first_shield.source_id, absorbed);
-        } else {
+        if credits.is_empty() {
             // No active shields - try recently closed (grace window)
             self.credit_recently_closed_shield(target_id, absorbed, event.timestamp);
         }
+        for (source_id, amount) in credits {
+            self.credit_shielding(source_id, amount);
+        }
     }
 
-    /// Get the first (oldest) active shield for a target
-    fn get_first_active_shield(
-        &self,
+    /// Draw absorption from a target's active shields, oldest first, updating each
+    /// shield's consumed capacity. Returns (source_id, amount) credits.
+    fn consume_active_shields(
+        &mut self,
         target_id: i64,
+        absorbed: i64,
         timestamp: NaiveDateTime,
-    ) -> Option<ActiveShield> {
-        let effects = self.effects.get(&target_id)?;
+    ) -> Vec<(i64, i64)> {
+        let Some(effects) = self.effects.get_mut(&target_id) else {
+            return Vec::new();
+        };
 
-        effects
-            .iter()
+        let mut active: Vec<&mut EffectInstance> = effects
+            .iter_mut()
             .filter(|e| {
                 e.is_shield
                     && e.applied_at < timestamp
-                    && e.removed_at.map_or(true, |r| r >= timestamp)
+                    && e.removed_at.is_none_or(|r| r >= timestamp)
             })
-            .min_by_key(|e| e.applied_at)
-            .map(|e| ActiveShield {
-                source_id: e.source_id,
+            .collect();
+        active.sort_by_key(|e| e.applied_at);
+
+        let remaining: Vec<Option<i64>> = active
+            .iter()
+            .map(|e| shield_capacity(e.effect_id).map(|max| max - e.absorbed))
+            .collect();
+
+        split_absorption(absorbed, &remaining)
+            .into_iter()
+            .zip(active)
+            .filter(|(amount, _)| *amount > 0)
+            .map(|(amount, shield)| {
+                shield.absorbed += amount;
+                (shield.source_id, amount)
             })
+            .collect()
     }
 
     /// Credit absorption to a recently closed shield within the grace window
@@ -171,9 +216,7 @@ impl CombatEncounter {
             .into_iter()
             .enumerate()
             .map(|(idx, e)| {
-                let estimated_max = get_shield_info(e.effect_id)
-                    .and_then(|info| info.estimated_absorb())
-                    .unwrap_or(0);
+                let estimated_max = shield_capacity(e.effect_id).unwrap_or(0);
                 ShieldContext {
                     effect_id: e.effect_id,
                     source_id: e.source_id,
@@ -184,3 +227,29 @@ impl CombatEncounter {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_shield_takes_everything() {
+        assert_eq!(split_absorption(500, &[Some(100)]), vec![500]);
+        assert_eq!(split_absorption(500, &[None]), vec![500]);
+    }
+
+    #[test]
+    fn test_spills_to_next_shield_when_capacity_used() {
+        assert_eq!(split_absorption(500, &[Some(300), Some(1000)]), vec![300, 200]);
+        // Exhausted first shield passes everything on
+        assert_eq!(split_absorption(500, &[Some(0), Some(1000)]), vec![0, 500]);
+        // Unlimited shields stop the spill
+        assert_eq!(split_absorption(500, &[None, Some(1000)]), vec![500, 0]);
+    }
+
+    #[test]
+    fn test_overflow_beyond_estimates_goes_to_newest() {
+        assert_eq!(split_absorption(500, &[Some(100), Some(100)]), vec![100, 400]);
+        assert!(split_absorption(500, &[]).is_empty());
+    }
+}
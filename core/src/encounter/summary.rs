@@ -10,6 +10,7 @@ use super::CombatEncounter;
 use super::PhaseType;
 use super::entity_info::PlayerInfo;
 use super::metrics::PlayerMetrics;
+use super::pvp::PvpPlayerStats;
 use crate::combat_log::EntityType;
 use crate::context::resolve;
 use crate::debug_log;
@@ -72,6 +73,11 @@ pub struct EncounterSummary {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub challenges: Vec<ChallengeSummary>,
 
+    // ─── PvP ─────────────────────────────────────────────────────────────────
+    /// Kill/death/assist and taunt numbers (empty outside PvP)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pvp_stats: Vec<PvpPlayerStats>,
+
     // ─── Parsely Integration ─────────────────────────────────────────────────
     /// Link to the uploaded encounter on Parsely (set after successful upload)
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -84,6 +90,12 @@ pub struct EncounterHistory {
     summaries: Vec<EncounterSummary>,
     boss_pull_counts: HashMap<String, u32>,
     trash_pull_count: u32,
+    /// PvP matches so far (kept across areas, separate from PvE pull counts)
+    pvp_match_count: u32,
+    /// Fights in the current PvP match
+    pvp_fight_count: u32,
+    /// AreaEntered line of the current PvP match
+    pvp_match_line: Option<u64>,
     /// Generation counter from AreaInfo, used to detect phase boundaries
     /// (including re-entering the same area).
     current_generation: Option<u64>,
@@ -116,6 +128,9 @@ impl EncounterHistory {
         self.summaries.clear();
         self.boss_pull_counts.clear();
         self.trash_pull_count = 0;
+        self.pvp_match_count = 0;
+        self.pvp_fight_count = 0;
+        self.pvp_match_line = None;
         self.current_generation = None;
     }
 
@@ -149,6 +164,7 @@ impl EncounterHistory {
     pub fn rebuild_pull_counts(&mut self) {
         self.trash_pull_count = 0;
         self.boss_pull_counts.clear();
+        self.rebuild_pvp_counts();
         
         // Find the index of the last phase start (area change)
         let last_phase_start_idx = self.summaries.iter()
@@ -165,6 +181,9 @@ impl EncounterHistory {
         };
         
         for summary in encounters_to_count {
+            if summary.encounter_type == PhaseType::PvP {
+                continue;
+            }
             if let Some(ref boss_name) = summary.boss_name {
                 // Extract count from "Boss Name - N" format
                 if let Some(count_str) = summary.display_name.rsplit(" - ").next() {
@@ -184,9 +203,61 @@ impl EncounterHistory {
         }
     }
 
+    /// PvP counts span the whole session, so they're rebuilt from every
+    /// PvP encounter ("PvP Match 2 - 3" = third fight of the second match).
+    fn rebuild_pvp_counts(&mut self) {
+        self.pvp_match_count = 0;
+        self.pvp_fight_count = 0;
+        self.pvp_match_line = None;
+
+        let Some(last) = self
+            .summaries
+            .iter()
+            .rev()
+            .find(|s| s.encounter_type == PhaseType::PvP)
+        else {
+            return;
+        };
+        if let Some((match_no, fight_no)) = last
+            .display_name
+            .strip_prefix("PvP Match ")
+            .and_then(|rest| rest.split_once(" - "))
+        {
+            self.pvp_match_count = match_no.parse().unwrap_or(0);
+            self.pvp_fight_count = fight_no.parse().unwrap_or(0);
+            self.pvp_match_line = last.area_entered_line;
+        }
+    }
+
+    /// Name a PvP fight. A new match starts on each visit to a PvP area
+    /// (a different AreaEntered line). PvP doesn't use boss names or PvE pull counts.
+    pub fn generate_pvp_name(&mut self, area_entered_line: Option<u64>) -> String {
+        if self.pvp_match_count == 0 || self.pvp_match_line != area_entered_line {
+            self.pvp_match_count += 1;
+            self.pvp_fight_count = 0;
+            self.pvp_match_line = area_entered_line;
+        }
+        self.pvp_fight_count += 1;
+        format!("PvP Match {} - {}", self.pvp_match_count, self.pvp_fight_count)
+    }
+
+    /// Peek the name the next PvP fight would get without incrementing.
+    pub fn peek_pvp_name(&self, area_entered_line: Option<u64>) -> String {
+        if self.pvp_match_count == 0 || self.pvp_match_line != area_entered_line {
+            format!("PvP Match {} - 1", self.pvp_match_count + 1)
+        } else {
+            format!("PvP Match {} - {}", self.pvp_match_count, self.pvp_fight_count + 1)
+        }
+    }
+
     /// Generate a human-readable name for an encounter based on its type and boss
     pub fn generate_name(&mut self, encounter_type: PhaseType, boss_name: Option<&str>) -> String {
         match (encounter_type, boss_name) {
+            // PvP fight: continues the current match
+            (PhaseType::PvP, _) => {
+                let line = self.pvp_match_line;
+                self.generate_pvp_name(line)
+            }
             // Boss encounter: "Brontes - 7"
             (_, Some(name)) => {
                 let count = self.boss_pull_counts.entry(name.to_string()).or_insert(0);
@@ -205,10 +276,6 @@ impl EncounterHistory {
                 self.trash_pull_count += 1;
                 format!("Dummy Parse {}", self.trash_pull_count)
             }
-            (PhaseType::PvP, None) => {
-                self.trash_pull_count += 1;
-                format!("PvP Match {}", self.trash_pull_count)
-            }
             (PhaseType::OpenWorld, None) => {
                 self.trash_pull_count += 1;
                 format!("Open World {}", self.trash_pull_count)
@@ -385,19 +452,28 @@ pub fn create_encounter_summary(
 
     // Get boss name: prefer active definition, fall back to detected boss NPC
     // This allows non-boss trigger entities to classify the encounter
-    let boss_name = encounter
-        .active_boss_definition()
-        .map(|def| def.name.clone())
-        .or_else(|| {
-            // Only fall back to hardcoded data if a boss NPC was actually seen
-            if encounter.npcs.values().any(|v| v.is_boss) {
-                boss_info.map(|b| b.boss.to_string())
-            } else {
-                None
-            }
-        });
+    // PvP fights are never named after bosses
+    let boss_name = if encounter_type == PhaseType::PvP {
+        None
+    } else {
+        encounter
+            .active_boss_definition()
+            .map(|def| def.name.clone())
+            .or_else(|| {
+                // Only fall back to hardcoded data if a boss NPC was actually seen
+                if encounter.npcs.values().any(|v| v.is_boss) {
+                    boss_info.map(|b| b.boss.to_string())
+                } else {
+                    None
+                }
+            })
+    };
 
-    let display_name = history.generate_name(encounter_type, boss_name.as_deref());
+    let display_name = if encounter_type == PhaseType::PvP {
+        history.generate_pvp_name(encounter.area_entered_line.or(area.entered_at_line))
+    } else {
+        history.generate_name(encounter_type, boss_name.as_deref())
+    };
 
     // Calculate metrics and filter to players seen during actual combat
     let combat_start = encounter.enter_combat_time;
@@ -520,6 +596,11 @@ pub fn create_encounter_summary(
         is_phase_start,
         npc_names,
         challenges,
+        pvp_stats: if encounter_type == PhaseType::PvP {
+            encounter.pvp.snapshot()
+        } else {
+            Vec::new()
+        },
         // Line number tracking for per-encounter Parsely uploads
         // Use encounter's area_entered_line (set when combat started) instead of cache's current area
         // This ensures we get the correct AreaEntered line even if player exits to a different area
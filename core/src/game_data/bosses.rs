@@ -178,6 +178,20 @@ pub fn is_boss(entity_id: i64) -> bool {
     BOSS_LOOKUP.contains_key(&entity_id)
 }
 
+/// Check if an entity ID is a training dummy
+pub fn is_training_dummy(entity_id: i64) -> bool {
+    lookup_boss(entity_id).is_some_and(|info| info.content_type == ContentType::TrainingDummy)
+}
+
+/// Entity IDs of all known training dummies
+pub fn training_dummy_ids() -> Vec<i64> {
+    BOSS_LOOKUP
+        .iter()
+        .filter(|(_, info)| info.content_type == ContentType::TrainingDummy)
+        .map(|(id, _)| *id)
+        .collect()
+}
+
 /// Get all boss IDs for a specific operation and boss name
 pub fn get_boss_ids(operation: &str, boss: &str) -> Vec<i64> {
     BOSS_LOOKUP
@@ -5,24 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
-/// Character role in group content
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Role {
-    Tank,
-    Healer,
-    Dps,
-}
-
-impl Role {
-    /// Get the icon filename for this role (without path)
-    pub const fn icon_name(&self) -> &'static str {
-        match self {
-            Role::Tank => "icon_tank.png",
-            Role::Healer => "icon_heal.png",
-            Role::Dps => "icon_dps.png",
-        }
-    }
-}
+// Shared with the config types (profile rules, overlay visibility)
+pub use baras_types::Role;
 
 /// SWTOR base classes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -0,0 +1,265 @@
+//! Global cooldown math and per-player alacrity/latency inference.
+//!
+//! The combat log doesn't record alacrity or latency, but they show up in how
+//! closely a player's on-GCD activations follow each other. Back-to-back casts
+//! land one GCD plus some network delay apart and can never be faster than the
+//! GCD, so the fastest spacings tell us which GCD tier the player is on (1.5s,
+//! 1.4s, 1.3s...) and the typical spacing above that tier is their latency.
+//! The game rounds the GCD up to the next tenth of a second, so only the tier is
+//! observable; we report the minimum alacrity for that tier.
+//!
+//! Until enough casts are seen, the manually configured alacrity and latency
+//! are used instead (see [`GcdEstimate::from_settings`]).
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::NaiveDateTime;
+
+use super::OFF_GCD_ABILITIES;
+
+/// Global cooldown with no alacrity.
+pub const BASE_GCD_SECS: f32 = 1.5;
+
+/// Fastest GCD tier we'll report (~25% alacrity).
+const MIN_GCD_SECS: f32 = 1.2;
+
+/// GCD tiers from slowest to fastest.
+const GCD_TIERS: [f32; 4] = [BASE_GCD_SECS, 1.4, 1.3, MIN_GCD_SECS];
+
+/// Spacings outside this window are not back-to-back GCDs (weaves, pauses).
+const MIN_SPACING_SECS: f32 = 1.0;
+const MAX_SPACING_SECS: f32 = 1.8;
+
+/// Back-to-back spacings needed before we trust an estimate.
+const MIN_SAMPLES: usize = 10;
+
+/// Rolling window of spacings kept per player.
+const MAX_SAMPLES: usize = 200;
+
+/// Latency estimates above this are treated as noise (player hesitation).
+const MAX_LATENCY_MS: f32 = 250.0;
+
+/// Tolerance for float noise when comparing spacings against a tier.
+const TIER_EPSILON_SECS: f32 = 0.005;
+
+/// GCD length for the given alacrity percentage (unrounded).
+pub fn gcd_secs(alacrity_percent: f32) -> f32 {
+    BASE_GCD_SECS / (1.0 + alacrity_percent.max(0.0) / 100.0)
+}
+
+/// Inferred GCD tier, alacrity and latency for one player.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GcdEstimate {
+    /// GCD tier in seconds (1.5, 1.4, 1.3, ...)
+    pub gcd_secs: f32,
+    /// Minimum alacrity that reaches this tier
+    pub alacrity_percent: f32,
+    /// Typical delay between the GCD coming up and the next activation
+    pub latency_ms: u16,
+    /// Number of back-to-back spacings the estimate is based on
+    pub samples: usize,
+}
+
+impl GcdEstimate {
+    /// Manually configured alacrity and latency, used until enough casts are seen.
+    pub fn from_settings(alacrity_percent: f32, latency_ms: u16) -> Self {
+        let alacrity_percent = alacrity_percent.max(0.0);
+        // The game rounds the GCD up to the next tenth
+        let tier = ((gcd_secs(alacrity_percent) - TIER_EPSILON_SECS) * 10.0).ceil() / 10.0;
+        Self {
+            gcd_secs: tier.clamp(MIN_GCD_SECS, BASE_GCD_SECS),
+            alacrity_percent,
+            latency_ms,
+            samples: 0,
+        }
+    }
+}
+
+impl GcdEstimate {
+    /// Duration of an alacrity-affected effect or cooldown: sped up by alacrity,
+    /// plus latency since the player only reacts once it's up.
+    pub fn adjust_duration(&self, base_secs: f32) -> f32 {
+        base_secs / (1.0 + self.alacrity_percent / 100.0) + self.latency_ms as f32 / 1000.0
+    }
+}
+
+impl Default for GcdEstimate {
+    fn default() -> Self {
+        Self::from_settings(0.0, 0)
+    }
+}
+
+/// Estimate GCD tier and latency from gaps between consecutive on-GCD activations.
+///
+/// Returns `None` until there are enough back-to-back casts to be meaningful.
+pub fn estimate_from_spacings(spacings: impl IntoIterator<Item = f32>) -> Option<GcdEstimate> {
+    let mut candidates: Vec<f32> = spacings
+        .into_iter()
+        .filter(|s| (MIN_SPACING_SECS..=MAX_SPACING_SECS).contains(s))
+        .collect();
+    if candidates.len() < MIN_SAMPLES {
+        return None;
+    }
+    candidates.sort_by(f32::total_cmp);
+
+    // Typical back-to-back spacing: one GCD plus latency
+    let cadence = candidates[candidates.len() / 2];
+    // Casts can't come faster than the GCD, so the fastest spacings (skipping
+    // the odd mis-ordered timestamp) bound the tier from above
+    let fastest = candidates[candidates.len() / 20];
+
+    // Slowest tier at or below the fastest spacing; everything above it is delay
+    let tier = GCD_TIERS
+        .into_iter()
+        .find(|tier| *tier <= fastest + TIER_EPSILON_SECS)
+        .unwrap_or(MIN_GCD_SECS);
+
+    // Typical delays beyond MAX_LATENCY_MS are hesitation, not network latency
+    let latency_ms = ((cadence - tier) * 1000.0).clamp(0.0, MAX_LATENCY_MS);
+
+    Some(GcdEstimate {
+        gcd_secs: tier,
+        alacrity_percent: (BASE_GCD_SECS / tier - 1.0) * 100.0,
+        latency_ms: latency_ms.round() as u16,
+        samples: candidates.len(),
+    })
+}
+
+/// Estimate from a sorted list of on-GCD activation times (seconds).
+pub fn estimate_from_activation_times(times: &[f32]) -> Option<GcdEstimate> {
+    estimate_from_spacings(times.windows(2).map(|w| w[1] - w[0]))
+}
+
+/// Spacing samples for one player.
+#[derive(Debug, Default)]
+struct PlayerCadence {
+    last_activation: Option<NaiveDateTime>,
+    spacings: VecDeque<f32>,
+}
+
+/// Live per-player GCD estimator fed with ability activations.
+#[derive(Debug, Default)]
+pub struct GcdEstimator {
+    players: HashMap<i64, PlayerCadence>,
+}
+
+impl GcdEstimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an ability activation by a player. Off-GCD abilities are ignored.
+    pub fn record_activation(&mut self, player_id: i64, ability_id: i64, timestamp: NaiveDateTime) {
+        if OFF_GCD_ABILITIES.contains(&ability_id) {
+            return;
+        }
+        let cadence = self.players.entry(player_id).or_default();
+        if let Some(last) = cadence.last_activation {
+            let spacing = (timestamp - last).num_milliseconds() as f32 / 1000.0;
+            if (MIN_SPACING_SECS..=MAX_SPACING_SECS).contains(&spacing) {
+                if cadence.spacings.len() == MAX_SAMPLES {
+                    cadence.spacings.pop_front();
+                }
+                cadence.spacings.push_back(spacing);
+            }
+        }
+        cadence.last_activation = Some(timestamp);
+    }
+
+    /// Current estimate for a player, if enough casts have been seen.
+    pub fn estimate(&self, player_id: i64) -> Option<GcdEstimate> {
+        let cadence = self.players.get(&player_id)?;
+        estimate_from_spacings(cadence.spacings.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn times(gcd: f32, delay: f32, count: usize) -> Vec<f32> {
+        (0..count).map(|i| i as f32 * (gcd + delay)).collect()
+    }
+
+    #[test]
+    fn test_needs_enough_samples() {
+        assert_eq!(estimate_from_activation_times(&times(1.5, 0.0, 5)), None);
+    }
+
+    #[test]
+    fn test_detects_gcd_tiers() {
+        let base = estimate_from_activation_times(&times(1.5, 0.03, 30)).unwrap();
+        assert_eq!(base.gcd_secs, 1.5);
+        assert!(base.alacrity_percent.abs() < 0.01);
+        assert_eq!(base.latency_ms, 30);
+
+        let mid = estimate_from_activation_times(&times(1.4, 0.06, 30)).unwrap();
+        assert_eq!(mid.gcd_secs, 1.4);
+        assert!((mid.alacrity_percent - 7.14).abs() < 0.01);
+        assert_eq!(mid.latency_ms, 60);
+
+        let fast = estimate_from_activation_times(&times(1.3, 0.0, 30)).unwrap();
+        assert_eq!(fast.gcd_secs, 1.3);
+        assert!((fast.alacrity_percent - 15.38).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_ignores_pauses_between_casts() {
+        let mut t = times(1.4, 0.02, 20);
+        // Movement gaps and a weave-sized spacing shouldn't move the estimate
+        let last = *t.last().unwrap();
+        t.extend([last + 4.0, last + 4.3, last + 9.0]);
+        let est = estimate_from_activation_times(&t).unwrap();
+        assert_eq!(est.gcd_secs, 1.4);
+    }
+
+    #[test]
+    fn test_live_estimator_tracks_players_separately() {
+        let start =
+            NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mut estimator = GcdEstimator::new();
+        for i in 0..20 {
+            let fast = start + chrono::Duration::milliseconds(i * 1320);
+            let slow = start + chrono::Duration::milliseconds(i * 1540);
+            estimator.record_activation(1, 100, fast);
+            estimator.record_activation(2, 100, slow);
+        }
+        assert_eq!(estimator.estimate(1).unwrap().gcd_secs, 1.3);
+        assert_eq!(estimator.estimate(2).unwrap().gcd_secs, 1.5);
+        assert_eq!(estimator.estimate(3), None);
+    }
+
+    #[test]
+    fn test_hasted_player_with_high_latency_keeps_their_tier() {
+        // 1.4s GCD with 60-180ms of jittery delay (typically ~120ms): most
+        // spacings are above 1.5s, but the fastest ones rule out the 1.5s tier
+        let delays = [0.06, 0.09, 0.12, 0.15, 0.18];
+        let mut t = vec![0.0f32];
+        for i in 0..40 {
+            t.push(t[i] + 1.4 + delays[i % delays.len()]);
+        }
+        let est = estimate_from_activation_times(&t).unwrap();
+        assert_eq!(est.gcd_secs, 1.4);
+        assert!((est.alacrity_percent - 7.14).abs() < 0.01);
+        assert!((100..=140).contains(&est.latency_ms), "{}", est.latency_ms);
+    }
+
+    #[test]
+    fn test_hesitant_player_latency_is_capped() {
+        // A handful of tight 1.3s casts, otherwise ~400ms between GCDs
+        let mut t = times(1.31, 0.0, 7);
+        let last = *t.last().unwrap();
+        t.extend((1..=24).map(|i| last + i as f32 * 1.7));
+        let est = estimate_from_activation_times(&t).unwrap();
+        assert_eq!(est.gcd_secs, 1.3);
+        assert_eq!(est.latency_ms, 250);
+    }
+
+    #[test]
+    fn test_settings_fallback_rounds_gcd_up_to_the_tier() {
+        assert_eq!(GcdEstimate::from_settings(0.0, 0).gcd_secs, 1.5);
+        assert_eq!(GcdEstimate::from_settings(7.5, 80).gcd_secs, 1.4);
+        assert_eq!(GcdEstimate::from_settings(15.4, 0).gcd_secs, 1.3);
+        assert_eq!(GcdEstimate::from_settings(3.0, 0).gcd_secs, 1.5);
+    }
+}
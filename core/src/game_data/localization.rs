@@ -0,0 +1,458 @@
+//! Localized game names
+//!
+//! SWTOR writes names in the client's language, but every ability, effect, NPC,
+//! area and event type also carries a language-independent game ID (GUID).
+//! Name tables map those IDs to names per [`GameLanguage`] and are used to:
+//!
+//! - detect the client language of a log from its event type names
+//! - normalize names to English by ID at ingest, so name-based selectors and
+//!   lookups written against the English client keep working for any client
+//! - show names in the user's chosen language
+//!
+//! The English table is built from the game data shipped with the app (ability
+//! names, operation and flashpoint areas, event types) plus the NPC rosters of
+//! the loaded encounter definitions. Localized tables are learned from the
+//! user's own logs and stored in `names/<code>.toml` in the user's definitions
+//! directory (see [`save_observed_names`]), which also overrides bundled entries.
+
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, btree_map};
+use std::path::Path;
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+
+use super::flashpoints::FLASHPOINT_AREAS;
+use super::raids::OPERATION_AREAS;
+use crate::context::{IStr, intern, resolve};
+
+pub use baras_types::GameLanguage;
+
+include!(concat!(env!("OUT_DIR"), "/ability_names.rs"));
+
+const BUNDLED_TABLES: &[(GameLanguage, &str)] = &[
+    (
+        GameLanguage::English,
+        include_str!("../../definitions/names/en.toml"),
+    ),
+    (
+        GameLanguage::German,
+        include_str!("../../definitions/names/de.toml"),
+    ),
+    (
+        GameLanguage::French,
+        include_str!("../../definitions/names/fr.toml"),
+    ),
+];
+
+/// On-disk name table (`[names]` with GUIDs as keys)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NameTableFile {
+    #[serde(default)]
+    names: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct NameTable {
+    names: HashMap<i64, IStr>,
+    /// Lowercased name -> IDs (different abilities can share a name)
+    by_name: HashMap<String, Vec<i64>>,
+}
+
+impl NameTable {
+    fn insert(&mut self, id: i64, name: IStr) {
+        if let Some(old) = self.names.insert(id, name)
+            && let Some(ids) = self.by_name.get_mut(&resolve(old).to_lowercase())
+        {
+            ids.retain(|i| *i != id);
+        }
+        self.by_name
+            .entry(resolve(name).to_lowercase())
+            .or_default()
+            .push(id);
+    }
+
+    fn merge_toml(&mut self, contents: &str) -> Result<usize, String> {
+        let file: NameTableFile = toml::from_str(contents).map_err(|e| e.to_string())?;
+        let mut count = 0;
+        for (key, name) in file.names {
+            if let Ok(id) = key.trim().parse::<i64>()
+                && !name.is_empty()
+            {
+                self.insert(id, intern(&name));
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// English names from the bundled game data
+    fn english_game_data() -> Self {
+        let mut table = Self::default();
+        for (id, name) in ENGLISH_ABILITY_NAMES.entries() {
+            table.insert(*id, intern(name));
+        }
+        for (id, info) in OPERATION_AREAS {
+            table.insert(*id, intern(info.log_name));
+        }
+        for (id, info) in FLASHPOINT_AREAS {
+            table.insert(*id, intern(info.log_name));
+        }
+        table
+    }
+}
+
+static NAME_TABLES: LazyLock<RwLock<HashMap<GameLanguage, Arc<NameTable>>>> = LazyLock::new(|| {
+    let mut tables = HashMap::new();
+    for (lang, contents) in BUNDLED_TABLES {
+        let mut table = match lang {
+            GameLanguage::English => NameTable::english_game_data(),
+            _ => NameTable::default(),
+        };
+        if let Err(e) = table.merge_toml(contents) {
+            tracing::error!(language = lang.code(), error = %e, "Invalid bundled name table");
+        }
+        tables.insert(*lang, Arc::new(table));
+    }
+    RwLock::new(tables)
+});
+
+/// Serializes writes to the user name tables
+static SAVE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Merge user name tables (`<dir>/<code>.toml`) over the bundled ones.
+pub fn load_user_name_tables(dir: &Path) {
+    let Ok(mut tables) = NAME_TABLES.write() else {
+        return;
+    };
+    for lang in GameLanguage::all() {
+        let path = dir.join(format!("{}.toml", lang.code()));
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let table = Arc::make_mut(tables.entry(*lang).or_default());
+        match table.merge_toml(&contents) {
+            Ok(count) => tracing::debug!(language = lang.code(), count, "Loaded user name table"),
+            Err(e) => tracing::warn!(path = %path.display(), error = %e, "Invalid name table"),
+        }
+    }
+}
+
+/// Names first seen in a log, waiting to be saved to the user name table
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservedNames {
+    pub language: GameLanguage,
+    pub names: BTreeMap<i64, IStr>,
+}
+
+/// Add names observed in a log to the shared tables and to the user name
+/// table (`<dir>/<code>.toml`). Returns how many names were new to the file.
+///
+/// Only the app saves observed names (parse workers only read the tables), and
+/// saves within it are serialized. The file is written to a temp file and
+/// renamed so a crash can't leave a truncated table behind.
+pub fn save_observed_names(dir: &Path, observed: ObservedNames) -> Result<usize, String> {
+    let _guard = SAVE_LOCK.lock().map_err(|e| e.to_string())?;
+
+    // Share with logs resolved after this one
+    {
+        let mut tables = NAME_TABLES.write().map_err(|e| e.to_string())?;
+        let table = Arc::make_mut(tables.entry(observed.language).or_default());
+        for (id, name) in &observed.names {
+            if !table.names.contains_key(id) {
+                table.insert(*id, *name);
+            }
+        }
+    }
+
+    let path = dir.join(format!("{}.toml", observed.language.code()));
+    let mut file: NameTableFile = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|c| toml::from_str(&c).ok())
+        .unwrap_or_default();
+    let mut written = 0;
+    for (id, name) in observed.names {
+        if let btree_map::Entry::Vacant(entry) = file.names.entry(id.to_string()) {
+            entry.insert(resolve(name).to_string());
+            written += 1;
+        }
+    }
+    if written == 0 {
+        return Ok(0);
+    }
+
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let contents = toml::to_string(&file).map_err(|e| e.to_string())?;
+    let tmp = path.with_extension("toml.tmp");
+    std::fs::write(&tmp, contents).map_err(|e| e.to_string())?;
+    std::fs::rename(&tmp, &path).map_err(|e| e.to_string())?;
+    Ok(written)
+}
+
+/// Name of a game ID in the given language, if known
+pub fn localized_name(id: i64, lang: GameLanguage) -> Option<&'static str> {
+    let tables = NAME_TABLES.read().ok()?;
+    tables
+        .get(&lang)
+        .and_then(|t| t.names.get(&id))
+        .map(|name| resolve(*name))
+}
+
+/// Name of a game ID in the given language, falling back to `name` if unknown
+pub fn display_name(id: i64, name: &str, lang: GameLanguage) -> String {
+    localized_name(id, lang).unwrap_or(name).to_string()
+}
+
+/// Game IDs with the given name in any client language (case-insensitive)
+pub fn ids_for_name(name: &str) -> Vec<i64> {
+    let Ok(tables) = NAME_TABLES.read() else {
+        return Vec::new();
+    };
+    let key = name.to_lowercase();
+    let mut ids: Vec<i64> = tables
+        .values()
+        .filter_map(|t| t.by_name.get(&key))
+        .flatten()
+        .copied()
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// Name tables resolved for one log
+///
+/// Snapshots the shared tables when the client language is detected, so
+/// normalizing events takes no locks. Names the log's own table doesn't know
+/// yet are collected until taken with [`LogNames::take_observed`].
+#[derive(Debug, Clone)]
+pub struct LogNames {
+    /// `None` for a non-English client whose language isn't known
+    language: Option<GameLanguage>,
+    english: Arc<NameTable>,
+    own: Option<Arc<NameTable>>,
+    /// English names of roster NPCs from the loaded encounter definitions
+    entities: HashMap<i64, IStr>,
+    observed: BTreeMap<i64, IStr>,
+    seen: HashSet<i64>,
+}
+
+impl LogNames {
+    /// Detect the client language from an event type name (e.g. `AreaEntered`
+    /// for the English client, `GebietBetreten` for the German one). A log
+    /// whose type names differ from English but match no table is only
+    /// normalized, unless the user `declared` their client's language; its
+    /// names aren't recorded, so a guess can't end up in another language's
+    /// table. Returns `None` while the event type isn't known in any table.
+    pub fn detect(type_id: i64, type_name: &str, declared: Option<GameLanguage>) -> Option<Self> {
+        if type_id == 0 {
+            return None;
+        }
+        let tables = NAME_TABLES.read().ok()?;
+        let english = tables
+            .get(&GameLanguage::English)
+            .cloned()
+            .unwrap_or_default();
+        let matching = GameLanguage::all().iter().copied().find(|lang| {
+            tables
+                .get(lang)
+                .and_then(|t| t.names.get(&type_id))
+                .is_some_and(|name| resolve(*name) == type_name)
+        });
+        let language = match matching {
+            Some(lang) => Some(lang),
+            None if english.names.contains_key(&type_id) => {
+                declared.filter(|lang| *lang != GameLanguage::English)
+            }
+            None => return None,
+        };
+
+        Some(Self {
+            language,
+            own: language.and_then(|lang| tables.get(&lang).cloned()),
+            english,
+            entities: HashMap::new(),
+            observed: BTreeMap::new(),
+            seen: HashSet::new(),
+        })
+    }
+
+    /// Client language of the log, `None` if it isn't English and unrecognized
+    pub fn language(&self) -> Option<GameLanguage> {
+        self.language
+    }
+
+    /// Add English NPC names (e.g. from encounter definition rosters)
+    pub fn register_entity_names<'a>(&mut self, names: impl IntoIterator<Item = (i64, &'a str)>) {
+        for (id, name) in names {
+            self.entities.insert(id, intern(name));
+        }
+    }
+
+    /// Record names from the log and rewrite them to English where the English
+    /// name of their ID is known. Each entry is a (game ID, name) pair; entries
+    /// with ID 0 or an empty name are ignored.
+    pub fn normalize(&mut self, entries: &mut [(i64, &mut IStr)]) {
+        for (id, name) in entries.iter_mut() {
+            if *id == 0 || resolve(**name).is_empty() {
+                continue;
+            }
+            if self.language.is_some()
+                && !self.own.as_ref().is_some_and(|t| t.names.contains_key(id))
+                && self.seen.insert(*id)
+            {
+                self.observed.insert(*id, **name);
+            }
+            if self.language != Some(GameLanguage::English)
+                && let Some(english) = self.english.names.get(id).or(self.entities.get(id))
+            {
+                **name = *english;
+            }
+        }
+    }
+
+    /// Take the names observed since the last call
+    pub fn take_observed(&mut self) -> Option<ObservedNames> {
+        let language = self.language?;
+        if self.observed.is_empty() {
+            return None;
+        }
+        Some(ObservedNames {
+            language,
+            names: std::mem::take(&mut self.observed),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_data::effect_type_id;
+
+    const FLEET: i64 = 137438989504;
+    /// "Cleave" in the bundled ability data
+    const CLEAVE: i64 = 2748083284738048;
+
+    #[test]
+    fn test_detect_language_from_event_type() {
+        let detect = |name: &str, declared: Option<GameLanguage>| {
+            LogNames::detect(effect_type_id::AREAENTERED, name, declared).map(|n| n.language())
+        };
+        assert_eq!(
+            detect("AreaEntered", Some(GameLanguage::German)),
+            Some(Some(GameLanguage::English))
+        );
+        assert_eq!(
+            detect("GebietBetreten", None),
+            Some(Some(GameLanguage::German))
+        );
+
+        // Unrecognized localized type names are only normalized...
+        assert_eq!(detect("???", None), Some(None));
+        assert_eq!(detect("???", Some(GameLanguage::English)), Some(None));
+        // ...unless the user declared their client's language
+        assert_eq!(
+            detect("???", Some(GameLanguage::French)),
+            Some(Some(GameLanguage::French))
+        );
+
+        assert!(LogNames::detect(0, "", None).is_none());
+    }
+
+    #[test]
+    fn test_unrecognized_logs_are_not_saved() {
+        let dir = std::env::temp_dir().join(format!("baras-names-fr-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // A French log read by a user who shows names in German
+        let mut names =
+            LogNames::detect(effect_type_id::AREAENTERED, "ZoneEntrée (test)", None).unwrap();
+        let mut ability = intern("Fendoir (test)");
+        names.normalize(&mut [(CLEAVE, &mut ability)]);
+        assert_eq!(resolve(ability), "Cleave");
+
+        if let Some(observed) = names.take_observed() {
+            save_observed_names(&dir, observed).unwrap();
+        }
+        assert!(!dir.join("de.toml").exists());
+        assert!(!dir.join("fr.toml").exists());
+        assert_eq!(localized_name(CLEAVE, GameLanguage::German), None);
+    }
+
+    #[test]
+    fn test_normalize_names_to_english_by_id() {
+        const BOSS: i64 = 9_000_000_000_101;
+        const UNKNOWN: i64 = 9_000_000_000_102;
+
+        let mut names = LogNames::detect(
+            effect_type_id::AREAENTERED,
+            "???",
+            Some(GameLanguage::French),
+        )
+        .unwrap();
+        names.register_entity_names([(BOSS, "Test Boss")]);
+
+        let mut area = intern("Flotte impériale (test)");
+        let mut ability = intern("Fendoir (test)");
+        let mut boss = intern("Boss de test");
+        let mut unknown = intern("Inconnu");
+        names.normalize(&mut [
+            (FLEET, &mut area),
+            (CLEAVE, &mut ability),
+            (BOSS, &mut boss),
+            (UNKNOWN, &mut unknown),
+        ]);
+        assert_eq!(resolve(area), "Imperial Fleet");
+        assert_eq!(resolve(ability), "Cleave");
+        assert_eq!(resolve(boss), "Test Boss");
+        assert_eq!(resolve(unknown), "Inconnu");
+
+        // The localized names are collected once for saving
+        let mut again = intern("Fendoir (test)");
+        names.normalize(&mut [(CLEAVE, &mut again)]);
+        let observed = names.take_observed().unwrap();
+        assert_eq!(observed.language, GameLanguage::French);
+        assert_eq!(observed.names.len(), 4);
+        assert_eq!(resolve(observed.names[&CLEAVE]), "Fendoir (test)");
+        assert!(names.take_observed().is_none());
+    }
+
+    #[test]
+    fn test_english_logs_are_not_rewritten() {
+        let mut names = LogNames::detect(effect_type_id::AREAENTERED, "AreaEntered", None).unwrap();
+        names.register_entity_names([(9_000_000_000_201, "Roster Name")]);
+        let mut npc = intern("Log Name");
+        names.normalize(&mut [(9_000_000_000_201, &mut npc)]);
+        assert_eq!(resolve(npc), "Log Name");
+    }
+
+    #[test]
+    fn test_save_observed_names_counts_new_names() {
+        const ABILITY: i64 = 9_000_000_000_301;
+        let dir = std::env::temp_dir().join(format!("baras-names-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let observed = ObservedNames {
+            language: GameLanguage::German,
+            names: BTreeMap::from([(ABILITY, intern("Teststoß"))]),
+        };
+        assert_eq!(save_observed_names(&dir, observed.clone()), Ok(1));
+        assert_eq!(save_observed_names(&dir, observed), Ok(0));
+
+        let contents = std::fs::read_to_string(dir.join("de.toml")).unwrap();
+        assert!(contents.contains("Teststoß"));
+        assert!(!dir.join("de.toml.tmp").exists());
+
+        // Saved names are shared with display and lookups
+        assert_eq!(
+            localized_name(ABILITY, GameLanguage::German),
+            Some("Teststoß")
+        );
+        assert_eq!(ids_for_name("TESTSTOß"), vec![ABILITY]);
+        assert_eq!(
+            display_name(FLEET, "Imperial Fleet", GameLanguage::French),
+            "Imperial Fleet"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -4,7 +4,9 @@ mod discipline;
 mod effects;
 mod flashpoint_bosses;
 mod flashpoints;
+mod gcd;
 mod lair_bosses;
+mod localization;
 mod pvp_instance;
 mod raid_bosses;
 mod raids;
@@ -18,12 +20,20 @@ pub use boss_registry::{
     clear_boss_registry, is_registered_boss, lookup_registered_name, register_hp_overlay_entity,
 };
 pub use bosses::{
-    BossInfo, ContentType, Difficulty, get_boss_ids, is_boss, lookup_area_content_type, lookup_boss,
+    BossInfo, ContentType, Difficulty, get_boss_ids, is_boss, is_training_dummy,
+    lookup_area_content_type, lookup_boss, training_dummy_ids,
 };
 pub use discipline::{Class, Discipline, Role};
 pub use effects::*;
 pub use flashpoints::{FLASHPOINT_AREAS, get_flashpoint_name, is_flashpoint};
-pub use pvp_instance::is_pvp_area;
+pub use gcd::{
+    BASE_GCD_SECS, GcdEstimate, GcdEstimator, estimate_from_activation_times, gcd_secs,
+};
+pub use localization::{
+    GameLanguage, LogNames, ObservedNames, display_name, ids_for_name, load_user_name_tables,
+    localized_name, save_observed_names,
+};
+pub use pvp_instance::{is_arena, is_pvp_area};
 pub use raids::{OPERATION_AREAS, get_operation_name, is_operation, is_world_boss};
 pub use shield_absorbs::{SHIELD_INFO, ShieldInfo, get_shield_info, is_known_shield};
 pub use attack_types::ATTACK_TYPES;
@@ -33,3 +33,19 @@ static PVP_AREA_IDS: LazyLock<HashSet<i64>> = LazyLock::new(|| {
 pub fn is_pvp_area(area_id: i64) -> bool {
     PVP_AREA_IDS.contains(&area_id)
 }
+
+/// Arena map names. Arenas are played in rounds; warzones are not.
+const ARENA_NAMES: &[&str] = &[
+    "Corellia Square",
+    "Orbital Station",
+    "Rishi Stronghold",
+    "Tatooine Canyon",
+    "Makeb Mesa",
+    "Mandalorian Battle Ring",
+];
+
+/// Check if a PvP area is an arena (by English area name)
+pub fn is_arena(area_name: &str) -> bool {
+    ARENA_NAMES.iter().any(|name| area_name.contains(name))
+        || area_name.to_lowercase().contains("arena")
+}
@@ -7,6 +7,8 @@ pub mod encounter;
 pub mod game_data;
 pub mod icons;
 pub mod query;
+pub mod report;
+pub mod rotations;
 pub mod serde_defaults;
 pub mod signal_processor;
 pub mod state;
@@ -465,40 +465,28 @@ impl EncounterQuery<'_> {
             .unwrap_or_default();
         let escaped_name = sql_escape(entity_name);
 
+        // Absorption per shield effect, split by remaining capacity
+        let entity_names = self.get_entity_names().await?;
+        let mut totals: HashMap<i64, (f64, i64)> = HashMap::new();
+        for credit in self.query_shield_credits(time_range).await? {
+            if entity_names.get(&credit.source_id).map(String::as_str) == Some(entity_name) {
+                let entry = totals.entry(credit.effect_id).or_default();
+                entry.0 += credit.amount as f64;
+                entry.1 += 1;
+            }
+        }
+        if totals.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Map shield effect IDs to the ability that applied them
         let query = format!(
             r#"
-            WITH shield_map AS (
-                SELECT DISTINCT effect_id as shield_eid, ability_id, ability_name
-                FROM events
-                WHERE effect_type_id = {apply_effect}
-                  AND source_name = '{name}'
-                  {time_filter}
-            ),
-            shield_totals AS (
-                SELECT
-                    CAST(shield['effect_id'] AS BIGINT) as shield_eid,
-                    SUM(CAST(dmg_absorbed AS BIGINT)) as total_absorbed,
-                    COUNT(*) as hit_count
-                FROM (
-                    SELECT dmg_absorbed, UNNEST(active_shields) as shield
-                    FROM events
-                    WHERE dmg_absorbed > 0 AND cardinality(active_shields) > 0
-                      {time_filter}
-                )
-                WHERE CAST(shield['position'] AS BIGINT) = 1
-                  AND CAST(shield['source_id'] AS BIGINT) IN (
-                      SELECT DISTINCT source_id FROM events
-                      WHERE source_name = '{name}' {time_filter}
-                  )
-                GROUP BY shield['effect_id']
-            )
-            SELECT sm.ability_id, sm.ability_name,
-                   COALESCE(st.total_absorbed, 0) as total_absorbed,
-                   COALESCE(st.hit_count, 0) as hit_count
-            FROM shield_totals st
-            JOIN shield_map sm ON st.shield_eid = sm.shield_eid
-            WHERE st.total_absorbed > 0
-            ORDER BY total_absorbed DESC
+            SELECT DISTINCT effect_id, ability_id, ability_name
+            FROM events
+            WHERE effect_type_id = {apply_effect}
+              AND source_name = '{name}'
+              {time_filter}
             "#,
             apply_effect = effect_type_id::APPLYEFFECT,
             name = escaped_name,
@@ -509,13 +497,15 @@ impl EncounterQuery<'_> {
         let mut results = Vec::new();
 
         for batch in &batches {
-            let ids = col_i64(batch, 0)?;
-            let names = col_strings(batch, 1)?;
-            let totals = col_f64(batch, 2)?;
-            let hits = col_i64(batch, 3)?;
+            let effect_ids = col_i64(batch, 0)?;
+            let ids = col_i64(batch, 1)?;
+            let names = col_strings(batch, 2)?;
 
             for i in 0..batch.num_rows() {
-                let h = hits[i] as f64;
+                let Some(&(total, hit_count)) = totals.get(&effect_ids[i]) else {
+                    continue;
+                };
+                let h = hit_count as f64;
                 results.push(AbilityBreakdown {
                     ability_name: names[i].clone(),
                     ability_id: ids[i],
@@ -523,26 +513,27 @@ impl EncounterQuery<'_> {
                     target_class_id: None,
                     target_log_id: None,
                     target_first_hit_secs: None,
-                    total_value: totals[i],
-                    hit_count: hits[i],
+                    total_value: total,
+                    hit_count,
                     crit_count: 0,
                     crit_rate: 0.0,
                     max_hit: 0.0,
-                    avg_hit: if h > 0.0 { totals[i] / h } else { 0.0 },
+                    avg_hit: if h > 0.0 { total / h } else { 0.0 },
                     miss_count: 0,
                     activation_count: 0,
                     crit_total: 0.0,
-                    effective_total: totals[i], // shield absorption is fully effective
+                    effective_total: total, // shield absorption is fully effective
                     is_shield: true,
                     attack_type: String::new(),
                     damage_type: String::new(),
                     shield_count: 0,
                     absorbed_total: 0.0,
-                    dps: totals[i] / duration,
+                    dps: total / duration,
                     percent_of_total: 0.0, // will be relative to heal total, leave 0
                 });
             }
         }
+        results.sort_by(|a, b| b.total_value.total_cmp(&a.total_value));
         Ok(results)
     }
 
@@ -3,30 +3,6 @@
 use super::*;
 use crate::game_data::{effect_id, effect_type_id};
 
-/// Build search clause supporting case-insensitive search and OR logic.
-/// Search terms separated by " OR " are combined with OR logic.
-fn build_search_clause(search: &str) -> String {
-    let terms: Vec<&str> = search.split(" OR ").map(|s| s.trim()).collect();
-
-    let term_clauses: Vec<String> = terms
-        .iter()
-        .filter(|t| !t.is_empty())
-        .map(|term| {
-            let escaped = sql_escape(term).to_lowercase();
-            format!(
-                "(LOWER(source_name) LIKE '%{0}%' OR LOWER(target_name) LIKE '%{0}%' OR LOWER(ability_name) LIKE '%{0}%' OR LOWER(effect_name) LIKE '%{0}%')",
-                escaped
-            )
-        })
-        .collect();
-
-    if term_clauses.is_empty() {
-        return "1=1".to_string();
-    }
-
-    format!("({})", term_clauses.join(" OR "))
-}
-
 /// Build event type filter clause based on CombatLogFilters.
 fn build_event_filter_clause(filters: &CombatLogFilters) -> Option<String> {
     let mut conditions = Vec::new();
@@ -83,6 +59,64 @@ fn build_event_filter_clause(filters: &CombatLogFilters) -> Option<String> {
     }
 }
 
+/// WHERE conditions for the rows the combat log shows with the given filters.
+pub(super) fn combat_log_where_clauses(
+    source_filter: Option<&str>,
+    target_filter: Option<&str>,
+    search_filter: Option<&LogFilter>,
+    time_range: Option<&TimeRange>,
+    event_filters: Option<&CombatLogFilters>,
+) -> Vec<String> {
+    // Always exclude Spend/Restore events (energy/resource changes)
+    let mut where_clauses = vec![
+        "combat_time_secs IS NOT NULL".to_string(),
+        format!(
+            "effect_type_id NOT IN ({}, {})",
+            effect_type_id::SPEND,
+            effect_type_id::RESTORE
+        ),
+    ];
+
+    if let Some(source) = source_filter {
+        match source {
+            "__ALL_FRIENDLY__" => {
+                where_clauses.push("source_entity_type IN ('Player', 'Companion')".to_string());
+            }
+            "__ALL_NPCS__" => {
+                where_clauses.push("source_entity_type = 'Npc'".to_string());
+            }
+            _ => {
+                where_clauses.push(format!("source_name = '{}'", sql_escape(source)));
+            }
+        }
+    }
+    if let Some(target) = target_filter {
+        match target {
+            "__ALL_FRIENDLY__" => {
+                where_clauses.push("target_entity_type IN ('Player', 'Companion')".to_string());
+            }
+            "__ALL_NPCS__" => {
+                where_clauses.push("target_entity_type = 'Npc'".to_string());
+            }
+            _ => {
+                where_clauses.push(format!("target_name = '{}'", sql_escape(target)));
+            }
+        }
+    }
+    if let Some(search) = search_filter {
+        where_clauses.push(search.to_sql());
+    }
+    if let Some(tr) = time_range {
+        where_clauses.push(tr.sql_filter());
+    }
+    if let Some(filters) = event_filters {
+        if let Some(filter_clause) = build_event_filter_clause(filters) {
+            where_clauses.push(filter_clause);
+        }
+    }
+    where_clauses
+}
+
 impl EncounterQuery<'_> {
     /// Query combat log rows for the combat log viewer.
     /// Supports pagination via offset/limit for virtual scrolling.
@@ -93,61 +127,18 @@ impl EncounterQuery<'_> {
         limit: u64,
         source_filter: Option<&str>,
         target_filter: Option<&str>,
-        search_filter: Option<&str>,
+        search_filter: Option<&LogFilter>,
         time_range: Option<&TimeRange>,
         event_filters: Option<&CombatLogFilters>,
     ) -> Result<Vec<CombatLogRow>, String> {
-        // Always exclude Spend/Restore events (energy/resource changes)
-        let mut where_clauses = vec![
-            "combat_time_secs IS NOT NULL".to_string(),
-            format!(
-                "effect_type_id NOT IN ({}, {})",
-                effect_type_id::SPEND,
-                effect_type_id::RESTORE
-            ),
-        ];
-
-        if let Some(source) = source_filter {
-            match source {
-                "__ALL_FRIENDLY__" => {
-                    where_clauses.push("source_entity_type IN ('Player', 'Companion')".to_string());
-                }
-                "__ALL_NPCS__" => {
-                    where_clauses.push("source_entity_type = 'Npc'".to_string());
-                }
-                _ => {
-                    where_clauses.push(format!("source_name = '{}'", sql_escape(source)));
-                }
-            }
-        }
-        if let Some(target) = target_filter {
-            match target {
-                "__ALL_FRIENDLY__" => {
-                    where_clauses.push("target_entity_type IN ('Player', 'Companion')".to_string());
-                }
-                "__ALL_NPCS__" => {
-                    where_clauses.push("target_entity_type = 'Npc'".to_string());
-                }
-                _ => {
-                    where_clauses.push(format!("target_name = '{}'", sql_escape(target)));
-                }
-            }
-        }
-        if let Some(search) = search_filter {
-            if !search.is_empty() {
-                where_clauses.push(build_search_clause(search));
-            }
-        }
-        if let Some(tr) = time_range {
-            where_clauses.push(tr.sql_filter());
-        }
-        if let Some(filters) = event_filters {
-            if let Some(filter_clause) = build_event_filter_clause(filters) {
-                where_clauses.push(filter_clause);
-            }
-        }
-
-        let where_clause = where_clauses.join(" AND ");
+        let where_clause = combat_log_where_clauses(
+            source_filter,
+            target_filter,
+            search_filter,
+            time_range,
+            event_filters,
+        )
+        .join(" AND ");
 
         let batches = self
             .sql(&format!(
@@ -245,61 +236,18 @@ impl EncounterQuery<'_> {
         &self,
         source_filter: Option<&str>,
         target_filter: Option<&str>,
-        search_filter: Option<&str>,
+        search_filter: Option<&LogFilter>,
         time_range: Option<&TimeRange>,
         event_filters: Option<&CombatLogFilters>,
     ) -> Result<u64, String> {
-        // Always exclude Spend/Restore events (energy/resource changes)
-        let mut where_clauses = vec![
-            "combat_time_secs IS NOT NULL".to_string(),
-            format!(
-                "effect_type_id NOT IN ({}, {})",
-                effect_type_id::SPEND,
-                effect_type_id::RESTORE
-            ),
-        ];
-
-        if let Some(source) = source_filter {
-            match source {
-                "__ALL_FRIENDLY__" => {
-                    where_clauses.push("source_entity_type IN ('Player', 'Companion')".to_string());
-                }
-                "__ALL_NPCS__" => {
-                    where_clauses.push("source_entity_type = 'Npc'".to_string());
-                }
-                _ => {
-                    where_clauses.push(format!("source_name = '{}'", sql_escape(source)));
-                }
-            }
-        }
-        if let Some(target) = target_filter {
-            match target {
-                "__ALL_FRIENDLY__" => {
-                    where_clauses.push("target_entity_type IN ('Player', 'Companion')".to_string());
-                }
-                "__ALL_NPCS__" => {
-                    where_clauses.push("target_entity_type = 'Npc'".to_string());
-                }
-                _ => {
-                    where_clauses.push(format!("target_name = '{}'", sql_escape(target)));
-                }
-            }
-        }
-        if let Some(search) = search_filter {
-            if !search.is_empty() {
-                where_clauses.push(build_search_clause(search));
-            }
-        }
-        if let Some(tr) = time_range {
-            where_clauses.push(tr.sql_filter());
-        }
-        if let Some(filters) = event_filters {
-            if let Some(filter_clause) = build_event_filter_clause(filters) {
-                where_clauses.push(filter_clause);
-            }
-        }
-
-        let where_clause = where_clauses.join(" AND ");
+        let where_clause = combat_log_where_clauses(
+            source_filter,
+            target_filter,
+            search_filter,
+            time_range,
+            event_filters,
+        )
+        .join(" AND ");
 
         let batches = self
             .sql(&format!("SELECT COUNT(*) FROM events WHERE {where_clause}"))
@@ -362,22 +310,18 @@ impl EncounterQuery<'_> {
         Ok(GroupedEntityNames { friendly, npcs })
     }
 
-    /// Find all row positions matching search text (for Find feature).
+    /// Find all row positions matching a filter (for Find feature).
     ///
     /// The position is the row's index in the filtered result set (for scrolling),
     /// and row_idx (line_number) is used for highlighting when that row is loaded.
     pub async fn query_combat_log_find(
         &self,
-        find_text: &str,
+        find: &LogFilter,
         source_filter: Option<&str>,
         target_filter: Option<&str>,
         time_range: Option<&TimeRange>,
         event_filters: Option<&CombatLogFilters>,
     ) -> Result<Vec<CombatLogFindMatch>, String> {
-        if find_text.is_empty() {
-            return Ok(vec![]);
-        }
-
         // Build base WHERE clause (same filters as main query)
         // Always exclude Spend/Restore events (energy/resource changes)
         let mut where_clauses = vec![
@@ -426,12 +370,7 @@ impl EncounterQuery<'_> {
 
         let base_where = where_clauses.join(" AND ");
 
-        // Find text filter - use COALESCE to handle NULLs
-        let find_lower = sql_escape(find_text).to_lowercase();
-        let find_filter = format!(
-            "(LOWER(COALESCE(src, '')) LIKE '%{0}%' OR LOWER(COALESCE(tgt, '')) LIKE '%{0}%' OR LOWER(COALESCE(abl, '')) LIKE '%{0}%' OR LOWER(COALESCE(eff, '')) LIKE '%{0}%')",
-            find_lower
-        );
+        let find_filter = find.to_sql();
 
         // CTE: number ALL rows in base result, then filter for find matches
         // This gives us the position in the FULL list for correct scrolling
@@ -440,12 +379,8 @@ impl EncounterQuery<'_> {
                 r#"
                 WITH numbered AS (
                     SELECT
-                        line_number,
-                        CAST(ROW_NUMBER() OVER (ORDER BY combat_time_secs) - 1 AS BIGINT) as pos,
-                        source_name as src,
-                        target_name as tgt,
-                        ability_name as abl,
-                        effect_name as eff
+                        *,
+                        CAST(ROW_NUMBER() OVER (ORDER BY combat_time_secs) - 1 AS BIGINT) as pos
                     FROM events
                     WHERE {base_where}
                 )
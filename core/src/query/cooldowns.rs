@@ -0,0 +1,439 @@
+//! Cooldown usage efficiency queries.
+//!
+//! Compares how often each tracked cooldown (effects with `DisplayTarget::Cooldowns`)
+//! was used against how often it could have been used, per player. Cooldowns
+//! restricted to a player's discipline are listed even when never pressed.
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::dsl::{AbilitySelector, EffectSelector, Trigger};
+use crate::effects::{DisplayTarget, EffectDefinition};
+use crate::game_data::{Discipline, effect_id, effect_type_id};
+
+/// Activations of the same cooldown closer than this are one use
+/// (e.g. a definition listing several effect IDs that fire together).
+const DUPLICATE_USE_WINDOW_SECS: f32 = 1.0;
+
+/// A matching trigger event for a cooldown definition.
+struct CooldownEvent {
+    player_name: String,
+    time_secs: f32,
+    effect_type_id: i64,
+    effect_id: i64,
+    effect_name: String,
+    ability_id: i64,
+    ability_name: String,
+}
+
+impl EncounterQuery<'_> {
+    /// Query cooldown efficiency for every player that used a tracked cooldown,
+    /// plus zero-use rows for cooldowns of the player's discipline (definitions
+    /// with a `disciplines` list) that were never pressed.
+    ///
+    /// Uses are collected over the whole encounter so a cooldown pressed before the
+    /// selected time range still counts as "not ready" at the start of the range.
+    pub async fn query_cooldown_efficiency(
+        &self,
+        definitions: &[EffectDefinition],
+        player_disciplines: &HashMap<String, Discipline>,
+        time_range: Option<&TimeRange>,
+    ) -> Result<Vec<CooldownEfficiencyRow>, String> {
+        let cooldowns: Vec<&EffectDefinition> = definitions
+            .iter()
+            .filter(|d| {
+                d.enabled
+                    && d.display_target == DisplayTarget::Cooldowns
+                    && d.duration_secs.is_some_and(|s| s > 0.0)
+            })
+            .collect();
+        if cooldowns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let Some(selector_filter) = selector_sql_filter(&cooldowns) else {
+            return Ok(Vec::new());
+        };
+
+        let (window_start, window_end) = match time_range {
+            Some(tr) => (tr.start, tr.end),
+            None => {
+                let end = scalar_f32(
+                    &self
+                        .sql("SELECT COALESCE(MAX(combat_time_secs), 0) FROM events WHERE combat_time_secs IS NOT NULL")
+                        .await?,
+                );
+                (0.0, end)
+            }
+        };
+
+        let sql = format!(
+            "SELECT source_name, combat_time_secs, effect_type_id, effect_id, effect_name, \
+                    ability_id, ability_name \
+             FROM events \
+             WHERE source_entity_type = 'Player' AND combat_time_secs IS NOT NULL \
+               AND ({selector_filter}) \
+             ORDER BY combat_time_secs ASC, line_number ASC"
+        );
+
+        let batches = self.sql(&sql).await?;
+        let mut events = Vec::new();
+        for batch in &batches {
+            let names = col_strings(batch, 0)?;
+            let times = col_f32(batch, 1)?;
+            let type_ids = col_i64(batch, 2)?;
+            let effect_ids = col_i64(batch, 3)?;
+            let effect_names = col_strings(batch, 4)?;
+            let ability_ids = col_i64(batch, 5)?;
+            let ability_names = col_strings(batch, 6)?;
+            for i in 0..batch.num_rows() {
+                events.push(CooldownEvent {
+                    player_name: names[i].clone(),
+                    time_secs: times[i],
+                    effect_type_id: type_ids[i],
+                    effect_id: effect_ids[i],
+                    effect_name: effect_names[i].clone(),
+                    ability_id: ability_ids[i],
+                    ability_name: ability_names[i].clone(),
+                });
+            }
+        }
+
+        // (player, definition index) -> use times
+        let mut uses: HashMap<(String, usize), Vec<f32>> = HashMap::new();
+        for evt in &events {
+            for (def_idx, def) in cooldowns.iter().enumerate() {
+                if event_matches(def, evt) {
+                    let times = uses.entry((evt.player_name.clone(), def_idx)).or_default();
+                    if times
+                        .last()
+                        .is_none_or(|&t| evt.time_secs - t >= DUPLICATE_USE_WINDOW_SECS)
+                    {
+                        times.push(evt.time_secs);
+                    }
+                }
+            }
+        }
+
+        // Cooldowns the player had but never pressed (only for players in this fight)
+        let players = self
+            .sql("SELECT DISTINCT source_name FROM events WHERE source_entity_type = 'Player'")
+            .await?;
+        for batch in &players {
+            for name in col_strings(batch, 0)? {
+                let Some(discipline) = player_disciplines.get(&name) else {
+                    continue;
+                };
+                for (def_idx, def) in cooldowns.iter().enumerate() {
+                    if def.disciplines.contains(discipline) {
+                        uses.entry((name.clone(), def_idx)).or_default();
+                    }
+                }
+            }
+        }
+
+        // Alacrity-affected cooldowns follow each player's inferred (or configured)
+        // alacrity and latency
+        let estimates = self.query_gcd_estimates().await?;
+
+        let mut results = Vec::new();
+        for ((player_name, def_idx), times) in uses {
+            let def = cooldowns[def_idx];
+            let base_secs = def.duration_secs.unwrap_or_default();
+            let cooldown_secs = if def.is_affected_by_alacrity {
+                estimates
+                    .get(&player_name)
+                    .unwrap_or(&self.gcd_fallback)
+                    .adjust_duration(base_secs)
+            } else {
+                base_secs
+            };
+            let usage = analyze_cooldown_usage(&times, cooldown_secs, window_start, window_end);
+            let efficiency_pct = if usage.possible_uses > 0 {
+                (usage.uses as f32 * 100.0 / usage.possible_uses as f32).min(100.0)
+            } else {
+                100.0
+            };
+            results.push(CooldownEfficiencyRow {
+                player_name,
+                cooldown_id: def.id.clone(),
+                cooldown_name: def.display_text().to_string(),
+                icon_ability_id: icon_ability_id(def),
+                cooldown_secs,
+                uses: usage.uses,
+                possible_uses: usage.possible_uses,
+                efficiency_pct,
+                idle_secs: usage
+                    .idle_gaps
+                    .iter()
+                    .map(|g| g.end_secs - g.start_secs)
+                    .sum(),
+                use_times: usage.use_times,
+                idle_gaps: usage.idle_gaps,
+            });
+        }
+
+        results.sort_by(|a, b| {
+            a.player_name
+                .cmp(&b.player_name)
+                .then_with(|| a.efficiency_pct.total_cmp(&b.efficiency_pct))
+        });
+        Ok(results)
+    }
+}
+
+/// Build a SQL prefilter matching any trigger event of the given cooldowns.
+/// Returns None if no definition has a usable selector.
+fn selector_sql_filter(cooldowns: &[&EffectDefinition]) -> Option<String> {
+    let mut ability_ids = Vec::new();
+    let mut ability_names = Vec::new();
+    let mut effect_ids = Vec::new();
+    let mut effect_names = Vec::new();
+
+    for def in cooldowns {
+        match &def.trigger {
+            Trigger::AbilityCast { abilities, .. } => {
+                for sel in abilities {
+                    match sel {
+                        AbilitySelector::Id(id) => ability_ids.push(id.to_string()),
+                        AbilitySelector::Name(n) => {
+                            ability_names.push(format!("'{}'", sql_escape(&n.to_lowercase())))
+                        }
+                    }
+                }
+            }
+            Trigger::EffectApplied { effects, .. } | Trigger::EffectRemoved { effects, .. } => {
+                for sel in effects {
+                    match sel {
+                        EffectSelector::Id(id) => effect_ids.push(id.to_string()),
+                        EffectSelector::Name(n) => {
+                            effect_names.push(format!("'{}'", sql_escape(&n.to_lowercase())))
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut ability_conds = Vec::new();
+    if !ability_ids.is_empty() {
+        ability_conds.push(format!("ability_id IN ({})", ability_ids.join(", ")));
+    }
+    if !ability_names.is_empty() {
+        ability_conds.push(format!(
+            "LOWER(ability_name) IN ({})",
+            ability_names.join(", ")
+        ));
+    }
+    let mut effect_conds = Vec::new();
+    if !effect_ids.is_empty() {
+        effect_conds.push(format!("effect_id IN ({})", effect_ids.join(", ")));
+    }
+    if !effect_names.is_empty() {
+        effect_conds.push(format!(
+            "LOWER(effect_name) IN ({})",
+            effect_names.join(", ")
+        ));
+    }
+
+    let mut clauses = Vec::new();
+    if !ability_conds.is_empty() {
+        clauses.push(format!(
+            "(effect_id = {} AND ({}))",
+            effect_id::ABILITYACTIVATE,
+            ability_conds.join(" OR ")
+        ));
+    }
+    if !effect_conds.is_empty() {
+        clauses.push(format!(
+            "(effect_type_id IN ({}, {}) AND ({}))",
+            effect_type_id::APPLYEFFECT,
+            effect_type_id::REMOVEEFFECT,
+            effect_conds.join(" OR ")
+        ));
+    }
+
+    (!clauses.is_empty()).then(|| clauses.join(" OR "))
+}
+
+/// Check whether an event is a trigger for the given cooldown definition.
+fn event_matches(def: &EffectDefinition, evt: &CooldownEvent) -> bool {
+    match &def.trigger {
+        Trigger::AbilityCast { abilities, .. } => {
+            !abilities.is_empty()
+                && evt.effect_id == effect_id::ABILITYACTIVATE
+                && def.matches_ability_cast(evt.ability_id as u64, Some(&evt.ability_name))
+        }
+        Trigger::EffectApplied { .. } => {
+            evt.effect_type_id == effect_type_id::APPLYEFFECT
+                && def.matches_effect(evt.effect_id as u64, Some(&evt.effect_name))
+        }
+        Trigger::EffectRemoved { .. } => {
+            evt.effect_type_id == effect_type_id::REMOVEEFFECT
+                && def.matches_effect(evt.effect_id as u64, Some(&evt.effect_name))
+        }
+        _ => false,
+    }
+}
+
+/// Pick an ability ID for the cooldown icon (explicit icon, else first ID selector).
+fn icon_ability_id(def: &EffectDefinition) -> Option<i64> {
+    if let Some(id) = def.icon_ability_id {
+        return Some(id as i64);
+    }
+    match &def.trigger {
+        Trigger::AbilityCast { abilities, .. } => abilities.iter().find_map(|s| match s {
+            AbilitySelector::Id(id) => Some(*id as i64),
+            AbilitySelector::Name(_) => None,
+        }),
+        Trigger::EffectApplied { effects, .. } | Trigger::EffectRemoved { effects, .. } => {
+            effects.iter().find_map(|s| match s {
+                EffectSelector::Id(id) => Some(*id as i64),
+                EffectSelector::Name(_) => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Result of replaying a cooldown's uses against a time window.
+#[derive(Debug, PartialEq)]
+struct CooldownUsage {
+    uses: i64,
+    possible_uses: i64,
+    use_times: Vec<f32>,
+    idle_gaps: Vec<CooldownIdleGap>,
+}
+
+/// Replay sorted use times of a cooldown over `[start, end]`.
+///
+/// The cooldown is assumed ready at combat start. Uses before `start` only affect
+/// when the cooldown is next ready. `possible_uses` is the count a player pressing
+/// it every time it came off cooldown would have reached from the same starting state.
+fn analyze_cooldown_usage(
+    times: &[f32],
+    cooldown_secs: f32,
+    start: f32,
+    end: f32,
+) -> CooldownUsage {
+    let mut ready_at = 0.0f32;
+    for &t in times.iter().filter(|&&t| t < start) {
+        ready_at = t + cooldown_secs;
+    }
+    let first_ready = ready_at.max(start);
+
+    let possible_uses = if first_ready > end || cooldown_secs <= 0.0 {
+        0
+    } else {
+        ((end - first_ready) / cooldown_secs).floor() as i64 + 1
+    };
+
+    let mut use_times = Vec::new();
+    let mut idle_gaps = Vec::new();
+    for &t in times.iter().filter(|&&t| t >= start && t <= end) {
+        let idle_from = ready_at.max(start);
+        if t > idle_from {
+            idle_gaps.push(CooldownIdleGap {
+                start_secs: idle_from,
+                end_secs: t,
+            });
+        }
+        use_times.push(t);
+        ready_at = t + cooldown_secs;
+    }
+    let idle_from = ready_at.max(start);
+    if idle_from < end {
+        idle_gaps.push(CooldownIdleGap {
+            start_secs: idle_from,
+            end_secs: end,
+        });
+    }
+
+    CooldownUsage {
+        uses: use_times.len() as i64,
+        possible_uses,
+        use_times,
+        idle_gaps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perfect_usage_has_no_gaps() {
+        let usage = analyze_cooldown_usage(&[0.0, 120.0, 240.0], 120.0, 0.0, 300.0);
+        assert_eq!(usage.uses, 3);
+        assert_eq!(usage.possible_uses, 3);
+        // Ready again at 360s, after the window ends
+        assert!(usage.idle_gaps.is_empty());
+    }
+
+    #[test]
+    fn test_late_first_use_creates_gap() {
+        let usage = analyze_cooldown_usage(&[30.0], 120.0, 0.0, 100.0);
+        assert_eq!(usage.uses, 1);
+        assert_eq!(usage.possible_uses, 1);
+        assert_eq!(
+            usage.idle_gaps,
+            vec![CooldownIdleGap {
+                start_secs: 0.0,
+                end_secs: 30.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unused_after_ready_counts_to_window_end() {
+        let usage = analyze_cooldown_usage(&[10.0], 60.0, 0.0, 200.0);
+        assert_eq!(usage.possible_uses, 4);
+        assert_eq!(usage.idle_gaps.len(), 2);
+        assert_eq!(usage.idle_gaps[1].start_secs, 70.0);
+        assert_eq!(usage.idle_gaps[1].end_secs, 200.0);
+    }
+
+    #[test]
+    fn test_never_used_cooldown_is_idle_for_the_whole_window() {
+        let usage = analyze_cooldown_usage(&[], 120.0, 0.0, 300.0);
+        assert_eq!(usage.uses, 0);
+        assert_eq!(usage.possible_uses, 3);
+        assert_eq!(
+            usage.idle_gaps,
+            vec![CooldownIdleGap {
+                start_secs: 0.0,
+                end_secs: 300.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_use_before_window_delays_readiness() {
+        // Used at 50s with a 120s cooldown: not ready until 170s
+        let usage = analyze_cooldown_usage(&[50.0, 200.0], 120.0, 100.0, 250.0);
+        assert_eq!(usage.uses, 1);
+        assert_eq!(usage.possible_uses, 1);
+        assert_eq!(
+            usage.idle_gaps,
+            vec![CooldownIdleGap {
+                start_secs: 170.0,
+                end_secs: 200.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_bundled_cooldowns_list_their_disciplines() {
+        let config: crate::effects::DefinitionConfig =
+            toml::from_str(include_str!("../../definitions/effects/dcds.toml")).unwrap();
+        let saber_ward = config
+            .effects
+            .iter()
+            .find(|d| d.id == "saber_ward_cd")
+            .unwrap();
+        assert_eq!(saber_ward.disciplines.len(), 12);
+        assert!(saber_ward.disciplines.contains(&Discipline::Watchman));
+        assert!(!saber_ward.disciplines.contains(&Discipline::Darkness));
+    }
+}
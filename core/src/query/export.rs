@@ -0,0 +1,345 @@
+//! Data Explorer exports (CSV and parquet).
+//!
+//! Typed query results are serialized to JSON and read back into Arrow batches
+//! (columns in the struct's field order), so every table goes through the same
+//! writer as the raw event slice.
+
+use std::fs::File;
+use std::io::Cursor;
+use std::path::Path;
+
+use datafusion::arrow::csv::writer::WriterBuilder;
+use datafusion::arrow::datatypes::{Field, Schema};
+use datafusion::arrow::json::ReaderBuilder;
+use datafusion::arrow::json::reader::infer_json_schema_from_iterator;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use serde::de::{Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+impl EncounterQuery<'_> {
+    /// Rows of a Data Explorer table or chart, with the time range applied.
+    /// `search_filter` is the parsed filter expression of a `CombatLog`
+    /// export (other sources ignore it).
+    pub async fn export_source(
+        &self,
+        source: &ExportSource,
+        time_range: Option<&TimeRange>,
+        duration_secs: Option<f32>,
+        search_filter: Option<&LogFilter>,
+    ) -> Result<Vec<RecordBatch>, String> {
+        match source {
+            ExportSource::RaidOverview => {
+                rows_to_batches(&self.query_raid_overview(time_range, duration_secs).await?)
+            }
+            ExportSource::Breakdown {
+                tab,
+                entity_name,
+                entity_types,
+                breakdown_mode,
+            } => {
+                let types: Option<Vec<&str>> = entity_types
+                    .as_ref()
+                    .map(|t| t.iter().map(String::as_str).collect());
+                let rows = self
+                    .query_breakdown(
+                        *tab,
+                        entity_name.as_deref(),
+                        time_range,
+                        types.as_deref(),
+                        breakdown_mode.as_ref(),
+                        duration_secs,
+                    )
+                    .await?;
+                rows_to_batches(&rows)
+            }
+            ExportSource::PlayerDeaths => {
+                let mut deaths = self.query_player_deaths().await?;
+                if let Some(tr) = time_range {
+                    deaths.retain(|d| d.death_time_secs >= tr.start && d.death_time_secs <= tr.end);
+                }
+                rows_to_batches(&deaths)
+            }
+            ExportSource::NpcHealth => rows_to_batches(&self.query_npc_health(time_range).await?),
+            ExportSource::EffectUptime { target_name } => {
+                let duration = time_range
+                    .map(|tr| tr.end - tr.start)
+                    .or(duration_secs)
+                    .unwrap_or(0.0);
+                let rows = self
+                    .query_effect_uptime(target_name.as_deref(), time_range, duration)
+                    .await?;
+                rows_to_batches(&rows)
+            }
+            ExportSource::TimeSeries {
+                metric,
+                entity_name,
+                bucket_ms,
+            } => {
+                let (bucket_ms, name) = (*bucket_ms, entity_name.as_deref());
+                match metric {
+                    TimeSeriesMetric::Dps => {
+                        rows_to_batches(&self.dps_over_time(bucket_ms, name, time_range).await?)
+                    }
+                    TimeSeriesMetric::Hps => {
+                        rows_to_batches(&self.hps_over_time(bucket_ms, name, time_range).await?)
+                    }
+                    TimeSeriesMetric::Ehps => {
+                        rows_to_batches(&self.ehps_over_time(bucket_ms, name, time_range).await?)
+                    }
+                    TimeSeriesMetric::Dtps => {
+                        rows_to_batches(&self.dtps_over_time(bucket_ms, name, time_range).await?)
+                    }
+                    TimeSeriesMetric::Hp => {
+                        rows_to_batches(&self.hp_over_time(bucket_ms, name, time_range).await?)
+                    }
+                }
+            }
+            ExportSource::CombatLog {
+                source_filter,
+                target_filter,
+                event_filters,
+                ..
+            } => {
+                // Every column of the matching events (including the ones the
+                // combat log hides), in log order
+                let where_clause = combat_log::combat_log_where_clauses(
+                    source_filter.as_deref(),
+                    target_filter.as_deref(),
+                    search_filter,
+                    time_range,
+                    event_filters.as_ref(),
+                )
+                .join(" AND ");
+                self.sql(&format!(
+                    "SELECT * FROM events WHERE {where_clause} ORDER BY line_number"
+                ))
+                .await
+            }
+        }
+    }
+}
+
+/// Field names of a serialized row, in serialization order
+struct FieldNames(Vec<String>);
+
+impl<'de> Deserialize<'de> for FieldNames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldNamesVisitor;
+
+        impl<'de> Visitor<'de> for FieldNamesVisitor {
+            type Value = FieldNames;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a struct")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<FieldNames, A::Error> {
+                let mut names = Vec::new();
+                while let Some(name) = map.next_key::<String>()? {
+                    map.next_value::<IgnoredAny>()?;
+                    names.push(name);
+                }
+                Ok(FieldNames(names))
+            }
+        }
+
+        deserializer.deserialize_map(FieldNamesVisitor)
+    }
+}
+
+/// Convert typed rows into Arrow batches, one column per field.
+/// Column types come from the serialized values: integers, floats, strings and
+/// bools keep their type, and fields missing from some rows (`None` with
+/// `skip_serializing_if`) are null there. No rows means no batches.
+pub fn rows_to_batches<T: Serialize>(rows: &[T]) -> Result<Vec<RecordBatch>, String> {
+    if rows.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut columns: Vec<String> = Vec::new();
+    let mut values = Vec::with_capacity(rows.len());
+    let mut json = Vec::new();
+    for row in rows {
+        let line = serde_json::to_string(row).map_err(|e| e.to_string())?;
+        // Fields only some rows have go after the field preceding them
+        let FieldNames(names) = serde_json::from_str(&line).map_err(|e| e.to_string())?;
+        let mut at = 0;
+        for name in names {
+            match columns.iter().position(|c| *c == name) {
+                Some(i) => at = i + 1,
+                None => {
+                    columns.insert(at, name);
+                    at += 1;
+                }
+            }
+        }
+        values.push(serde_json::from_str::<serde_json::Value>(&line).map_err(|e| e.to_string())?);
+        json.extend_from_slice(line.as_bytes());
+        json.push(b'\n');
+    }
+
+    let inferred =
+        infer_json_schema_from_iterator(values.iter().map(Ok)).map_err(|e| e.to_string())?;
+    let fields: Vec<Field> = columns
+        .iter()
+        .filter_map(|name| inferred.field_with_name(name).ok().cloned())
+        .collect();
+    let reader = ReaderBuilder::new(Arc::new(Schema::new(fields)))
+        .build(Cursor::new(json))
+        .map_err(|e| e.to_string())?;
+    reader
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())
+}
+
+/// Write batches to `path`. CSV drops nested columns (they have no text
+/// form). Returns the number of rows written.
+pub fn write_export(
+    batches: &[RecordBatch],
+    format: ExportFormat,
+    path: &Path,
+) -> Result<usize, String> {
+    let Some(first) = batches.first() else {
+        return Err("Nothing to export".to_string());
+    };
+    let file = File::create(path).map_err(|e| format!("Failed to create {:?}: {}", path, e))?;
+    let rows = batches.iter().map(|b| b.num_rows()).sum();
+
+    match format {
+        ExportFormat::Csv => {
+            let flat: Vec<usize> = first
+                .schema()
+                .fields()
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !f.data_type().is_nested())
+                .map(|(i, _)| i)
+                .collect();
+            let mut writer = WriterBuilder::new().with_header(true).build(file);
+            for batch in batches {
+                let batch = batch.project(&flat).map_err(|e| e.to_string())?;
+                writer.write(&batch).map_err(|e| e.to_string())?;
+            }
+        }
+        ExportFormat::Parquet => {
+            let props = WriterProperties::builder()
+                .set_compression(Compression::LZ4)
+                .build();
+            let mut writer = ArrowWriter::try_new(file, first.schema(), Some(props))
+                .map_err(|e| e.to_string())?;
+            for batch in batches {
+                writer.write(batch).map_err(|e| e.to_string())?;
+            }
+            writer.close().map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::arrow::datatypes::DataType;
+
+    #[test]
+    fn test_rows_keep_field_order_and_types() {
+        let rows = vec![
+            NpcHealthRow {
+                name: "Brontes".to_string(),
+                first_seen_secs: 0.5,
+                death_time_secs: Some(301.25),
+                max_hp: 12_000_000,
+                final_hp: 0,
+                final_hp_pct: 0.0,
+            },
+            NpcHealthRow {
+                name: "Hand, Left".to_string(),
+                first_seen_secs: 12.0,
+                death_time_secs: None,
+                max_hp: 900_000,
+                final_hp: 450_000,
+                final_hp_pct: 50.0,
+            },
+        ];
+        let batches = rows_to_batches(&rows).unwrap();
+        let schema = batches[0].schema();
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "name",
+                "first_seen_secs",
+                "death_time_secs",
+                "max_hp",
+                "final_hp",
+                "final_hp_pct"
+            ]
+        );
+        assert_eq!(schema.field(3).data_type(), &DataType::Int64);
+        assert_eq!(batches[0].num_rows(), 2);
+        assert_eq!(batches[0].column(2).null_count(), 1);
+        assert!(rows_to_batches::<NpcHealthRow>(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rows_with_and_without_optional_fields() {
+        let row = |name: &str, target: Option<&str>| AbilityBreakdown {
+            ability_name: name.to_string(),
+            ability_id: 1,
+            target_name: target.map(str::to_string),
+            target_class_id: target.map(|_| 42),
+            target_log_id: target.map(|_| 7),
+            target_first_hit_secs: target.map(|_| 1.5),
+            total_value: 1000.0,
+            hit_count: 2,
+            crit_count: 1,
+            crit_rate: 50.0,
+            max_hit: 600.0,
+            avg_hit: 500.0,
+            miss_count: 0,
+            activation_count: 2,
+            crit_total: 600.0,
+            effective_total: 900.0,
+            is_shield: target.is_none(),
+            attack_type: String::new(),
+            damage_type: String::new(),
+            shield_count: 0,
+            absorbed_total: 0.0,
+            dps: 10.0,
+            percent_of_total: 50.0,
+        };
+        // Shield rows of a by-target healing breakdown have no target
+        let rows = vec![
+            row("Static Barrier", None),
+            row("Kolto Probe", Some("Jerran")),
+            row("1337", Some("Kessa")),
+        ];
+        let batches = rows_to_batches(&rows).unwrap();
+        let batch = &batches[0];
+        let schema = batch.schema();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(schema.fields().len(), 23);
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(2).name(), "target_name");
+        assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(3).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(5).data_type(), &DataType::Float64);
+        assert_eq!(schema.field(6).data_type(), &DataType::Float64);
+        assert_eq!(
+            schema.field_with_name("is_shield").unwrap().data_type(),
+            &DataType::Boolean
+        );
+        assert_eq!(batch.column(2).null_count(), 1);
+
+        let path = std::env::temp_dir().join(format!("baras-export-{}.csv", std::process::id()));
+        assert_eq!(write_export(&batches, ExportFormat::Csv, &path), Ok(3));
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(csv.starts_with("ability_name,ability_id,target_name,"));
+        assert!(csv.contains("Static Barrier,1,,"));
+    }
+}
@@ -0,0 +1,431 @@
+//! GCD uptime ("always be casting") queries.
+//!
+//! Builds on the same on-GCD/off-GCD split as rotation analysis: every on-GCD
+//! activation locks the player for one GCD, or until the matching
+//! AbilityDeactivate for channels and casts. Time not covered by any lock is
+//! downtime. Each player's GCD is inferred from their own activation spacing.
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::game_data::{
+    GcdEstimate, OFF_GCD_ABILITIES, effect_id, estimate_from_activation_times,
+};
+
+/// An on-GCD activation (off-GCD abilities are filtered out before this point).
+struct GcdCast {
+    time_secs: f32,
+    ability_id: i64,
+    ability_name: String,
+}
+
+/// A gap between GCD locks, with indices into the cast list on either side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RawGap {
+    start: f32,
+    end: f32,
+    before: Option<usize>,
+    after: Option<usize>,
+}
+
+impl EncounterQuery<'_> {
+    /// GCD uptime and downtime gaps for one player.
+    pub async fn query_gcd_uptime(
+        &self,
+        source_name: &str,
+        time_range: Option<&TimeRange>,
+    ) -> Result<GcdUptimeAnalysis, String> {
+        let escaped = sql_escape(source_name);
+        let time_filter = time_range
+            .map(|tr| format!(" AND {}", tr.sql_filter()))
+            .unwrap_or_default();
+        let estimate = self.query_gcd_estimates().await?.remove(source_name);
+        let stats = estimate.unwrap_or(self.gcd_fallback);
+        let gcd = stats.gcd_secs;
+
+        let (window_start, window_end) = match time_range {
+            Some(tr) => (tr.start, tr.end),
+            None => (0.0, self.max_combat_time().await?),
+        };
+
+        // 1. Activations and deactivations for this player
+        let sql = format!(
+            "SELECT combat_time_secs, effect_id, ability_id, ability_name \
+             FROM events \
+             WHERE effect_id IN ({}, {}) AND source_name = '{}' \
+               AND combat_time_secs IS NOT NULL{} \
+             ORDER BY combat_time_secs ASC, line_number ASC",
+            effect_id::ABILITYACTIVATE,
+            effect_id::ABILITYDEACTIVATE,
+            escaped,
+            time_filter
+        );
+        let batches = self.sql(&sql).await?;
+        let mut casts = Vec::new();
+        let mut deactivations: HashMap<i64, Vec<f32>> = HashMap::new();
+        for batch in &batches {
+            let times = col_f32(batch, 0)?;
+            let effect_ids = col_i64(batch, 1)?;
+            let ability_ids = col_i64(batch, 2)?;
+            let names = col_strings(batch, 3)?;
+            for i in 0..batch.num_rows() {
+                if effect_ids[i] == effect_id::ABILITYDEACTIVATE {
+                    deactivations
+                        .entry(ability_ids[i])
+                        .or_default()
+                        .push(times[i]);
+                } else if !OFF_GCD_ABILITIES.contains(&ability_ids[i]) {
+                    casts.push(GcdCast {
+                        time_secs: times[i],
+                        ability_id: ability_ids[i],
+                        ability_name: names[i].clone(),
+                    });
+                }
+            }
+        }
+
+        let cast_keys: Vec<(f32, i64)> =
+            casts.iter().map(|c| (c.time_secs, c.ability_id)).collect();
+        let busy = gcd_busy_intervals(&cast_keys, &deactivations, gcd);
+        let (active_secs, raw_gaps) = uptime_and_gaps(&busy, window_start, window_end, gcd);
+
+        // 2. Logged events that can explain a gap
+        let context_events = self
+            .query_gap_context(source_name, &escaped, &time_filter)
+            .await?;
+
+        let gaps = raw_gaps
+            .into_iter()
+            .map(|g| GcdGap {
+                start_secs: g.start,
+                end_secs: g.end,
+                before_ability: g.before.map(|i| casts[i].ability_name.clone()),
+                after_ability: g.after.map(|i| casts[i].ability_name.clone()),
+                context: context_events
+                    .iter()
+                    .filter(|c| c.time_secs >= g.start && c.time_secs <= g.end)
+                    .cloned()
+                    .collect(),
+            })
+            .collect();
+
+        let window_secs = (window_end - window_start).max(0.0);
+        Ok(GcdUptimeAnalysis {
+            gcd_secs: gcd,
+            alacrity_percent: stats.alacrity_percent,
+            latency_ms: stats.latency_ms,
+            gcd_inferred: estimate.is_some(),
+            window_secs,
+            active_secs,
+            abc_pct: abc_pct(active_secs, window_secs),
+            gcd_count: casts.len() as i64,
+            gaps,
+        })
+    }
+
+    /// ABC % for every player, keyed by name (used by the raid overview).
+    pub(super) async fn query_abc_by_player(
+        &self,
+        time_range: Option<&TimeRange>,
+        duration_secs: Option<f32>,
+    ) -> Result<HashMap<String, f64>, String> {
+        let time_filter = time_range
+            .map(|tr| format!(" AND {}", tr.sql_filter()))
+            .unwrap_or_default();
+        let estimates = self.query_gcd_estimates().await?;
+
+        let (window_start, window_end) = match (time_range, duration_secs) {
+            (Some(tr), _) => (tr.start, tr.end),
+            (None, Some(d)) => (0.0, d),
+            (None, None) => (0.0, self.max_combat_time().await?),
+        };
+
+        let sql = format!(
+            "SELECT source_name, combat_time_secs, effect_id, ability_id \
+             FROM events \
+             WHERE effect_id IN ({}, {}) AND source_entity_type = 'Player' \
+               AND combat_time_secs IS NOT NULL{} \
+             ORDER BY source_name ASC, combat_time_secs ASC, line_number ASC",
+            effect_id::ABILITYACTIVATE,
+            effect_id::ABILITYDEACTIVATE,
+            time_filter
+        );
+        let batches = self.sql(&sql).await?;
+
+        type PlayerCasts = (Vec<(f32, i64)>, HashMap<i64, Vec<f32>>);
+        let mut per_player: HashMap<String, PlayerCasts> = HashMap::new();
+        for batch in &batches {
+            let names = col_strings(batch, 0)?;
+            let times = col_f32(batch, 1)?;
+            let effect_ids = col_i64(batch, 2)?;
+            let ability_ids = col_i64(batch, 3)?;
+            for i in 0..batch.num_rows() {
+                let (casts, deactivations) = per_player.entry(names[i].clone()).or_default();
+                if effect_ids[i] == effect_id::ABILITYDEACTIVATE {
+                    deactivations
+                        .entry(ability_ids[i])
+                        .or_default()
+                        .push(times[i]);
+                } else if !OFF_GCD_ABILITIES.contains(&ability_ids[i]) {
+                    casts.push((times[i], ability_ids[i]));
+                }
+            }
+        }
+
+        let window_secs = (window_end - window_start).max(0.0);
+        Ok(per_player
+            .into_iter()
+            .map(|(name, (casts, deactivations))| {
+                let gcd = estimates.get(&name).unwrap_or(&self.gcd_fallback).gcd_secs;
+                let busy = gcd_busy_intervals(&casts, &deactivations, gcd);
+                let (active, _) = uptime_and_gaps(&busy, window_start, window_end, gcd);
+                (name, abc_pct(active, window_secs) as f64)
+            })
+            .collect())
+    }
+
+    /// Per-player GCD/alacrity/latency inferred from on-GCD activation spacing
+    /// over the whole encounter. Players with too few casts are omitted; callers
+    /// use the configured `gcd_fallback` for them.
+    pub(super) async fn query_gcd_estimates(&self) -> Result<HashMap<String, GcdEstimate>, String> {
+        let sql = format!(
+            "SELECT source_name, combat_time_secs, ability_id \
+             FROM events \
+             WHERE effect_id = {} AND source_entity_type = 'Player' \
+               AND combat_time_secs IS NOT NULL \
+             ORDER BY source_name ASC, combat_time_secs ASC, line_number ASC",
+            effect_id::ABILITYACTIVATE,
+        );
+        let mut times_by_player: HashMap<String, Vec<f32>> = HashMap::new();
+        for batch in &self.sql(&sql).await? {
+            let names = col_strings(batch, 0)?;
+            let times = col_f32(batch, 1)?;
+            let ability_ids = col_i64(batch, 2)?;
+            for i in 0..batch.num_rows() {
+                if !OFF_GCD_ABILITIES.contains(&ability_ids[i]) {
+                    times_by_player
+                        .entry(names[i].clone())
+                        .or_default()
+                        .push(times[i]);
+                }
+            }
+        }
+        Ok(times_by_player
+            .into_iter()
+            .filter_map(|(name, times)| Some((name, estimate_from_activation_times(&times)?)))
+            .collect())
+    }
+
+    /// Latest combat time in the registered encounter.
+    async fn max_combat_time(&self) -> Result<f32, String> {
+        Ok(scalar_f32(
+            &self
+                .sql("SELECT COALESCE(MAX(combat_time_secs), 0) FROM events WHERE combat_time_secs IS NOT NULL")
+                .await?,
+        ))
+    }
+
+    /// Deaths, revives, interrupts, cancels, target drops and phase changes
+    /// relevant to a player, ordered by time.
+    async fn query_gap_context(
+        &self,
+        source_name: &str,
+        escaped: &str,
+        time_filter: &str,
+    ) -> Result<Vec<GcdGapContext>, String> {
+        let sql = format!(
+            "SELECT combat_time_secs, effect_id, ability_name, source_name, target_name \
+             FROM events \
+             WHERE effect_id IN ({death}, {revived}, {interrupt}, {cancel}, {cleared}) \
+               AND (source_name = '{escaped}' OR target_name = '{escaped}') \
+               AND combat_time_secs IS NOT NULL{time_filter} \
+             ORDER BY combat_time_secs ASC, line_number ASC",
+            death = effect_id::DEATH,
+            revived = effect_id::REVIVED,
+            interrupt = effect_id::ABILITYINTERRUPT,
+            cancel = effect_id::ABILITYCANCEL,
+            cleared = effect_id::TARGETCLEARED,
+        );
+        let batches = self.sql(&sql).await?;
+        let mut context = Vec::new();
+        for batch in &batches {
+            let times = col_f32(batch, 0)?;
+            let effect_ids = col_i64(batch, 1)?;
+            let ability_names = col_strings(batch, 2)?;
+            let sources = col_strings(batch, 3)?;
+            let targets = col_strings(batch, 4)?;
+            for i in 0..batch.num_rows() {
+                let is_source = sources[i] == source_name;
+                let is_target = targets[i] == source_name;
+                let (kind, detail) = match effect_ids[i] {
+                    id if id == effect_id::DEATH && is_target => (GcdGapKind::Death, String::new()),
+                    id if id == effect_id::REVIVED && is_target => {
+                        (GcdGapKind::Revived, String::new())
+                    }
+                    id if id == effect_id::ABILITYINTERRUPT => {
+                        (GcdGapKind::Interrupted, ability_names[i].clone())
+                    }
+                    id if id == effect_id::ABILITYCANCEL && is_source => {
+                        (GcdGapKind::Cancelled, ability_names[i].clone())
+                    }
+                    id if id == effect_id::TARGETCLEARED && is_source => {
+                        (GcdGapKind::TargetCleared, String::new())
+                    }
+                    _ => continue,
+                };
+                context.push(GcdGapContext {
+                    kind,
+                    time_secs: times[i],
+                    detail,
+                });
+            }
+        }
+
+        // Phase transitions (encounter-wide, not player-specific)
+        let sql = format!(
+            "SELECT t, phase FROM ( \
+                SELECT combat_time_secs AS t, COALESCE(phase_name, '') AS phase, \
+                       LAG(COALESCE(phase_name, '')) OVER (ORDER BY line_number) AS prev \
+                FROM events WHERE combat_time_secs IS NOT NULL{time_filter} \
+             ) WHERE prev IS NOT NULL AND phase != prev \
+             ORDER BY t ASC"
+        );
+        for batch in &self.sql(&sql).await? {
+            let times = col_f32(batch, 0)?;
+            let phases = col_strings(batch, 1)?;
+            for (time_secs, phase) in times.into_iter().zip(phases) {
+                context.push(GcdGapContext {
+                    kind: GcdGapKind::PhaseTransition,
+                    time_secs,
+                    detail: phase,
+                });
+            }
+        }
+
+        context.sort_by(|a, b| a.time_secs.total_cmp(&b.time_secs));
+        Ok(context)
+    }
+}
+
+fn abc_pct(active_secs: f32, window_secs: f32) -> f32 {
+    if window_secs > 0.0 {
+        (active_secs / window_secs * 100.0).min(100.0)
+    } else {
+        0.0
+    }
+}
+
+/// Lock interval for each on-GCD cast: one GCD, extended to the matching
+/// deactivation (channel/cast end) if one arrives before the next cast.
+fn gcd_busy_intervals(
+    casts: &[(f32, i64)],
+    deactivations: &HashMap<i64, Vec<f32>>,
+    gcd: f32,
+) -> Vec<(f32, f32)> {
+    casts
+        .iter()
+        .enumerate()
+        .map(|(i, &(t, ability_id))| {
+            let next = casts.get(i + 1).map(|c| c.0).unwrap_or(f32::INFINITY);
+            let channel_end = deactivations.get(&ability_id).and_then(|times| {
+                let pos = times.partition_point(|&d| d <= t);
+                times.get(pos).copied().filter(|&d| d <= next)
+            });
+            (t, channel_end.map_or(t + gcd, |d| d.max(t + gcd)))
+        })
+        .collect()
+}
+
+/// Union the busy intervals inside the window. Returns the active time and
+/// every uncovered stretch longer than one GCD.
+fn uptime_and_gaps(
+    busy: &[(f32, f32)],
+    window_start: f32,
+    window_end: f32,
+    gcd: f32,
+) -> (f32, Vec<RawGap>) {
+    let mut active = 0.0;
+    let mut gaps = Vec::new();
+    let mut cursor = window_start;
+    let mut before = None;
+
+    for (i, &(start, end)) in busy.iter().enumerate() {
+        let start = start.clamp(window_start, window_end);
+        let end = end.clamp(window_start, window_end);
+        if start - cursor > gcd {
+            gaps.push(RawGap {
+                start: cursor,
+                end: start,
+                before,
+                after: Some(i),
+            });
+        }
+        active += (end - start.max(cursor)).max(0.0);
+        cursor = cursor.max(end);
+        before = Some(i);
+    }
+
+    if window_end - cursor > gcd {
+        gaps.push(RawGap {
+            start: cursor,
+            end: window_end,
+            before,
+            after: None,
+        });
+    }
+
+    (active, gaps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_back_to_back_casts_are_fully_active() {
+        let casts: Vec<(f32, i64)> = (0..10).map(|i| (i as f32 * 1.5, 1)).collect();
+        let busy = gcd_busy_intervals(&casts, &HashMap::new(), 1.5);
+        let (active, gaps) = uptime_and_gaps(&busy, 0.0, 15.0, 1.5);
+        assert!((active - 15.0).abs() < 0.001);
+        assert!(gaps.is_empty());
+    }
+
+    #[test]
+    fn test_channel_extends_lock_until_deactivate() {
+        let casts = vec![(0.0, 1), (3.0, 2)];
+        let mut deactivations = HashMap::new();
+        deactivations.insert(1, vec![3.0]);
+        let busy = gcd_busy_intervals(&casts, &deactivations, 1.5);
+        assert_eq!(busy[0], (0.0, 3.0));
+        let (active, gaps) = uptime_and_gaps(&busy, 0.0, 4.5, 1.5);
+        assert!((active - 4.5).abs() < 0.001);
+        assert!(gaps.is_empty());
+    }
+
+    #[test]
+    fn test_reports_gaps_longer_than_one_gcd() {
+        let casts = vec![(0.0, 1), (1.5, 1), (6.0, 1), (7.0, 1)];
+        let busy = gcd_busy_intervals(&casts, &HashMap::new(), 1.5);
+        let (active, gaps) = uptime_and_gaps(&busy, 0.0, 12.0, 1.5);
+        // 0-3 and 6-8.5 covered
+        assert!((active - 5.5).abs() < 0.001);
+        assert_eq!(
+            gaps,
+            vec![
+                RawGap {
+                    start: 3.0,
+                    end: 6.0,
+                    before: Some(1),
+                    after: Some(2),
+                },
+                RawGap {
+                    start: 8.5,
+                    end: 12.0,
+                    before: Some(3),
+                    after: None,
+                },
+            ]
+        );
+        assert!((abc_pct(active, 12.0) - 45.833).abs() < 0.01);
+    }
+}
@@ -0,0 +1,186 @@
+//! Healing matrix queries (healer × recipient).
+
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+/// Friendly entity types that can give or receive healing.
+const FRIENDLY_TYPES: &str = "('Player', 'Companion')";
+
+impl EncounterQuery<'_> {
+    /// Build a healer × recipient matrix of raw, effective and overhealing plus
+    /// shield absorption.
+    ///
+    /// Shield absorption uses the same capacity-aware split as the raid overview,
+    /// credited to each shield's caster on the damaged target.
+    pub async fn query_healing_matrix(
+        &self,
+        time_range: Option<&TimeRange>,
+    ) -> Result<HealingMatrix, String> {
+        let time_filter = time_range
+            .map(|tr| format!("AND {}", tr.sql_filter()))
+            .unwrap_or_default();
+
+        let mut cells: HashMap<(String, String), HealingMatrixCell> = HashMap::new();
+
+        // 1. Direct healing per (source, target)
+        let batches = self
+            .sql(&format!(
+                r#"
+            SELECT source_name, target_name,
+                   SUM(heal_amount) as raw_heal,
+                   SUM(heal_effective) as effective_heal
+            FROM events
+            WHERE heal_amount > 0
+              AND source_entity_type IN {FRIENDLY_TYPES}
+              AND target_entity_type IN {FRIENDLY_TYPES} {time_filter}
+            GROUP BY source_name, target_name
+        "#
+            ))
+            .await?;
+        for batch in &batches {
+            let sources = col_strings(batch, 0)?;
+            let targets = col_strings(batch, 1)?;
+            let raw = col_f64(batch, 2)?;
+            let effective = col_f64(batch, 3)?;
+            for i in 0..batch.num_rows() {
+                let c = matrix_cell(&mut cells, &sources[i], &targets[i]);
+                c.raw_heal += raw[i];
+                c.effective_heal += effective[i];
+                c.overheal += (raw[i] - effective[i]).max(0.0);
+            }
+        }
+
+        // 2. Shield absorption per (shield source, damaged target), friendlies only
+        let credits = self.query_shield_credits(time_range).await?;
+        if !credits.is_empty() {
+            let entity_names = self.get_entity_names().await?;
+            let friendly = self.friendly_entity_ids().await?;
+            for credit in &credits {
+                if !friendly.contains(&credit.source_id) || !friendly.contains(&credit.target_id) {
+                    continue;
+                }
+                if let Some(source) = entity_names.get(&credit.source_id) {
+                    matrix_cell(&mut cells, source, &credit.target_name).shield_absorbed +=
+                        credit.amount as f64;
+                }
+            }
+        }
+
+        Ok(build_matrix(cells.into_values().collect()))
+    }
+
+    /// IDs of all players and companions seen as a source or target.
+    async fn friendly_entity_ids(&self) -> Result<HashSet<i64>, String> {
+        let batches = self
+            .sql(&format!(
+                r#"
+            SELECT source_id AS id FROM events WHERE source_entity_type IN {FRIENDLY_TYPES}
+            UNION
+            SELECT target_id AS id FROM events WHERE target_entity_type IN {FRIENDLY_TYPES}
+        "#
+            ))
+            .await?;
+        let mut ids = HashSet::new();
+        for batch in &batches {
+            ids.extend(col_i64(batch, 0)?);
+        }
+        Ok(ids)
+    }
+}
+
+/// Get or create the cell for a (source, target) pair.
+fn matrix_cell<'a>(
+    cells: &'a mut HashMap<(String, String), HealingMatrixCell>,
+    source: &str,
+    target: &str,
+) -> &'a mut HealingMatrixCell {
+    cells
+        .entry((source.to_string(), target.to_string()))
+        .or_insert_with(|| HealingMatrixCell {
+            source_name: source.to_string(),
+            target_name: target.to_string(),
+            raw_heal: 0.0,
+            effective_heal: 0.0,
+            overheal: 0.0,
+            shield_absorbed: 0.0,
+        })
+}
+
+/// Sort healers and recipients by total contribution and order cells to match.
+fn build_matrix(mut cells: Vec<HealingMatrixCell>) -> HealingMatrix {
+    let mut given: HashMap<String, f64> = HashMap::new();
+    let mut received: HashMap<String, f64> = HashMap::new();
+    for c in &cells {
+        let total = c.effective_heal + c.shield_absorbed;
+        *given.entry(c.source_name.clone()).or_default() += total;
+        *received.entry(c.target_name.clone()).or_default() += total;
+    }
+
+    let sorted_names = |totals: HashMap<String, f64>| {
+        let mut names: Vec<(String, f64)> = totals.into_iter().collect();
+        names.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        names.into_iter().map(|(n, _)| n).collect::<Vec<_>>()
+    };
+    let healers = sorted_names(given);
+    let recipients = sorted_names(received);
+
+    let healer_idx: HashMap<&str, usize> = healers
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.as_str(), i))
+        .collect();
+    let recipient_idx: HashMap<&str, usize> = recipients
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.as_str(), i))
+        .collect();
+    cells.sort_by_key(|c| {
+        (
+            healer_idx[c.source_name.as_str()],
+            recipient_idx[c.target_name.as_str()],
+        )
+    });
+
+    HealingMatrix {
+        healers,
+        recipients,
+        cells,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(source: &str, target: &str, effective: f64, shield: f64) -> HealingMatrixCell {
+        HealingMatrixCell {
+            source_name: source.to_string(),
+            target_name: target.to_string(),
+            raw_heal: effective,
+            effective_heal: effective,
+            overheal: 0.0,
+            shield_absorbed: shield,
+        }
+    }
+
+    #[test]
+    fn test_orders_by_total_contribution() {
+        let matrix = build_matrix(vec![
+            cell("Small", "Tank", 100.0, 0.0),
+            cell("Big", "Dps", 50.0, 0.0),
+            cell("Big", "Tank", 500.0, 300.0),
+        ]);
+        assert_eq!(matrix.healers, vec!["Big", "Small"]);
+        assert_eq!(matrix.recipients, vec!["Tank", "Dps"]);
+        let order: Vec<_> = matrix
+            .cells
+            .iter()
+            .map(|c| (c.source_name.as_str(), c.target_name.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![("Big", "Tank"), ("Big", "Dps"), ("Small", "Tank")]
+        );
+    }
+}
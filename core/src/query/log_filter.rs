@@ -0,0 +1,725 @@
+//! Combat log filter language.
+//!
+//! A small query language for the combat log tab, compiled into a SQL
+//! predicate over the `events` table:
+//!
+//! - Bare words and `"quoted phrases"` search source, target, ability and
+//!   effect names (case-insensitive substring)
+//! - `source:` / `target:` match a name, or `player`, `companion`, `npc`,
+//!   `friendly` or `boss` (quote a value to match it as a name)
+//! - `ability:` / `effect:` match a name or a numeric ID
+//! - `type:` matches an event kind (`damage`, `heal`, `action`, `apply`,
+//!   `remove`, `death`, `interrupt`, `other`)
+//! - `dmgtype:` matches a damage type, `phase:` a phase name
+//! - `time:1:30-2:45` limits to a combat time range
+//! - `amount`, `effective`, `absorbed`, `overheal`, `threat`, `time`,
+//!   `source_hp` and `target_hp` compare with `>`, `>=`, `<`, `<=`, `=`, `!=`
+//!   (numbers accept `k`/`m` suffixes, times accept `m:ss`)
+//! - `crit` and `reflect` match flagged events
+//! - Terms combine with `AND` (or juxtaposition), `OR`, `NOT` / `!` and
+//!   parentheses
+//!
+//! Example: `crit amount>100k target:Tanky source:npc !source:boss`
+
+use std::fmt;
+
+use thiserror::Error;
+
+use super::sql_escape;
+use crate::game_data::{effect_id, effect_type_id};
+
+/// A syntax error, with the character position it was found at
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("{message} (at column {})", position + 1)]
+pub struct LogFilterError {
+    pub message: String,
+    pub position: usize,
+}
+
+/// Numeric column a comparison can target
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumericField {
+    Amount,
+    Effective,
+    Absorbed,
+    Overheal,
+    Threat,
+    Time,
+    SourceHp,
+    TargetHp,
+}
+
+impl NumericField {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "amount" => Self::Amount,
+            "effective" | "value" => Self::Effective,
+            "absorbed" => Self::Absorbed,
+            "overheal" => Self::Overheal,
+            "threat" => Self::Threat,
+            "time" => Self::Time,
+            "source_hp" => Self::SourceHp,
+            "target_hp" => Self::TargetHp,
+            _ => return None,
+        })
+    }
+
+    fn sql(self) -> &'static str {
+        match self {
+            Self::Amount => "(COALESCE(dmg_amount, 0) + COALESCE(heal_amount, 0))",
+            Self::Effective => "(COALESCE(dmg_effective, 0) + COALESCE(heal_effective, 0))",
+            Self::Absorbed => "COALESCE(dmg_absorbed, 0)",
+            Self::Overheal => "GREATEST(COALESCE(heal_amount, 0) - COALESCE(heal_effective, 0), 0)",
+            Self::Threat => "COALESCE(threat, 0.0)",
+            Self::Time => "combat_time_secs",
+            Self::SourceHp => "source_hp",
+            Self::TargetHp => "target_hp",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl CompareOp {
+    fn sql(self) -> &'static str {
+        match self {
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Eq => "=",
+            Self::Ne => "<>",
+        }
+    }
+}
+
+/// Which side of an event an entity term applies to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side {
+    Source,
+    Target,
+}
+
+impl Side {
+    fn column(self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::Target => "target",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum EntityKind {
+    Player,
+    Companion,
+    Npc,
+    Friendly,
+    Boss,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EventKind {
+    Damage,
+    Heal,
+    Action,
+    Apply,
+    Remove,
+    Death,
+    Interrupt,
+    Other,
+}
+
+impl EventKind {
+    const NAMES: &'static str = "damage, heal, action, apply, remove, death, interrupt, other";
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "damage" | "dmg" => Self::Damage,
+            "heal" | "healing" => Self::Heal,
+            "action" | "actions" | "activate" => Self::Action,
+            "apply" | "applied" | "gained" => Self::Apply,
+            "remove" | "removed" | "lost" => Self::Remove,
+            "death" | "died" => Self::Death,
+            "interrupt" | "interrupted" => Self::Interrupt,
+            "other" => Self::Other,
+            _ => return None,
+        })
+    }
+
+    fn sql(self) -> String {
+        let actions = format!(
+            "{}, {}, {}",
+            effect_id::ABILITYACTIVATE,
+            effect_id::ABILITYDEACTIVATE,
+            effect_id::ABILITYINTERRUPT
+        );
+        match self {
+            Self::Damage => format!("effect_id = {}", effect_id::DAMAGE),
+            Self::Heal => format!("effect_id = {}", effect_id::HEAL),
+            Self::Action => format!(
+                "(effect_type_id = {} AND effect_id IN ({}))",
+                effect_type_id::EVENT,
+                actions
+            ),
+            Self::Apply | Self::Remove => format!(
+                "(effect_type_id = {} AND effect_id NOT IN ({}, {}))",
+                if self == Self::Apply {
+                    effect_type_id::APPLYEFFECT
+                } else {
+                    effect_type_id::REMOVEEFFECT
+                },
+                effect_id::DAMAGE,
+                effect_id::HEAL
+            ),
+            Self::Death => format!("effect_id = {}", effect_id::DEATH),
+            Self::Interrupt => format!("effect_id = {}", effect_id::ABILITYINTERRUPT),
+            Self::Other => format!(
+                "(effect_type_id = {} AND effect_id NOT IN ({}))",
+                effect_type_id::EVENT,
+                actions
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    /// Substring of any name column
+    Text(String),
+    EntityName(Side, String),
+    EntityKind(Side, EntityKind),
+    AbilityName(String),
+    AbilityId(i64),
+    EffectName(String),
+    EffectId(i64),
+    Event(EventKind),
+    DamageType(String),
+    Phase(String),
+    Compare(NumericField, CompareOp, f64),
+    TimeRange(f64, f64),
+    Crit,
+    Reflect,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Term(Term),
+    Not(Box<Expr>),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+}
+
+/// A parsed combat log filter
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFilter {
+    expr: Option<Expr>,
+    /// NPC IDs (`source_class_id` / `target_class_id`) that `boss` matches
+    boss_npc_ids: Vec<i64>,
+}
+
+impl LogFilter {
+    /// Parse a filter. An empty or blank input matches everything.
+    pub fn parse(input: &str) -> Result<Self, LogFilterError> {
+        let mut parser = Parser {
+            chars: input.chars().collect(),
+            pos: 0,
+        };
+        parser.skip_ws();
+        let expr = if parser.at_end() {
+            None
+        } else {
+            let expr = parser.parse_or()?;
+            parser.skip_ws();
+            if !parser.at_end() {
+                return Err(parser.error("unexpected ')'"));
+            }
+            Some(expr)
+        };
+        Ok(Self {
+            expr,
+            boss_npc_ids: Vec::new(),
+        })
+    }
+
+    /// Set the NPC IDs that `source:boss` / `target:boss` match
+    pub fn with_boss_npc_ids(mut self, ids: Vec<i64>) -> Self {
+        self.boss_npc_ids = ids;
+        self
+    }
+
+    /// SQL predicate for the filter
+    pub fn to_sql(&self) -> String {
+        match &self.expr {
+            Some(expr) => self.expr_sql(expr),
+            None => "1=1".to_string(),
+        }
+    }
+
+    fn expr_sql(&self, expr: &Expr) -> String {
+        match expr {
+            Expr::Term(term) => self.term_sql(term),
+            Expr::Not(inner) => format!("(NOT {})", self.expr_sql(inner)),
+            Expr::And(parts) => self.join_sql(parts, " AND "),
+            Expr::Or(parts) => self.join_sql(parts, " OR "),
+        }
+    }
+
+    fn join_sql(&self, parts: &[Expr], op: &str) -> String {
+        let parts: Vec<String> = parts.iter().map(|p| self.expr_sql(p)).collect();
+        format!("({})", parts.join(op))
+    }
+
+    fn term_sql(&self, term: &Term) -> String {
+        let like = |column: &str, value: &str| {
+            format!(
+                "LOWER(COALESCE({}, '')) LIKE '%{}%' ESCAPE '\\'",
+                column,
+                sql_escape(&like_escape(&value.to_lowercase()))
+            )
+        };
+        match term {
+            Term::Text(text) => format!(
+                "({} OR {} OR {} OR {})",
+                like("source_name", text),
+                like("target_name", text),
+                like("ability_name", text),
+                like("effect_name", text)
+            ),
+            Term::EntityName(side, name) => like(&format!("{}_name", side.column()), name),
+            Term::EntityKind(side, kind) => {
+                let side = side.column();
+                match kind {
+                    EntityKind::Player => format!("{}_entity_type = 'Player'", side),
+                    EntityKind::Companion => format!("{}_entity_type = 'Companion'", side),
+                    EntityKind::Npc => format!("{}_entity_type = 'Npc'", side),
+                    EntityKind::Friendly => {
+                        format!("{}_entity_type IN ('Player', 'Companion')", side)
+                    }
+                    EntityKind::Boss if self.boss_npc_ids.is_empty() => "1=0".to_string(),
+                    EntityKind::Boss => {
+                        let ids: Vec<String> =
+                            self.boss_npc_ids.iter().map(|id| id.to_string()).collect();
+                        format!(
+                            "({0}_entity_type = 'Npc' AND {0}_class_id IN ({1}))",
+                            side,
+                            ids.join(", ")
+                        )
+                    }
+                }
+            }
+            Term::AbilityName(name) => like("ability_name", name),
+            Term::AbilityId(id) => format!("ability_id = {}", id),
+            Term::EffectName(name) => like("effect_name", name),
+            Term::EffectId(id) => format!("effect_id = {}", id),
+            Term::Event(kind) => kind.sql(),
+            Term::DamageType(name) => like("dmg_type", name),
+            Term::Phase(name) => like("phase_name", name),
+            Term::Compare(field, op, value) => {
+                format!("{} {} {}", field.sql(), op.sql(), value)
+            }
+            Term::TimeRange(start, end) => {
+                format!("combat_time_secs BETWEEN {} AND {}", start, end)
+            }
+            Term::Crit => "COALESCE(is_crit, false)".to_string(),
+            Term::Reflect => "COALESCE(is_reflect, false)".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for LogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_sql())
+    }
+}
+
+/// Recursive descent parser over the input characters
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self, message: impl Into<String>) -> LogFilterError {
+        LogFilterError {
+            message: message.into(),
+            position: self.pos,
+        }
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consume an operator keyword (`AND`, `OR`, `NOT`) or symbol if next
+    fn eat_keyword(&mut self, keyword: &str, symbol: &str) -> bool {
+        let rest: String = self.chars[self.pos..]
+            .iter()
+            .take(keyword.len() + 1)
+            .collect();
+        if rest.starts_with(keyword)
+            && rest[keyword.len()..]
+                .chars()
+                .next()
+                .is_none_or(|c| c.is_whitespace() || c == '(')
+        {
+            self.pos += keyword.len();
+            return true;
+        }
+        if rest.starts_with(symbol) {
+            self.pos += symbol.chars().count();
+            return true;
+        }
+        false
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, LogFilterError> {
+        let mut parts = vec![self.parse_and()?];
+        loop {
+            self.skip_ws();
+            if !self.eat_keyword("OR", "||") {
+                break;
+            }
+            parts.push(self.parse_and()?);
+        }
+        Ok(if parts.len() == 1 {
+            parts.remove(0)
+        } else {
+            Expr::Or(parts)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, LogFilterError> {
+        let mut parts = vec![self.parse_unary()?];
+        loop {
+            self.skip_ws();
+            if self.at_end() || self.peek() == Some(')') {
+                break;
+            }
+            let before = self.pos;
+            if self.eat_keyword("OR", "||") {
+                self.pos = before;
+                break;
+            }
+            // `AND` is optional between terms
+            self.eat_keyword("AND", "&&");
+            parts.push(self.parse_unary()?);
+        }
+        Ok(if parts.len() == 1 {
+            parts.remove(0)
+        } else {
+            Expr::And(parts)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, LogFilterError> {
+        self.skip_ws();
+        if self.eat_keyword("NOT", "!") {
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, LogFilterError> {
+        self.skip_ws();
+        match self.peek() {
+            None => Err(self.error("expected a filter term")),
+            Some('(') => {
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                self.skip_ws();
+                if self.peek() != Some(')') {
+                    return Err(self.error("expected ')'"));
+                }
+                self.pos += 1;
+                Ok(expr)
+            }
+            Some(')') => Err(self.error("unexpected ')'")),
+            Some(_) => Ok(Expr::Term(self.parse_term()?)),
+        }
+    }
+
+    /// A quoted string (returns None if not at a quote)
+    fn parse_quoted(&mut self) -> Result<Option<String>, LogFilterError> {
+        if self.peek() != Some('"') {
+            return Ok(None);
+        }
+        let start = self.pos;
+        self.pos += 1;
+        let mut value = String::new();
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == '"' {
+                return Ok(Some(value));
+            }
+            value.push(c);
+        }
+        self.pos = start;
+        Err(self.error("unterminated quote"))
+    }
+
+    /// Characters up to whitespace or a parenthesis
+    fn parse_word(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && c != '(' && c != ')')
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn parse_term(&mut self) -> Result<Term, LogFilterError> {
+        let start = self.pos;
+        if let Some(text) = self.parse_quoted()? {
+            return Ok(Term::Text(text));
+        }
+
+        // Field name, then `:` or a comparison operator
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.pos += 1;
+        }
+        let name: String = self.chars[start..self.pos].iter().collect();
+        let field = name.to_lowercase();
+
+        if !name.is_empty() && self.peek() == Some(':') {
+            self.pos += 1;
+            return self.parse_field(start, &field);
+        }
+        if !name.is_empty()
+            && let Some(op) = self.parse_compare_op()
+        {
+            let Some(numeric) = NumericField::from_name(&field) else {
+                self.pos = start;
+                return Err(self.error(format!("'{}' can't be compared", name)));
+            };
+            let value_start = self.pos;
+            let value = self.parse_word();
+            let value = parse_number(&value, numeric == NumericField::Time).ok_or_else(|| {
+                LogFilterError {
+                    message: format!("expected a number after '{}'", name),
+                    position: value_start,
+                }
+            })?;
+            return Ok(Term::Compare(numeric, op, value));
+        }
+
+        // Bare word: flag or text search
+        self.pos = start;
+        let word = self.parse_word();
+        Ok(match word.to_lowercase().as_str() {
+            "crit" | "crits" => Term::Crit,
+            "reflect" | "reflected" => Term::Reflect,
+            _ => Term::Text(word),
+        })
+    }
+
+    fn parse_compare_op(&mut self) -> Option<CompareOp> {
+        let two: String = self.chars[self.pos..].iter().take(2).collect();
+        let (op, len) = match two.as_str() {
+            ">=" => (CompareOp::Ge, 2),
+            "<=" => (CompareOp::Le, 2),
+            "!=" => (CompareOp::Ne, 2),
+            _ => match self.peek()? {
+                '>' => (CompareOp::Gt, 1),
+                '<' => (CompareOp::Lt, 1),
+                '=' => (CompareOp::Eq, 1),
+                _ => return None,
+            },
+        };
+        self.pos += len;
+        Some(op)
+    }
+
+    fn parse_field(&mut self, start: usize, field: &str) -> Result<Term, LogFilterError> {
+        let value_start = self.pos;
+        let (value, quoted) = match self.parse_quoted()? {
+            Some(value) => (value, true),
+            None => (self.parse_word(), false),
+        };
+        if value.is_empty() {
+            return Err(self.error(format!("expected a value after '{}:'", field)));
+        }
+        let value_error = |message: String| LogFilterError {
+            message,
+            position: value_start,
+        };
+        let lower = value.to_lowercase();
+
+        let entity = |side: Side| {
+            let kind = match lower.as_str() {
+                _ if quoted => None,
+                "player" | "players" => Some(EntityKind::Player),
+                "companion" | "companions" => Some(EntityKind::Companion),
+                "npc" | "npcs" => Some(EntityKind::Npc),
+                "friendly" => Some(EntityKind::Friendly),
+                "boss" | "bosses" => Some(EntityKind::Boss),
+                _ => None,
+            };
+            match kind {
+                Some(kind) => Term::EntityKind(side, kind),
+                None => Term::EntityName(side, value.clone()),
+            }
+        };
+
+        Ok(match field {
+            "source" | "src" => entity(Side::Source),
+            "target" | "tgt" => entity(Side::Target),
+            "ability" => match value.parse::<i64>() {
+                Ok(id) if !quoted => Term::AbilityId(id),
+                _ => Term::AbilityName(value),
+            },
+            "effect" => match value.parse::<i64>() {
+                Ok(id) if !quoted => Term::EffectId(id),
+                _ => Term::EffectName(value),
+            },
+            "type" => Term::Event(EventKind::from_name(&lower).ok_or_else(|| {
+                value_error(format!(
+                    "unknown event type '{}' (expected {})",
+                    value,
+                    EventKind::NAMES
+                ))
+            })?),
+            "dmgtype" | "damage_type" => Term::DamageType(value),
+            "phase" => Term::Phase(value),
+            "time" => {
+                let range = value
+                    .split_once('-')
+                    .and_then(|(a, b)| Some((parse_number(a, true)?, parse_number(b, true)?)));
+                let Some((from, to)) = range else {
+                    return Err(value_error(
+                        "expected a time range like 'time:1:30-2:45'".to_string(),
+                    ));
+                };
+                Term::TimeRange(from.min(to), from.max(to))
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error(format!(
+                    "unknown field '{}' (expected source, target, ability, effect, type, dmgtype, phase or time)",
+                    field
+                )));
+            }
+        })
+    }
+}
+
+/// Parse a number with an optional `k`/`m` suffix, or `m:ss` if `time`
+fn parse_number(text: &str, time: bool) -> Option<f64> {
+    let text = text.trim();
+    if time && let Some((mins, secs)) = text.split_once(':') {
+        let mins: f64 = mins.parse().ok()?;
+        let secs: f64 = secs.parse().ok()?;
+        return Some(mins * 60.0 + secs);
+    }
+    let lower = text.to_lowercase();
+    let (digits, scale) = if let Some(d) = lower.strip_suffix('k') {
+        (d, 1_000.0)
+    } else if let Some(d) = lower.strip_suffix('m') {
+        (d, 1_000_000.0)
+    } else {
+        (lower.as_str(), 1.0)
+    };
+    let value: f64 = digits.parse().ok()?;
+    value.is_finite().then_some(value * scale)
+}
+
+/// Escape LIKE wildcards so user text matches literally (with `ESCAPE '\'`)
+fn like_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compiles_fields_comparisons_and_operators() {
+        let filter = LogFilter::parse("crit amount>100k target:Tanky source:npc !source:boss")
+            .unwrap()
+            .with_boss_npc_ids(vec![101, 102]);
+        assert_eq!(
+            filter.to_sql(),
+            "(COALESCE(is_crit, false) AND (COALESCE(dmg_amount, 0) + COALESCE(heal_amount, 0)) > 100000 \
+             AND LOWER(COALESCE(target_name, '')) LIKE '%tanky%' ESCAPE '\\' AND source_entity_type = 'Npc' \
+             AND (NOT (source_entity_type = 'Npc' AND source_class_id IN (101, 102))))"
+        );
+
+        // The old "a OR b" search syntax still works
+        let filter = LogFilter::parse("Kolto OR O'Brien").unwrap();
+        assert!(
+            filter
+                .to_sql()
+                .starts_with("((LOWER(COALESCE(source_name, '')) LIKE '%kolto%'")
+        );
+        assert!(filter.to_sql().contains("'%o''brien%'"));
+
+        let filter =
+            LogFilter::parse(r#"(type:heal OR ability:"Kolto Probe") AND time:1:30-2:00"#).unwrap();
+        assert_eq!(
+            filter.to_sql(),
+            format!(
+                "((effect_id = {} OR LOWER(COALESCE(ability_name, '')) LIKE '%kolto probe%' ESCAPE '\\') \
+                 AND combat_time_secs BETWEEN 90 AND 120)",
+                effect_id::HEAL
+            )
+        );
+
+        assert_eq!(LogFilter::parse("  ").unwrap().to_sql(), "1=1");
+    }
+
+    #[test]
+    fn test_search_text_matches_wildcards_literally() {
+        let filter = LogFilter::parse(r"ability:100%_\x").unwrap();
+        assert_eq!(
+            filter.to_sql(),
+            r"LOWER(COALESCE(ability_name, '')) LIKE '%100\%\_\\x%' ESCAPE '\'"
+        );
+    }
+
+    #[test]
+    fn test_reports_syntax_errors_with_position() {
+        let err = LogFilter::parse("(crit OR heal").unwrap_err();
+        assert_eq!(err.message, "expected ')'");
+        assert_eq!(err.position, 13);
+
+        let err = LogFilter::parse("crit type:dance").unwrap_err();
+        assert!(err.message.starts_with("unknown event type 'dance'"));
+        assert_eq!(err.position, 10);
+
+        let err = LogFilter::parse("amount>lots").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a number after 'amount' (at column 8)"
+        );
+
+        assert!(LogFilter::parse("colour:red").is_err());
+        assert!(LogFilter::parse("source:\"Brontes").is_err());
+        assert!(LogFilter::parse("crit)").is_err());
+        assert!(LogFilter::parse("NOT").is_err());
+    }
+}
@@ -7,14 +7,22 @@
 mod breakdown;
 mod column_helpers;
 mod combat_log;
+mod cooldowns;
 mod effects;
+mod export;
 pub mod error;
+mod gcd;
+mod healing;
+mod log_filter;
 mod overview;
 mod rotation;
+mod shielding;
 mod time_series;
 mod timeline;
 
 pub use error::QueryError;
+pub use export::{rows_to_batches, write_export};
+pub use log_filter::{LogFilter, LogFilterError};
 
 use std::path::Path;
 use std::sync::Arc;
@@ -26,12 +34,16 @@ use datafusion::prelude::*;
 
 use column_helpers::*;
 
+use crate::game_data::GcdEstimate;
+
 // Re-export query types from shared types crate
 pub use baras_types::{
     AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow,
-    DamageTakenSummary, DataTab, EffectChartData, EffectWindow, EncounterTimeline,
-    EntityBreakdown, GcdSlot, GroupedEntityNames, HpPoint, NpcHealthRow, PhaseSegment, PlayerDeath,
-    RaidOverviewRow, RotationAnalysis, RotationCycle, RotationEvent, TimeRange, TimeSeriesPoint,
+    CooldownEfficiencyRow, CooldownIdleGap, DamageTakenSummary, DataTab, EffectChartData,
+    EffectWindow, EncounterTimeline, EntityBreakdown, ExportFormat, ExportSource, GcdGap,
+    GcdGapContext, GcdGapKind, GcdSlot, GcdUptimeAnalysis, GroupedEntityNames, HealingMatrix,
+    HealingMatrixCell, HpPoint, NpcHealthRow, PhaseSegment, PlayerDeath, RaidOverviewRow,
+    RotationAnalysis, RotationCycle, RotationEvent, TimeRange, TimeSeriesMetric, TimeSeriesPoint,
 };
 
 /// Escape single quotes for SQL string literals (O'Brien -> O''Brien)
@@ -185,6 +197,7 @@ impl QueryContextGuard<'_> {
     pub fn query(&self) -> EncounterQuery<'_> {
         EncounterQuery {
             ctx: &self.guard.ctx,
+            gcd_fallback: GcdEstimate::default(),
         }
     }
 }
@@ -195,9 +208,17 @@ impl QueryContextGuard<'_> {
 
 pub struct EncounterQuery<'a> {
     ctx: &'a SessionContext,
+    /// GCD/alacrity/latency for players with too few casts to infer their own
+    gcd_fallback: GcdEstimate,
 }
 
 impl EncounterQuery<'_> {
+    /// Use the configured alacrity/latency for players whose GCD can't be inferred
+    pub fn with_gcd_fallback(mut self, fallback: GcdEstimate) -> Self {
+        self.gcd_fallback = fallback;
+        self
+    }
+
     /// Execute SQL query, returning empty results if table doesn't exist.
     /// This prevents panics when queries are made before parquet data is loaded.
     async fn sql(&self, query: &str) -> Result<Vec<RecordBatch>, String> {
@@ -6,59 +6,24 @@ use super::*;
 use crate::game_data::effect_id;
 
 impl EncounterQuery<'_> {
-    /// Query shield attribution - maps shield source IDs to total shielding given.
+    /// Query shield attribution - maps shield source names to total shielding given.
     ///
     /// Uses the pre-computed `active_shields` column which contains shield context
-    /// (source_id, position, estimated_max) captured at parse time.
-    ///
-    /// Attribution logic:
-    /// - Single shield: credit it fully with dmg_absorbed
-    /// - Multiple shields + full absorb: credit FIRST applied (FIFO)
-    /// - Multiple shields + damage through: credit earlier shields with estimated_max,
-    ///   LAST shield with actual dmg_absorbed
+    /// (source_id, position, estimated_max) captured at parse time. Absorbed damage
+    /// is split across stacked shields by remaining estimated capacity (FIFO), the
+    /// same model the live metrics use.
     async fn query_shield_attribution(
         &self,
         time_range: Option<&TimeRange>,
     ) -> Result<HashMap<String, f64>, String> {
-        let time_filter = time_range
-            .map(|tr| format!("AND {}", tr.sql_filter()))
-            .unwrap_or_default();
-        // Query with UNNEST, only fetch columns we need for FIFO attribution
-        // Only keep position=1 rows (first shield) to avoid double-counting
-        let batches = self
-            .sql(&format!(
-                r#"
-            SELECT
-                CAST(dmg_absorbed AS BIGINT) as dmg_absorbed,
-                shield['source_id'] as source_id
-            FROM (
-                SELECT dmg_absorbed, UNNEST(active_shields) as shield
-                FROM events
-                WHERE dmg_absorbed > 0 AND cardinality(active_shields) > 0 {time_filter}
-            )
-            WHERE CAST(shield['position'] AS BIGINT) = 1
-        "#
-            ))
-            .await;
-
-        let batches = match batches {
-            Ok(b) => b,
-            Err(_) => return Ok(HashMap::new()),
-        };
+        let credits = self.query_shield_credits(time_range).await?;
+        if credits.is_empty() {
+            return Ok(HashMap::new());
+        }
 
-        // Simple FIFO attribution: credit all absorbed damage to the first shield.
-        // The log's dmg_absorbed is the TOTAL absorbed by all shields combined.
         let mut shielding_given: HashMap<i64, f64> = HashMap::new();
-
-        for batch in &batches {
-            let dmg_absorbeds = col_i64(batch, 0)?;
-            let source_ids = col_i64(batch, 1)?;
-
-            for i in 0..batch.num_rows() {
-                let dmg_absorbed = dmg_absorbeds[i] as f64;
-                let source_id = source_ids[i];
-                *shielding_given.entry(source_id).or_default() += dmg_absorbed;
-            }
+        for credit in &credits {
+            *shielding_given.entry(credit.source_id).or_default() += credit.amount as f64;
         }
 
         // Convert source_id to source_name
@@ -70,7 +35,7 @@ impl EncounterQuery<'_> {
     }
 
     /// Get entity ID to name mapping
-    async fn get_entity_names(&self) -> Result<HashMap<i64, String>, String> {
+    pub(super) async fn get_entity_names(&self) -> Result<HashMap<i64, String>, String> {
         let batches = self
             .sql("SELECT DISTINCT source_id, source_name FROM events")
             .await?;
@@ -105,6 +70,12 @@ impl EncounterQuery<'_> {
             .await
             .unwrap_or_default();
 
+        // GCD uptime per player
+        let abc_by_player = self
+            .query_abc_by_player(time_range, duration_secs)
+            .await
+            .unwrap_or_default();
+
         // CTE-based query to aggregate multiple metrics per player
         // participants: all unique source names (players who did anything)
         // damage_dealt: sum of dmg_amount WHERE source = player
@@ -194,6 +165,7 @@ impl EncounterQuery<'_> {
             for i in 0..batch.num_rows() {
                 let name = names[i].clone();
                 let shield_total = shielding_given.get(&name).copied().unwrap_or(0.0);
+                let abc_pct = abc_by_player.get(&name).copied().unwrap_or(0.0);
                 // Include shielding in healing totals (shields are pre-emptive healing)
                 let healing_total = healing_totals[i] + shield_total;
                 let healing_effective = healing_effectives[i] + shield_total;
@@ -224,6 +196,7 @@ impl EncounterQuery<'_> {
                     ehps: healing_effective * 1000.0 / duration_ms as f64,
                     healing_pct,
                     apm: action_counts[i] * 60000.0 / duration_ms as f64,
+                    abc_pct,
                 });
             }
         }
@@ -5,7 +5,8 @@
 use std::collections::HashMap;
 
 use super::*;
-use crate::game_data::{OFF_GCD_ABILITIES, effect_id};
+use crate::game_data::{OFF_GCD_ABILITIES, effect_id, effect_type_id};
+use crate::rotations::{RotationDefinition, check_rotation, effect_windows};
 
 /// A damage or heal event to attribute to a rotation cycle.
 struct ValueEvent {
@@ -51,12 +52,14 @@ impl EncounterQuery<'_> {
     }
 
     /// Build a full rotation analysis: fetch ability activations, group into
-    /// GCD slots, split into cycles, and calculate per-cycle stats.
+    /// GCD slots, split into cycles, and calculate per-cycle stats. Cycles are
+    /// scored against `reference` if given.
     pub async fn query_rotation(
         &self,
         source_name: &str,
         anchor_ability_id: i64,
         time_range: Option<&TimeRange>,
+        reference: Option<&RotationDefinition>,
     ) -> Result<RotationAnalysis, String> {
         // 1. Get distinct abilities for the dropdown
         let abilities = self.query_player_abilities(source_name, time_range).await?;
@@ -155,14 +158,20 @@ impl EncounterQuery<'_> {
             }
         }
 
-        // 8. Apply stats and durations
+        // 8. Apply stats and durations (the last cycle ends one GCD after its last cast)
+        let gcd = self
+            .query_gcd_estimates()
+            .await?
+            .get(source_name)
+            .unwrap_or(&self.gcd_fallback)
+            .gcd_secs;
         for (i, cycle) in cycles.iter_mut().enumerate() {
             let start = starts[i];
             let end = starts.get(i + 1).copied().unwrap_or_else(|| {
                 cycle
                     .slots
                     .last()
-                    .map(|s| s.gcd_ability.time_secs + 1.5)
+                    .map(|s| s.gcd_ability.time_secs + gcd)
                     .unwrap_or(start)
             });
             cycle.duration_secs = end - start;
@@ -173,7 +182,99 @@ impl EncounterQuery<'_> {
             cycle.hit_count = hits;
         }
 
-        Ok(RotationAnalysis { cycles, abilities })
+        // 9. Score against the reference rotation, valuing each ability at the
+        //    player's average damage + effective healing per cast
+        let check = match reference {
+            Some(reference) if !cycles.is_empty() => {
+                let mut casts: HashMap<i64, u32> = HashMap::new();
+                for slot in cycles.iter().flat_map(|c| &c.slots) {
+                    for event in std::iter::once(&slot.gcd_ability).chain(&slot.off_gcd) {
+                        *casts.entry(event.ability_id).or_default() += 1;
+                    }
+                }
+                let mut values: HashMap<i64, f64> = HashMap::new();
+                for evt in &value_events {
+                    if casts.contains_key(&evt.ability_id) {
+                        *values.entry(evt.ability_id).or_default() +=
+                            evt.damage as f64 + evt.effective_heal as f64;
+                    }
+                }
+                for (id, value) in values.iter_mut() {
+                    *value /= casts[id] as f64;
+                }
+
+                let windows = self
+                    .query_proc_windows(source_name, reference, time_range)
+                    .await?;
+                Some(check_rotation(reference, &cycles, &windows, &values))
+            }
+            _ => None,
+        };
+
+        Ok(RotationAnalysis {
+            cycles,
+            abilities,
+            check,
+        })
+    }
+
+    /// Windows in which each reference step's required effect (proc) was on
+    /// the player, one entry per step.
+    async fn query_proc_windows(
+        &self,
+        source_name: &str,
+        reference: &RotationDefinition,
+        time_range: Option<&TimeRange>,
+    ) -> Result<Vec<Vec<(f32, f32)>>, String> {
+        if reference
+            .abilities
+            .iter()
+            .all(|s| s.requires_effect.is_none())
+        {
+            return Ok(Vec::new());
+        }
+
+        let time_filter = time_range
+            .map(|tr| format!(" AND {}", tr.sql_filter()))
+            .unwrap_or_default();
+        let sql = format!(
+            "SELECT combat_time_secs, effect_type_id, effect_id, effect_name \
+             FROM events WHERE target_name = '{}' \
+             AND effect_type_id IN ({}, {}){} \
+             ORDER BY combat_time_secs ASC, line_number ASC",
+            sql_escape(source_name),
+            effect_type_id::APPLYEFFECT,
+            effect_type_id::REMOVEEFFECT,
+            time_filter
+        );
+
+        let batches = self.sql(&sql).await?;
+        let mut events = Vec::new();
+        for batch in &batches {
+            let times = col_f32(batch, 0)?;
+            let types = col_i64(batch, 1)?;
+            let ids = col_i64(batch, 2)?;
+            let names = col_strings(batch, 3)?;
+            for i in 0..times.len() {
+                events.push((
+                    times[i],
+                    types[i] == effect_type_id::APPLYEFFECT,
+                    ids[i],
+                    names[i].clone(),
+                ));
+            }
+        }
+
+        Ok(reference
+            .abilities
+            .iter()
+            .map(|step| {
+                step.requires_effect
+                    .as_ref()
+                    .map(|selector| effect_windows(&events, selector))
+                    .unwrap_or_default()
+            })
+            .collect())
     }
 }
 
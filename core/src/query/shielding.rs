@@ -0,0 +1,246 @@
+//! Capacity-aware shield attribution over stored encounter data.
+//!
+//! Mirrors the live model in `encounter::shielding`: absorbed damage is split
+//! across the stacked shields in `active_shields` by remaining estimated capacity.
+//! Capacity is tracked per shield instance by replaying shield applications, so
+//! the whole encounter is scanned and the time range only filters what is credited.
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::encounter::shielding::split_absorption;
+use crate::game_data::{SHIELD_INFO, effect_type_id};
+
+/// Absorption credited to one shield for one damage event.
+pub(super) struct ShieldCredit {
+    pub source_id: i64,
+    pub effect_id: i64,
+    pub target_id: i64,
+    pub target_name: String,
+    pub amount: i64,
+}
+
+/// One stacked shield as captured in the `active_shields` context.
+struct StackedShield {
+    effect_id: i64,
+    source_id: i64,
+    estimated_max: i64,
+}
+
+/// A damage event with absorption and its shield stack (FIFO order).
+struct AbsorbEvent {
+    line_number: u64,
+    time_secs: f32,
+    target_id: i64,
+    target_name: String,
+    absorbed: i64,
+    shields: Vec<StackedShield>,
+}
+
+/// Shield instance key: (target_id, effect_id, source_id)
+type ShieldKey = (i64, i64, i64);
+
+impl EncounterQuery<'_> {
+    /// Split every absorbed hit across its shield stack and return the credits
+    /// that fall inside `time_range`.
+    pub(super) async fn query_shield_credits(
+        &self,
+        time_range: Option<&TimeRange>,
+    ) -> Result<Vec<ShieldCredit>, String> {
+        // Shield applications reset the consumed capacity of that instance
+        let limited_ids: Vec<String> = SHIELD_INFO
+            .entries()
+            .filter(|(_, info)| info.is_limited())
+            .map(|(id, _)| id.to_string())
+            .collect();
+        let apply_batches = self
+            .sql(&format!(
+                "SELECT line_number, target_id, effect_id, source_id FROM events \
+                 WHERE effect_type_id = {} AND effect_id IN ({}) \
+                 ORDER BY line_number",
+                effect_type_id::APPLYEFFECT,
+                limited_ids.join(", ")
+            ))
+            .await?;
+        let mut applies: Vec<(u64, ShieldKey)> = Vec::new();
+        for batch in &apply_batches {
+            let lines = col_i64(batch, 0)?;
+            let targets = col_i64(batch, 1)?;
+            let effects = col_i64(batch, 2)?;
+            let sources = col_i64(batch, 3)?;
+            for i in 0..batch.num_rows() {
+                applies.push((lines[i] as u64, (targets[i], effects[i], sources[i])));
+            }
+        }
+
+        // One row per (absorb event, stacked shield), in log and FIFO order
+        let shield_batches = self
+            .sql(
+                r#"
+            SELECT line_number, combat_time_secs, target_id, target_name,
+                   CAST(dmg_absorbed AS BIGINT) as absorbed,
+                   CAST(shield['effect_id'] AS BIGINT) as effect_id,
+                   CAST(shield['source_id'] AS BIGINT) as source_id,
+                   CAST(shield['estimated_max'] AS BIGINT) as estimated_max
+            FROM (
+                SELECT line_number, combat_time_secs, target_id, target_name, dmg_absorbed,
+                       UNNEST(active_shields) as shield
+                FROM events
+                WHERE dmg_absorbed > 0 AND cardinality(active_shields) > 0
+            )
+            ORDER BY line_number, CAST(shield['position'] AS BIGINT)
+        "#,
+            )
+            .await
+            .unwrap_or_default();
+        let mut events: Vec<AbsorbEvent> = Vec::new();
+        for batch in &shield_batches {
+            let lines = col_i64(batch, 0)?;
+            let times = col_opt_f32(batch, 1)?;
+            let target_ids = col_i64(batch, 2)?;
+            let target_names = col_strings(batch, 3)?;
+            let absorbed = col_i64(batch, 4)?;
+            let effect_ids = col_i64(batch, 5)?;
+            let source_ids = col_i64(batch, 6)?;
+            let maxes = col_i64(batch, 7)?;
+            for i in 0..batch.num_rows() {
+                let shield = StackedShield {
+                    effect_id: effect_ids[i],
+                    source_id: source_ids[i],
+                    estimated_max: maxes[i],
+                };
+                match events.last_mut() {
+                    Some(evt) if evt.line_number == lines[i] as u64 => evt.shields.push(shield),
+                    _ => events.push(AbsorbEvent {
+                        line_number: lines[i] as u64,
+                        time_secs: times[i].unwrap_or(0.0),
+                        target_id: target_ids[i],
+                        target_name: target_names[i].clone(),
+                        absorbed: absorbed[i],
+                        shields: vec![shield],
+                    }),
+                }
+            }
+        }
+
+        Ok(replay_shield_credits(&applies, &events, time_range))
+    }
+}
+
+/// Walk applications and absorb events in log order, splitting each absorb by
+/// the remaining capacity of its shields.
+fn replay_shield_credits(
+    applies: &[(u64, ShieldKey)],
+    events: &[AbsorbEvent],
+    time_range: Option<&TimeRange>,
+) -> Vec<ShieldCredit> {
+    let mut consumed: HashMap<ShieldKey, i64> = HashMap::new();
+    let mut applies = applies.iter().peekable();
+    let mut credits = Vec::new();
+
+    for evt in events {
+        while let Some((_, key)) = applies.next_if(|(line, _)| *line < evt.line_number) {
+            consumed.remove(key);
+        }
+
+        let key = |s: &StackedShield| (evt.target_id, s.effect_id, s.source_id);
+        let remaining: Vec<Option<i64>> = evt
+            .shields
+            .iter()
+            .map(|s| {
+                (s.estimated_max > 0)
+                    .then(|| s.estimated_max - consumed.get(&key(s)).copied().unwrap_or(0))
+            })
+            .collect();
+
+        let in_range =
+            time_range.is_none_or(|tr| evt.time_secs >= tr.start && evt.time_secs <= tr.end);
+        for (shield, amount) in evt
+            .shields
+            .iter()
+            .zip(split_absorption(evt.absorbed, &remaining))
+        {
+            if amount <= 0 {
+                continue;
+            }
+            *consumed.entry(key(shield)).or_default() += amount;
+            if in_range {
+                credits.push(ShieldCredit {
+                    source_id: shield.source_id,
+                    effect_id: shield.effect_id,
+                    target_id: evt.target_id,
+                    target_name: evt.target_name.clone(),
+                    amount,
+                });
+            }
+        }
+    }
+    credits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shield(effect_id: i64, source_id: i64, estimated_max: i64) -> StackedShield {
+        StackedShield {
+            effect_id,
+            source_id,
+            estimated_max,
+        }
+    }
+
+    fn absorb(line_number: u64, absorbed: i64, shields: Vec<StackedShield>) -> AbsorbEvent {
+        AbsorbEvent {
+            line_number,
+            time_secs: line_number as f32,
+            target_id: 1,
+            target_name: "Tank".to_string(),
+            absorbed,
+            shields,
+        }
+    }
+
+    fn totals(credits: &[ShieldCredit]) -> HashMap<i64, i64> {
+        let mut out = HashMap::new();
+        for c in credits {
+            *out.entry(c.source_id).or_default() += c.amount;
+        }
+        out
+    }
+
+    #[test]
+    fn test_spills_once_first_shield_is_used_up() {
+        let events = vec![
+            absorb(10, 600, vec![shield(100, 7, 1000), shield(200, 8, 5000)]),
+            absorb(11, 600, vec![shield(100, 7, 1000), shield(200, 8, 5000)]),
+        ];
+        let credits = totals(&replay_shield_credits(&[], &events, None));
+        assert_eq!(credits[&7], 1000);
+        assert_eq!(credits[&8], 200);
+    }
+
+    #[test]
+    fn test_reapplication_restores_capacity() {
+        let events = vec![
+            absorb(10, 1000, vec![shield(100, 7, 1000), shield(200, 8, 5000)]),
+            absorb(20, 500, vec![shield(100, 7, 1000), shield(200, 8, 5000)]),
+        ];
+        let applies = [(15, (1, 100, 7))];
+        let credits = totals(&replay_shield_credits(&applies, &events, None));
+        assert_eq!(credits[&7], 1500);
+        assert!(!credits.contains_key(&8));
+    }
+
+    #[test]
+    fn test_time_range_filters_credits_but_not_consumption() {
+        let events = vec![
+            absorb(10, 1000, vec![shield(100, 7, 1000), shield(200, 8, 5000)]),
+            absorb(20, 300, vec![shield(100, 7, 1000), shield(200, 8, 5000)]),
+        ];
+        let range = TimeRange::new(15.0, 25.0);
+        let credits = totals(&replay_shield_credits(&[], &events, Some(&range)));
+        assert_eq!(credits.get(&7), None);
+        assert_eq!(credits[&8], 300);
+    }
+}
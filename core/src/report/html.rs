@@ -0,0 +1,277 @@
+//! Self-contained HTML report
+
+use std::fmt::Write;
+
+use super::{EncounterReport, TOP_ABILITIES, format_amount, format_clock, svg};
+use crate::query::AbilityBreakdown;
+
+const STYLE: &str = r#"
+body { background: #14171c; color: #d8dde6; font: 14px/1.45 "Segoe UI", Roboto, sans-serif; margin: 0; padding: 24px; }
+main { max-width: 960px; margin: 0 auto; }
+h1 { font-size: 22px; margin: 0 0 4px; }
+h2 { font-size: 18px; margin: 40px 0 2px; border-bottom: 1px solid #2c3340; padding-bottom: 4px; }
+h3 { font-size: 14px; margin: 20px 0 6px; color: #9fb3d1; text-transform: uppercase; letter-spacing: .04em; }
+a { color: #7fb2ff; }
+.meta, .muted { color: #8a93a6; }
+.kill { color: #4fc46a; } .wipe { color: #e05d44; }
+table { border-collapse: collapse; width: 100%; font-variant-numeric: tabular-nums; }
+th, td { padding: 3px 8px; text-align: right; border-bottom: 1px solid #222833; white-space: nowrap; }
+th { color: #8a93a6; font-weight: 600; }
+th:first-child, td:first-child { text-align: left; }
+td.name { white-space: normal; }
+.chart { width: 100%; height: auto; background: #1a1e25; border-radius: 4px; }
+.legend { display: flex; gap: 14px; font-size: 12px; color: #8a93a6; margin: 4px 0; }
+.legend i { display: inline-block; width: 10px; height: 10px; border-radius: 2px; margin-right: 4px; }
+details { margin: 4px 0; } summary { cursor: pointer; }
+ul.toc { columns: 2; }
+footer { margin-top: 40px; font-size: 12px; color: #5d6575; }
+"#;
+
+/// Render encounters into one HTML page. A table of contents is added when
+/// there's more than one encounter.
+pub fn render_html(title: &str, reports: &[EncounterReport]) -> String {
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<main>\n<h1>{}</h1>\n",
+        escape(title),
+        escape(title)
+    );
+
+    if reports.len() > 1 {
+        out.push_str("<ul class=\"toc\">\n");
+        for report in reports {
+            let _ = writeln!(
+                out,
+                "<li><a href=\"#enc-{}\">{}</a> <span class=\"muted\">{}</span></li>",
+                report.summary.encounter_id,
+                escape(&report.heading()),
+                format_clock(report.summary.duration_seconds as f32)
+            );
+        }
+        out.push_str("</ul>\n");
+    }
+
+    for report in reports {
+        render_encounter(&mut out, report);
+    }
+
+    let _ = write!(
+        out,
+        "<footer>Generated by BARAS {}</footer>\n</main>\n</body>\n</html>\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    out
+}
+
+fn render_encounter(out: &mut String, report: &EncounterReport) {
+    let summary = &report.summary;
+    let outcome = match (&summary.boss_name, summary.success) {
+        (Some(_), true) => " class=\"kill\"",
+        (Some(_), false) => " class=\"wipe\"",
+        (None, _) => "",
+    };
+    let _ = write!(
+        out,
+        "<section id=\"enc-{}\">\n<h2{outcome}>{}</h2>\n<div class=\"meta\">{}",
+        summary.encounter_id,
+        escape(&report.heading()),
+        escape(&report.meta_line())
+    );
+    if let Some(link) = &summary.parsely_link {
+        let _ = write!(out, " · <a href=\"{}\">Parsely</a>", escape(link));
+    }
+    out.push_str("</div>\n");
+
+    out.push_str(&svg::render_chart(report));
+    out.push_str(&svg::legend());
+
+    if !report.annotations.is_empty() {
+        out.push_str("<h3>Notes</h3>\n<table>\n");
+        for note in &report.annotations {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td class=\"name\" style=\"text-align:left\">{}</td></tr>",
+                format_clock(note.time_secs),
+                escape(&note.text)
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    if !report.timeline.phases.is_empty() {
+        out.push_str("<h3>Phases</h3>\n<table>\n<tr><th>Phase</th><th>Start</th><th>End</th><th>Duration</th></tr>\n");
+        for phase in &report.timeline.phases {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&phase.phase_name),
+                format_clock(phase.start_secs),
+                format_clock(phase.end_secs),
+                format_clock(phase.end_secs - phase.start_secs)
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    render_overview(out, report);
+
+    if !report.deaths.is_empty() {
+        out.push_str("<h3>Deaths</h3>\n<table>\n<tr><th>Time</th><th>Player</th></tr>\n");
+        for death in &report.deaths {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td class=\"name\" style=\"text-align:left\">{}</td></tr>",
+                format_clock(death.death_time_secs),
+                escape(&death.name)
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    for challenge in &summary.challenges {
+        let _ = write!(
+            out,
+            "<h3>{}</h3>\n<div class=\"muted\">Total {}",
+            escape(&challenge.name),
+            format_amount(challenge.total_value as f64)
+        );
+        if let Some(per_second) = challenge.per_second {
+            let _ = write!(out, " · {}/s", format_amount(f64::from(per_second)));
+        }
+        out.push_str(
+            "</div>\n<table>\n<tr><th>Player</th><th>Value</th><th>%</th><th>Per sec</th></tr>\n",
+        );
+        for player in &challenge.by_player {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{:.1}%</td><td>{}</td></tr>",
+                escape(&player.name),
+                format_amount(player.value as f64),
+                player.percent,
+                player
+                    .per_second
+                    .map(|v| format_amount(f64::from(v)))
+                    .unwrap_or_default()
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    if !report.breakdowns.is_empty() {
+        let _ = writeln!(out, "<h3>Top {TOP_ABILITIES} abilities</h3>");
+        for player in &report.breakdowns {
+            if player.damage.is_empty() && player.healing.is_empty() {
+                continue;
+            }
+            let _ = writeln!(
+                out,
+                "<details>\n<summary>{}</summary>",
+                escape(&player.name)
+            );
+            render_abilities(out, "Damage", &player.damage);
+            render_abilities(out, "Healing", &player.healing);
+            out.push_str("</details>\n");
+        }
+    }
+
+    out.push_str("</section>\n");
+}
+
+fn render_overview(out: &mut String, report: &EncounterReport) {
+    if report.overview.is_empty() {
+        return;
+    }
+    out.push_str(
+        "<h3>Raid overview</h3>\n<table>\n<tr><th>Player</th><th>Discipline</th><th>Damage</th>\
+         <th>DPS</th><th>Healing</th><th>HPS</th><th>EHPS</th><th>DTPS</th><th>APM</th>\
+         <th>Deaths</th></tr>\n",
+    );
+    for row in &report.overview {
+        let deaths = report.deaths_of(&row.name);
+        let _ = writeln!(
+            out,
+            "<tr><td class=\"name\">{}</td><td class=\"muted\">{}</td><td>{}</td><td>{}</td>\
+             <td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.1}</td><td>{}</td></tr>",
+            escape(&row.name),
+            escape(row.discipline_name.as_deref().unwrap_or("")),
+            format_amount(row.damage_total),
+            format_amount(row.dps),
+            format_amount(row.healing_total),
+            format_amount(row.hps),
+            format_amount(row.ehps),
+            format_amount(row.dtps),
+            row.apm,
+            if deaths > 0 {
+                deaths.to_string()
+            } else {
+                String::new()
+            }
+        );
+    }
+    out.push_str("</table>\n");
+}
+
+fn render_abilities(out: &mut String, label: &str, abilities: &[AbilityBreakdown]) {
+    if abilities.is_empty() {
+        return;
+    }
+    let _ = writeln!(
+        out,
+        "<table>\n<tr><th>{label}</th><th>Total</th><th>%</th><th>Per sec</th><th>Hits</th>\
+         <th>Crit</th><th>Max</th></tr>"
+    );
+    for ability in abilities {
+        let _ = writeln!(
+            out,
+            "<tr><td class=\"name\">{}</td><td>{}</td><td>{:.1}%</td><td>{}</td><td>{}</td>\
+             <td>{:.1}%</td><td>{}</td></tr>",
+            escape(&ability.ability_name),
+            format_amount(ability.total_value),
+            ability.percent_of_total,
+            format_amount(ability.dps),
+            ability.hit_count,
+            ability.crit_rate,
+            format_amount(ability.max_hit)
+        );
+    }
+    out.push_str("</table>\n");
+}
+
+/// Escape text for HTML element content and attribute values
+pub(super) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::sample_report;
+
+    #[test]
+    fn test_html_is_escaped_and_self_contained() {
+        let html = render_html("Raid night", &[sample_report(), sample_report()]);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("Kel &lt;Heals&gt;"));
+        assert!(!html.contains("Kel <Heals>"));
+        assert!(html.contains("<svg class=\"chart\""));
+        assert!(html.contains("<ul class=\"toc\">"));
+        assert!(html.contains("Hand damage"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("src=\"http"));
+    }
+}
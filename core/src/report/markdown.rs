@@ -0,0 +1,137 @@
+//! Markdown summary (fits in a Discord or forum post)
+
+use std::fmt::Write;
+
+use super::{EncounterReport, format_amount, format_clock};
+
+/// Render encounters as a Markdown summary: overview table, phases, deaths,
+/// challenges and notes. Ability breakdowns are left to the HTML report.
+pub fn render_markdown(title: &str, reports: &[EncounterReport]) -> String {
+    let mut out = format!("# {}\n", escape(title));
+
+    for report in reports {
+        let summary = &report.summary;
+        let _ = write!(
+            out,
+            "\n## {}\n\n{}\n",
+            escape(&report.heading()),
+            escape(&report.meta_line())
+        );
+        if let Some(link) = &summary.parsely_link {
+            let _ = writeln!(out, "\nParsely: <{}>", link);
+        }
+
+        if !report.overview.is_empty() {
+            out.push_str(
+                "\n| Player | Discipline | DPS | HPS | EHPS | DTPS | Deaths |\n\
+                 |---|---|--:|--:|--:|--:|--:|\n",
+            );
+            for row in &report.overview {
+                let deaths = report.deaths_of(&row.name);
+                let _ = writeln!(
+                    out,
+                    "| {} | {} | {} | {} | {} | {} | {} |",
+                    escape(&row.name),
+                    escape(row.discipline_name.as_deref().unwrap_or("")),
+                    format_amount(row.dps),
+                    format_amount(row.hps),
+                    format_amount(row.ehps),
+                    format_amount(row.dtps),
+                    if deaths > 0 {
+                        deaths.to_string()
+                    } else {
+                        String::new()
+                    }
+                );
+            }
+        }
+
+        if !report.timeline.phases.is_empty() {
+            let phases: Vec<String> = report
+                .timeline
+                .phases
+                .iter()
+                .map(|p| {
+                    format!(
+                        "{} {}-{}",
+                        escape(&p.phase_name),
+                        format_clock(p.start_secs),
+                        format_clock(p.end_secs)
+                    )
+                })
+                .collect();
+            let _ = writeln!(out, "\n**Phases:** {}", phases.join(" · "));
+        }
+
+        if !report.deaths.is_empty() {
+            let deaths: Vec<String> = report
+                .deaths
+                .iter()
+                .map(|d| format!("{} {}", format_clock(d.death_time_secs), escape(&d.name)))
+                .collect();
+            let _ = writeln!(out, "\n**Deaths:** {}", deaths.join(" · "));
+        }
+
+        for challenge in &summary.challenges {
+            let players: Vec<String> = challenge
+                .by_player
+                .iter()
+                .map(|p| format!("{} {:.1}%", escape(&p.name), p.percent))
+                .collect();
+            let _ = writeln!(
+                out,
+                "\n**{}:** {} ({})",
+                escape(&challenge.name),
+                format_amount(challenge.total_value as f64),
+                players.join(", ")
+            );
+        }
+
+        if !report.annotations.is_empty() {
+            out.push_str("\n**Notes:**\n");
+            for note in &report.annotations {
+                let _ = writeln!(
+                    out,
+                    "- {} {}",
+                    format_clock(note.time_secs),
+                    escape(&note.text)
+                );
+            }
+        }
+    }
+
+    out
+}
+
+/// Escape characters that would break table cells or add formatting
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '|' | '*' | '_' | '`' | '<' | '>' | '[' | ']' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::sample_report;
+
+    #[test]
+    fn test_markdown_summary() {
+        let md = render_markdown("Raid night", &[sample_report()]);
+
+        assert!(md.starts_with("# Raid night\n"));
+        assert!(md.contains("## Brontes - 2 (Kill)"));
+        assert!(md.contains("| Jerran | Lightning | 21.00K |"));
+        assert!(md.contains("**Deaths:** 1:35 Kel \\<Heals\\>"));
+        assert!(md.contains("- 1:02 Heal CD missed \\| here"));
+    }
+}
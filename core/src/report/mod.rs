@@ -0,0 +1,309 @@
+//! Standalone encounter reports
+//!
+//! Renders one or more encounters into a self-contained HTML file (inline
+//! styles and SVG charts, no scripts or external assets) and a Markdown
+//! summary that can be pasted into Discord or a forum post, for people who
+//! don't run the app.
+
+mod html;
+mod markdown;
+mod svg;
+
+pub use html::render_html;
+pub use markdown::render_markdown;
+
+use crate::encounter::annotations::EncounterAnnotation;
+use crate::encounter::summary::EncounterSummary;
+use crate::query::{
+    AbilityBreakdown, DataTab, EncounterQuery, EncounterTimeline, PlayerDeath, RaidOverviewRow,
+    TimeSeriesPoint,
+};
+
+/// Abilities listed per player and tab
+pub const TOP_ABILITIES: usize = 8;
+
+/// Time series bucket size
+const BUCKET_MS: i64 = 1000;
+
+/// One player's top abilities
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerBreakdown {
+    pub name: String,
+    pub damage: Vec<AbilityBreakdown>,
+    pub healing: Vec<AbilityBreakdown>,
+}
+
+/// Everything a report shows for one encounter
+#[derive(Debug, Clone)]
+pub struct EncounterReport {
+    pub summary: EncounterSummary,
+    pub timeline: EncounterTimeline,
+    /// Players and companions, highest damage first
+    pub overview: Vec<RaidOverviewRow>,
+    pub breakdowns: Vec<PlayerBreakdown>,
+    pub deaths: Vec<PlayerDeath>,
+    /// Raid-wide damage, healing and damage taken per second
+    pub dps: Vec<TimeSeriesPoint>,
+    pub hps: Vec<TimeSeriesPoint>,
+    pub dtps: Vec<TimeSeriesPoint>,
+    pub annotations: Vec<EncounterAnnotation>,
+}
+
+impl EncounterReport {
+    /// Query the report data for the encounter registered in `query`
+    pub async fn build(
+        query: &EncounterQuery<'_>,
+        summary: EncounterSummary,
+        annotations: Vec<EncounterAnnotation>,
+    ) -> Result<Self, String> {
+        let timeline = query.encounter_timeline().await?;
+        let duration = Some(timeline.duration_secs);
+
+        let overview: Vec<RaidOverviewRow> = query
+            .query_raid_overview(None, duration)
+            .await?
+            .into_iter()
+            .filter(|row| row.entity_type == "Player" || row.entity_type == "Companion")
+            .collect();
+
+        let mut breakdowns = Vec::new();
+        for row in overview.iter().filter(|r| r.entity_type == "Player") {
+            let top = |mut abilities: Vec<AbilityBreakdown>| {
+                abilities.sort_by(|a, b| b.total_value.total_cmp(&a.total_value));
+                abilities.truncate(TOP_ABILITIES);
+                abilities
+            };
+            let damage = if row.damage_total > 0.0 {
+                top(query
+                    .query_breakdown(DataTab::Damage, Some(&row.name), None, None, None, duration)
+                    .await?)
+            } else {
+                Vec::new()
+            };
+            let healing = if row.healing_total > 0.0 {
+                top(query
+                    .query_breakdown(
+                        DataTab::Healing,
+                        Some(&row.name),
+                        None,
+                        None,
+                        None,
+                        duration,
+                    )
+                    .await?)
+            } else {
+                Vec::new()
+            };
+            breakdowns.push(PlayerBreakdown {
+                name: row.name.clone(),
+                damage,
+                healing,
+            });
+        }
+
+        // Unfiltered series would include what the NPCs deal and heal, so the
+        // raid lines are summed from the players' own series
+        let (mut dps, mut hps, mut dtps) = (Vec::new(), Vec::new(), Vec::new());
+        for row in &overview {
+            let name = Some(row.name.as_str());
+            add_series(&mut dps, query.dps_over_time(BUCKET_MS, name, None).await?);
+            add_series(&mut hps, query.hps_over_time(BUCKET_MS, name, None).await?);
+            add_series(
+                &mut dtps,
+                query.dtps_over_time(BUCKET_MS, name, None).await?,
+            );
+        }
+
+        Ok(Self {
+            summary,
+            timeline,
+            overview,
+            breakdowns,
+            deaths: query.query_player_deaths().await?,
+            dps,
+            hps,
+            dtps,
+            annotations,
+        })
+    }
+
+    /// Heading for the encounter, with the outcome for boss pulls
+    fn heading(&self) -> String {
+        let summary = &self.summary;
+        match (&summary.boss_name, summary.success) {
+            (Some(_), true) => format!("{} (Kill)", summary.display_name),
+            (Some(_), false) => format!("{} (Wipe)", summary.display_name),
+            (None, _) => summary.display_name.clone(),
+        }
+    }
+
+    /// Area, difficulty, start time and duration
+    fn meta_line(&self) -> String {
+        let summary = &self.summary;
+        let mut parts = vec![summary.area_name.clone()];
+        if let Some(difficulty) = &summary.difficulty {
+            parts.push(difficulty.clone());
+        }
+        if let Some(start) = &summary.start_time {
+            parts.push(start.replace('T', " "));
+        }
+        parts.push(format_clock(summary.duration_seconds as f32));
+        parts.join(" · ")
+    }
+
+    /// Number of times a player or companion died
+    fn deaths_of(&self, name: &str) -> usize {
+        self.deaths.iter().filter(|d| d.name == name).count()
+    }
+}
+
+/// Add `series` into `total` bucket by bucket
+fn add_series(total: &mut Vec<TimeSeriesPoint>, series: Vec<TimeSeriesPoint>) {
+    if total.is_empty() {
+        *total = series;
+        return;
+    }
+    for point in series {
+        match total.binary_search_by_key(&point.bucket_start_ms, |p| p.bucket_start_ms) {
+            Ok(i) => total[i].total_value += point.total_value,
+            Err(i) => total.insert(i, point),
+        }
+    }
+}
+
+/// Combat time as M:SS
+fn format_clock(secs: f32) -> String {
+    let secs = secs.max(0.0) as u32;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Large numbers as 1.2K / 3.4M
+fn format_amount(value: f64) -> String {
+    baras_types::formatting::format_compact_f64(value, false)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::encounter::PhaseType;
+    use crate::encounter::summary::{ChallengePlayerSummary, ChallengeSummary};
+    use crate::query::PhaseSegment;
+
+    fn overview_row(name: &str, dps: f64, hps: f64) -> RaidOverviewRow {
+        RaidOverviewRow {
+            name: name.to_string(),
+            entity_type: "Player".to_string(),
+            class_name: None,
+            discipline_name: Some("Lightning".to_string()),
+            class_icon: None,
+            role_icon: None,
+            damage_total: dps * 300.0,
+            dps,
+            threat_total: 0.0,
+            tps: 0.0,
+            damage_taken_total: 0.0,
+            dtps: 1200.0,
+            aps: 0.0,
+            shielding_given_total: 0.0,
+            sps: 0.0,
+            healing_total: hps * 300.0,
+            hps,
+            healing_effective: hps * 250.0,
+            ehps: hps * 0.8,
+            healing_pct: 0.0,
+            apm: 42.0,
+            abc_pct: 96.5,
+        }
+    }
+
+    pub(crate) fn sample_report() -> EncounterReport {
+        let series = |scale: f64| -> Vec<TimeSeriesPoint> {
+            (0..300)
+                .map(|i| TimeSeriesPoint {
+                    bucket_start_ms: i * 1000,
+                    total_value: scale * (1.0 + (i % 7) as f64),
+                })
+                .collect()
+        };
+        EncounterReport {
+            summary: EncounterSummary {
+                encounter_id: 3,
+                display_name: "Brontes - 2".to_string(),
+                encounter_type: PhaseType::Raid,
+                start_time: Some("2025-12-01T20:15:00".to_string()),
+                end_time: None,
+                duration_seconds: 300,
+                success: true,
+                area_name: "Dread Fortress".to_string(),
+                difficulty: Some("Veteran 8".to_string()),
+                boss_name: Some("Brontes".to_string()),
+                player_metrics: Vec::new(),
+                is_phase_start: false,
+                npc_names: Vec::new(),
+                area_entered_line: None,
+                event_start_line: None,
+                event_end_line: None,
+                challenges: vec![ChallengeSummary {
+                    name: "Hand damage".to_string(),
+                    metric: "damage".to_string(),
+                    total_value: 1_500_000,
+                    event_count: 900,
+                    duration_secs: 300.0,
+                    per_second: Some(5000.0),
+                    by_player: vec![ChallengePlayerSummary {
+                        entity_id: 1,
+                        name: "Jerran".to_string(),
+                        value: 900_000,
+                        percent: 60.0,
+                        per_second: Some(3000.0),
+                    }],
+                }],
+                pvp_stats: Vec::new(),
+                parsely_link: None,
+            },
+            timeline: EncounterTimeline {
+                duration_secs: 300.0,
+                phases: vec![PhaseSegment {
+                    phase_id: "p1".to_string(),
+                    phase_name: "Hands".to_string(),
+                    instance: 1,
+                    start_secs: 0.0,
+                    end_secs: 120.0,
+                }],
+            },
+            overview: vec![
+                overview_row("Jerran", 21_000.0, 0.0),
+                overview_row("Kel <Heals>", 2_000.0, 14_000.0),
+            ],
+            breakdowns: vec![PlayerBreakdown {
+                name: "Jerran".to_string(),
+                damage: Vec::new(),
+                healing: Vec::new(),
+            }],
+            deaths: vec![PlayerDeath {
+                name: "Kel <Heals>".to_string(),
+                death_time_secs: 95.0,
+            }],
+            dps: series(20_000.0),
+            hps: series(8_000.0),
+            dtps: series(5_000.0),
+            annotations: vec![EncounterAnnotation {
+                id: 1,
+                time_secs: 62.0,
+                row_idx: None,
+                text: "Heal CD missed | here".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_heading_and_meta() {
+        let report = sample_report();
+        assert_eq!(report.heading(), "Brontes - 2 (Kill)");
+        assert_eq!(
+            report.meta_line(),
+            "Dread Fortress · Veteran 8 · 2025-12-01 20:15:00 · 5:00"
+        );
+        assert_eq!(report.deaths_of("Kel <Heals>"), 1);
+    }
+}
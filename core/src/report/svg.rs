@@ -0,0 +1,195 @@
+//! Inline SVG line chart for report time series
+
+use std::fmt::Write;
+
+use super::html::escape;
+use super::{EncounterReport, format_amount, format_clock};
+use crate::query::TimeSeriesPoint;
+
+const WIDTH: f32 = 900.0;
+const HEIGHT: f32 = 240.0;
+const PAD_LEFT: f32 = 56.0;
+const PAD_RIGHT: f32 = 12.0;
+const PAD_TOP: f32 = 12.0;
+const PAD_BOTTOM: f32 = 28.0;
+
+/// Seconds averaged per point (raw 1s buckets are too spiky to read)
+const SMOOTHING_SECS: usize = 5;
+
+/// Series colours, matching the app's charts
+const SERIES: [(&str, &str); 3] = [("DPS", "#e05d44"), ("HPS", "#4fc46a"), ("DTPS", "#e8a33d")];
+
+/// Raid DPS/HPS/DTPS over the fight, with phase bands, notes and deaths
+pub(super) fn render_chart(report: &EncounterReport) -> String {
+    let series = [&report.dps, &report.hps, &report.dtps].map(|s| smooth(s, SMOOTHING_SECS));
+    let duration = report
+        .timeline
+        .duration_secs
+        .max(series.iter().map(|s| s.len()).max().unwrap_or(0) as f32)
+        .max(1.0);
+    let max_value = series
+        .iter()
+        .flatten()
+        .map(|(_, v)| *v)
+        .fold(0.0_f64, f64::max)
+        .max(1.0);
+
+    let plot_w = WIDTH - PAD_LEFT - PAD_RIGHT;
+    let plot_h = HEIGHT - PAD_TOP - PAD_BOTTOM;
+    let x = |secs: f32| PAD_LEFT + (secs / duration).clamp(0.0, 1.0) * plot_w;
+    let y = |value: f64| PAD_TOP + plot_h - (value / max_value) as f32 * plot_h;
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        r#"<svg class="chart" viewBox="0 0 {WIDTH} {HEIGHT}" xmlns="http://www.w3.org/2000/svg" role="img">"#
+    );
+
+    // Phase bands, alternating shade so neighbouring phases stay distinct
+    for (i, phase) in report.timeline.phases.iter().enumerate() {
+        let (x0, x1) = (x(phase.start_secs), x(phase.end_secs));
+        let fill = if i % 2 == 0 { "#ffffff0d" } else { "#ffffff05" };
+        let _ = write!(
+            out,
+            r#"<rect x="{x0:.1}" y="{PAD_TOP}" width="{:.1}" height="{plot_h}" fill="{fill}"><title>{}</title></rect>"#,
+            (x1 - x0).max(0.0),
+            escape(&phase.phase_name)
+        );
+        let _ = write!(
+            out,
+            r##"<text x="{:.1}" y="{:.1}" fill="#8a93a6" font-size="10">{}</text>"##,
+            x0 + 3.0,
+            PAD_TOP + 10.0,
+            escape(&phase.phase_name)
+        );
+    }
+
+    // Axes and grid
+    for i in 0..=4 {
+        let value = max_value * f64::from(i) / 4.0;
+        let gy = y(value);
+        let _ = write!(
+            out,
+            r##"<line x1="{PAD_LEFT}" y1="{gy:.1}" x2="{:.1}" y2="{gy:.1}" stroke="#2c3340" stroke-width="1"/><text x="{:.1}" y="{:.1}" fill="#8a93a6" font-size="10" text-anchor="end">{}</text>"##,
+            WIDTH - PAD_RIGHT,
+            PAD_LEFT - 6.0,
+            gy + 3.0,
+            format_amount(value)
+        );
+    }
+    let step = tick_step(duration);
+    let mut tick = 0.0;
+    while tick <= duration {
+        let _ = write!(
+            out,
+            r##"<text x="{:.1}" y="{:.1}" fill="#8a93a6" font-size="10" text-anchor="middle">{}</text>"##,
+            x(tick),
+            HEIGHT - 10.0,
+            format_clock(tick)
+        );
+        tick += step;
+    }
+
+    // Series lines
+    for ((label, color), points) in SERIES.iter().zip(&series) {
+        if points.iter().all(|(_, v)| *v <= 0.0) {
+            continue;
+        }
+        let path: Vec<String> = points
+            .iter()
+            .map(|(secs, value)| format!("{:.1},{:.1}", x(*secs), y(*value)))
+            .collect();
+        let _ = write!(
+            out,
+            r#"<polyline fill="none" stroke="{color}" stroke-width="1.5" points="{}"><title>{label}</title></polyline>"#,
+            path.join(" ")
+        );
+    }
+
+    // Deaths (red ticks along the bottom) and notes (dashed lines)
+    for death in &report.deaths {
+        let dx = x(death.death_time_secs);
+        let _ = write!(
+            out,
+            r##"<line x1="{dx:.1}" y1="{:.1}" x2="{dx:.1}" y2="{:.1}" stroke="#ff4d4d" stroke-width="2"><title>{} died at {}</title></line>"##,
+            PAD_TOP + plot_h - 10.0,
+            PAD_TOP + plot_h,
+            escape(&death.name),
+            format_clock(death.death_time_secs)
+        );
+    }
+    for note in &report.annotations {
+        let nx = x(note.time_secs);
+        let _ = write!(
+            out,
+            r##"<line x1="{nx:.1}" y1="{PAD_TOP}" x2="{nx:.1}" y2="{:.1}" stroke="#f2d15c" stroke-width="1" stroke-dasharray="4 3"><title>{} {}</title></line>"##,
+            PAD_TOP + plot_h,
+            format_clock(note.time_secs),
+            escape(&note.text)
+        );
+    }
+
+    out.push_str("</svg>");
+    out
+}
+
+/// Legend entries for the chart
+pub(super) fn legend() -> String {
+    let mut out = String::from(r#"<div class="legend">"#);
+    for (label, color) in SERIES {
+        let _ = write!(
+            out,
+            r#"<span><i style="background:{color}"></i>{label}</span>"#
+        );
+    }
+    out.push_str(r#"<span><i style="background:#ff4d4d"></i>Death</span><span><i style="background:#f2d15c"></i>Note</span></div>"#);
+    out
+}
+
+/// Trailing average over `window` buckets, as (seconds, value) pairs
+fn smooth(points: &[TimeSeriesPoint], window: usize) -> Vec<(f32, f64)> {
+    let mut sum = 0.0;
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| {
+            sum += point.total_value;
+            if i >= window {
+                sum -= points[i - window].total_value;
+            }
+            let count = (i + 1).min(window) as f64;
+            (point.bucket_start_ms as f32 / 1000.0, sum / count)
+        })
+        .collect()
+}
+
+/// Time axis spacing that gives roughly 6-12 labels
+fn tick_step(duration: f32) -> f32 {
+    [15.0, 30.0, 60.0, 120.0, 300.0, 600.0]
+        .into_iter()
+        .find(|step| duration / step <= 12.0)
+        .unwrap_or(1200.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smooth_averages_trailing_window() {
+        let points: Vec<TimeSeriesPoint> = [10.0, 20.0, 30.0, 40.0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| TimeSeriesPoint {
+                bucket_start_ms: i as i64 * 1000,
+                total_value: v,
+            })
+            .collect();
+        let smoothed = smooth(&points, 2);
+        assert_eq!(
+            smoothed,
+            vec![(0.0, 10.0), (1.0, 15.0), (2.0, 25.0), (3.0, 35.0)]
+        );
+        assert_eq!(tick_step(300.0), 30.0);
+    }
+}
@@ -0,0 +1,588 @@
+//! Scoring rotation cycles against a reference rotation
+//!
+//! Every GCD cast of a listed ability is scored: in a priority rotation it
+//! must be the highest entry that was available (off cooldown, proc up, DoT
+//! due), in a fixed rotation it must be the next ability of the sequence.
+//! DoT reapplications are checked against the refresh window. Each deviation
+//! carries an estimate of the damage lost, based on the player's own average
+//! per cast of the abilities involved.
+
+use std::collections::HashMap;
+
+use super::definition::{RotationDefinition, RotationKind};
+use crate::dsl::{AbilitySelector, EffectSelector};
+
+pub use baras_types::{
+    CycleScore, RotationCheck, RotationCycle, RotationDeviation, RotationDeviationKind,
+};
+
+/// Slack for cooldown and DoT timings (log timestamps, latency)
+const TIMING_TOLERANCE_SECS: f32 = 0.3;
+
+/// An ability activation and its place in the analysis
+struct Cast<'a> {
+    time_secs: f32,
+    cycle: usize,
+    slot: usize,
+    on_gcd: bool,
+    ability_id: i64,
+    name: &'a str,
+}
+
+fn matches(selector: &AbilitySelector, cast: &Cast) -> bool {
+    selector.matches(cast.ability_id as u64, Some(cast.name))
+}
+
+/// Last time in a sorted list strictly before `t`
+fn last_before(times: &[f32], t: f32) -> Option<f32> {
+    let pos = times.partition_point(|&x| x < t);
+    pos.checked_sub(1).map(|i| times[i])
+}
+
+/// Windows in which an effect was on the player, from
+/// `(time, applied, effect_id, effect_name)` apply/remove events in time
+/// order. A window still open at the end runs to `f32::MAX`.
+pub fn effect_windows(
+    events: &[(f32, bool, i64, String)],
+    selector: &EffectSelector,
+) -> Vec<(f32, f32)> {
+    let mut windows = Vec::new();
+    let mut open = None;
+    for (time, applied, id, name) in events {
+        if !selector.matches(*id as u64, Some(name)) {
+            continue;
+        }
+        match (*applied, open) {
+            (true, None) => open = Some(*time),
+            (false, Some(start)) => {
+                windows.push((start, *time));
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = open {
+        windows.push((start, f32::MAX));
+    }
+    windows
+}
+
+/// Deviations and scored cast counts per cycle
+struct Tally {
+    deviations: Vec<Vec<RotationDeviation>>,
+    scored: Vec<usize>,
+}
+
+impl Tally {
+    fn push(&mut self, cycle: usize, deviation: RotationDeviation) {
+        self.deviations[cycle].push(deviation);
+    }
+}
+
+struct Checker<'a> {
+    definition: &'a RotationDefinition,
+    casts: Vec<Cast<'a>>,
+    /// First cast of each step (names and values come from the log)
+    first_cast: Vec<Option<usize>>,
+    /// Steps cast on the GCD at least once
+    on_gcd: Vec<bool>,
+    effect_windows: &'a [Vec<(f32, f32)>],
+    values: &'a HashMap<i64, f64>,
+}
+
+impl<'a> Checker<'a> {
+    fn new(
+        definition: &'a RotationDefinition,
+        cycles: &'a [RotationCycle],
+        effect_windows: &'a [Vec<(f32, f32)>],
+        values: &'a HashMap<i64, f64>,
+    ) -> Self {
+        let mut casts = Vec::new();
+        for (cycle, c) in cycles.iter().enumerate() {
+            for (slot, s) in c.slots.iter().enumerate() {
+                let events = s
+                    .off_gcd
+                    .iter()
+                    .map(|e| (e, false))
+                    .chain(std::iter::once((&s.gcd_ability, true)));
+                for (event, on_gcd) in events {
+                    casts.push(Cast {
+                        time_secs: event.time_secs,
+                        cycle,
+                        slot,
+                        on_gcd,
+                        ability_id: event.ability_id,
+                        name: &event.ability_name,
+                    });
+                }
+            }
+        }
+        casts.sort_by(|a, b| a.time_secs.total_cmp(&b.time_secs));
+
+        let steps = &definition.abilities;
+        let first_cast = steps
+            .iter()
+            .map(|s| casts.iter().position(|c| matches(&s.ability, c)))
+            .collect();
+        let on_gcd = steps
+            .iter()
+            .map(|s| casts.iter().any(|c| c.on_gcd && matches(&s.ability, c)))
+            .collect();
+
+        Self {
+            definition,
+            casts,
+            first_cast,
+            on_gcd,
+            effect_windows,
+            values,
+        }
+    }
+
+    fn step_name(&self, k: usize) -> String {
+        match self.first_cast[k] {
+            Some(i) => self.casts[i].name.to_string(),
+            None => self.definition.abilities[k].ability.display(),
+        }
+    }
+
+    fn step_value(&self, k: usize) -> f64 {
+        self.first_cast[k]
+            .map(|i| self.cast_value(&self.casts[i]))
+            .unwrap_or(0.0)
+    }
+
+    fn cast_value(&self, cast: &Cast) -> f64 {
+        self.values.get(&cast.ability_id).copied().unwrap_or(0.0)
+    }
+
+    fn cast_times(&self, selector: &AbilitySelector) -> Vec<f32> {
+        self.casts
+            .iter()
+            .filter(|c| matches(selector, c))
+            .map(|c| c.time_secs)
+            .collect()
+    }
+
+    fn not_used(&self, tally: &mut Tally) {
+        if tally.deviations.is_empty() {
+            return;
+        }
+        let start = self.casts.first().map_or(0.0, |c| c.time_secs);
+        for k in (0..self.first_cast.len()).filter(|&k| self.first_cast[k].is_none()) {
+            tally.push(
+                0,
+                RotationDeviation {
+                    kind: RotationDeviationKind::NotUsed,
+                    time_secs: start,
+                    slot_index: None,
+                    expected: Some(self.step_name(k)),
+                    actual: None,
+                    detail: String::new(),
+                    lost_damage: 0.0,
+                },
+            );
+        }
+    }
+
+    fn check_priority(&self, tally: &mut Tally) {
+        let steps = &self.definition.abilities;
+        let step_times: Vec<Vec<f32>> = steps.iter().map(|s| self.cast_times(&s.ability)).collect();
+        // DoT applications, for steps that apply a DoT
+        let dot_times: Vec<Option<(f32, f32, Vec<f32>)>> = (0..steps.len())
+            .map(|k| {
+                let cast = &self.casts[self.first_cast[k]?];
+                let dot = self
+                    .definition
+                    .dots
+                    .iter()
+                    .find(|d| matches(&d.ability, cast))?;
+                Some((
+                    dot.duration_secs,
+                    dot.refresh_window_secs,
+                    self.cast_times(&dot.ability),
+                ))
+            })
+            .collect();
+
+        let available = |k: usize, t: f32| {
+            let step = &steps[k];
+            let off_cooldown = step.cooldown_secs <= 0.0
+                || last_before(&step_times[k], t)
+                    .is_none_or(|last| t - last + TIMING_TOLERANCE_SECS >= step.cooldown_secs);
+            let proc_up = step.requires_effect.is_none()
+                || self.effect_windows.get(k).is_some_and(|windows| {
+                    windows.iter().any(|(start, end)| {
+                        *start <= t + TIMING_TOLERANCE_SECS && t <= *end + TIMING_TOLERANCE_SECS
+                    })
+                });
+            let dot_due = dot_times[k]
+                .as_ref()
+                .is_none_or(|(duration, window, times)| {
+                    last_before(times, t).is_none_or(|last| last + duration - t <= *window)
+                });
+            off_cooldown && proc_up && dot_due
+        };
+
+        for cast in self.casts.iter().filter(|c| c.on_gcd) {
+            let Some(actual) = steps.iter().position(|s| matches(&s.ability, cast)) else {
+                continue;
+            };
+            tally.scored[cast.cycle] += 1;
+            let t = cast.time_secs;
+            let expected = (0..actual).find(|&k| self.on_gcd[k] && available(k, t));
+            if let Some(k) = expected {
+                tally.push(
+                    cast.cycle,
+                    RotationDeviation {
+                        kind: RotationDeviationKind::Priority,
+                        time_secs: t,
+                        slot_index: Some(cast.slot),
+                        expected: Some(self.step_name(k)),
+                        actual: Some(cast.name.to_string()),
+                        detail: String::new(),
+                        lost_damage: (self.step_value(k) - self.cast_value(cast)).max(0.0),
+                    },
+                );
+            }
+        }
+    }
+
+    fn check_fixed(&self, tally: &mut Tally) {
+        let steps = &self.definition.abilities;
+        // Abilities only ever weaved off the GCD aren't part of the sequence
+        let sequence: Vec<usize> = (0..steps.len())
+            .filter(|&k| self.on_gcd[k] || self.first_cast[k].is_none())
+            .collect();
+        if sequence.is_empty() {
+            return;
+        }
+        let last_cycle = tally.scored.len().saturating_sub(1);
+
+        for cycle in 0..tally.scored.len() {
+            let mut pos = 0;
+            let mut last_time = None;
+            for cast in self.casts.iter().filter(|c| c.on_gcd && c.cycle == cycle) {
+                last_time = Some(cast.time_secs);
+                let is_step = |p: usize| matches(&steps[sequence[p]].ability, cast);
+                if pos >= sequence.len() {
+                    // Past the end of the sequence (filler), not scored
+                    continue;
+                }
+                tally.scored[cycle] += 1;
+                if is_step(pos) {
+                    pos += 1;
+                } else if let Some(found) = (pos + 1..sequence.len()).find(|&p| is_step(p)) {
+                    // Skipped ahead: everything in between is missing
+                    for &k in &sequence[pos..found] {
+                        tally.scored[cycle] += 1;
+                        self.push_missing(tally, cycle, k, cast.time_secs, Some(cast.slot));
+                    }
+                    pos = found + 1;
+                } else {
+                    let k = sequence[pos];
+                    tally.push(
+                        cycle,
+                        RotationDeviation {
+                            kind: RotationDeviationKind::OutOfOrder,
+                            time_secs: cast.time_secs,
+                            slot_index: Some(cast.slot),
+                            expected: Some(self.step_name(k)),
+                            actual: Some(cast.name.to_string()),
+                            detail: format!("step {} of {}", pos + 1, sequence.len()),
+                            lost_damage: (self.step_value(k) - self.cast_value(cast)).max(0.0),
+                        },
+                    );
+                    pos += 1;
+                }
+            }
+            // The last cycle is usually cut short by the end of the fight
+            if cycle != last_cycle
+                && let Some(time) = last_time
+            {
+                for &k in &sequence[pos..] {
+                    tally.scored[cycle] += 1;
+                    self.push_missing(tally, cycle, k, time, None);
+                }
+            }
+        }
+    }
+
+    fn push_missing(
+        &self,
+        tally: &mut Tally,
+        cycle: usize,
+        k: usize,
+        time_secs: f32,
+        slot_index: Option<usize>,
+    ) {
+        tally.push(
+            cycle,
+            RotationDeviation {
+                kind: RotationDeviationKind::Missing,
+                time_secs,
+                slot_index,
+                expected: Some(self.step_name(k)),
+                actual: None,
+                detail: String::new(),
+                lost_damage: self.step_value(k),
+            },
+        );
+    }
+
+    fn check_dots(&self, tally: &mut Tally) {
+        for dot in &self.definition.dots {
+            let duration = dot.duration_secs.max(0.1);
+            let applications: Vec<&Cast> = self
+                .casts
+                .iter()
+                .filter(|c| matches(&dot.ability, c))
+                .collect();
+            for pair in applications.windows(2) {
+                let (prev, cast) = (pair[0], pair[1]);
+                let remaining = prev.time_secs + duration - cast.time_secs;
+                let value = self.cast_value(cast);
+                let (kind, detail, lost_damage) =
+                    if remaining > dot.refresh_window_secs + TIMING_TOLERANCE_SECS {
+                        (
+                            RotationDeviationKind::DotClipped,
+                            format!("reapplied with {:.1}s left", remaining),
+                            value * (remaining / duration).min(1.0) as f64,
+                        )
+                    } else if remaining < -TIMING_TOLERANCE_SECS {
+                        (
+                            RotationDeviationKind::DotDropped,
+                            format!("down for {:.1}s", -remaining),
+                            value * (-remaining / duration) as f64,
+                        )
+                    } else {
+                        continue;
+                    };
+                tally.push(
+                    cast.cycle,
+                    RotationDeviation {
+                        kind,
+                        time_secs: cast.time_secs,
+                        slot_index: Some(cast.slot),
+                        expected: None,
+                        actual: Some(cast.name.to_string()),
+                        detail,
+                        lost_damage,
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Share of scored casts without a deviation
+fn score_pct(penalized: usize, scored: usize) -> f32 {
+    if scored == 0 {
+        100.0
+    } else {
+        (1.0 - penalized as f32 / scored as f32).max(0.0) * 100.0
+    }
+}
+
+/// Score rotation cycles against a reference rotation.
+///
+/// `effect_windows` holds, per ability step, the windows in which its
+/// `requires_effect` was on the player (ignored for steps without one).
+/// `values` is the average damage plus effective healing per cast by ability
+/// ID, used to estimate what each deviation cost.
+pub fn check_rotation(
+    definition: &RotationDefinition,
+    cycles: &[RotationCycle],
+    effect_windows: &[Vec<(f32, f32)>],
+    values: &HashMap<i64, f64>,
+) -> RotationCheck {
+    let checker = Checker::new(definition, cycles, effect_windows, values);
+    let mut tally = Tally {
+        deviations: vec![Vec::new(); cycles.len()],
+        scored: vec![0; cycles.len()],
+    };
+
+    checker.not_used(&mut tally);
+    match definition.kind {
+        RotationKind::Priority => checker.check_priority(&mut tally),
+        RotationKind::Fixed => checker.check_fixed(&mut tally),
+    }
+    checker.check_dots(&mut tally);
+
+    let (mut total_penalized, mut total_scored, mut total_lost) = (0, 0, 0.0);
+    let cycles = tally
+        .deviations
+        .into_iter()
+        .zip(tally.scored)
+        .map(|(mut deviations, scored)| {
+            deviations.sort_by(|a, b| a.time_secs.total_cmp(&b.time_secs));
+            let penalized = deviations
+                .iter()
+                .filter(|d| d.kind != RotationDeviationKind::NotUsed)
+                .count();
+            let lost_damage = deviations.iter().map(|d| d.lost_damage).sum();
+            total_penalized += penalized;
+            total_scored += scored;
+            total_lost += lost_damage;
+            CycleScore {
+                score_pct: score_pct(penalized, scored),
+                deviations,
+                lost_damage,
+            }
+        })
+        .collect();
+
+    RotationCheck {
+        name: definition.display_name().to_string(),
+        discipline: definition.discipline.clone(),
+        score_pct: score_pct(total_penalized, total_scored),
+        lost_damage: total_lost,
+        cycles,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rotations::RotationConfig;
+    use baras_types::{GcdSlot, RotationEvent};
+
+    fn cycle(casts: &[(f32, i64, &str)]) -> RotationCycle {
+        RotationCycle {
+            slots: casts
+                .iter()
+                .map(|(time_secs, ability_id, name)| GcdSlot {
+                    gcd_ability: RotationEvent {
+                        time_secs: *time_secs,
+                        ability_id: *ability_id,
+                        ability_name: name.to_string(),
+                    },
+                    off_gcd: Vec::new(),
+                    gcd_gap: None,
+                })
+                .collect(),
+            duration_secs: 0.0,
+            total_damage: 0.0,
+            effective_heal: 0.0,
+            crit_count: 0,
+            hit_count: 0,
+        }
+    }
+
+    fn definition(toml: &str) -> RotationDefinition {
+        let config: RotationConfig = toml::from_str(toml).unwrap();
+        config.rotations.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn test_priority_flags_lower_casts_while_higher_was_available() {
+        let def = definition(
+            r#"
+            [[rotation]]
+            discipline = "Lightning"
+
+            [[rotation.abilities]]
+            ability = "Thundering Blast"
+            cooldown_secs = 15.0
+
+            [[rotation.abilities]]
+            ability = "Lightning Flash"
+            requires_effect = "Lightning Barrage"
+
+            [[rotation.abilities]]
+            ability = 3
+
+            [[rotation.dots]]
+            ability = "Affliction"
+            duration_secs = 18.0
+            "#,
+        );
+        let cycles = vec![cycle(&[
+            (0.0, 1, "Thundering Blast"),
+            (1.5, 3, "Lightning Bolt"),
+            // Flash proc up at 3.0, but Bolt cast instead
+            (3.0, 3, "Lightning Bolt"),
+            (4.5, 2, "Lightning Flash"),
+            // Blast back off cooldown at 15.0
+            (16.5, 3, "Lightning Bolt"),
+            (18.0, 1, "Thundering Blast"),
+        ])];
+        let windows = vec![Vec::new(), vec![(2.5, 4.6)], Vec::new()];
+        let values = HashMap::from([(1, 10_000.0), (2, 8_000.0), (3, 5_000.0)]);
+
+        let check = check_rotation(&def, &cycles, &windows, &values);
+        let devs = &check.cycles[0].deviations;
+        assert_eq!(devs.len(), 2);
+        assert!(
+            devs.iter()
+                .all(|d| d.kind == RotationDeviationKind::Priority)
+        );
+        assert_eq!(devs[0].expected.as_deref(), Some("Lightning Flash"));
+        assert_eq!(devs[0].slot_index, Some(2));
+        assert_eq!(devs[0].lost_damage, 3_000.0);
+        assert_eq!(devs[1].expected.as_deref(), Some("Thundering Blast"));
+        assert_eq!(devs[1].time_secs, 16.5);
+        assert!((check.cycles[0].score_pct - 66.667).abs() < 0.01);
+        assert_eq!(check.lost_damage, 8_000.0);
+    }
+
+    #[test]
+    fn test_fixed_sequence_reports_missing_steps_and_dot_timing() {
+        let def = definition(
+            r#"
+            [[rotation]]
+            name = "Opener loop"
+            discipline = "Lightning"
+            kind = "fixed"
+
+            [[rotation.abilities]]
+            ability = "Affliction"
+
+            [[rotation.abilities]]
+            ability = "Thundering Blast"
+
+            [[rotation.abilities]]
+            ability = "Lightning Bolt"
+
+            [[rotation.dots]]
+            ability = "Affliction"
+            duration_secs = 6.0
+            refresh_window_secs = 1.0
+            "#,
+        );
+        let cycles = vec![
+            // Skipped Thundering Blast
+            cycle(&[(0.0, 1, "Affliction"), (1.5, 3, "Lightning Bolt")]),
+            // Affliction clipped with 3s left
+            cycle(&[
+                (3.0, 1, "Affliction"),
+                (4.5, 2, "Thundering Blast"),
+                (6.0, 3, "Lightning Bolt"),
+            ]),
+            // Affliction dropped for 3s
+            cycle(&[(12.0, 1, "Affliction")]),
+        ];
+        let values = HashMap::from([(1, 6_000.0), (2, 9_000.0), (3, 4_000.0)]);
+
+        let check = check_rotation(&def, &cycles, &[], &values);
+        assert_eq!(check.name, "Opener loop");
+
+        let first = &check.cycles[0].deviations;
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].kind, RotationDeviationKind::Missing);
+        assert_eq!(first[0].expected.as_deref(), Some("Thundering Blast"));
+        assert_eq!(check.cycles[0].lost_damage, 9_000.0);
+
+        let second = &check.cycles[1].deviations;
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].kind, RotationDeviationKind::DotClipped);
+        assert_eq!(second[0].lost_damage, 3_000.0);
+        assert!((check.cycles[1].score_pct - 66.667).abs() < 0.01);
+
+        // Last cycle is cut short: no missing steps, but the drop counts
+        let third = &check.cycles[2].deviations;
+        assert_eq!(third.len(), 1);
+        assert_eq!(third[0].kind, RotationDeviationKind::DotDropped);
+        assert_eq!(third[0].detail, "down for 3.0s");
+    }
+}
@@ -0,0 +1,96 @@
+//! Reference rotation definition types
+//!
+//! A reference rotation describes how a discipline should be played: either a
+//! priority list (cast the highest entry that's available) or a fixed sequence
+//! repeated every cycle, plus DoTs that must be kept up.
+
+use serde::{Deserialize, Serialize};
+
+use crate::dsl::{AbilitySelector, EffectSelector};
+use crate::game_data::Discipline;
+
+/// Default window before a DoT expires in which reapplying it is fine
+fn default_refresh_window() -> f32 {
+    3.0
+}
+
+/// Root of a rotation TOML file (`[[rotation]]` tables)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RotationConfig {
+    #[serde(default, rename = "rotation")]
+    pub rotations: Vec<RotationDefinition>,
+}
+
+/// How the ability list of a rotation is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RotationKind {
+    /// Highest available ability first
+    #[default]
+    Priority,
+    /// The same sequence every cycle, starting at the anchor ability
+    Fixed,
+}
+
+/// Reference rotation for one discipline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotationDefinition {
+    /// Display name (e.g. "Single target")
+    #[serde(default)]
+    pub name: String,
+
+    /// Discipline display name (e.g. "Lightning", "Innovative Ordnance")
+    pub discipline: String,
+
+    #[serde(default)]
+    pub kind: RotationKind,
+
+    /// Abilities in priority or sequence order
+    #[serde(default)]
+    pub abilities: Vec<RotationStep>,
+
+    /// DoTs to keep up
+    #[serde(default)]
+    pub dots: Vec<DotRule>,
+}
+
+impl RotationDefinition {
+    pub fn discipline(&self) -> Option<Discipline> {
+        Discipline::from_name(&self.discipline)
+    }
+
+    /// Name to show for the definition
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            &self.discipline
+        } else {
+            &self.name
+        }
+    }
+}
+
+/// One ability in a priority list or sequence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotationStep {
+    pub ability: AbilitySelector,
+
+    /// Cooldown; the ability isn't expected again until it's ready (priority only)
+    #[serde(default, skip_serializing_if = "crate::serde_defaults::is_zero_f32")]
+    pub cooldown_secs: f32,
+
+    /// Only expected while the player has this effect (procs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_effect: Option<EffectSelector>,
+}
+
+/// A DoT applied by an ability that should never drop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DotRule {
+    pub ability: AbilitySelector,
+
+    pub duration_secs: f32,
+
+    /// Reapplying earlier than this before expiry clips the DoT
+    #[serde(default = "default_refresh_window")]
+    pub refresh_window_secs: f32,
+}
@@ -0,0 +1,90 @@
+//! Reference rotations
+//!
+//! Per-discipline priority lists or fixed rotations, loaded from TOML files
+//! and used to score rotation cycles from the data explorer:
+//!
+//! ```toml
+//! [[rotation]]
+//! name = "Single target"
+//! discipline = "Lightning"
+//! kind = "priority"            # or "fixed"
+//!
+//! [[rotation.abilities]]
+//! ability = "Thundering Blast" # name or ability ID
+//! cooldown_secs = 15.0
+//!
+//! [[rotation.abilities]]
+//! ability = "Lightning Flash"
+//! requires_effect = "Lightning Barrage"
+//!
+//! [[rotation.abilities]]
+//! ability = "Lightning Bolt"
+//!
+//! [[rotation.dots]]
+//! ability = "Affliction"
+//! duration_secs = 18.0
+//! refresh_window_secs = 3.0
+//! ```
+
+mod checker;
+mod definition;
+
+pub use checker::{check_rotation, effect_windows};
+pub use definition::{DotRule, RotationConfig, RotationDefinition, RotationKind, RotationStep};
+
+use std::path::Path;
+
+use crate::game_data::Discipline;
+
+/// Load rotation definitions from a TOML file
+pub fn load_rotations_from_file(path: &Path) -> Result<Vec<RotationDefinition>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read rotation file {:?}: {}", path, e))?;
+
+    let config: RotationConfig = toml::from_str(&content)
+        .map_err(|e| format!("Failed to parse rotation file {:?}: {}", path, e))?;
+
+    for def in &config.rotations {
+        if def.discipline().is_none() {
+            return Err(format!(
+                "Unknown discipline {:?} in rotation file {:?}",
+                def.discipline, path
+            ));
+        }
+    }
+
+    Ok(config.rotations)
+}
+
+/// Load all rotation definitions from a directory, in file name order
+pub fn load_rotations_from_dir(dir: &Path) -> Vec<RotationDefinition> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new(); // Empty if directory doesn't exist
+    };
+
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    let mut rotations = Vec::new();
+    for path in paths {
+        match load_rotations_from_file(&path) {
+            Ok(defs) => rotations.extend(defs),
+            Err(e) => tracing::warn!(error = %e, "Failed to load rotation file"),
+        }
+    }
+    rotations
+}
+
+/// First reference rotation defined for a discipline
+pub fn find_rotation(
+    rotations: &[RotationDefinition],
+    discipline: Discipline,
+) -> Option<&RotationDefinition> {
+    rotations
+        .iter()
+        .find(|r| r.discipline() == Some(discipline))
+}
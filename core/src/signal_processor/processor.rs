@@ -3,7 +3,7 @@ use crate::context::resolve;
 use crate::encounter::combat::ActiveBoss;
 use crate::encounter::entity_info::PlayerInfo;
 use crate::encounter::EncounterState;
-use crate::game_data::{correct_apply_charges, effect_id, effect_type_id};
+use crate::game_data::{correct_apply_charges, effect_id, effect_type_id, LogNames};
 use crate::signal_processor::signal::GameSignal;
 use crate::state::cache::SessionCache;
 
@@ -30,11 +30,14 @@ impl EventProcessor {
     /// The bool indicates whether the event was accumulated (for parquet filtering).
     pub fn process_event(
         &mut self,
-        event: CombatEvent,
+        mut event: CombatEvent,
         cache: &mut SessionCache,
     ) -> (Vec<GameSignal>, CombatEvent, bool) {
         let mut signals = Vec::new();
 
+        // 0. Localized clients: normalize names to English (by game ID)
+        self.normalize_event_names(&mut event, cache);
+
         // ═══════════════════════════════════════════════════════════════════════
         // PHASE 1: Global Event Handlers (state-independent)
         // ═══════════════════════════════════════════════════════════════════════
@@ -64,6 +67,9 @@ impl EventProcessor {
         // 1g. NPC Target Tracking
         signals.extend(self.handle_target_changed(&event, cache));
 
+        // 1h. Training dummy parses
+        signals.extend(self.handle_dummy_parse(&event, cache));
+
         // ═══════════════════════════════════════════════════════════════════════
         // PHASE 2: Signal Emission (pure transformation)
         // ═══════════════════════════════════════════════════════════════════════
@@ -149,6 +155,8 @@ impl EventProcessor {
             received_revive_immunity: false,
             current_target_id: 0,
             last_seen_at: Some(event.timestamp),
+            current_hp: 0,
+            max_hp: 0,
         };
 
         // Upsert into session-level player discipline registry (source of truth)
@@ -401,6 +409,48 @@ impl EventProcessor {
         signals
     }
 
+    /// Detect the client language and rewrite localized names to English so
+    /// name-based selectors and lookups work for any client.
+    fn normalize_event_names(&self, event: &mut CombatEvent, cache: &mut SessionCache) {
+        // An unrecognized localized log may still be recognized by the name of
+        // a later area change
+        let recognized = cache.log_names.as_ref().map(|n| n.language().is_some());
+        if recognized.is_none()
+            || (recognized == Some(false) && event.effect.type_id == effect_type_id::AREAENTERED)
+        {
+            cache.log_names = LogNames::detect(
+                event.effect.type_id,
+                resolve(event.effect.type_name),
+                cache.client_language,
+            );
+            cache.register_roster_names();
+        }
+        let Some(names) = cache.log_names.as_mut() else {
+            return;
+        };
+
+        let source_npc = event.source_entity.entity_type == EntityType::Npc;
+        let target_npc = event.target_entity.entity_type == EntityType::Npc;
+        let effect = &mut event.effect;
+        let mut entries = [
+            (effect.type_id, &mut effect.type_name),
+            (effect.effect_id, &mut effect.effect_name),
+            (effect.difficulty_id, &mut effect.difficulty_name),
+            (effect.discipline_id, &mut effect.discipline_name),
+            (event.action.action_id, &mut event.action.name),
+            (event.details.dmg_type_id, &mut event.details.dmg_type),
+            (
+                if source_npc { event.source_entity.class_id } else { 0 },
+                &mut event.source_entity.name,
+            ),
+            (
+                if target_npc { event.target_entity.class_id } else { 0 },
+                &mut event.target_entity.name,
+            ),
+        ];
+        names.normalize(&mut entries);
+    }
+
     /// Handle AreaEntered events.
     fn handle_area_transition(
         &self,
@@ -771,6 +821,22 @@ impl EventProcessor {
         signals
     }
 
+    /// Track the local player's training dummy parse (emits once the limit is reached).
+    fn handle_dummy_parse(&self, event: &CombatEvent, cache: &mut SessionCache) -> Option<GameSignal> {
+        if !cache.player_initialized || event.source_entity.log_id != cache.player.id {
+            return None;
+        }
+        let parse = cache.dummy_parse.process(
+            event,
+            resolve(cache.player.name),
+            &cache.player.discipline_name,
+        )?;
+        Some(GameSignal::DummyParseCompleted {
+            parse,
+            timestamp: event.timestamp,
+        })
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // Phase 2: Signal Emission (pure transformation, no state changes)
     // ═══════════════════════════════════════════════════════════════════════════
@@ -108,6 +108,7 @@ fn signal_type_name(signal: &GameSignal) -> &'static str {
         GameSignal::PhaseChanged { .. } => "PhaseChanged",
         GameSignal::PhaseEndTriggered { .. } => "PhaseEndTriggered",
         GameSignal::CounterChanged { .. } => "CounterChanged",
+        GameSignal::DummyParseCompleted { .. } => "DummyParseCompleted",
     }
 }
 
@@ -633,6 +634,8 @@ fn test_bestia_complete_encounter() {
             boss: None,
             display_target: Default::default(),
             difficulties: Vec::new(),
+            roles: bt.roles.clone(),
+            disciplines: bt.disciplines.clone(),
             phases: Vec::new(),
             counter_condition: None,
             per_target: bt.per_target,
@@ -1,5 +1,6 @@
 use crate::combat_log::EntityType;
 use crate::context::IStr;
+use crate::encounter::dummy::DummyParse;
 use chrono::NaiveDateTime;
 
 /// Signals emitted by the EventProcessor for cross-cutting concerns.
@@ -244,6 +245,13 @@ pub enum GameSignal {
         new_value: u32,
         timestamp: NaiveDateTime,
     },
+
+    // ─── Training Dummy Signals ────────────────────────────────────────────────
+    /// The local player's training dummy parse reached its limit
+    DummyParseCompleted {
+        parse: DummyParse,
+        timestamp: NaiveDateTime,
+    },
 }
 
 impl GameSignal {
@@ -270,7 +278,8 @@ impl GameSignal {
             | Self::BossHpChanged { timestamp, .. }
             | Self::PhaseChanged { timestamp, .. }
             | Self::PhaseEndTriggered { timestamp, .. }
-            | Self::CounterChanged { timestamp, .. } => *timestamp,
+            | Self::CounterChanged { timestamp, .. }
+            | Self::DummyParseCompleted { timestamp, .. } => *timestamp,
         }
     }
 }
@@ -1,10 +1,13 @@
 use chrono::NaiveDateTime;
 
 use crate::dsl::BossEncounterDefinition;
+use crate::encounter::dummy::DummyParseTracker;
 use crate::encounter::entity_info::PlayerInfo;
 use crate::encounter::summary::{create_encounter_summary, EncounterHistory};
 use crate::encounter::{CombatEncounter, EncounterState, OverlayHealthEntry, ProcessingMode};
-use crate::game_data::{clear_boss_registry, register_hp_overlay_entity, Difficulty};
+use crate::game_data::{
+    clear_boss_registry, register_hp_overlay_entity, Difficulty, GameLanguage, LogNames,
+};
 use crate::state::info::AreaInfo;
 use crate::state::ipc::{
     ParseWorkerOutput, WorkerAreaInfo, WorkerPlayerDiscipline, WorkerPlayerInfo,
@@ -50,6 +53,16 @@ pub struct SessionCache {
     /// Timestamp of last combat exit - used to detect fake combat splits
     /// (e.g., loot chest "enemies" or Kephess SM walker phase)
     pub last_combat_exit_time: Option<NaiveDateTime>,
+
+    /// Name tables for the log's client language (resolved once it's detected)
+    pub log_names: Option<LogNames>,
+
+    /// Game client language declared by the user for localized logs the name
+    /// tables don't recognize (`None` to only detect it)
+    pub client_language: Option<GameLanguage>,
+
+    /// Live training dummy parse for the local player
+    pub dummy_parse: DummyParseTracker,
 }
 
 impl Default for SessionCache {
@@ -71,6 +84,9 @@ impl SessionCache {
             seen_npc_instances: HashSet::new(),
             player_disciplines: HashMap::new(),
             last_combat_exit_time: None,
+            log_names: None,
+            client_language: None,
+            dummy_parse: DummyParseTracker::default(),
         };
         cache.push_new_encounter();
         cache
@@ -248,6 +264,7 @@ impl SessionCache {
         }
         let definitions = Arc::new(definitions);
         self.boss_definitions = Arc::clone(&definitions);
+        self.register_roster_names();
 
         // Share definitions with current encounter (Arc clone is cheap)
         // BUT only if the encounter doesn't already have definitions loaded
@@ -260,6 +277,17 @@ impl SessionCache {
         }
     }
 
+    /// Give the log's name tables the English roster names of the loaded definitions
+    pub fn register_roster_names(&mut self) {
+        let Some(names) = self.log_names.as_mut() else {
+            return;
+        };
+        let entities = self.boss_definitions.iter().flat_map(|def| &def.entities);
+        names.register_entity_names(
+            entities.flat_map(|e| e.ids.iter().map(|id| (*id, e.name.as_str()))),
+        );
+    }
+
     /// Try to detect which boss encounter is active based on an NPC class ID.
     /// Only matches entities with `triggers_encounter=true` (defaults to `is_boss`).
     /// This allows non-boss NPCs to trigger encounter detection for areas where
@@ -78,6 +78,8 @@ impl WorkerPlayerDiscipline {
             received_revive_immunity: false,
             current_target_id: 0,
             last_seen_at: None,
+            current_hp: 0,
+            max_hp: 0,
         }
     }
 }
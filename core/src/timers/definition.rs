@@ -9,7 +9,7 @@ use crate::dsl::AudioConfig;
 use crate::dsl::CounterCondition;
 use crate::dsl::EntityDefinition;
 use crate::dsl::Trigger;
-use crate::game_data::Difficulty;
+use crate::game_data::{Difficulty, Discipline, Role};
 use baras_types::AlertTrigger;
 
 // Re-export Trigger as TimerTrigger for backward compatibility during migration
@@ -125,6 +125,15 @@ pub struct TimerDefinition {
     #[serde(default)]
     pub difficulties: Vec<String>,
 
+    /// Roles this timer is shown to (empty = all roles).
+    /// Matched against the local player's detected discipline.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Role>,
+
+    /// Disciplines this timer is shown to (empty = all disciplines)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disciplines: Vec<Discipline>,
+
     // ─── Phase/Counter Conditions (optional) ─────────────────────────────────
     /// Only active during these phases (empty = all phases)
     #[serde(default)]
@@ -332,6 +341,20 @@ impl TimerDefinition {
 
         true
     }
+
+    /// Check if this timer applies to the local player's discipline.
+    /// Passes when neither `roles` nor `disciplines` is set, or when the
+    /// discipline is not yet known (same fallback as effect definitions).
+    pub fn matches_player_discipline(&self, discipline: Option<&Discipline>) -> bool {
+        if self.roles.is_empty() && self.disciplines.is_empty() {
+            return true;
+        }
+        let Some(discipline) = discipline else {
+            return true;
+        };
+        (self.roles.is_empty() || self.roles.contains(&discipline.role()))
+            && (self.disciplines.is_empty() || self.disciplines.contains(discipline))
+    }
 }
 
 // NOTE: Trigger matching functions have been moved to `impl Trigger` in dsl/triggers/mod.rs.
@@ -13,7 +13,8 @@ use chrono::{Local, NaiveDateTime};
 
 use crate::combat_log::EntityType;
 use crate::context::{IStr, resolve};
-use crate::dsl::{BossEncounterDefinition, EntityDefinition};
+use crate::dsl::{AlertContext, BossEncounterDefinition, EntityDefinition, render_alert_text};
+use crate::game_data::Discipline;
 use crate::signal_processor::{GameSignal, SignalHandler};
 
 use super::matching::{is_definition_active, matches_source_target_filters};
@@ -76,6 +77,9 @@ pub struct TimerManager {
     /// Local player's entity ID (for LocalPlayer filter)
     pub(super) local_player_id: Option<i64>,
 
+    /// Local player's discipline (for role/discipline-scoped timers)
+    local_player_discipline: Option<Discipline>,
+
     /// Local player's current target entity ID (for CurrentTarget filter)
     pub(super) current_target_id: Option<i64>,
 
@@ -117,6 +121,7 @@ impl TimerManager {
             combat_start_time: None,
             last_timestamp: None,
             local_player_id: None,
+            local_player_discipline: None,
             current_target_id: None,
             boss_entity_ids: HashSet::new(),
             boss_npc_class_ids: HashSet::new(),
@@ -327,6 +332,13 @@ impl TimerManager {
         self.local_player_id = Some(entity_id);
     }
 
+    /// Set the local player's identity and discipline (for role/discipline-scoped timers).
+    /// Call this when the player context is known but its signals were not replayed.
+    pub fn set_player_context(&mut self, entity_id: i64, discipline_id: i64) {
+        self.local_player_id = Some(entity_id);
+        self.local_player_discipline = Discipline::from_guid(discipline_id);
+    }
+
     /// Validate that all timer chain references (triggers_timer/chains_to) point to existing timers
     fn validate_timer_chains(&self) {
         let mut broken_chains = Vec::new();
@@ -474,6 +486,59 @@ impl TimerManager {
         &self.cancelled_this_tick
     }
 
+    // ─── Manual Control (hotkeys) ───────────────────────────────────────────────
+
+    /// Start a timer with a `manual` trigger by definition ID.
+    ///
+    /// Unlike signal-driven timers this ignores area/boss/phase context (the user asked
+    /// for it explicitly) and works out of combat. Returns false if no enabled manual
+    /// timer has this ID.
+    pub fn start_manual_timer(
+        &mut self,
+        timer_id: &str,
+        encounter: Option<&crate::encounter::CombatEncounter>,
+    ) -> bool {
+        let Some(def) = self.definitions.get(timer_id).cloned() else {
+            return false;
+        };
+        if !matches!(def.trigger, TimerTrigger::Manual)
+            || !def.enabled
+            || !self.preferences.is_enabled(&def)
+        {
+            return false;
+        }
+
+        let now = Local::now().naive_local();
+        // Expirations are processed relative to the last game timestamp
+        self.last_timestamp.get_or_insert(now);
+        // Adopt the current encounter so the next signal doesn't treat it as an
+        // encounter change and wipe the timer (e.g. "next pull in 30 s" after a wipe)
+        if !self.in_combat
+            && let Some(enc) = encounter
+        {
+            self.active_encounter_id = Some(enc.id);
+        }
+
+        self.started_this_tick.clear();
+        self.cancelled_this_tick.clear();
+        let alert_ctx = encounter
+            .map(crate::encounter::CombatEncounter::alert_context)
+            .unwrap_or_default();
+        self.start_timer(&def, now, None, &alert_ctx);
+        true
+    }
+
+    /// Stop all running instances of a timer. Returns false if none were running.
+    pub fn reset_timer(&mut self, timer_id: &str) -> bool {
+        let before = self.active_timers.len();
+        self.active_timers.retain(|key, _| key.definition_id != timer_id);
+        let removed = self.active_timers.len() != before;
+        if removed {
+            self.cancelled_this_tick.push(timer_id.to_string());
+        }
+        removed
+    }
+
     /// Check if a timer definition is active for current encounter context.
     /// Reads context directly from the encounter (single source of truth).
     /// Also checks preference override for enabled state.
@@ -486,6 +551,9 @@ impl TimerManager {
         if !self.preferences.is_enabled(def) {
             return false;
         }
+        if !def.matches_player_discipline(self.local_player_discipline.as_ref()) {
+            return false;
+        }
         is_definition_active(def, encounter)
     }
 
@@ -495,6 +563,7 @@ impl TimerManager {
         def: &TimerDefinition,
         timestamp: NaiveDateTime,
         target_id: Option<i64>,
+        alert_ctx: &AlertContext<'_>,
     ) {
         // Apply preference overrides
         let color = self.preferences.get_color(def);
@@ -507,7 +576,10 @@ impl TimerManager {
 
         // Fire start alert if needed (instant alerts always fire, or alert_on == OnApply)
         if should_alert_on_start {
-            let raw_text = def.alert_text.clone().unwrap_or_else(|| def.name.clone());
+            let raw_text = match &def.alert_text {
+                Some(template) => render_alert_text(template, alert_ctx),
+                None => def.name.clone(),
+            };
             let text = self.format_alert_text(&raw_text, timestamp);
             // For instant alerts, audio fires with the alert since there's no timer lifecycle.
             // For regular timers, audio fires independently via offset/countdown/expiration,
@@ -576,7 +648,10 @@ impl TimerManager {
             &audio_with_prefs,
             def.display_target,
             alert_on_expire,
-            def.alert_text.clone(),
+            // Placeholders are filled from the starting signal, not the expiring tick
+            def.alert_text
+                .as_deref()
+                .map(|template| render_alert_text(template, alert_ctx)),
         );
 
         self.active_timers.insert(key, timer);
@@ -748,12 +823,17 @@ impl TimerManager {
             }
         }
 
+        // Chained and expiry-triggered timers have no signal; only encounter state is available
+        let alert_ctx = encounter
+            .map(crate::encounter::CombatEncounter::alert_context)
+            .unwrap_or_default();
+
         // Start chained timers (outside the borrow)
         for (next_timer_id, target_id) in chains_to_start {
             if let Some(next_def) = self.definitions.get(&next_timer_id).cloned()
                 && self.is_definition_active(&next_def, encounter)
             {
-                self.start_timer(&next_def, current_time, target_id);
+                self.start_timer(&next_def, current_time, target_id, &alert_ctx);
             }
         }
 
@@ -770,7 +850,7 @@ impl TimerManager {
                 .collect();
 
             for def in matching {
-                self.start_timer(&def, current_time, None);
+                self.start_timer(&def, current_time, None, &alert_ctx);
             }
         }
 
@@ -854,6 +934,16 @@ impl SignalHandler for TimerManager {
                 self.local_player_id = Some(*entity_id);
                 return;
             }
+            GameSignal::DisciplineChanged {
+                entity_id,
+                discipline_id,
+                ..
+            } => {
+                if self.local_player_id == Some(*entity_id) {
+                    self.local_player_discipline = Discipline::from_guid(*discipline_id);
+                }
+                return;
+            }
             // AreaEntered: Context is now read from CombatEncounter directly
             GameSignal::AreaEntered { .. } => return,
 
@@ -981,6 +1071,7 @@ impl SignalHandler for TimerManager {
                 target_name,
                 target_npc_id,
                 timestamp,
+                charges,
                 ..
             } => {
                 signal_handlers::handle_effect_applied(
@@ -996,6 +1087,7 @@ impl SignalHandler for TimerManager {
                     *target_entity_type,
                     *target_name,
                     *target_npc_id,
+                    *charges,
                     *timestamp,
                 );
             }
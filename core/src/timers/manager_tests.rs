@@ -35,6 +35,8 @@ fn make_timer(id: &str, name: &str, trigger: TimerTrigger, duration: f32) -> Tim
         boss: None,
         display_target: Default::default(),
         difficulties: Vec::new(),
+        roles: Vec::new(),
+        disciplines: Vec::new(),
         phases: Vec::new(),
         counter_condition: None,
         per_target: true, // Tests use per-target behavior by default
@@ -144,6 +146,49 @@ fn test_effect_applied_triggers_timer() {
     assert_eq!(active[0].name, "Debuff Active");
 }
 
+#[test]
+fn test_alert_text_placeholders_from_signal() {
+    use crate::context::intern;
+
+    let mut manager = TimerManager::new();
+
+    let mut timer = make_timer(
+        "bomb",
+        "Bomb",
+        TimerTrigger::EffectApplied {
+            effects: vec![EffectSelector::Id(424242)],
+            source: EntityFilter::Any,
+            target: EntityFilter::Any,
+        },
+        0.0,
+    );
+    timer.is_alert = true;
+    timer.alert_text = Some("Bomb on {target_name} x{stacks} ({source_name})".to_string());
+    manager.load_definitions(vec![timer]);
+
+    let signal = GameSignal::EffectApplied {
+        effect_id: 424242,
+        effect_name: crate::context::IStr::default(),
+        action_id: 0,
+        action_name: crate::context::IStr::default(),
+        source_id: 1,
+        source_name: intern("Operator IX"),
+        source_entity_type: crate::combat_log::EntityType::Npc,
+        source_npc_id: 12345,
+        target_id: 2,
+        target_name: intern("Kira"),
+        target_entity_type: crate::combat_log::EntityType::Player,
+        target_npc_id: 0,
+        timestamp: now(),
+        charges: Some(3),
+    };
+    manager.handle_signal(&signal, None);
+
+    let alerts = manager.take_fired_alerts();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].text, "Bomb on Kira x3 (Operator IX)");
+}
+
 #[test]
 fn test_npc_first_seen_triggers_timer() {
     let mut manager = TimerManager::new();
@@ -428,6 +473,83 @@ fn test_combat_end_clears_timers() {
     );
 }
 
+#[test]
+fn test_manual_timer_start_and_reset() {
+    let mut manager = TimerManager::new();
+
+    let add_wave = make_timer("add_wave", "Add Wave", TimerTrigger::Manual, 30.0);
+    let mut pull = make_timer("pull", "Next Pull", TimerTrigger::Manual, 30.0);
+    pull.enabled = false;
+    let enrage = make_timer("enrage", "Enrage", TimerTrigger::CombatStart, 300.0);
+    manager.load_definitions(vec![add_wave, pull, enrage]);
+
+    // Only enabled timers with a manual trigger can be started by hand, even out of combat
+    assert!(!manager.start_manual_timer("enrage", None));
+    assert!(!manager.start_manual_timer("pull", None));
+    assert!(!manager.start_manual_timer("missing", None));
+    assert!(manager.start_manual_timer("add_wave", None));
+    assert_eq!(manager.active_timers().len(), 1);
+    assert_eq!(manager.started_timer_ids(), ["add_wave".to_string()]);
+
+    assert!(manager.reset_timer("add_wave"));
+    assert!(manager.active_timers().is_empty());
+    assert!(!manager.reset_timer("add_wave"));
+}
+
+#[test]
+fn test_role_and_discipline_scoped_timers() {
+    use crate::game_data::{Discipline, Role};
+
+    let mut manager = TimerManager::new();
+
+    let mut tank_swap = make_timer("tank_swap", "Tank Swap", TimerTrigger::CombatStart, 20.0);
+    tank_swap.roles = vec![Role::Tank];
+    let mut madness_only = make_timer("madness", "Madness Only", TimerTrigger::CombatStart, 20.0);
+    madness_only.disciplines = vec![Discipline::Madness];
+    let everyone = make_timer("everyone", "Everyone", TimerTrigger::CombatStart, 20.0);
+    manager.load_definitions(vec![tank_swap, madness_only, everyone]);
+
+    // Local player is an Immortal Juggernaut (tank)
+    manager.handle_signal(
+        &GameSignal::PlayerInitialized {
+            entity_id: 42,
+            timestamp: now(),
+        },
+        None,
+    );
+    manager.handle_signal(
+        &GameSignal::DisciplineChanged {
+            entity_id: 42,
+            class_id: 0,
+            discipline_id: 2031339142381577,
+            timestamp: now(),
+        },
+        None,
+    );
+    // Another player's discipline must not change the local context
+    manager.handle_signal(
+        &GameSignal::DisciplineChanged {
+            entity_id: 7,
+            class_id: 0,
+            discipline_id: 2031339142381584,
+            timestamp: now(),
+        },
+        None,
+    );
+
+    manager.handle_signal(
+        &GameSignal::CombatStarted {
+            timestamp: now(),
+            encounter_id: 1,
+        },
+        None,
+    );
+
+    let mut names: Vec<_> = manager.active_timers().iter().map(|t| t.name.clone()).collect();
+    names.sort();
+    assert_eq!(names, vec!["Everyone", "Tank Swap"]);
+}
+
 #[test]
 fn test_timer_expires_triggers_chain() {
     let mut manager = TimerManager::new();
@@ -6,8 +6,8 @@
 use chrono::NaiveDateTime;
 
 use crate::combat_log::EntityType;
-use crate::context::IStr;
-use crate::dsl::EntityDefinition;
+use crate::context::{IStr, resolve};
+use crate::dsl::{AlertContext, EntityDefinition};
 use crate::encounter::CombatEncounter;
 
 use super::{TimerManager, TimerTrigger};
@@ -24,6 +24,11 @@ fn get_entities(encounter: Option<&CombatEncounter>) -> &[EntityDefinition] {
     enc.boss_definitions()[idx].entities.as_slice()
 }
 
+/// Alert text context from the encounter alone (phase, counters, boss HP)
+fn encounter_alert_context(encounter: Option<&CombatEncounter>) -> AlertContext<'_> {
+    encounter.map(CombatEncounter::alert_context).unwrap_or_default()
+}
+
 /// Handle ability activation
 pub(super) fn handle_ability(
     manager: &mut TimerManager,
@@ -41,7 +46,7 @@ pub(super) fn handle_ability(
     timestamp: NaiveDateTime,
 ) {
     let ability_id = ability_id as u64;
-    let ability_name_str = crate::context::resolve(ability_name);
+    let ability_name_str = resolve(ability_name);
 
     let matching: Vec<_> = manager
         .definitions
@@ -65,13 +70,19 @@ pub(super) fn handle_ability(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        source_name: Some(resolve(source_name)),
+        target_name: Some(resolve(target_name)),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
         let instance_id = if def.per_target {
             Some(target_id)
         } else {
             None
         };
-        manager.start_timer(&def, timestamp, instance_id);
+        manager.start_timer(&def, timestamp, instance_id, &alert_ctx);
     }
 
     // Check for cancel triggers on ability cast
@@ -95,6 +106,7 @@ pub(super) fn handle_effect_applied(
     target_type: EntityType,
     target_name: IStr,
     target_npc_id: i64,
+    charges: Option<u8>,
     timestamp: NaiveDateTime,
 ) {
     // Convert i64 to u64 for matching (game IDs are always positive)
@@ -122,13 +134,20 @@ pub(super) fn handle_effect_applied(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        source_name: Some(resolve(source_name)),
+        target_name: Some(resolve(target_name)),
+        stacks: charges,
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
         let instance_id = if def.per_target {
             Some(target_id)
         } else {
             None
         };
-        manager.start_timer(&def, timestamp, instance_id);
+        manager.start_timer(&def, timestamp, instance_id, &alert_ctx);
     }
 
     // Check for cancel triggers on effect applied
@@ -179,13 +198,19 @@ pub(super) fn handle_effect_removed(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        source_name: Some(resolve(source_name)),
+        target_name: Some(resolve(target_name)),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
         let instance_id = if def.per_target {
             Some(target_id)
         } else {
             None
         };
-        manager.start_timer(&def, timestamp, instance_id);
+        manager.start_timer(&def, timestamp, instance_id, &alert_ctx);
     }
 
     // Check for cancel triggers on effect removed
@@ -225,8 +250,14 @@ pub(super) fn handle_boss_hp_change(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        target_name: Some(npc_name),
+        hp_percent: Some(current_hp),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on boss HP threshold
@@ -253,8 +284,10 @@ pub(super) fn handle_phase_change(
         .cloned()
         .collect();
 
+    let alert_ctx = encounter_alert_context(encounter);
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on phase entered
@@ -279,8 +312,10 @@ pub(super) fn handle_phase_ended(
         .cloned()
         .collect();
 
+    let alert_ctx = encounter_alert_context(encounter);
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on phase ended
@@ -310,8 +345,10 @@ pub(super) fn handle_counter_change(
         .cloned()
         .collect();
 
+    let alert_ctx = encounter_alert_context(encounter);
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on counter change
@@ -343,8 +380,13 @@ pub(super) fn handle_npc_first_seen(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        target_name: Some(npc_name),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on NPC appears
@@ -375,8 +417,13 @@ pub(super) fn handle_entity_death(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        target_name: Some(entity_name),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on entity death
@@ -401,7 +448,7 @@ pub(super) fn handle_target_set(
     target_name: IStr,
     timestamp: NaiveDateTime,
 ) {
-    let source_name_str = crate::context::resolve(source_name);
+    let source_name_str = resolve(source_name);
     let entities = get_entities(encounter);
 
     let matching: Vec<_> = manager
@@ -426,8 +473,14 @@ pub(super) fn handle_target_set(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        source_name: Some(source_name_str),
+        target_name: Some(resolve(target_name)),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on target set
@@ -456,7 +509,7 @@ pub(super) fn handle_damage_taken(
     timestamp: NaiveDateTime,
 ) {
     let ability_id = ability_id as u64;
-    let ability_name_str = crate::context::resolve(ability_name);
+    let ability_name_str = resolve(ability_name);
 
     let matching: Vec<_> = manager
         .definitions
@@ -480,13 +533,19 @@ pub(super) fn handle_damage_taken(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        source_name: Some(resolve(source_name)),
+        target_name: Some(resolve(target_name)),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
         let instance_id = if def.per_target {
             Some(target_id)
         } else {
             None
         };
-        manager.start_timer(&def, timestamp, instance_id);
+        manager.start_timer(&def, timestamp, instance_id, &alert_ctx);
     }
 
     // Check for cancel triggers on damage taken
@@ -513,7 +572,7 @@ pub(super) fn handle_healing_taken(
     timestamp: NaiveDateTime,
 ) {
     let ability_id = ability_id as u64;
-    let ability_name_str = crate::context::resolve(ability_name);
+    let ability_name_str = resolve(ability_name);
 
     let matching: Vec<_> = manager
         .definitions
@@ -537,13 +596,19 @@ pub(super) fn handle_healing_taken(
         .cloned()
         .collect();
 
+    let alert_ctx = AlertContext {
+        source_name: Some(resolve(source_name)),
+        target_name: Some(resolve(target_name)),
+        ..encounter_alert_context(encounter)
+    };
+
     for def in matching {
         let instance_id = if def.per_target {
             Some(target_id)
         } else {
             None
         };
-        manager.start_timer(&def, timestamp, instance_id);
+        manager.start_timer(&def, timestamp, instance_id, &alert_ctx);
     }
 
     // Check for cancel triggers on healing taken
@@ -582,8 +647,10 @@ pub(super) fn handle_time_elapsed(
         .cloned()
         .collect();
 
+    let alert_ctx = encounter_alert_context(encounter);
+
     for def in matching {
-        manager.start_timer(&def, _timestamp, None);
+        manager.start_timer(&def, _timestamp, None, &alert_ctx);
     }
 
     // Check for cancel triggers on time elapsed
@@ -609,8 +676,10 @@ pub(super) fn handle_combat_start(
         .cloned()
         .collect();
 
+    let alert_ctx = encounter_alert_context(encounter);
+
     for def in matching {
-        manager.start_timer(&def, timestamp, None);
+        manager.start_timer(&def, timestamp, None, &alert_ctx);
     }
 }
 
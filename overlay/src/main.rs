@@ -1270,6 +1270,7 @@ mod examples {
                 current: 6_200_000,
                 max: 8_000_000,
                 first_seen_at: None,
+                class_id: 0,
                 target_name: Some("Tanky McTank".to_string()),
             },
             OverlayHealthEntry {
@@ -1277,6 +1278,7 @@ mod examples {
                 current: 3_200_000,
                 max: 8_000_000,
                 first_seen_at: None,
+                class_id: 0,
                 target_name: Some("StabbySith".to_string()),
             },
             OverlayHealthEntry {
@@ -1284,6 +1286,7 @@ mod examples {
                 current: 7_100_000,
                 max: 8_000_000,
                 first_seen_at: None,
+                class_id: 0,
                 target_name: None,
             },
         ];
@@ -1293,6 +1296,7 @@ mod examples {
             current: 8_500_000,
             max: 12_000_000,
             first_seen_at: None,
+            class_id: 0,
             target_name: Some("Tanky McTank".to_string()),
         }];
 
@@ -1302,6 +1306,7 @@ mod examples {
                 current: 6_200_000,
                 max: 8_000_000,
                 first_seen_at: None,
+                class_id: 0,
                 target_name: Some("Shield Wall".to_string()),
             },
             OverlayHealthEntry {
@@ -1309,6 +1314,7 @@ mod examples {
                 current: 5_800_000,
                 max: 8_000_000,
                 first_seen_at: None,
+                class_id: 0,
                 target_name: Some("Tanky McTank".to_string()),
             },
         ];
@@ -12,7 +12,7 @@ use crate::platform::{OverlayConfig, PlatformError};
 use crate::utils::color_from_rgba;
 use crate::widgets::colors;
 use crate::widgets::{CompoundRow, CompoundValue, LabeledValue};
-use baras_types::formatting;
+use baras_types::{PersonalRank, formatting};
 use tiny_skia::Color;
 
 /// Data for the personal overlay
@@ -47,6 +47,8 @@ pub struct PersonalStats {
     pub total_shield_absorbed: i64,
     pub current_phase: Option<String>,
     pub phase_time_secs: f32,
+    /// Current boss pull against the player's own history
+    pub personal_rank: Option<Box<PersonalRank>>,
 }
 
 /// Base dimensions for scaling calculations
@@ -224,6 +226,23 @@ impl PersonalOverlay {
                     vec![CompoundValue::new(phase), CompoundValue::new(time)],
                 )
             }
+            PersonalStat::PersonalBestGroup => {
+                let values = self
+                    .stats
+                    .personal_rank
+                    .as_ref()
+                    .and_then(|rank| rank.headline().first().map(|(_, m)| (*m).clone()))
+                    .map(|metric| {
+                        vec![
+                            CompoundValue::new(formatting::format_pct_f32(metric.percentile, eu))
+                                .with_prefix("Pctl:"),
+                            CompoundValue::new(formatting::format_compact(metric.best, eu))
+                                .with_prefix("PB:"),
+                        ]
+                    })
+                    .unwrap_or_default();
+                ("Personal", values)
+            }
             // Single-value stats should not be called here
             _ => (stat.label(), Vec::new()),
         }
@@ -265,6 +284,11 @@ impl PersonalOverlay {
             }
 
             // Separators are never empty
+            PersonalStat::PersonalBestGroup => self
+                .stats
+                .personal_rank
+                .as_ref()
+                .is_none_or(|rank| rank.compared_pulls == 0),
             PersonalStat::Separator => false,
 
             // Legacy no-ops — treat as empty so they get hidden
@@ -3,6 +3,7 @@
 //! Displays a grid of player frames showing health, effects, and role icons.
 //! Supports click-to-swap rearrangement of frames.
 
+use baras_types::overlay_colors;
 use std::time::Instant;
 use tiny_skia::Color;
 
@@ -404,6 +405,16 @@ pub struct RaidOverlayConfig {
     /// Spacing between raid frames in the grid (before scaling)
     /// Clamped to [0.0, 20.0]
     pub frame_spacing: f32,
+
+    // ─── HP Bar Settings ───────────────────────────────────────────────────
+    /// Draw last known HP along the bottom of each frame
+    pub show_hp_bars: bool,
+    /// HP bar fill color
+    pub hp_bar_color: [u8; 4],
+    /// HP bar fill and frame outline color at or below the low HP threshold
+    pub low_hp_color: [u8; 4],
+    /// Low HP threshold (0.0 - 1.0, same scale as `RaidFrame::hp_percent`)
+    pub low_hp_threshold: f32,
 }
 
 impl Default for RaidOverlayConfig {
@@ -419,6 +430,10 @@ impl Default for RaidOverlayConfig {
             effect_fill_opacity: 255, // Fully opaque when no icons
             show_effect_icons: false,
             frame_spacing: BASE_GAP,
+            show_hp_bars: false,
+            hp_bar_color: overlay_colors::RAID_HP,
+            low_hp_color: overlay_colors::RAID_LOW_HP,
+            low_hp_threshold: 0.35,
         }
     }
 }
@@ -449,6 +464,10 @@ impl From<baras_core::context::RaidOverlaySettings> for RaidOverlayConfig {
             effect_fill_opacity: settings.effect_fill_opacity,
             show_effect_icons: settings.show_effect_icons,
             frame_spacing: settings.frame_spacing.clamp(0.0, 20.0),
+            show_hp_bars: settings.show_hp_bars,
+            hp_bar_color: settings.hp_bar_color,
+            low_hp_color: settings.low_hp_color,
+            low_hp_threshold: f32::from(settings.low_hp_threshold.min(100)) / 100.0,
         }
     }
 }
@@ -828,6 +847,11 @@ impl RaidOverlay {
             return;
         }
 
+        // HP bar (BOTTOM, under the icons)
+        if self.config.show_hp_bars {
+            self.render_hp_bar(raid_frame.hp_percent, x, y, w, h, corner_radius);
+        }
+
         // Effect indicators (TOP-LEFT, to match SWTOR's debuff placement)
         let effect_size = self.render_effects(raid_frame, x, y);
 
@@ -848,6 +872,61 @@ impl RaidOverlay {
         }
     }
 
+    /// Render the HP bar along the bottom edge of a frame.
+    ///
+    /// At or below the low HP threshold the bar takes the low HP color and the
+    /// frame gets an outline in the same color so it stands out in the grid.
+    /// Dead players (0 HP) show just the empty track.
+    fn render_hp_bar(
+        &mut self,
+        hp_percent: f32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        corner_radius: f32,
+    ) {
+        let hp = hp_percent.clamp(0.0, 1.0);
+        let low = hp > 0.0 && hp <= self.config.low_hp_threshold;
+        let [r, g, b, a] = if low {
+            self.config.low_hp_color
+        } else {
+            self.config.hp_bar_color
+        };
+
+        let inset = 2.0;
+        let bar_h = (h * 0.12).clamp(3.0, 8.0).round();
+        let bar_w = w - inset * 2.0;
+        let bar_x = x + inset;
+        let bar_y = y + h - inset - bar_h;
+
+        self.frame
+            .fill_rounded_rect(bar_x, bar_y, bar_w, bar_h, 1.0, colors::effect_bar_bg());
+        let fill_w = (bar_w * hp).round();
+        if fill_w > 0.0 {
+            self.frame.fill_rounded_rect(
+                bar_x,
+                bar_y,
+                fill_w,
+                bar_h,
+                1.0,
+                Color::from_rgba8(r, g, b, a),
+            );
+        }
+
+        if low {
+            self.frame.stroke_rounded_rect(
+                x,
+                y,
+                w,
+                h,
+                corner_radius,
+                2.0,
+                Color::from_rgba8(r, g, b, a),
+            );
+        }
+    }
+
     /// Render role and/or class icons at bottom-left, below the effects row.
     ///
     /// Layout:
@@ -20,7 +20,7 @@ use arrow::record_batch::RecordBatch;
 use baras_core::combat_log::{CombatEvent, EntityType, LogParser};
 use baras_core::context::{parse_log_filename, resolve};
 use baras_core::dsl::{build_area_index, load_bosses_with_custom};
-use baras_core::game_data::defense_type;
+use baras_core::game_data::{defense_type, load_user_name_tables};
 use baras_core::signal_processor::{EventProcessor, GameSignal};
 use baras_core::state::{ParseWorkerOutput, SessionCache};
 use baras_core::storage::encounter_filename;
@@ -537,6 +537,13 @@ fn main() {
     let user_dir =
         dirs::config_dir().map(|p| p.join("baras").join("definitions").join("encounters"));
 
+    // User name tables for localized clients (bundled tables are built in).
+    // Only read here: the app is the single writer of names learned from logs.
+    let names_dir = dirs::config_dir().map(|p| p.join("baras").join("definitions").join("names"));
+    if let Some(ref dir) = names_dir {
+        load_user_name_tables(dir);
+    }
+
     // Build area index from both bundled and custom definitions (lightweight - only reads headers)
     // Custom definitions can override bundled ones (e.g., for new areas like flashpoints)
     let area_index = {
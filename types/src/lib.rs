@@ -210,6 +210,9 @@ pub struct RaidOverviewRow {
     // Activity
     /// Actions per minute
     pub apm: f64,
+    /// Percentage of the window spent on the GCD ("always be casting")
+    #[serde(default)]
+    pub abc_pct: f64,
 }
 
 /// Query result for time-series data (DPS/HPS over time).
@@ -325,7 +328,7 @@ pub struct CombatLogRow {
 }
 
 /// Filter options for combat log event types.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CombatLogFilters {
     /// Show damage events
     pub damage: bool,
@@ -398,6 +401,196 @@ pub struct RotationAnalysis {
     pub cycles: Vec<RotationCycle>,
     /// Distinct abilities for the anchor dropdown: (ability_id, ability_name).
     pub abilities: Vec<(i64, String)>,
+    /// Cycles scored against the reference rotation for the player's
+    /// discipline (None if no reference is defined)
+    #[serde(default)]
+    pub check: Option<RotationCheck>,
+}
+
+/// How a cast deviated from the reference rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RotationDeviationKind {
+    /// A higher-priority ability was available
+    Priority,
+    /// Fixed rotation: a different ability was expected at this position
+    OutOfOrder,
+    /// Fixed rotation: an expected ability was missing from the cycle
+    Missing,
+    /// DoT reapplied before its refresh window
+    DotClipped,
+    /// DoT expired before it was reapplied
+    DotDropped,
+    /// A listed ability was never used in the analyzed range
+    NotUsed,
+}
+
+/// One deviation from the reference rotation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RotationDeviation {
+    pub kind: RotationDeviationKind,
+    pub time_secs: f32,
+    /// GCD slot in the cycle the deviation belongs to (None if not tied to a cast)
+    pub slot_index: Option<usize>,
+    /// Ability the reference called for
+    pub expected: Option<String>,
+    /// Ability actually cast
+    pub actual: Option<String>,
+    /// Extra context (e.g. DoT time remaining or missing)
+    pub detail: String,
+    /// Estimated damage (plus effective healing) lost
+    pub lost_damage: f64,
+}
+
+/// Score of one cycle against the reference rotation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleScore {
+    /// Share of scored casts without a deviation (0-100)
+    pub score_pct: f32,
+    pub deviations: Vec<RotationDeviation>,
+    pub lost_damage: f64,
+}
+
+/// Rotation cycles scored against a reference rotation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RotationCheck {
+    /// Reference rotation name
+    pub name: String,
+    pub discipline: String,
+    /// Score over all cycles (0-100)
+    pub score_pct: f32,
+    pub lost_damage: f64,
+    /// One score per analysis cycle, in the same order
+    pub cycles: Vec<CycleScore>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// GCD Uptime Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Kind of logged event found inside a GCD gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GcdGapKind {
+    Death,
+    Revived,
+    PhaseTransition,
+    Interrupted,
+    Cancelled,
+    TargetCleared,
+}
+
+/// A logged event that may explain a GCD gap.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GcdGapContext {
+    pub kind: GcdGapKind,
+    pub time_secs: f32,
+    /// Ability name, phase name, etc. depending on the kind
+    pub detail: String,
+}
+
+/// A stretch longer than one GCD where the player was not casting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GcdGap {
+    pub start_secs: f32,
+    pub end_secs: f32,
+    /// Last on-GCD ability before the gap (None if the gap opens the window)
+    pub before_ability: Option<String>,
+    /// First on-GCD ability after the gap (None if the gap closes the window)
+    pub after_ability: Option<String>,
+    /// Logged events inside the gap. Empty means nothing in the log explains
+    /// it (usually movement or mechanics, which the combat log doesn't record).
+    pub context: Vec<GcdGapContext>,
+}
+
+/// "Always be casting" analysis for a single player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GcdUptimeAnalysis {
+    /// GCD tier inferred from activation spacing (from the configured alacrity
+    /// when there are not enough casts)
+    pub gcd_secs: f32,
+    pub alacrity_percent: f32,
+    pub latency_ms: u16,
+    /// False when the player had too few back-to-back casts to infer a GCD
+    pub gcd_inferred: bool,
+    pub window_secs: f32,
+    /// Time covered by on-GCD activations (GCD lock or channel, whichever is longer)
+    pub active_secs: f32,
+    pub abc_pct: f32,
+    pub gcd_count: i64,
+    pub gaps: Vec<GcdGap>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Healing Matrix Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Healing from one healer to one recipient.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealingMatrixCell {
+    pub source_name: String,
+    pub target_name: String,
+    pub raw_heal: f64,
+    pub effective_heal: f64,
+    pub overheal: f64,
+    /// Damage absorbed on the recipient by the healer's shields
+    pub shield_absorbed: f64,
+}
+
+/// Healer × recipient grid for an encounter.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealingMatrix {
+    /// Healers, sorted by effective healing + shielding done (descending)
+    pub healers: Vec<String>,
+    /// Recipients, sorted by effective healing + shielding received (descending)
+    pub recipients: Vec<String>,
+    /// Non-empty cells only
+    pub cells: Vec<HealingMatrixCell>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Cooldown Efficiency Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A window where a cooldown was ready but not pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CooldownIdleGap {
+    pub start_secs: f32,
+    pub end_secs: f32,
+}
+
+/// Usage efficiency of one tracked cooldown for one player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CooldownEfficiencyRow {
+    pub player_name: String,
+    /// Effect definition ID (e.g., "deflection_cd")
+    pub cooldown_id: String,
+    /// Display name of the cooldown
+    pub cooldown_name: String,
+    /// Ability ID for icon lookup
+    pub icon_ability_id: Option<i64>,
+    /// Cooldown length in seconds (from the definition's `duration_secs`)
+    pub cooldown_secs: f32,
+    /// Times the cooldown was used in the time range
+    pub uses: i64,
+    /// Times it could have been used if pressed whenever ready
+    pub possible_uses: i64,
+    /// uses / possible_uses (0-100)
+    pub efficiency_pct: f32,
+    /// Total seconds the cooldown sat ready but unused
+    pub idle_secs: f32,
+    /// Combat times (seconds) of each use
+    pub use_times: Vec<f32>,
+    /// Windows where the cooldown was ready but unused
+    pub idle_gaps: Vec<CooldownIdleGap>,
 }
 
 /// A phase segment - one occurrence of a phase (phases can repeat).
@@ -449,6 +642,72 @@ impl TimeRange {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Data Explorer Export
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// File format for Data Explorer exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ExportFormat {
+    /// Comma-separated text (spreadsheets)
+    #[default]
+    Csv,
+    /// Apache Parquet (pandas, polars, DuckDB)
+    Parquet,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Parquet => "parquet",
+        }
+    }
+}
+
+/// Time series shown in the charts panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeSeriesMetric {
+    Dps,
+    Hps,
+    Ehps,
+    Dtps,
+    /// HP over time (percent, current and max)
+    Hp,
+}
+
+/// Data Explorer table or chart to export. The time range is applied
+/// separately, the same way it is for the on-screen queries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum ExportSource {
+    RaidOverview,
+    Breakdown {
+        tab: DataTab,
+        entity_name: Option<String>,
+        entity_types: Option<Vec<String>>,
+        breakdown_mode: Option<BreakdownMode>,
+    },
+    PlayerDeaths,
+    NpcHealth,
+    EffectUptime {
+        target_name: Option<String>,
+    },
+    TimeSeries {
+        metric: TimeSeriesMetric,
+        entity_name: Option<String>,
+        bucket_ms: i64,
+    },
+    /// Raw events behind the combat log, with its filters
+    CombatLog {
+        source_filter: Option<String>,
+        target_filter: Option<String>,
+        /// Filter expression (same syntax as the combat log filter box)
+        search_filter: Option<String>,
+        event_filters: Option<CombatLogFilters>,
+    },
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Color Type
 // ─────────────────────────────────────────────────────────────────────────────
@@ -804,7 +1063,7 @@ pub enum Trigger {
     TimeElapsed { secs: f32 },
 
     // ─── System-specific ───────────────────────────────────────────────────
-    /// Manual/debug trigger. [T only]
+    /// Started from a user hotkey. [T only]
     Manual,
 
     /// Never triggers. [C only]
@@ -873,6 +1132,31 @@ impl Trigger {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Definition Lint Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// How serious a definition lint finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintSeverity {
+    /// Definition will misbehave at raid time (dangling reference, unusable trigger)
+    Error,
+    /// Likely mistake that still loads (e.g. selector falls back to name matching)
+    Warning,
+}
+
+/// A single problem found by statically checking a definition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintIssue {
+    pub severity: LintSeverity,
+    /// Owning definition: a boss ID, or "effects" for effect definitions
+    pub scope: String,
+    /// Offending item (e.g. "timer 'adds_spawn'")
+    pub item: String,
+    pub message: String,
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Default Color Constants
 // ─────────────────────────────────────────────────────────────────────────────
@@ -889,6 +1173,8 @@ pub mod overlay_colors {
     pub const ABS: Color = [100, 150, 200, 255]; // Light blue
     pub const BOSS_BAR: Color = [200, 50, 50, 255]; // Boss health red
     pub const FRAME_BG: Color = [40, 40, 40, 200]; // Raid frame background
+    pub const RAID_HP: Color = [60, 170, 90, 200]; // Raid frame HP bar
+    pub const RAID_LOW_HP: Color = [220, 60, 50, 230]; // Raid frame HP bar below threshold
 
     /// Get the default bar color for an overlay type by its config key
     pub fn for_key(key: &str) -> Color {
@@ -1040,6 +1326,8 @@ pub enum PersonalStat {
     DefensiveGroup,
     /// Phase  |  Time
     PhaseGroup,
+    /// Percentile vs own history  |  Personal best
+    PersonalBestGroup,
 
     // ── Standalone metric ──
     Apm,
@@ -1086,6 +1374,7 @@ impl PersonalStat {
             Self::MitigationGroup => "DTPS",
             Self::DefensiveGroup => "Defense",
             Self::PhaseGroup => "Phase",
+            Self::PersonalBestGroup => "Personal Best",
             Self::Apm => "APM",
             Self::Separator => "── Separator ──",
             // Legacy no-ops
@@ -1152,7 +1441,7 @@ impl PersonalStat {
 
             Self::PhaseGroup => PersonalStatCategory::Info,
 
-            Self::Apm => PersonalStatCategory::Utility,
+            Self::Apm | Self::PersonalBestGroup => PersonalStatCategory::Utility,
 
             Self::Separator => PersonalStatCategory::Info,
 
@@ -1196,6 +1485,7 @@ impl PersonalStat {
                 | Self::MitigationGroup
                 | Self::DefensiveGroup
                 | Self::PhaseGroup
+                | Self::PersonalBestGroup
         )
     }
 
@@ -1215,6 +1505,7 @@ impl PersonalStat {
             Self::MitigationGroup,
             Self::DefensiveGroup,
             Self::PhaseGroup,
+            Self::PersonalBestGroup,
             Self::Apm,
             Self::Separator,
         ]
@@ -1332,6 +1623,16 @@ pub struct RaidOverlaySettings {
     pub show_effect_icons: bool,
     #[serde(default = "default_frame_spacing")]
     pub frame_spacing: f32,
+    /// Draw last known HP along the bottom of each frame
+    #[serde(default)]
+    pub show_hp_bars: bool,
+    #[serde(default = "default_raid_hp_color")]
+    pub hp_bar_color: Color,
+    #[serde(default = "default_raid_low_hp_color")]
+    pub low_hp_color: Color,
+    /// HP percent at or below which the bar switches to `low_hp_color`
+    #[serde(default = "default_low_hp_threshold")]
+    pub low_hp_threshold: u8,
 }
 
 fn default_grid_columns() -> u8 {
@@ -1358,6 +1659,15 @@ fn default_effect_fill_opacity() -> u8 {
 fn default_frame_spacing() -> f32 {
     4.0
 }
+fn default_raid_hp_color() -> Color {
+    overlay_colors::RAID_HP
+}
+fn default_raid_low_hp_color() -> Color {
+    overlay_colors::RAID_LOW_HP
+}
+fn default_low_hp_threshold() -> u8 {
+    35
+}
 
 impl Default for RaidOverlaySettings {
     fn default() -> Self {
@@ -1373,6 +1683,10 @@ impl Default for RaidOverlaySettings {
             effect_fill_opacity: 255,
             show_effect_icons: false,
             frame_spacing: 4.0,
+            show_hp_bars: false,
+            hp_bar_color: overlay_colors::RAID_HP,
+            low_hp_color: overlay_colors::RAID_LOW_HP,
+            low_hp_threshold: 35,
         }
     }
 }
@@ -1878,6 +2192,56 @@ pub struct HotkeySettings {
     pub toggle_move_mode: Option<String>,
     #[serde(default)]
     pub toggle_rearrange_mode: Option<String>,
+    /// Hotkeys that start/reset manual timers or change boss counters
+    #[serde(default)]
+    pub actions: Vec<ActionHotkey>,
+}
+
+/// A global hotkey bound to a timer or counter action
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ActionHotkey {
+    /// Key combination in Tauri format (e.g. "CommandOrControl+Shift+1")
+    pub key: String,
+    pub action: HotkeyAction,
+}
+
+/// What an action hotkey does when pressed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HotkeyAction {
+    /// Start a timer with a `manual` trigger
+    StartTimer { timer_id: String },
+    /// Stop a running timer
+    ResetTimer { timer_id: String },
+    IncrementCounter { counter_id: String },
+    DecrementCounter { counter_id: String },
+    /// Set a counter to a fixed value (e.g. 0 to reset it)
+    SetCounter { counter_id: String, value: u32 },
+}
+
+impl HotkeyAction {
+    /// Timer or counter ID the action targets
+    pub fn target_id(&self) -> &str {
+        match self {
+            Self::StartTimer { timer_id } | Self::ResetTimer { timer_id } => timer_id,
+            Self::IncrementCounter { counter_id }
+            | Self::DecrementCounter { counter_id }
+            | Self::SetCounter { counter_id, .. } => counter_id,
+        }
+    }
+
+    /// Short description for settings and shortcut portals
+    pub fn label(&self) -> String {
+        match self {
+            Self::StartTimer { timer_id } => format!("Start timer {}", timer_id),
+            Self::ResetTimer { timer_id } => format!("Reset timer {}", timer_id),
+            Self::IncrementCounter { counter_id } => format!("Increment counter {}", counter_id),
+            Self::DecrementCounter { counter_id } => format!("Decrement counter {}", counter_id),
+            Self::SetCounter { counter_id, value } => {
+                format!("Set counter {} to {}", counter_id, value)
+            }
+        }
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1900,6 +2264,127 @@ impl OverlayProfile {
     }
 }
 
+/// Kind of area matched by profile switch rules and overlay visibility conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileArea {
+    Operation,
+    Flashpoint,
+    Pvp,
+    /// Fighting a training dummy (detected from the boss, not the area)
+    TrainingDummy,
+    /// Anything that is not an operation, flashpoint or PvP area
+    OpenWorld,
+}
+
+impl ProfileArea {
+    pub fn all() -> &'static [ProfileArea] {
+        &[
+            ProfileArea::Operation,
+            ProfileArea::Flashpoint,
+            ProfileArea::Pvp,
+            ProfileArea::TrainingDummy,
+            ProfileArea::OpenWorld,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProfileArea::Operation => "Operation",
+            ProfileArea::Flashpoint => "Flashpoint",
+            ProfileArea::Pvp => "PvP",
+            ProfileArea::TrainingDummy => "Training Dummy",
+            ProfileArea::OpenWorld => "Open World",
+        }
+    }
+}
+
+/// Character role in group content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Role {
+    Tank,
+    Healer,
+    Dps,
+}
+
+impl Role {
+    /// Get the icon filename for this role (without path)
+    pub const fn icon_name(&self) -> &'static str {
+        match self {
+            Role::Tank => "icon_tank.png",
+            Role::Healer => "icon_heal.png",
+            Role::Dps => "icon_dps.png",
+        }
+    }
+}
+
+/// Rule that loads an overlay profile automatically when the game context matches.
+///
+/// Rules are evaluated in order and the first enabled match wins. Empty lists
+/// match anything, so a rule without conditions acts as a fallback.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileSwitchRule {
+    /// Profile to load when the rule matches
+    pub profile_name: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Area kinds (any of)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub areas: Vec<ProfileArea>,
+    /// Local player roles (any of)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Role>,
+    /// Local player discipline names, e.g. "Kinetic Combat" (any of)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disciplines: Vec<String>,
+    /// Boss definition IDs, e.g. "apex_vanguard" (any of)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bosses: Vec<String>,
+}
+
+impl Default for ProfileSwitchRule {
+    fn default() -> Self {
+        Self {
+            profile_name: String::new(),
+            enabled: true,
+            areas: Vec::new(),
+            roles: Vec::new(),
+            disciplines: Vec::new(),
+            bosses: Vec::new(),
+        }
+    }
+}
+
+/// Conditions under which an enabled overlay is shown.
+///
+/// All set conditions must hold; the default shows the overlay unconditionally.
+/// Conditions are ignored while move mode is on so overlays can be positioned.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OverlayVisibilityConditions {
+    /// Only show while in combat
+    #[serde(default)]
+    pub in_combat_only: bool,
+    /// Only show while a boss encounter is active
+    #[serde(default)]
+    pub boss_encounters_only: bool,
+    /// Only show in these kinds of areas (any of)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub areas: Vec<ProfileArea>,
+    /// Only show for these local player roles (any of)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Role>,
+    /// Hide once out of combat for this many seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_after_combat_secs: Option<u32>,
+}
+
+impl OverlayVisibilityConditions {
+    /// True when no condition is set (always shown)
+    pub fn is_unconditional(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Overlay Settings (combined)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1990,6 +2475,9 @@ pub struct OverlaySettings {
     /// Auto-hide overlays when not in a live session (historical, logged out, etc.)
     #[serde(default)]
     pub hide_when_not_live: bool,
+    /// Per-overlay visibility conditions, keyed by overlay config key
+    #[serde(default)]
+    pub visibility_conditions: HashMap<String, OverlayVisibilityConditions>,
 }
 
 impl Default for OverlaySettings {
@@ -2035,6 +2523,7 @@ impl Default for OverlaySettings {
             notes_opacity: 180,
             hide_during_conversations: false,
             hide_when_not_live: false,
+            visibility_conditions: HashMap::new(),
         }
     }
 }
@@ -2128,6 +2617,175 @@ pub struct ParselySettings {
     pub guild: String,
 }
 
+/// Training dummy parse settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyParseSettings {
+    /// Track fixed-length parses against training dummies
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub limit: DummyParseLimit,
+}
+
+impl Default for DummyParseSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            limit: DummyParseLimit::default(),
+        }
+    }
+}
+
+/// Length of a training dummy parse, measured from the first hit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DummyParseLimit {
+    /// Parse ends after a fixed time
+    Duration { secs: u32 },
+    /// Parse ends once this much damage has been dealt
+    Damage { hp: i64 },
+}
+
+impl Default for DummyParseLimit {
+    fn default() -> Self {
+        Self::Duration { secs: 180 }
+    }
+}
+
+impl DummyParseLimit {
+    pub fn label(&self, european: bool) -> String {
+        match self {
+            Self::Duration { secs } => format!("{}:{:02}", secs / 60, secs % 60),
+            Self::Damage { hp } => format!("{} HP", formatting::format_compact(*hp, european)),
+        }
+    }
+}
+
+/// A completed training dummy parse
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DummyParse {
+    pub character: String,
+    pub discipline: String,
+    /// Time of the first hit (`YYYY-MM-DD HH:MM:SS`)
+    pub started_at: String,
+    pub duration_secs: f32,
+    pub damage: i64,
+    pub dps: f64,
+}
+
+/// Dummy parses of one character and discipline, newest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DummyParseHistory {
+    pub character: String,
+    pub discipline: String,
+    pub parses: Vec<DummyParse>,
+    pub best_dps: f64,
+    /// DPS of the newest parse as a percentage of the best (100 = new best)
+    pub last_vs_best_pct: f64,
+}
+
+/// One metric of a pull compared to the character's own history
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricRank {
+    pub value: i64,
+    /// Best of the compared pulls
+    pub best: i64,
+    /// Share of compared pulls this one matched or beat (0-100)
+    pub percentile: f32,
+    /// Beat every compared pull
+    pub is_best: bool,
+}
+
+/// How a boss pull compares to the same character's other pulls on that
+/// boss, difficulty and discipline
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersonalRank {
+    pub encounter_id: u64,
+    /// Number of other pulls compared against
+    pub compared_pulls: u32,
+    /// Healing discipline (HPS/eHPS are the headline metrics)
+    pub healer: bool,
+    pub dps: MetricRank,
+    pub hps: MetricRank,
+    pub ehps: MetricRank,
+}
+
+impl PersonalRank {
+    /// The metrics that matter for the discipline's role: HPS and eHPS for
+    /// healers, DPS otherwise
+    pub fn headline(&self) -> Vec<(&'static str, &MetricRank)> {
+        if self.healer {
+            vec![("HPS", &self.hps), ("eHPS", &self.ehps)]
+        } else {
+            vec![("DPS", &self.dps)]
+        }
+    }
+
+    /// Headline metrics that set a new personal best
+    pub fn new_bests(&self) -> Vec<(&'static str, &MetricRank)> {
+        self.headline()
+            .into_iter()
+            .filter(|(_, rank)| rank.is_best)
+            .collect()
+    }
+}
+
+/// A note pinned to a moment of an encounter, or to one combat log row
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EncounterAnnotation {
+    /// Unique within the encounter (0 for a note not saved yet)
+    pub id: u64,
+    /// Combat time the note points at
+    pub time_secs: f32,
+    /// Combat log line the note is pinned to, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_idx: Option<u64>,
+    pub text: String,
+}
+
+/// One player's line on a PvP scoreboard
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PvpScoreboardEntry {
+    pub name: String,
+    pub discipline_name: Option<String>,
+    pub damage: i64,
+    pub healing: i64,
+    pub shielding: i64,
+    /// Estimated damage prevented by taunts
+    pub taunt_mitigation: i64,
+    /// Shielding plus taunt mitigation
+    pub protection: i64,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+}
+
+/// A single arena round
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvpRound {
+    /// 1-based round number within the match
+    pub number: u32,
+    pub start_time: Option<String>,
+    /// Combat time in the round
+    pub duration_seconds: i64,
+    pub scoreboard: Vec<PvpScoreboardEntry>,
+}
+
+/// A warzone or arena match (one visit to a PvP area)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvpMatch {
+    pub area_name: String,
+    pub is_arena: bool,
+    pub start_time: Option<String>,
+    /// Combat time across the whole match
+    pub duration_seconds: i64,
+    /// Encounters that make up the match
+    pub encounter_ids: Vec<u64>,
+    /// Arena rounds (empty for warzones)
+    pub rounds: Vec<PvpRound>,
+    pub scoreboard: Vec<PvpScoreboardEntry>,
+}
+
 ///
 /// Note: Persistence methods (load/save) are provided by baras-core via the
 /// `AppConfigExt` trait, as they require platform-specific dependencies.
@@ -2154,6 +2812,9 @@ pub struct AppConfig {
     pub profiles: Vec<OverlayProfile>,
     #[serde(default)]
     pub active_profile_name: Option<String>,
+    /// Rules for switching profiles automatically by area, role and boss
+    #[serde(default)]
+    pub profile_rules: Vec<ProfileSwitchRule>,
     #[serde(default)]
     pub parsely: ParselySettings,
     #[serde(default)]
@@ -2170,12 +2831,12 @@ pub struct AppConfig {
     pub hide_small_log_files: bool,
 
     /// Player alacrity percentage (e.g., 15.4 for 15.4% alacrity).
-    /// Used to calculate actual effect durations.
+    /// Used for effect durations until it can be inferred from casts.
     #[serde(default = "default_alacrity")]
     pub alacrity_percent: f32,
 
     /// Average network latency in milliseconds (e.g., 50 for 50ms).
-    /// Used to adjust effect duration calculations.
+    /// Used for effect durations until it can be inferred from casts.
     #[serde(default = "default_latency")]
     pub latency_ms: u16,
 
@@ -2188,6 +2849,54 @@ pub struct AppConfig {
     /// e.g., `1.50K` becomes `1,50K` and `1,500` becomes `1.500`.
     #[serde(default)]
     pub european_number_format: bool,
+
+    /// Language for ability, effect and NPC names (logs from any client are
+    /// normalized by game ID, then shown in this language where a name is known).
+    #[serde(default)]
+    pub name_language: GameLanguage,
+
+    /// Language of the user's game client, for localized logs whose event type
+    /// names aren't in the name tables yet (`None` to only detect it). Names
+    /// from such logs are only learned once their language is known.
+    #[serde(default)]
+    pub client_language: Option<GameLanguage>,
+
+    /// Fixed-length training dummy parses.
+    #[serde(default)]
+    pub dummy_parse: DummyParseSettings,
+}
+
+/// SWTOR client language
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameLanguage {
+    #[default]
+    English,
+    German,
+    French,
+}
+
+impl GameLanguage {
+    pub fn all() -> &'static [GameLanguage] {
+        &[Self::English, Self::German, Self::French]
+    }
+
+    /// ISO 639-1 code (also the name table file stem)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+            Self::French => "fr",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::German => "Deutsch",
+            Self::French => "Français",
+        }
+    }
 }
 
 fn default_retention_days() -> u32 {
@@ -2217,6 +2926,7 @@ impl AppConfig {
             hotkeys: HotkeySettings::default(),
             profiles: Vec::new(),
             active_profile_name: None,
+            profile_rules: Vec::new(),
             parsely: ParselySettings::default(),
             audio: AudioSettings::default(),
             show_only_bosses: false,
@@ -2226,6 +2936,9 @@ impl AppConfig {
             latency_ms: 0,
             last_viewed_changelog_version: None,
             european_number_format: false,
+            name_language: GameLanguage::English,
+            client_language: None,
+            dummy_parse: DummyParseSettings::default(),
         }
     }
 }
@@ -2494,6 +3207,8 @@ pub enum ViewMode {
     CombatLog,
     Detailed(DataTab),
     Rotation,
+    Cooldowns,
+    HealingMatrix,
 }
 
 impl ViewMode {
@@ -0,0 +1,740 @@
+//! Cadence mode: mine boss ability timing across logs and draft timers.
+//!
+//! Every pull of the requested boss contributes the cast times of each boss
+//! ability. Across pulls we measure when an ability is first cast (after combat
+//! start, or after entering the phase it belongs to) and how regularly it
+//! repeats, then emit `[[boss.timer]]` drafts annotated with those statistics.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
+use encoding_rs::WINDOWS_1252;
+
+use baras_core::boss::{BossEncounterDefinition, BossTimerDefinition};
+use baras_core::combat_log::{EntityType, LogParser};
+use baras_core::context::resolve;
+use baras_core::encounter::combat::ActiveBoss;
+use baras_core::game_data::effect_id;
+use baras_core::signal_processor::{EventProcessor, GameSignal};
+use baras_core::state::SessionCache;
+use baras_core::{AbilitySelector, EntityFilter, TimerTrigger};
+
+/// Abilities seen in fewer pulls than this fraction are not drafted.
+const MIN_PRESENCE: f32 = 0.5;
+
+/// Intervals further than this fraction from the median count as irregular
+/// (phase pushes, stuns, boss running between platforms).
+const REGULAR_TOLERANCE: f32 = 0.25;
+
+/// Opener timers are only drafted when the first cast is this consistent.
+const MAX_OPENER_STDDEV: f32 = 3.0;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Samples
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One boss ability activation within a pull.
+#[derive(Debug, Clone)]
+struct Cast {
+    /// Seconds since combat start
+    at: f32,
+    /// Phase active when the ability was cast
+    phase: Option<String>,
+    /// Seconds since that phase was entered (equals `at` without phases)
+    since_phase: f32,
+}
+
+/// Boss ability casts from a single kill or wipe.
+#[derive(Debug, Default)]
+struct Pull {
+    casts: HashMap<i64, Vec<Cast>>,
+}
+
+/// In-progress pull while scanning a log.
+struct PullBuilder {
+    start: NaiveDateTime,
+    phase: Option<String>,
+    phase_start: NaiveDateTime,
+    boss_seen: bool,
+    pull: Pull,
+}
+
+fn secs_between(from: NaiveDateTime, to: NaiveDateTime) -> f32 {
+    (to - from).num_milliseconds() as f32 / 1000.0
+}
+
+/// Replay a log and collect every pull of `boss`.
+fn collect_pulls(
+    path: &Path,
+    boss: &BossEncounterDefinition,
+    ability_names: &mut HashMap<i64, String>,
+) -> Result<Vec<Pull>, Box<dyn std::error::Error>> {
+    let mut bytes = Vec::new();
+    File::open(path)?.read_to_end(&mut bytes)?;
+    let (content, _, _) = WINDOWS_1252.decode(&bytes);
+
+    let session_date = chrono::Local::now()
+        .naive_local()
+        .date()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let parser = LogParser::new(session_date);
+    let mut processor = EventProcessor::new();
+    let mut cache = SessionCache::default();
+    cache.load_boss_definitions(vec![boss.clone()]);
+
+    let boss_ids: HashSet<i64> = boss
+        .entities
+        .iter()
+        .filter(|e| e.is_boss)
+        .flat_map(|e| e.ids.iter().copied())
+        .collect();
+
+    let mut pulls = Vec::new();
+    let mut current: Option<PullBuilder> = None;
+
+    for (line_num, line) in content.lines().enumerate() {
+        let Some(event) = parser.parse_line(line_num as u64, line) else {
+            continue;
+        };
+        let (signals, _, _) = processor.process_event(event.clone(), &mut cache);
+
+        for signal in &signals {
+            match signal {
+                GameSignal::CombatStarted { timestamp, .. } => {
+                    if let Some(enc) = cache.current_encounter_mut() {
+                        enc.area_id = Some(boss.area_id);
+                        enc.area_name = Some(boss.area_name.clone());
+                    }
+                    current = Some(PullBuilder {
+                        start: *timestamp,
+                        phase: None,
+                        phase_start: *timestamp,
+                        boss_seen: false,
+                        pull: Pull::default(),
+                    });
+                }
+                GameSignal::BossEncounterDetected {
+                    definition_id,
+                    boss_name,
+                    entity_id,
+                    ..
+                } if *definition_id == boss.id => {
+                    if let Some(enc) = cache.current_encounter_mut() {
+                        enc.set_boss(ActiveBoss {
+                            definition_id: definition_id.clone(),
+                            name: boss_name.clone(),
+                            entity_id: *entity_id,
+                            max_hp: 0,
+                            current_hp: 0,
+                        });
+                    }
+                    if let Some(b) = current.as_mut() {
+                        b.boss_seen = true;
+                    }
+                }
+                GameSignal::PhaseChanged {
+                    new_phase,
+                    timestamp,
+                    ..
+                } => {
+                    if let Some(b) = current.as_mut() {
+                        b.phase = Some(new_phase.clone());
+                        b.phase_start = *timestamp;
+                    }
+                }
+                GameSignal::CombatEnded { .. } => {
+                    if let Some(b) = current.take()
+                        && b.boss_seen
+                    {
+                        pulls.push(b.pull);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let Some(b) = current.as_mut() else {
+            continue;
+        };
+        if event.effect.effect_id != effect_id::ABILITYACTIVATE
+            || event.source_entity.entity_type != EntityType::Npc
+            || !boss_ids.contains(&event.source_entity.class_id)
+            || event.action.action_id == 0
+        {
+            continue;
+        }
+
+        let ability_id = event.action.action_id;
+        ability_names
+            .entry(ability_id)
+            .or_insert_with(|| resolve(event.action.name).to_string());
+        b.pull.casts.entry(ability_id).or_default().push(Cast {
+            at: secs_between(b.start, event.timestamp),
+            phase: b.phase.clone(),
+            since_phase: secs_between(b.phase_start, event.timestamp),
+        });
+    }
+
+    // Log ended mid-fight: keep the partial pull
+    if let Some(b) = current
+        && b.boss_seen
+    {
+        pulls.push(b.pull);
+    }
+
+    Ok(pulls)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Statistics
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Summary statistics over a set of samples (seconds).
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stats {
+    n: usize,
+    mean: f32,
+    median: f32,
+    stddev: f32,
+    min: f32,
+    max: f32,
+}
+
+impl Stats {
+    fn of(samples: &[f32]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<f32>() / n as f32;
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        let variance = sorted.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / n as f32;
+        Some(Self {
+            n,
+            mean,
+            median,
+            stddev: variance.sqrt(),
+            min: sorted[0],
+            max: sorted[n - 1],
+        })
+    }
+
+    /// Coefficient of variation (0 = perfectly regular)
+    fn cv(&self) -> f32 {
+        if self.mean > 0.0 {
+            self.stddev / self.mean
+        } else {
+            1.0
+        }
+    }
+}
+
+/// What the first cast of an ability is measured from.
+#[derive(Debug, Clone, PartialEq)]
+enum Anchor {
+    CombatStart,
+    Phase(String),
+}
+
+/// Cadence of one boss ability across all pulls.
+#[derive(Debug, Clone)]
+struct AbilityCadence {
+    ability_id: i64,
+    name: String,
+    pulls_seen: usize,
+    total_casts: usize,
+    anchor: Anchor,
+    /// Only phase the ability is cast in, if it is confined to one
+    phase: Option<String>,
+    first_cast: Stats,
+    /// Regular repeat intervals (outliers removed)
+    interval: Option<Stats>,
+    /// Intervals dropped as irregular
+    irregular_intervals: usize,
+    /// 0..1 score combining presence across pulls and timing regularity
+    confidence: f32,
+}
+
+fn analyze(ability_id: i64, name: &str, pulls: &[Pull]) -> Option<AbilityCadence> {
+    let per_pull: Vec<&Vec<Cast>> = pulls
+        .iter()
+        .filter_map(|p| p.casts.get(&ability_id))
+        .filter(|c| !c.is_empty())
+        .collect();
+    if per_pull.is_empty() {
+        return None;
+    }
+
+    let phases: HashSet<Option<&str>> = per_pull
+        .iter()
+        .flat_map(|casts| casts.iter().map(|c| c.phase.as_deref()))
+        .collect();
+    let phase = match phases.iter().next() {
+        Some(Some(p)) if phases.len() == 1 => Some(p.to_string()),
+        _ => None,
+    };
+
+    // Anchor to the phase only when it starts after the pull does, otherwise
+    // combat start is the simpler (and equivalent) trigger.
+    let anchor = match &phase {
+        Some(p) if per_pull.iter().any(|c| c[0].at - c[0].since_phase > 1.0) => {
+            Anchor::Phase(p.clone())
+        }
+        _ => Anchor::CombatStart,
+    };
+    let firsts: Vec<f32> = per_pull
+        .iter()
+        .map(|c| match anchor {
+            Anchor::CombatStart => c[0].at,
+            Anchor::Phase(_) => c[0].since_phase,
+        })
+        .collect();
+
+    // Only consecutive casts within the same phase say anything about the
+    // repeat interval; a phase change resets most boss rotations.
+    let raw_intervals: Vec<f32> = per_pull
+        .iter()
+        .flat_map(|casts| {
+            casts
+                .windows(2)
+                .filter(|w| w[0].phase == w[1].phase)
+                .map(|w| w[1].at - w[0].at)
+        })
+        .filter(|i| *i > 0.5)
+        .collect();
+    let (interval, irregular_intervals) = match Stats::of(&raw_intervals) {
+        Some(all) => {
+            let regular: Vec<f32> = raw_intervals
+                .iter()
+                .copied()
+                .filter(|i| (i - all.median).abs() <= all.median * REGULAR_TOLERANCE)
+                .collect();
+            (Stats::of(&regular), raw_intervals.len() - regular.len())
+        }
+        None => (None, 0),
+    };
+
+    let first_cast = Stats::of(&firsts)?;
+    let presence = per_pull.len() as f32 / pulls.len() as f32;
+    let regularity = match interval {
+        Some(s) => {
+            let kept = s.n as f32 / (s.n + irregular_intervals) as f32;
+            kept * (1.0 - s.cv().min(1.0))
+        }
+        None => 1.0 - (first_cast.stddev / MAX_OPENER_STDDEV).min(1.0),
+    };
+
+    Some(AbilityCadence {
+        ability_id,
+        name: name.to_string(),
+        pulls_seen: per_pull.len(),
+        total_casts: per_pull.iter().map(|c| c.len()).sum(),
+        anchor,
+        phase,
+        first_cast,
+        interval,
+        irregular_intervals,
+        confidence: presence * regularity,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Draft Output
+// ═══════════════════════════════════════════════════════════════════════════════
+
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('_') {
+            slug.push('_');
+        }
+    }
+    slug.trim_matches('_').to_string()
+}
+
+fn round_tenth(secs: f32) -> f32 {
+    (secs * 10.0).round() / 10.0
+}
+
+fn draft_timer(
+    id: String,
+    name: &str,
+    trigger: TimerTrigger,
+    duration: f32,
+) -> BossTimerDefinition {
+    BossTimerDefinition {
+        id,
+        name: name.to_string(),
+        display_text: None,
+        trigger,
+        duration_secs: round_tenth(duration),
+        is_alert: false,
+        alert_on: Default::default(),
+        alert_text: None,
+        color: baras_core::serde_defaults::default_timer_color(),
+        phases: Vec::new(),
+        counter_condition: None,
+        difficulties: Vec::new(),
+        roles: Vec::new(),
+        disciplines: Vec::new(),
+        enabled: true,
+        can_be_refreshed: false,
+        repeats: 0,
+        chains_to: None,
+        cancel_trigger: None,
+        alert_at_secs: None,
+        show_on_raid_frames: false,
+        show_at_secs: 0.0,
+        display_target: Default::default(),
+        audio: Default::default(),
+        per_target: false,
+    }
+}
+
+/// Build the opener and repeat timers for one ability (either may be absent).
+fn draft_timers(boss_id: &str, cadence: &AbilityCadence) -> Vec<BossTimerDefinition> {
+    let slug = slugify(&cadence.name);
+    let base_id = format!(
+        "{}_{}",
+        boss_id,
+        if slug.is_empty() {
+            cadence.ability_id.to_string()
+        } else {
+            slug
+        }
+    );
+    let mut timers = Vec::new();
+
+    if cadence.first_cast.stddev <= MAX_OPENER_STDDEV && cadence.first_cast.mean >= 1.0 {
+        let trigger = match &cadence.anchor {
+            Anchor::CombatStart => TimerTrigger::CombatStart,
+            Anchor::Phase(phase_id) => TimerTrigger::PhaseEntered {
+                phase_id: phase_id.clone(),
+            },
+        };
+        timers.push(draft_timer(
+            format!("{base_id}_opener"),
+            &cadence.name,
+            trigger,
+            cadence.first_cast.mean,
+        ));
+    }
+
+    if let Some(interval) = cadence.interval
+        && interval.n >= 2
+    {
+        let mut timer = draft_timer(
+            base_id,
+            &cadence.name,
+            TimerTrigger::AbilityCast {
+                abilities: vec![AbilitySelector::Id(cadence.ability_id as u64)],
+                source: EntityFilter::Boss,
+                target: EntityFilter::Any,
+            },
+            interval.median,
+        );
+        timer.can_be_refreshed = true;
+        timer.phases = cadence.phase.iter().cloned().collect();
+        timers.push(timer);
+    }
+
+    timers
+}
+
+fn stats_comment(label: &str, s: &Stats) -> String {
+    format!(
+        "#   {label}: {:.1}s mean, {:.1}s median, ±{:.1}s (min {:.1}s, max {:.1}s, n={})",
+        s.mean, s.median, s.stddev, s.min, s.max, s.n
+    )
+}
+
+/// Serialize one timer as a `[[boss.timer]]` block, nesting its sub-tables.
+fn timer_toml(timer: &BossTimerDefinition) -> Result<String, toml::ser::Error> {
+    let body = toml::to_string(timer)?;
+    let mut out = String::from("[[boss.timer]]\n");
+    for line in body.lines() {
+        if let Some(rest) = line.strip_prefix("[[") {
+            out.push_str(&format!("[[boss.timer.{rest}\n"));
+        } else if let Some(rest) = line.strip_prefix('[') {
+            out.push_str(&format!("[boss.timer.{rest}\n"));
+        } else if line.starts_with("duration_secs = ") {
+            // f32 widens to noisy f64 digits; drafts are rounded to 0.1s anyway
+            out.push_str(&format!("duration_secs = {:.1}\n", timer.duration_secs));
+        } else {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
+
+fn render_drafts(
+    boss: &BossEncounterDefinition,
+    log_count: usize,
+    pull_count: usize,
+    cadences: &[AbilityCadence],
+) -> Result<String, toml::ser::Error> {
+    let mut out = format!(
+        "# Draft timers for {} ({}) mined from {} log(s), {} pull(s).\n\
+         # Review durations and names before use; drop this file next to the\n\
+         # bundled one as <area>_custom.toml to merge it.\n\n\
+         [[boss]]\nid = \"{}\"\n",
+        boss.name, boss.area_name, log_count, pull_count, boss.id
+    );
+
+    for cadence in cadences {
+        let timers = draft_timers(&boss.id, cadence);
+        if timers.is_empty() {
+            continue;
+        }
+        out.push('\n');
+        out.push_str(&format!(
+            "# {} ({}): seen in {}/{} pulls, {} casts, confidence {:.0}%\n",
+            cadence.name,
+            cadence.ability_id,
+            cadence.pulls_seen,
+            pull_count,
+            cadence.total_casts,
+            cadence.confidence * 100.0
+        ));
+        let anchor = match &cadence.anchor {
+            Anchor::CombatStart => "first cast after combat start".to_string(),
+            Anchor::Phase(p) => format!("first cast after entering '{p}'"),
+        };
+        out.push_str(&stats_comment(&anchor, &cadence.first_cast));
+        out.push('\n');
+        if let Some(interval) = &cadence.interval {
+            out.push_str(&stats_comment("repeat interval", interval));
+            if cadence.irregular_intervals > 0 {
+                out.push_str(&format!(
+                    ", {} irregular dropped",
+                    cadence.irregular_intervals
+                ));
+            }
+            out.push('\n');
+        }
+        for (i, timer) in timers.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&timer_toml(timer)?);
+        }
+    }
+    Ok(out)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Entry Point
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Mine ability cadence for `boss` from `logs` and write draft timers to `out`
+/// ("-" for stdout). Prints a summary table unless `quiet`.
+pub fn run(
+    boss: &BossEncounterDefinition,
+    logs: &[PathBuf],
+    out: &Path,
+    quiet: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tracked: HashSet<u64> = boss
+        .timers
+        .iter()
+        .filter_map(|t| match &t.trigger {
+            TimerTrigger::AbilityCast { abilities, .. } => Some(abilities),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|s| match s {
+            AbilitySelector::Id(id) => Some(*id),
+            AbilitySelector::Name(_) => None,
+        })
+        .collect();
+
+    let mut names = HashMap::new();
+    let mut pulls = Vec::new();
+    for log in logs {
+        let found = collect_pulls(log, boss, &mut names)?;
+        eprintln!("{}: {} pull(s)", log.display(), found.len());
+        pulls.extend(found);
+    }
+    if pulls.is_empty() {
+        return Err(format!("No pulls of '{}' found in the given logs", boss.id).into());
+    }
+
+    // Deterministic order: by ability ID, then sorted by first cast for output
+    let ids: BTreeMap<i64, String> = names
+        .into_iter()
+        .map(|(id, n)| {
+            let name = if n.trim().is_empty() {
+                format!("Ability {id}")
+            } else {
+                n
+            };
+            (id, name)
+        })
+        .collect();
+    let mut cadences: Vec<AbilityCadence> = ids
+        .iter()
+        .filter_map(|(id, name)| analyze(*id, name, &pulls))
+        .collect();
+    cadences.sort_by(|a, b| a.first_cast.mean.total_cmp(&b.first_cast.mean));
+
+    if !quiet {
+        println!();
+        println!(
+            "  {:20} {:28} {:>7} {:>6} {:>12} {:>14} {:>6}",
+            "ID", "Name", "Pulls", "Casts", "First", "Interval", "Conf"
+        );
+        println!("  {}", "─".repeat(100));
+        for c in &cadences {
+            let interval = c
+                .interval
+                .map(|s| format!("{:.1}s ±{:.1}", s.median, s.stddev))
+                .unwrap_or_else(|| "-".to_string());
+            let tracked_mark = if tracked.contains(&(c.ability_id as u64)) {
+                " ✓"
+            } else {
+                ""
+            };
+            println!(
+                "  {:20} {:28} {:>7} {:>6} {:>12} {:>14} {:>5.0}%{}",
+                c.ability_id,
+                crate::truncate(&c.name, 28),
+                format!("{}/{}", c.pulls_seen, pulls.len()),
+                c.total_casts,
+                format!("{:.1}s ±{:.1}", c.first_cast.mean, c.first_cast.stddev),
+                interval,
+                c.confidence * 100.0,
+                tracked_mark
+            );
+        }
+    }
+
+    let drafted: Vec<AbilityCadence> = cadences
+        .into_iter()
+        .filter(|c| {
+            !tracked.contains(&(c.ability_id as u64))
+                && c.pulls_seen as f32 / pulls.len() as f32 >= MIN_PRESENCE
+        })
+        .collect();
+    let toml = render_drafts(boss, logs.len(), pulls.len(), &drafted)?;
+
+    if out == Path::new("-") {
+        print!("{toml}");
+    } else {
+        std::fs::write(out, toml)?;
+        eprintln!(
+            "Wrote draft timers for {} untracked abilities to {}",
+            drafted.len(),
+            out.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cast(at: f32, phase: Option<&str>, phase_start: f32) -> Cast {
+        Cast {
+            at,
+            phase: phase.map(str::to_string),
+            since_phase: at - phase_start,
+        }
+    }
+
+    fn pull(id: i64, casts: Vec<Cast>) -> Pull {
+        Pull {
+            casts: HashMap::from([(id, casts)]),
+        }
+    }
+
+    #[test]
+    fn test_regular_ability_drops_outlier_intervals() {
+        let pulls = vec![
+            pull(
+                1,
+                vec![
+                    cast(10.0, None, 0.0),
+                    cast(40.0, None, 0.0),
+                    cast(70.0, None, 0.0),
+                ],
+            ),
+            // Boss pushed to a platform: one 55s gap
+            pull(
+                1,
+                vec![
+                    cast(11.0, None, 0.0),
+                    cast(41.0, None, 0.0),
+                    cast(96.0, None, 0.0),
+                ],
+            ),
+            Pull::default(),
+        ];
+        let c = analyze(1, "Smash", &pulls).unwrap();
+
+        assert_eq!(c.pulls_seen, 2);
+        assert_eq!(c.anchor, Anchor::CombatStart);
+        assert!((c.first_cast.mean - 10.5).abs() < 0.01);
+        let interval = c.interval.unwrap();
+        assert_eq!(interval.n, 3);
+        assert_eq!(c.irregular_intervals, 1);
+        assert!((interval.median - 30.0).abs() < 0.01);
+        // Seen in 2/3 pulls, 3/4 intervals regular
+        assert!((c.confidence - 0.5).abs() < 0.01);
+
+        let timers = draft_timers("boss", &c);
+        assert_eq!(timers.len(), 2);
+        assert_eq!(timers[0].id, "boss_smash_opener");
+        assert_eq!(timers[1].duration_secs, 30.0);
+        assert!(timers[1].can_be_refreshed);
+    }
+
+    #[test]
+    fn test_phase_bound_ability_anchors_on_phase_entry() {
+        let pulls = vec![
+            pull(
+                2,
+                vec![cast(95.0, Some("p2"), 90.0), cast(115.0, Some("p2"), 90.0)],
+            ),
+            pull(
+                2,
+                vec![
+                    cast(126.0, Some("p2"), 120.0),
+                    cast(146.0, Some("p2"), 120.0),
+                ],
+            ),
+        ];
+        let c = analyze(2, "Lightning Storm", &pulls).unwrap();
+
+        assert_eq!(c.anchor, Anchor::Phase("p2".into()));
+        assert!((c.first_cast.mean - 5.5).abs() < 0.01);
+
+        let timers = draft_timers("boss", &c);
+        assert!(matches!(
+            &timers[0].trigger,
+            TimerTrigger::PhaseEntered { phase_id } if phase_id == "p2"
+        ));
+        assert_eq!(timers[1].phases, vec!["p2".to_string()]);
+
+        let toml = timer_toml(&timers[1]).unwrap();
+        assert!(toml.starts_with("[[boss.timer]]\n"));
+        assert!(
+            !toml
+                .lines()
+                .any(|l| l.starts_with('[') && !l.contains("boss.timer"))
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Lint mode: statically check definitions without a combat log.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use baras_core::boss::{LintIssue, LintSeverity, lint_boss, lint_effects, load_bosses_with_paths};
+use baras_core::effects::{DefinitionConfig, EffectDefinition};
+
+/// Lint encounter (and, without a boss filter, effect) definitions.
+/// Prints findings grouped by scope. Returns true when no errors were found.
+pub fn run(
+    encounters_dir: &Path,
+    effects_dir: &Path,
+    boss_filter: Option<&str>,
+    quiet: bool,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let use_colors = atty::is(atty::Stream::Stdout);
+    let paint = |code: &str, text: &str| {
+        if use_colors {
+            format!("\x1b[{code}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    };
+
+    let bosses = load_bosses_with_paths(encounters_dir)?;
+    let known_timer_ids: HashSet<String> = bosses
+        .iter()
+        .flat_map(|b| b.boss.timers.iter().map(|t| t.id.clone()))
+        .collect();
+
+    let mut issues: Vec<(String, LintIssue)> = Vec::new();
+    let mut checked = 0;
+    for bwp in &bosses {
+        if boss_filter.is_some_and(|id| !bwp.boss.id.eq_ignore_ascii_case(id)) {
+            continue;
+        }
+        checked += 1;
+        let file = bwp.file_path.display().to_string();
+        issues.extend(lint_boss(&bwp.boss).into_iter().map(|i| (file.clone(), i)));
+    }
+    if let Some(id) = boss_filter
+        && checked == 0
+    {
+        return Err(format!("Boss '{id}' not found in {}", encounters_dir.display()).into());
+    }
+
+    if boss_filter.is_none() {
+        let effects = load_effect_files(effects_dir)?;
+        let file = effects_dir.display().to_string();
+        issues.extend(
+            lint_effects(&effects, &known_timer_ids)
+                .into_iter()
+                .map(|i| (file.clone(), i)),
+        );
+    }
+
+    let errors = issues
+        .iter()
+        .filter(|(_, i)| i.severity == LintSeverity::Error)
+        .count();
+    let warnings = issues.len() - errors;
+
+    if !quiet {
+        let mut last_scope = None;
+        for (file, issue) in &issues {
+            if last_scope != Some(&issue.scope) {
+                println!();
+                println!("{} ({})", paint("1", &issue.scope), file);
+                last_scope = Some(&issue.scope);
+            }
+            let tag = match issue.severity {
+                LintSeverity::Error => paint("31", "error"),
+                LintSeverity::Warning => paint("33", "warning"),
+            };
+            println!("  {tag}: {}: {}", issue.item, issue.message);
+        }
+    }
+
+    println!();
+    println!(
+        "Linted {checked} boss definition(s): {} error(s), {} warning(s)",
+        errors, warnings
+    );
+    Ok(errors == 0)
+}
+
+/// Load every effect definition file in a directory (skipping the custom template).
+fn load_effect_files(dir: &Path) -> Result<Vec<EffectDefinition>, Box<dyn std::error::Error>> {
+    let mut effects = Vec::new();
+    if !dir.exists() {
+        return Ok(effects);
+    }
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "toml")
+            || path.file_name().is_some_and(|n| n == "custom.toml")
+        {
+            continue;
+        }
+        let contents = std::fs::read_to_string(&path)?;
+        let config: DefinitionConfig =
+            toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+        effects.extend(config.effects);
+    }
+    Ok(effects)
+}
@@ -2,9 +2,13 @@
 //!
 //! Replays combat logs through boss definitions and validates timer behavior:
 //! - Realtime mode (1x): Debug timer display issues
-//! - Accelerated mode: Fast CI validation with checkpoints
+//! - Accelerated mode: Fast CI validation with checkpoints (or `--record` them)
 //! - Visual mode: Display actual overlay window (requires --features visual)
+//! - Lint mode: Statically check encounter and effect definitions (no log needed)
+//! - Draft mode: Mine boss ability cadence across logs into draft timers
 
+mod cadence;
+mod lint;
 mod output;
 mod replay;
 mod verification;
@@ -35,7 +39,7 @@ use baras_core::timers::TimerManager;
 
 use crate::output::{CliOutput, OutputLevel};
 use crate::replay::{LagSimulator, VirtualClock};
-use crate::verification::{CheckpointVerifier, Expectations};
+use crate::verification::{CheckpointVerifier, ExpectationRecorder, Expectations, Observed};
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // CLI Arguments
@@ -60,18 +64,36 @@ impl Default for ReplayMode {
 #[command(about = "Validate timer definitions against combat logs")]
 #[command(version)]
 struct Args {
-    /// Path to combat log file
-    #[arg(short, long)]
-    log: PathBuf,
+    /// Path to combat log file (repeat to mine several logs with --draft-timers)
+    #[arg(short, long, required_unless_present = "lint")]
+    log: Vec<PathBuf>,
 
-    /// Boss ID to validate (e.g., "sword_squadron")
-    #[arg(short, long)]
-    boss: String,
+    /// Boss ID to validate (e.g., "sword_squadron"); optional filter in lint mode
+    #[arg(short, long, required_unless_present = "lint")]
+    boss: Option<String>,
 
     /// Path to definitions directory (defaults to bundled)
     #[arg(short, long)]
     definitions: Option<PathBuf>,
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Lint Mode
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Statically check definitions instead of replaying a log
+    #[arg(long)]
+    lint: bool,
+
+    /// Path to effect definitions directory for lint mode (defaults to bundled)
+    #[arg(long)]
+    effects: Option<PathBuf>,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Draft Mode
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Write draft timers mined from boss ability cadence to this file ("-" for stdout)
+    #[arg(long, value_name = "FILE")]
+    draft_timers: Option<PathBuf>,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Replay Mode
     // ─────────────────────────────────────────────────────────────────────────
@@ -113,6 +135,14 @@ struct Args {
     #[arg(long)]
     expect: Option<PathBuf>,
 
+    /// Record an expectations TOML file from this replay (first boss pull)
+    #[arg(long, value_name = "FILE")]
+    record: Option<PathBuf>,
+
+    /// Seconds between recorded checkpoints
+    #[arg(long, default_value_t = 15.0)]
+    record_interval: f32,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Debug
     // ─────────────────────────────────────────────────────────────────────────
@@ -173,6 +203,21 @@ struct ValidationState {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
+    if args.lint {
+        let bundled = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("core/definitions");
+        let encounters_dir = args
+            .definitions
+            .clone()
+            .unwrap_or_else(|| bundled.join("encounters"));
+        let effects_dir = args.effects.clone().unwrap_or_else(|| bundled.join("effects"));
+        let clean = lint::run(&encounters_dir, &effects_dir, args.boss.as_deref(), args.quiet)?;
+        std::process::exit(if clean { 0 } else { 1 });
+    }
+    let boss_id = args.boss.clone().expect("--boss is required outside lint mode");
+
     // Determine output level
     let output_level = if args.quiet {
         OutputLevel::Quiet
@@ -215,11 +260,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Find the requested boss
     let boss_def = bosses
         .iter()
-        .find(|b| b.id.eq_ignore_ascii_case(&args.boss))
+        .find(|b| b.id.eq_ignore_ascii_case(&boss_id))
         .ok_or_else(|| {
             format!(
                 "Boss '{}' not found. Available: {}",
-                args.boss,
+                boss_id,
                 bosses
                     .iter()
                     .map(|b| b.id.as_str())
@@ -228,6 +273,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             )
         })?;
 
+    if let Some(out) = &args.draft_timers {
+        eprintln!("Drafting timers: {} ({})", boss_def.name, boss_def.area_name);
+        return cadence::run(boss_def, &args.log, out, args.quiet);
+    }
+    let [log_path] = args.log.as_slice() else {
+        return Err("Replay takes a single --log (several are only allowed with --draft-timers)".into());
+    };
+
     eprintln!("Validating: {} ({})", boss_def.name, boss_def.area_name);
     eprintln!(
         "Mode: {} (speed: {}x)",
@@ -256,13 +309,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     } else {
         None
     };
+    let mut recorder = args
+        .record
+        .as_ref()
+        .map(|_| ExpectationRecorder::new(&boss_def.id, args.record_interval));
+    let mut observed = Observed::default();
+    // Checkpoints refer to the boss pull, not trash fought earlier in the log
+    let mut boss_engaged = false;
 
     // Build tracking state
     let mut state = ValidationState::default();
     populate_tracked_ids(&mut state, boss_def);
 
     // Parse log file with Windows-1252 encoding (SWTOR uses this for non-ASCII characters)
-    let mut file = File::open(&args.log)?;
+    let mut file = File::open(log_path)?;
     let mut bytes = Vec::new();
     file.read_to_end(&mut bytes)?;
     let (content, _, _) = WINDOWS_1252.decode(&bytes);
@@ -316,6 +376,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut event_count = 0;
     let mut local_player_id: i64 = 0;
     let mut kill_target_death_time: Option<NaiveDateTime> = None;
+    let mut combat_ended_this_event: bool;
 
     for (line_num, line) in lines.iter().enumerate() {
         let Some(event) = parser.parse_line(line_num as u64, line) else {
@@ -357,6 +418,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // Process event
         let (signals, _processed_event, _) = processor.process_event(event.clone(), &mut cache);
+        combat_ended_this_event = false;
 
         // Detect local player
         if local_player_id == 0 {
@@ -413,10 +475,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     timer.duration.as_secs_f32(),
                     &timer.definition_id,
                 );
-
-                if let Some(ref mut v) = verifier {
-                    v.record_timer_start(&timer.definition_id, combat_time_secs);
-                }
+                observed.record_timer_start(&timer.definition_id, combat_time_secs);
             }
         }
 
@@ -453,10 +512,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Process alerts
         for alert in timer_manager.take_fired_alerts() {
             cli.alert(event.timestamp, &alert.name, &alert.text);
-
-            if let Some(ref mut v) = verifier {
-                v.record_alert(&alert.id);
-            }
+            observed.record_alert(&alert.id);
         }
 
         // Process counter triggers from timer events (expires and starts)
@@ -475,6 +531,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             } = signal
             {
                 cli.counter_change(*timestamp, counter_id, *old_value, *new_value);
+                observed.counters.insert(counter_id.clone(), *new_value);
             }
         }
 
@@ -494,6 +551,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     entity.health.0 as i64,
                     entity.health.1 as i64,
                 );
+                observed.record_boss_hp(&name, entity.health.0 as i64, entity.health.1 as i64);
             }
         }
 
@@ -509,6 +567,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 GameSignal::CombatStarted { timestamp, .. } => {
                     state.combat_start = Some(*timestamp);
                     cli.combat_start(*timestamp);
+                    observed.reset_encounter();
+                    boss_engaged = false;
                     // Reset challenge tracker for new encounter
                     challenge_tracker.start(
                         boss_def.challenges.clone(),
@@ -534,6 +594,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     challenge_tracker.set_duration(duration);
                     let challenge_snapshot = challenge_tracker.snapshot();
                     cli.combat_end(*timestamp, duration, &challenge_snapshot);
+                    combat_ended_this_event = true;
                 }
                 GameSignal::BossEncounterDetected {
                     definition_id,
@@ -543,6 +604,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     ..
                 } => {
                     cli.boss_detected(*timestamp, boss_name);
+                    boss_engaged = true;
+                    if let Some(ref mut r) = recorder {
+                        r.boss_engaged(&observed);
+                    }
 
                     // CRITICAL: Set active boss for timer context
                     if let Some(enc) = cache.current_encounter_mut() {
@@ -562,6 +627,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     ..
                 } => {
                     cli.phase_change(*timestamp, old_phase.as_deref(), new_phase);
+                    observed.phase = Some(new_phase.clone());
                     challenge_ctx.current_phase = Some(new_phase.clone());
                     challenge_tracker.set_phase(new_phase, *timestamp);
                 }
@@ -579,6 +645,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     ..
                 } => {
                     cli.counter_change(*timestamp, counter_id, *old_value, *new_value);
+                    observed.counters.insert(counter_id.clone(), *new_value);
                     challenge_ctx
                         .counters
                         .insert(counter_id.clone(), *new_value);
@@ -660,15 +727,34 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             );
         }
 
-        // Check verification checkpoints
-        if let Some(ref mut v) = verifier {
+        // Check (or record) verification checkpoints
+        let checkpoint_due = boss_engaged
+            && (combat_ended_this_event
+                || verifier.as_ref().is_some_and(|v| v.is_due(combat_time_secs))
+                || recorder.as_ref().is_some_and(|r| r.is_due(combat_time_secs)));
+        if checkpoint_due {
+            observed.challenges = challenge_tracker
+                .snapshot()
+                .into_iter()
+                .map(|cv| (cv.id, cv.value))
+                .collect();
             let active_timers: Vec<(String, f32)> = timer_manager
                 .active_timers()
                 .iter()
                 .map(|t| (t.definition_id.clone(), t.remaining_secs(event.timestamp)))
                 .collect();
 
-            if let Some(result) = v.check_time(combat_time_secs, &active_timers) {
+            if let Some(ref mut r) = recorder {
+                r.capture_due(combat_time_secs, &active_timers, &observed);
+                if combat_ended_this_event {
+                    r.combat_ended(combat_time_secs, &active_timers, &observed);
+                }
+            }
+
+            while let Some(result) = verifier
+                .as_mut()
+                .and_then(|v| v.check_time(combat_time_secs, &active_timers, &observed))
+            {
                 if result.passed {
                     eprintln!("  [PASS] Checkpoint at {:.1}s", result.at_secs);
                 } else {
@@ -705,6 +791,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     cli.print_summary(checkpoint_result);
 
+    // Write recorded expectations
+    if let (Some(path), Some(r)) = (&args.record, recorder) {
+        let count = r.checkpoint_count();
+        let log_name = log_path.file_name().unwrap_or_default().to_string_lossy();
+        r.finish(Some(format!("Recorded from {}", log_name)))
+            .save(path)?;
+        eprintln!("Recorded {} checkpoint(s) to {}", count, path.display());
+    }
+
     // Print detailed report (unless quiet)
     if !args.quiet {
         print_detailed_report(
@@ -908,7 +1003,12 @@ fn print_detailed_report(
     challenges: &ChallengeTracker,
     player_names: &HashMap<i64, String>,
 ) {
-    let log_name = args.log.file_name().unwrap_or_default().to_string_lossy();
+    let log_name = args
+        .log
+        .first()
+        .and_then(|l| l.file_name())
+        .unwrap_or_default()
+        .to_string_lossy();
 
     println!();
     println!("══════════════════════════════════════════════════════════════════════");
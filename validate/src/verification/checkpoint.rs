@@ -4,6 +4,7 @@
 //! and verifying that actual timer behavior matches expectations.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 /// Expected timer state at a checkpoint
@@ -14,30 +15,46 @@ pub struct ExpectedTimer {
 
     /// Expected remaining time range [min, max] in seconds
     /// If None, timer should be active but remaining time is not checked
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub remaining_secs: Option<(f32, f32)>,
 }
 
 /// A checkpoint defines expected timer state at a specific combat time
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Checkpoint {
     /// Combat time in seconds when to check
     pub at_secs: f32,
 
     /// Timers that should be active at this time
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub active_timers: Vec<ExpectedTimer>,
 
     /// Timers that should have fired by this time
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub timers_fired: Vec<String>,
 
     /// Alerts that should have fired by this time
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub alerts_fired: Vec<String>,
 
+    /// Phase that should be active at this time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+
+    /// Expected counter values (counter ID -> value)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub counters: BTreeMap<String, u32>,
+
+    /// Expected challenge totals (challenge ID -> value)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub challenges: BTreeMap<String, i64>,
+
+    /// Expected boss HP percent range [min, max] (entity name -> range)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub boss_hp: BTreeMap<String, (f32, f32)>,
+
     /// Optional description for debugging
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
@@ -58,7 +75,7 @@ pub struct ExpectationsMeta {
     pub boss_id: String,
 
     /// Optional description
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Tolerance in seconds for timing comparisons (default 0.5)
@@ -77,6 +94,86 @@ impl Expectations {
         let expectations: Expectations = toml::from_str(&content)?;
         Ok(expectations)
     }
+
+    /// Write expectations to a TOML file
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        // f32 fields widen to noisy f64 digits; round them for reviewable diffs
+        let mut value = toml::Value::try_from(self)?;
+        round_floats(&mut value);
+        // Tables serialize alphabetically; keep [meta] at the top of the file
+        let mut body = value.as_table().cloned().unwrap_or_default();
+        let mut head = toml::Table::new();
+        if let Some(meta) = body.remove("meta") {
+            head.insert("meta".to_string(), meta);
+        }
+        let content = format!(
+            "{}\n{}",
+            toml::to_string_pretty(&head)?,
+            toml::to_string_pretty(&body)?
+        );
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+fn round_floats(value: &mut toml::Value) {
+    match value {
+        toml::Value::Float(f) => *f = (*f * 10_000.0).round() / 10_000.0,
+        toml::Value::Array(items) => items.iter_mut().for_each(round_floats),
+        toml::Value::Table(table) => table.iter_mut().for_each(|(_, v)| round_floats(v)),
+        _ => {}
+    }
+}
+
+/// Replay state observed so far, shared by verification and recording
+#[derive(Debug, Default)]
+pub struct Observed {
+    /// Timers that have been started (timer_id -> start_time_secs)
+    pub timers_started: Vec<(String, f32)>,
+
+    /// Alerts that have fired (alert_id)
+    pub alerts_fired: Vec<String>,
+
+    /// Current phase of the active encounter
+    pub phase: Option<String>,
+
+    /// Current counter values
+    pub counters: HashMap<String, u32>,
+
+    /// Challenge totals (only refreshed when a checkpoint is due)
+    pub challenges: HashMap<String, i64>,
+
+    /// Boss HP percent by entity name
+    pub boss_hp: HashMap<String, f32>,
+}
+
+impl Observed {
+    /// Record that a timer started
+    pub fn record_timer_start(&mut self, timer_id: &str, combat_time_secs: f32) {
+        self.timers_started
+            .push((timer_id.to_string(), combat_time_secs));
+    }
+
+    /// Record that an alert fired
+    pub fn record_alert(&mut self, alert_id: &str) {
+        self.alerts_fired.push(alert_id.to_string());
+    }
+
+    /// Record boss HP from current/max health
+    pub fn record_boss_hp(&mut self, name: &str, current: i64, max: i64) {
+        if max > 0 {
+            self.boss_hp
+                .insert(name.to_string(), current as f32 / max as f32 * 100.0);
+        }
+    }
+
+    /// Clear per-encounter state on a new pull (fired timers/alerts are cumulative)
+    pub fn reset_encounter(&mut self) {
+        self.phase = None;
+        self.counters.clear();
+        self.challenges.clear();
+        self.boss_hp.clear();
+    }
 }
 
 /// Result of verifying a single checkpoint
@@ -100,12 +197,6 @@ pub struct CheckpointVerifier {
     expectations: Expectations,
     current_checkpoint_idx: usize,
     results: Vec<CheckpointResult>,
-
-    /// Timers that have been started (timer_id -> start_time_secs)
-    timers_started: Vec<(String, f32)>,
-
-    /// Alerts that have fired (alert_id)
-    alerts_fired: Vec<String>,
 }
 
 impl CheckpointVerifier {
@@ -114,20 +205,16 @@ impl CheckpointVerifier {
             expectations,
             current_checkpoint_idx: 0,
             results: Vec::new(),
-            timers_started: Vec::new(),
-            alerts_fired: Vec::new(),
         }
     }
 
-    /// Record that a timer started
-    pub fn record_timer_start(&mut self, timer_id: &str, combat_time_secs: f32) {
-        self.timers_started
-            .push((timer_id.to_string(), combat_time_secs));
-    }
-
-    /// Record that an alert fired
-    pub fn record_alert(&mut self, alert_id: &str) {
-        self.alerts_fired.push(alert_id.to_string());
+    /// Whether the next checkpoint is reached at the given combat time
+    /// (lets the caller refresh expensive state like challenge totals first)
+    pub fn is_due(&self, combat_time_secs: f32) -> bool {
+        self.expectations
+            .checkpoints
+            .get(self.current_checkpoint_idx)
+            .is_some_and(|c| combat_time_secs >= c.at_secs - self.expectations.meta.tolerance_secs)
     }
 
     /// Check if there's a checkpoint at or before the given combat time
@@ -136,19 +223,15 @@ impl CheckpointVerifier {
         &mut self,
         combat_time_secs: f32,
         active_timers: &[(String, f32)],
+        observed: &Observed,
     ) -> Option<CheckpointResult> {
-        if self.current_checkpoint_idx >= self.expectations.checkpoints.len() {
+        if !self.is_due(combat_time_secs) {
             return None;
         }
 
         let checkpoint = &self.expectations.checkpoints[self.current_checkpoint_idx];
         let tolerance = self.expectations.meta.tolerance_secs;
 
-        // Check if we've reached this checkpoint
-        if combat_time_secs < checkpoint.at_secs - tolerance {
-            return None;
-        }
-
         // We've reached the checkpoint - verify it
         let mut failures = Vec::new();
 
@@ -178,18 +261,64 @@ impl CheckpointVerifier {
 
         // Check timers that should have fired
         for timer_id in &checkpoint.timers_fired {
-            if !self.timers_started.iter().any(|(id, _)| id == timer_id) {
+            if !observed.timers_started.iter().any(|(id, _)| id == timer_id) {
                 failures.push(format!("Timer '{}' should have fired but hasn't", timer_id));
             }
         }
 
         // Check alerts that should have fired
         for alert_id in &checkpoint.alerts_fired {
-            if !self.alerts_fired.contains(alert_id) {
+            if !observed.alerts_fired.contains(alert_id) {
                 failures.push(format!("Alert '{}' should have fired but hasn't", alert_id));
             }
         }
 
+        // Check current phase
+        if let Some(expected) = &checkpoint.phase
+            && observed.phase.as_ref() != Some(expected)
+        {
+            failures.push(format!(
+                "Phase should be '{}' but is '{}'",
+                expected,
+                observed.phase.as_deref().unwrap_or("none")
+            ));
+        }
+
+        // Check counter values (unset counters read as 0)
+        for (counter_id, expected) in &checkpoint.counters {
+            let actual = observed.counters.get(counter_id).copied().unwrap_or(0);
+            if actual != *expected {
+                failures.push(format!(
+                    "Counter '{}' is {} but expected {}",
+                    counter_id, actual, expected
+                ));
+            }
+        }
+
+        // Check challenge totals
+        for (challenge_id, expected) in &checkpoint.challenges {
+            match observed.challenges.get(challenge_id) {
+                Some(actual) if actual == expected => {}
+                Some(actual) => failures.push(format!(
+                    "Challenge '{}' total is {} but expected {}",
+                    challenge_id, actual, expected
+                )),
+                None => failures.push(format!("Challenge '{}' has no value", challenge_id)),
+            }
+        }
+
+        // Check boss HP ranges
+        for (name, (min, max)) in &checkpoint.boss_hp {
+            match observed.boss_hp.get(name) {
+                Some(pct) if *pct >= *min && *pct <= *max => {}
+                Some(pct) => failures.push(format!(
+                    "Boss '{}' HP {:.1}% not in expected range [{:.1}, {:.1}]",
+                    name, pct, min, max
+                )),
+                None => failures.push(format!("Boss '{}' HP has not been seen", name)),
+            }
+        }
+
         let result = CheckpointResult {
             at_secs: checkpoint.at_secs,
             passed: failures.is_empty(),
@@ -234,6 +363,7 @@ mod tests {
                 timers_fired: vec!["test_timer".to_string()],
                 alerts_fired: vec![],
                 description: Some("First checkpoint".to_string()),
+                ..Default::default()
             }],
         }
     }
@@ -242,12 +372,13 @@ mod tests {
     fn test_checkpoint_pass() {
         let exp = sample_expectations();
         let mut verifier = CheckpointVerifier::new(exp);
+        let mut observed = Observed::default();
 
-        verifier.record_timer_start("test_timer", 5.0);
+        observed.record_timer_start("test_timer", 5.0);
 
         // Active timers: test_timer with 11s remaining (started at 5s, now at 15s, duration unknown)
         let active = vec![("test_timer".to_string(), 11.0)];
-        let result = verifier.check_time(15.0, &active);
+        let result = verifier.check_time(15.0, &active, &observed);
 
         assert!(result.is_some());
         let r = result.unwrap();
@@ -258,12 +389,13 @@ mod tests {
     fn test_checkpoint_fail_missing_timer() {
         let exp = sample_expectations();
         let mut verifier = CheckpointVerifier::new(exp);
+        let mut observed = Observed::default();
 
-        verifier.record_timer_start("test_timer", 5.0);
+        observed.record_timer_start("test_timer", 5.0);
 
         // No active timers
         let active: Vec<(String, f32)> = vec![];
-        let result = verifier.check_time(15.0, &active);
+        let result = verifier.check_time(15.0, &active, &observed);
 
         assert!(result.is_some());
         let r = result.unwrap();
@@ -275,12 +407,13 @@ mod tests {
     fn test_checkpoint_fail_wrong_remaining() {
         let exp = sample_expectations();
         let mut verifier = CheckpointVerifier::new(exp);
+        let mut observed = Observed::default();
 
-        verifier.record_timer_start("test_timer", 5.0);
+        observed.record_timer_start("test_timer", 5.0);
 
         // Timer active but with wrong remaining time
         let active = vec![("test_timer".to_string(), 5.0)]; // 5s remaining, expected 10-12s
-        let result = verifier.check_time(15.0, &active);
+        let result = verifier.check_time(15.0, &active, &observed);
 
         assert!(result.is_some());
         let r = result.unwrap();
@@ -291,4 +424,33 @@ mod tests {
                 .any(|f| f.contains("not in expected range"))
         );
     }
+
+    #[test]
+    fn test_checkpoint_state_assertions() {
+        let mut exp = sample_expectations();
+        exp.checkpoints[0] = Checkpoint {
+            at_secs: 15.0,
+            phase: Some("burn".to_string()),
+            counters: BTreeMap::from([("adds".to_string(), 2), ("unset".to_string(), 0)]),
+            challenges: BTreeMap::from([("boss_damage".to_string(), 1000)]),
+            boss_hp: BTreeMap::from([("Boss".to_string(), (40.0, 50.0))]),
+            ..Default::default()
+        };
+        let mut verifier = CheckpointVerifier::new(exp);
+        let mut observed = Observed::default();
+        assert!(!verifier.is_due(10.0));
+        assert!(verifier.is_due(14.6));
+
+        observed.phase = Some("opener".to_string());
+        observed.counters.insert("adds".to_string(), 3);
+        observed.challenges.insert("boss_damage".to_string(), 1000);
+        observed.record_boss_hp("Boss", 30, 100);
+
+        let r = verifier.check_time(15.0, &[], &observed).unwrap();
+        assert!(!r.passed);
+        assert_eq!(r.failures.len(), 3, "Failures: {:?}", r.failures);
+        assert!(r.failures.iter().any(|f| f.contains("Phase should be 'burn'")));
+        assert!(r.failures.iter().any(|f| f.contains("Counter 'adds' is 3")));
+        assert!(r.failures.iter().any(|f| f.contains("Boss 'Boss' HP 30.0%")));
+    }
 }
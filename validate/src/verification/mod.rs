@@ -1,7 +1,10 @@
-//! Checkpoint verification for timer validation
-//!
-//! Defines expected timer sequences and verifies them against actual behavior.
-
-pub mod checkpoint;
-
-pub use checkpoint::{CheckpointVerifier, Expectations};
+//! Checkpoint verification for timer validation
+//!
+//! Defines expected timer sequences and verifies them against actual behavior,
+//! or records them from a reference replay.
+
+pub mod checkpoint;
+pub mod recorder;
+
+pub use checkpoint::{CheckpointVerifier, Expectations, Observed};
+pub use recorder::ExpectationRecorder;
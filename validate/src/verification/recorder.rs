@@ -0,0 +1,210 @@
+//! Golden expectation recording
+//!
+//! Captures checkpoints from a reference replay so the same log can later be
+//! verified with `--expect`. Checkpoints are taken at fixed combat-time marks
+//! using the same due rule as [`CheckpointVerifier`](super::CheckpointVerifier),
+//! so a recording always verifies against the log it came from.
+
+use super::checkpoint::{Checkpoint, Expectations, ExpectationsMeta, ExpectedTimer, Observed};
+
+/// Records checkpoints for the first boss pull of a replay
+#[derive(Debug)]
+pub struct ExpectationRecorder {
+    boss_id: String,
+    interval_secs: f32,
+    tolerance_secs: f32,
+    next_at: f32,
+    checkpoints: Vec<Checkpoint>,
+
+    /// Timers/alerts already reported by an earlier checkpoint
+    timers_seen: usize,
+    alerts_seen: usize,
+
+    recording: bool,
+    finished: bool,
+}
+
+impl ExpectationRecorder {
+    pub fn new(boss_id: &str, interval_secs: f32) -> Self {
+        Self {
+            boss_id: boss_id.to_string(),
+            interval_secs,
+            tolerance_secs: 0.5,
+            next_at: interval_secs,
+            checkpoints: Vec::new(),
+            timers_seen: 0,
+            alerts_seen: 0,
+            recording: false,
+            finished: false,
+        }
+    }
+
+    /// The boss was engaged: start recording this pull (only the first one)
+    pub fn boss_engaged(&mut self, observed: &Observed) {
+        if self.finished || self.recording {
+            return;
+        }
+        self.recording = true;
+        self.next_at = self.interval_secs;
+        self.timers_seen = observed.timers_started.len();
+        self.alerts_seen = observed.alerts_fired.len();
+    }
+
+    /// Whether a checkpoint will be captured at this combat time
+    pub fn is_due(&self, combat_time_secs: f32) -> bool {
+        self.recording && combat_time_secs >= self.next_at - self.tolerance_secs
+    }
+
+    /// Capture every interval checkpoint that is due at this combat time
+    pub fn capture_due(
+        &mut self,
+        combat_time_secs: f32,
+        active_timers: &[(String, f32)],
+        observed: &Observed,
+    ) {
+        while self.is_due(combat_time_secs) {
+            let at_secs = self.next_at;
+            self.capture(at_secs, active_timers, observed);
+            self.next_at += self.interval_secs;
+        }
+    }
+
+    /// Combat ended: record a final checkpoint and stop recording
+    pub fn combat_ended(
+        &mut self,
+        combat_time_secs: f32,
+        active_timers: &[(String, f32)],
+        observed: &Observed,
+    ) {
+        if !self.recording {
+            return;
+        }
+        // Due exactly at this event: half a millisecond before it, minus tolerance
+        let at_secs = combat_time_secs + self.tolerance_secs - 0.0005;
+        self.capture(at_secs, active_timers, observed);
+        self.recording = false;
+        self.finished = true;
+    }
+
+    fn capture(&mut self, at_secs: f32, active_timers: &[(String, f32)], observed: &Observed) {
+        let round = |v: f32| (v * 10.0).round() / 10.0;
+
+        let mut active: Vec<ExpectedTimer> = active_timers
+            .iter()
+            .map(|(id, remaining)| ExpectedTimer {
+                id: id.clone(),
+                remaining_secs: Some((round(*remaining), round(*remaining))),
+            })
+            .collect();
+        active.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut timers_fired: Vec<String> = observed.timers_started[self.timers_seen..]
+            .iter()
+            .map(|(id, _)| id.clone())
+            .collect();
+        timers_fired.sort();
+        timers_fired.dedup();
+
+        let mut alerts_fired = observed.alerts_fired[self.alerts_seen..].to_vec();
+        alerts_fired.sort();
+        alerts_fired.dedup();
+
+        self.timers_seen = observed.timers_started.len();
+        self.alerts_seen = observed.alerts_fired.len();
+
+        self.checkpoints.push(Checkpoint {
+            at_secs,
+            active_timers: active,
+            timers_fired,
+            alerts_fired,
+            phase: observed.phase.clone(),
+            counters: observed
+                .counters
+                .iter()
+                .map(|(k, v)| (k.clone(), *v))
+                .collect(),
+            challenges: observed
+                .challenges
+                .iter()
+                .map(|(k, v)| (k.clone(), *v))
+                .collect(),
+            boss_hp: observed
+                .boss_hp
+                .iter()
+                .map(|(k, pct)| (k.clone(), (pct - 0.1, pct + 0.1)))
+                .collect(),
+            description: None,
+        });
+    }
+
+    /// Number of checkpoints recorded so far
+    pub fn checkpoint_count(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Build the expectations file
+    pub fn finish(self, description: Option<String>) -> Expectations {
+        Expectations {
+            meta: ExpectationsMeta {
+                boss_id: self.boss_id,
+                description,
+                tolerance_secs: self.tolerance_secs,
+            },
+            checkpoints: self.checkpoints,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::CheckpointVerifier;
+    use super::*;
+
+    #[test]
+    fn test_recording_verifies_against_same_replay() {
+        // (combat time, active timers, phase) per event
+        type Event<'a> = (f32, Vec<(String, f32)>, &'a str);
+        let events: Vec<Event> = vec![
+            (0.2, vec![], "opener"),
+            (9.7, vec![("smash".to_string(), 12.3)], "opener"),
+            (14.4, vec![("smash".to_string(), 7.6)], "opener"),
+            (14.6, vec![("smash".to_string(), 7.4)], "burn"),
+            (21.0, vec![], "burn"),
+        ];
+
+        let mut observed = Observed::default();
+        let mut recorder = ExpectationRecorder::new("test_boss", 10.0);
+        recorder.boss_engaged(&observed);
+        for (i, (t, active, phase)) in events.iter().enumerate() {
+            observed.phase = Some(phase.to_string());
+            if i == 1 {
+                observed.record_timer_start("smash", *t);
+                observed.counters.insert("adds".to_string(), 1);
+            }
+            recorder.capture_due(*t, active, &observed);
+        }
+        recorder.combat_ended(21.0, &[], &observed);
+
+        let expectations = recorder.finish(None);
+        assert_eq!(expectations.checkpoints.len(), 3);
+        assert_eq!(expectations.checkpoints[0].at_secs, 10.0);
+        assert_eq!(expectations.checkpoints[0].timers_fired, vec!["smash"]);
+        assert_eq!(expectations.checkpoints[1].phase.as_deref(), Some("burn"));
+        assert!(expectations.checkpoints[1].timers_fired.is_empty());
+
+        // Replaying the same events through the verifier passes every checkpoint
+        let mut verifier = CheckpointVerifier::new(expectations);
+        let mut observed = Observed::default();
+        for (i, (t, active, phase)) in events.iter().enumerate() {
+            observed.phase = Some(phase.to_string());
+            if i == 1 {
+                observed.record_timer_start("smash", *t);
+                observed.counters.insert("adds".to_string(), 1);
+            }
+            while let Some(r) = verifier.check_time(*t, active, &observed) {
+                assert!(r.passed, "Failures at {}: {:?}", r.at_secs, r.failures);
+            }
+        }
+        assert_eq!(verifier.finalize().checkpoints_passed, 3);
+    }
+}